@@ -77,11 +77,13 @@ use crate::simv::SimvCommandConfigurator;
 pub mod trace_observer;
 use crate::trace_observer::{RocketExecTrace, BoomExecTrace, CVA6ExecTrace};
 
-use libpresifuzz_mutators::riscv_isa::riscv_mutations;
+use libpresifuzz_mutators::memory::{MemoryAccessConf, MemoryRegion};
+use libpresifuzz_mutators::riscv_isa::riscv_mutations_with_memory;
+use libpresifuzz_riscv::profile::CpuProfile;
 use libpresifuzz_mutators::scheduled::StdISAScheduledMutator;
+use libpresifuzz_stages::mutation_stats::MutationStatsStage;
 
 use libpresifuzz_ec::llmp::Launcher;
-use libpresifuzz_stages::sync::SyncFromDiskStage;
 use libpresifuzz_feedbacks::transferred::TransferredFeedback;
 
 #[derive(Debug)]
@@ -136,6 +138,15 @@ pub fn fuzz() {
     let max_testcase_size: usize = config["fuzzer"]["max_testcase_size"]
         .as_u64()
         .unwrap_or(1024).try_into().unwrap();
+
+    // the instructions the core implements, all known instructions without fuzzer.isa
+    let profile = CpuProfile::from_config(&config).expect("Invalid fuzzer.isa in config.yml");
+
+    // legal memory for the generated loads and stores, see fuzzer.memory_regions
+    let memory = MemoryAccessConf::from_config(&config).expect("Invalid fuzzer.memory_regions in config.yml");
+
+    // adapt the mutation probabilities to their successes every fuzzer.adaptive_period executions
+    let adaptive_period = config["fuzzer"]["adaptive_period"].as_u64();
     
     // allocate the shared memory provider for later use
     #[cfg(target_vendor = "apple")]
@@ -280,8 +291,19 @@ pub fn fuzz() {
         // Simle FIFO scheduler
         let scheduler = QueueScheduler::new();
 
+        // without fuzzer.memory_regions, the writable sections of the template
+        let mut memory = memory.clone();
+        if memory.regions.is_empty() {
+            memory.regions = MemoryRegion::from_elf("iram.elf").unwrap_or_default();
+        }
+
         // RISCV ISA mutator
-        let mutator = StdISAScheduledMutator::with_max_stack_pow(riscv_mutations(), 8);
+        let mutator = StdISAScheduledMutator::with_max_stack_pow(riscv_mutations_with_memory(&profile, &memory), 8)
+            .with_profile(profile.clone());
+        let mutator = match adaptive_period {
+            Some(period) => mutator.with_adaptive_schedule(period),
+            None => mutator,
+        };
 
         // Finally, instantiate the fuzzer
         let mut fuzzer = StdFuzzer::new(scheduler, feedback, objective);
@@ -295,9 +317,9 @@ pub fn fuzz() {
 
         // load initial inputs if any seeds provided
         state.load_initial_inputs(&mut fuzzer, &mut executor, &mut mgr, &[corpus_dir.clone()]).unwrap();
-        // Instantiate a mutational stage that will apply mutations to the selected testcase
-        let sync_dir = PathBuf::from(sync_dir.to_string());
-        let mut stages = tuple_list!(SyncFromDiskStage::new(sync_dir), StdMutationalStage::with_max_iterations(mutator, 1));
+        // Instantiate a mutational stage that will apply mutations to the selected testcase.
+        // Testcases of the other clients are imported by the event manager.
+        let mut stages = tuple_list!(StdMutationalStage::with_max_iterations(mutator, 1), MutationStatsStage::default());
 
         fuzzer.fuzz_loop(&mut stages, &mut executor, &mut state, &mut mgr)
             .expect("Error in fuzzing loop");
@@ -39,7 +39,7 @@ use libafl_bolts::{
     current_nanos,
     rands::StdRand,
     shmem::{ShMem, ShMemProvider},
-    tuples::tuple_list,
+    tuples::{tuple_list, Merge},
     AsMutSlice,
 };
 #[cfg(feature = "std")]
@@ -64,13 +64,19 @@ use libpresifuzz_observers::verdi_xml_observer::VerdiXMLMapObserver;
 pub mod simv;
 use crate::simv::SimvCommandConfigurator;
 
-use libpresifuzz_mutators::riscv_isa::riscv_mutations;
+use libpresifuzz_mutators::memory::{MemoryAccessConf, MemoryRegion};
+use libpresifuzz_mutators::riscv_isa::riscv_mutations_with_memory;
+use libpresifuzz_riscv::profile::CpuProfile;
 use libpresifuzz_mutators::scheduled::StdISAScheduledMutator;
+use libpresifuzz_mutators::template::{ProgramTemplate, TemplateBlockMutator, TemplateGenerator};
+use libpresifuzz_mutators::Weighted;
+use libpresifuzz_stages::mutation_stats::MutationStatsStage;
 
 use libpresifuzz_ec::llmp::Launcher;
 use libpresifuzz_ec::manager::*;
 use libpresifuzz_feedbacks::transferred::TransferredFeedback;
-use libpresifuzz_stages::sync::SyncFromDiskStage;
+use libpresifuzz_stages::export::CorpusExportStage;
+use libpresifuzz_riscv::dasm::objdump_dasm::ObjdumpDasm;
 
 mod differential;
 mod differential_feedback;
@@ -168,13 +174,33 @@ pub fn fuzz() {
         .try_into()
         .unwrap();
 
+    // the instructions the core implements, all known instructions without fuzzer.isa
+    let profile = CpuProfile::from_config(&config).expect("Invalid fuzzer.isa in config.yml");
+
+    // legal memory for the generated loads and stores, see fuzzer.memory_regions
+    let memory = MemoryAccessConf::from_config(&config).expect("Invalid fuzzer.memory_regions in config.yml");
+
+    // structured programs for the seeds and block regeneration, see the generator section
+    let template = ProgramTemplate::from_config(&config).expect("Invalid generator in config.yml");
+    let generated_seeds = config["generator"]["seeds"].as_u64().unwrap_or(0) as usize;
+
+    // adapt the mutation probabilities to their successes every fuzzer.adaptive_period executions
+    let adaptive_period = config["fuzzer"]["adaptive_period"].as_u64();
+
+    // spawn one client per core, otherwise clients are started by hand
+    let cores = config["fuzzer"]["cores"]
+        .as_str()
+        .map(|cores| Cores::from_cmdline(cores).expect("Invalid fuzzer.cores in config.yml"));
+
     let mon = MultiMonitor::new(|s| println!("{s}"));
 
     let sync_dir = format!("{}/sync/", std::env::current_dir().unwrap().display());
 
     let corpus_dir = format!("{}/seeds", env::current_dir().unwrap().display());
 
-    let mut run_client = |_state: Option<_>, mut mgr, _core_id| {
+    let export_dir = format!("{}/export", env::current_dir().unwrap().display());
+
+    let mut run_client = |state: Option<_>, mut mgr, core_id| {
         // get a unique temp-dir name
         let tmp_dir = WorkDir::new("presifuzz_").expect("Unable to create temporary directory");
         let workdir = tmp_dir.path().as_os_str().to_str().unwrap().to_owned();
@@ -192,6 +218,13 @@ pub fn fuzz() {
 
         std::env::set_current_dir(&workdir).expect("Unable to change into {dir}");
 
+        // Every new corpus entry or objective is exported as a reproducer: elf, listing, simv command
+        let export_dir = PathBuf::from(format!("{}/client_{}", export_dir, core_id));
+        let disassembler = ObjdumpDasm::new("iram.elf").expect("Unable to load iram.elf template");
+        let export_stage = CorpusExportStage::new(export_dir, "iram.elf", &simv.command_line())
+            .expect("Unable to load iram.elf template")
+            .with_disassembler(Box::new(disassembler));
+
         create_verdi_observer_and_feedback!(
             verdi_observer_condition,
             verdi_feedback_condition,
@@ -230,22 +263,45 @@ pub fn fuzz() {
 
         let mut objective = ();
 
-        // Instantiate State with feedback, objective, in/out corpus
-        let mut state = StdState::new(
-            StdRand::with_seed(current_nanos()),
-            OnDiskCorpus::<BytesInput>::new(&PathBuf::from("./corpus")).unwrap(),
-            InMemoryCorpus::new(),
-            &mut feedback,
-            &mut objective,
-        )
-        .unwrap();
+        // Instantiate State with feedback, objective, in/out corpus, unless the client restarted
+        let mut state = state.unwrap_or_else(|| {
+            StdState::new(
+                StdRand::with_seed(current_nanos()),
+                OnDiskCorpus::<BytesInput>::new(&PathBuf::from("./corpus")).unwrap(),
+                InMemoryCorpus::new(),
+                &mut feedback,
+                &mut objective,
+            )
+            .unwrap()
+        });
         state.set_max_size(max_testcase_size);
 
         // Simle FIFO scheduler
         let scheduler = QueueScheduler::new();
 
+        // without fuzzer.memory_regions, the writable sections of the template
+        let mut memory = memory.clone();
+        if memory.regions.is_empty() {
+            memory.regions = MemoryRegion::from_elf("iram.elf").unwrap_or_default();
+        }
+
         // RISCV ISA mutator
-        let mutator = StdISAScheduledMutator::new(riscv_mutations());
+        let generator = TemplateGenerator::with_profile(template.clone(), max_testcase_size, profile.clone())
+            .with_memory(memory.clone());
+        let mutations = tuple_list!(Weighted::new(
+            TemplateBlockMutator::new(
+                TemplateGenerator::with_profile(template.clone(), max_testcase_size, profile.clone())
+                    .with_memory(memory.clone())
+            ),
+            2.0
+        ))
+        .merge(riscv_mutations_with_memory(&profile, &memory));
+        let mutator = StdISAScheduledMutator::new(mutations)
+            .with_profile(profile.clone());
+        let mutator = match adaptive_period {
+            Some(period) => mutator.with_adaptive_schedule(period),
+            None => mutator,
+        };
 
         // Finally, instantiate the fuzzer
         let mut fuzzer = StdFuzzer::new(scheduler, feedback, objective);
@@ -259,16 +315,26 @@ pub fn fuzz() {
 
         let corpus_dir = PathBuf::from(corpus_dir.to_string());
 
-        // load initial inputs if any seeds provided
-        state
-            .load_initial_inputs(&mut fuzzer, &mut executor, &mut mgr, &[corpus_dir.clone()])
-            .unwrap();
-
-        // Instantiate a mutational stage that will apply mutations to the selected testcase
-        let sync_dir = PathBuf::from(sync_dir.to_string());
+        // load initial inputs if any seeds provided, a restored state already has its corpus
+        if state.must_load_initial_inputs() {
+            state
+                .load_initial_inputs(&mut fuzzer, &mut executor, &mut mgr, &[corpus_dir.clone()])
+                .unwrap();
+
+            if generated_seeds > 0 {
+                let mut generator = generator;
+                state
+                    .generate_initial_inputs(&mut fuzzer, &mut executor, &mut generator, &mut mgr, generated_seeds)
+                    .unwrap();
+            }
+        }
+
+        // Instantiate a mutational stage that will apply mutations to the selected testcase.
+        // Testcases of the other clients are imported by the event manager.
         let mut stages = tuple_list!(
-            SyncFromDiskStage::new(sync_dir),
-            StdMutationalStage::new(mutator)
+            StdMutationalStage::new(mutator),
+            MutationStatsStage::default(),
+            export_stage
         );
 
         fuzzer
@@ -284,6 +350,7 @@ pub fn fuzz() {
         .run_client(&mut run_client)
         .stdout_file(Some("/dev/null"))
         .sync_dir(sync_dir.clone())
+        .cores(cores.as_ref())
         .build()
         .launch()
     {
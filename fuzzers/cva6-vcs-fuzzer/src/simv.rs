@@ -98,26 +98,7 @@ impl<'a> CommandConfigurator for SimvCommandConfigurator<'a> {
         self.generate_testcase(input);
 
         // 2. args string into vec
-        let mut forged_cmd_args = format!("\
-            +vcs+finish+{vcs_timeout} \
-            -cm {coverage_metrics} \
-            -cm_dir {coverage_directory} \
-            {plus_args} \
-            {vcs_args} \
-            ",
-            plus_args = self.plus_args,
-            vcs_args = self.vcs_args,
-            vcs_timeout = self.vcs_timeout,
-            coverage_metrics = self.coverage_metrics,
-            coverage_directory = self.coverage_directory);
-
-        if cfg!(feature = "root_snapshot")
-        {
-            forged_cmd_args.push_str(" +restore ");
-            forged_cmd_args.push_str(&format!(" +ntb_random_reseed={} ", self.seed));
-        } else {
-            forged_cmd_args.push_str(&format!(" +ntb_random_seed={} ", self.seed));
-        }
+        let forged_cmd_args = self.command_args();
 
         let args_vec: Vec<&str> = forged_cmd_args.split(' ').collect();
         let args_v = &args_vec[0 .. args_vec.len()];
@@ -288,6 +269,37 @@ impl<'a> SimvCommandConfigurator<'a> {
         };
     }
 
+    /// Returns the simv arguments used to run `testcase.elf`
+    pub fn command_args(&self) -> String {
+        let mut forged_cmd_args = format!("\
+            +vcs+finish+{vcs_timeout} \
+            -cm {coverage_metrics} \
+            -cm_dir {coverage_directory} \
+            {plus_args} \
+            {vcs_args} \
+            ",
+            plus_args = self.plus_args,
+            vcs_args = self.vcs_args,
+            vcs_timeout = self.vcs_timeout,
+            coverage_metrics = self.coverage_metrics,
+            coverage_directory = self.coverage_directory);
+
+        if cfg!(feature = "root_snapshot")
+        {
+            forged_cmd_args.push_str(" +restore ");
+            forged_cmd_args.push_str(&format!(" +ntb_random_reseed={} ", self.seed));
+        } else {
+            forged_cmd_args.push_str(&format!(" +ntb_random_seed={} ", self.seed));
+        }
+
+        forged_cmd_args
+    }
+
+    /// Returns the full simv command line, as written in the exported reproducers
+    pub fn command_line(&self) -> String {
+        format!("./simv {}", self.command_args())
+    }
+
     fn generate_testcase<I: Input + HasTargetBytes>(&mut self, input: &I) {
 
         let target = input.target_bytes();
@@ -0,0 +1,270 @@
+// SPDX-FileCopyrightText: 2024 Intel Corporation
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Campaign-wide view kept by the broker: the coverage maps of all the clients are merged
+//! per metric, so that the global coverage does not count twice what several clients hit.
+//! The [`CampaignTracker`] periodically dumps its view as `campaign_summary.json`.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    path::Path,
+    time::{Duration, SystemTime},
+};
+
+use libafl::{events::Event, inputs::Input, Error};
+use serde::{Deserialize, Serialize};
+
+/// Tag of the [`Event::CustomBuf`] carrying the coverage map of a metric, followed by the
+/// name of the metric
+pub const COVERAGE_MAP_TAG_PREFIX: &str = "coverage_map_";
+/// Name of the campaign summary written by the broker
+pub const CAMPAIGN_SUMMARY_FILE_NAME: &str = "campaign_summary.json";
+/// Default time between two campaign summaries
+pub const DEFAULT_SUMMARY_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Serialize a coverage map, one bit per coverage point
+#[must_use]
+pub fn encode_coverage_map(map: &[u32]) -> Vec<u8> {
+    map.iter().flat_map(|word| word.to_le_bytes()).collect()
+}
+
+/// Deserialize a coverage map written by [`encode_coverage_map`]
+pub fn decode_coverage_map(buf: &[u8]) -> Result<Vec<u32>, Error> {
+    if !buf.len().is_multiple_of(4) {
+        return Err(Error::serialize(format!("Coverage map of {} bytes is not made of words", buf.len())));
+    }
+
+    Ok(buf
+        .chunks_exact(4)
+        .map(|word| u32::from_le_bytes(word.try_into().unwrap()))
+        .collect())
+}
+
+/// The event a client fires to share its coverage map for `metric` with the broker
+#[must_use]
+pub fn coverage_map_event<I>(metric: &str, map: &[u32]) -> Event<I>
+where
+    I: Input,
+{
+    Event::CustomBuf {
+        tag: format!("{}{}", COVERAGE_MAP_TAG_PREFIX, metric),
+        buf: encode_coverage_map(map),
+    }
+}
+
+/// Coverage of one metric
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq)]
+pub struct MetricCoverage {
+    /// Coverage points hit by at least one client
+    pub covered: u64,
+    /// Coverage points of the metric
+    pub coverable: u64,
+    /// `covered` over `coverable`, in percent
+    pub percent: f64,
+}
+
+/// The global coverage of a metric at some point of the campaign
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CoverageSample {
+    /// Seconds since the start of the campaign
+    pub elapsed_secs: u64,
+    /// The name of the metric
+    pub metric: String,
+    /// Coverage points hit by at least one client
+    pub covered: u64,
+}
+
+/// What the broker knows about a client
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ClientSummary {
+    /// Executions reported by the client
+    pub executions: u64,
+    /// Corpus size reported by the client
+    pub corpus_size: u64,
+    /// Number of objectives found by the client
+    pub objectives: u64,
+}
+
+/// The content of `campaign_summary.json`
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct CampaignSummary {
+    /// Seconds since the start of the campaign
+    pub elapsed_secs: u64,
+    /// Executions of all the clients
+    pub executions: u64,
+    /// Objectives found by all the clients
+    pub objectives: u64,
+    /// Merged coverage, by metric
+    pub coverage: BTreeMap<String, MetricCoverage>,
+    /// Evolution of the merged coverage
+    pub coverage_over_time: Vec<CoverageSample>,
+    /// Per-client figures, by client id
+    pub clients: BTreeMap<u32, ClientSummary>,
+}
+
+/// Merges the coverage maps and the figures reported by the clients
+#[derive(Debug, Clone)]
+pub struct CampaignTracker {
+    start_time: SystemTime,
+    maps: HashMap<String, Vec<u32>>,
+    coverage_over_time: Vec<CoverageSample>,
+    clients: BTreeMap<u32, ClientSummary>,
+}
+
+impl Default for CampaignTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CampaignTracker {
+    /// Start tracking a campaign
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            start_time: SystemTime::now(),
+            maps: HashMap::new(),
+            coverage_over_time: vec![],
+            clients: BTreeMap::new(),
+        }
+    }
+
+    /// Merge the coverage map of `metric` sent by a client. Returns the number of coverage
+    /// points no other client had hit.
+    pub fn merge_coverage(&mut self, metric: &str, map: &[u32]) -> u64 {
+        let merged = self.maps.entry(metric.to_string()).or_default();
+        if merged.len() < map.len() {
+            merged.resize(map.len(), 0);
+        }
+
+        let mut new_bits = 0;
+        for (global, &word) in merged.iter_mut().zip(map) {
+            new_bits += u64::from((word & !*global).count_ones());
+            *global |= word;
+        }
+
+        if new_bits > 0 {
+            let covered = Self::covered(merged);
+            self.coverage_over_time.push(CoverageSample {
+                elapsed_secs: self.elapsed().as_secs(),
+                metric: metric.to_string(),
+                covered,
+            });
+        }
+
+        new_bits
+    }
+
+    /// Record what an event tells about its client
+    pub fn on_event<I>(&mut self, client_id: u32, event: &Event<I>) -> Result<(), Error>
+    where
+        I: Input,
+    {
+        match event {
+            Event::NewTestcase {
+                corpus_size,
+                executions,
+                forward_id,
+                ..
+            } => {
+                let client_id = forward_id.map_or(client_id, |id| id.0);
+                let client = self.clients.entry(client_id).or_default();
+                client.corpus_size = *corpus_size as u64;
+                client.executions = client.executions.max(*executions as u64);
+            }
+            Event::UpdateExecStats { executions, .. } => {
+                let client = self.clients.entry(client_id).or_default();
+                client.executions = client.executions.max(*executions as u64);
+            }
+            Event::Objective { objective_size } => {
+                self.clients.entry(client_id).or_default().objectives = *objective_size as u64;
+            }
+            Event::CustomBuf { tag, buf } => {
+                if let Some(metric) = tag.strip_prefix(COVERAGE_MAP_TAG_PREFIX) {
+                    self.merge_coverage(metric, &decode_coverage_map(buf)?);
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    /// The merged coverage of `metric`, if any client reported it
+    #[must_use]
+    pub fn coverage(&self, metric: &str) -> Option<MetricCoverage> {
+        self.maps.get(metric).map(|map| {
+            let covered = Self::covered(map);
+            let coverable = map.len() as u64 * 32;
+            MetricCoverage {
+                covered,
+                coverable,
+                percent: if coverable == 0 { 0.0 } else { covered as f64 * 100.0 / coverable as f64 },
+            }
+        })
+    }
+
+    /// A snapshot of the campaign
+    #[must_use]
+    pub fn summary(&self) -> CampaignSummary {
+        CampaignSummary {
+            elapsed_secs: self.elapsed().as_secs(),
+            executions: self.clients.values().map(|c| c.executions).sum(),
+            objectives: self.clients.values().map(|c| c.objectives).sum(),
+            coverage: self
+                .maps
+                .keys()
+                .filter_map(|metric| self.coverage(metric).map(|c| (metric.clone(), c)))
+                .collect(),
+            coverage_over_time: self.coverage_over_time.clone(),
+            clients: self.clients.clone(),
+        }
+    }
+
+    /// Write the summary as JSON, replacing the previous one atomically
+    pub fn write_summary(&self, path: &Path) -> Result<(), Error> {
+        let json = serde_json::to_string_pretty(&self.summary())
+            .map_err(|e| Error::serialize(format!("Unable to serialize campaign summary: {e}")))?;
+
+        let lock_file = path.with_extension("json.presifuzz_lock");
+        fs::write(&lock_file, json)?;
+        fs::rename(&lock_file, path)?;
+        Ok(())
+    }
+
+    fn elapsed(&self) -> Duration {
+        SystemTime::now().duration_since(self.start_time).unwrap_or_default()
+    }
+
+    fn covered(map: &[u32]) -> u64 {
+        map.iter().map(|word| u64::from(word.count_ones())).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use libafl::{events::Event, inputs::BytesInput};
+
+    use super::{coverage_map_event, CampaignTracker};
+
+    #[test]
+    fn merge_overlapping_clients() {
+        let mut tracker = CampaignTracker::new();
+
+        // both clients hit bit 0, the global coverage counts it once
+        tracker.on_event(1, &coverage_map_event::<BytesInput>("line", &[0b0011])).unwrap();
+        tracker.on_event(2, &coverage_map_event::<BytesInput>("line", &[0b0101, 0b1])).unwrap();
+        tracker.on_event(2, &Event::<BytesInput>::Objective { objective_size: 3 }).unwrap();
+
+        let summary = tracker.summary();
+        assert_eq!(summary.coverage["line"].covered, 4);
+        assert_eq!(summary.coverage["line"].coverable, 64);
+        assert_eq!(summary.coverage_over_time.len(), 2);
+        assert_eq!(summary.objectives, 3);
+        assert_eq!(summary.clients[&2].objectives, 3);
+
+        assert_eq!(tracker.merge_coverage("line", &[0b0001]), 0);
+    }
+}
@@ -0,0 +1,457 @@
+// SPDX-FileCopyrightText: 2024 Intel Corporation
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Garbage collection of the sync dir. Event files processed by the broker are merged into
+//! segment files under `segments/`, old segments are dropped according to a
+//! [`RetentionPolicy`], and stats files are truncated to the latest value of each stat.
+//! The [`SyncDirReader`] reads events from both raw event files and segments.
+
+use std::{
+    collections::{HashMap, HashSet},
+    fs::{self, File},
+    io::Write,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use libafl::Error;
+use serde::{Deserialize, Serialize};
+
+use crate::manager::SyncFileKind;
+
+/// Name of the folder, inside the sync dir, holding the compacted events
+pub const SEGMENTS_DIR_NAME: &str = "segments";
+/// Name of the file recording the highest event count compacted for each client
+pub const EVENT_COUNTS_FILE_NAME: &str = "counts.json";
+/// Extension of the segment files
+pub const SEGMENT_EXTENSION: &str = "segment";
+
+/// Default age of an event file before it gets compacted, every client should have
+/// imported it by then
+pub const DEFAULT_COMPACTION_MIN_AGE: Duration = Duration::from_secs(5 * 60);
+/// Default number of event files worth a new segment
+pub const DEFAULT_COMPACTION_MIN_EVENTS: usize = 100;
+/// Size above which a client truncates its stats file
+pub const STATS_COMPACTION_THRESHOLD: usize = 1 << 20;
+
+/// An event file merged into a segment
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct SegmentEntry {
+    /// The name of the original event file, e.g. `client_3_42.event`
+    pub name: String,
+    /// The content of the original event file
+    pub data: Vec<u8>,
+}
+
+/// How long compacted events are kept
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RetentionPolicy {
+    /// Remove the segments older than this
+    pub max_age: Option<Duration>,
+    /// Only keep this many segments, the oldest ones are removed first
+    pub max_segments: Option<usize>,
+}
+
+/// An event read from the sync dir, whether from a raw event file or from a segment
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyncEntry {
+    /// The name of the event file
+    pub name: String,
+    /// The id of the client that fired the event
+    pub client_id: u32,
+    /// The sequence number of the event for this client
+    pub count: u32,
+    /// The serialized event
+    pub data: Vec<u8>,
+}
+
+/// Write `entries` as a new segment of `segments_dir`. The segment only becomes visible once
+/// complete.
+pub fn write_segment(segments_dir: &Path, entries: &[SegmentEntry]) -> Result<PathBuf, Error> {
+    fs::create_dir_all(segments_dir)?;
+
+    let nanos = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+    let segment = segments_dir.join(format!("segment_{:024}.{}", nanos, SEGMENT_EXTENSION));
+    let lock_file = segment.with_extension("presifuzz_lock");
+
+    let serialized_entries = postcard::to_allocvec(entries)?;
+    let mut file = File::create(&lock_file)?;
+    file.write_all(&serialized_entries)?;
+    file.sync_all()?;
+    fs::rename(&lock_file, &segment)?;
+
+    Ok(segment)
+}
+
+/// Read back the events of a segment
+pub fn read_segment(segment: &Path) -> Result<Vec<SegmentEntry>, Error> {
+    let serialized_entries = fs::read(segment)?;
+    Ok(postcard::from_bytes(&serialized_entries)?)
+}
+
+/// The segments of `segments_dir`, oldest first
+fn list_segments(segments_dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(segments_dir) else {
+        return vec![];
+    };
+
+    let mut segments: Vec<PathBuf> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == SEGMENT_EXTENSION))
+        .collect();
+
+    // names embed the creation time
+    segments.sort();
+    segments
+}
+
+/// The highest event count compacted for each client
+fn read_event_counts(segments_dir: &Path) -> HashMap<u32, u32> {
+    fs::read(segments_dir.join(EVENT_COUNTS_FILE_NAME))
+        .ok()
+        .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+        .unwrap_or_default()
+}
+
+/// Returns the first event count not used yet by `client_id`, in raw event files or segments
+#[must_use]
+pub fn next_event_count(sync_dir: &str, client_id: u32) -> u32 {
+    let compacted = read_event_counts(&Path::new(sync_dir).join(SEGMENTS_DIR_NAME))
+        .get(&client_id)
+        .map_or(0, |count| count + 1);
+
+    let Ok(entries) = fs::read_dir(sync_dir) else {
+        return compacted;
+    };
+
+    entries
+        .flatten()
+        .filter_map(|e| e.file_name().to_str().and_then(SyncFileKind::parse))
+        .filter_map(|kind| match kind {
+            SyncFileKind::Event { client_id: id, count } if id == client_id => Some(count + 1),
+            _ => None,
+        })
+        .fold(compacted, u32::max)
+}
+
+/// Truncate stats (one JSON `UpdateUserStats`, `UpdateExecStats` or `UpdatePerfMonitor`
+/// event per line) to the latest value of each stat. Returns the truncated stats and the
+/// number of lines removed.
+#[must_use]
+pub fn compact_stats(serialized_stats: &str) -> (String, usize) {
+    let lines: Vec<&str> = serialized_stats.lines().filter(|l| !l.trim().is_empty()).collect();
+
+    let stat_name = |line: &str| -> Option<String> {
+        let event: serde_json::Value = serde_json::from_str(line).ok()?;
+        match event["UpdateUserStats"]["name"].as_str() {
+            Some(name) => Some(format!("UpdateUserStats_{name}")),
+            // the execution figures of a client are a single stat
+            None => event.as_object()?.keys().next().cloned(),
+        }
+    };
+
+    // keep the last line of each stat, in the order they were last updated
+    let mut seen = HashSet::new();
+    let mut kept: Vec<&str> = vec![];
+    for line in lines.iter().rev() {
+        if stat_name(line).is_none_or(|name| seen.insert(name)) {
+            kept.push(line);
+        }
+    }
+    kept.reverse();
+
+    (kept.join("\n"), lines.len() - kept.len())
+}
+
+/// What a call to [`SyncDirCompactor::compact`] did
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CompactionReport {
+    /// Names of the event files merged into a new segment
+    pub compacted: Vec<String>,
+    /// Number of segments removed by the retention policy
+    pub removed_segments: usize,
+}
+
+/// Merges old event files of the sync dir into segments and applies the retention policy.
+/// Only one compactor (the broker) may run on a sync dir.
+#[derive(Debug, Clone)]
+pub struct SyncDirCompactor {
+    sync_dir: PathBuf,
+    min_age: Duration,
+    min_events: usize,
+    retention: RetentionPolicy,
+}
+
+impl SyncDirCompactor {
+    /// Create a compactor for `sync_dir`, with the default thresholds and no retention limit
+    #[must_use]
+    pub fn new(sync_dir: &str) -> Self {
+        Self {
+            sync_dir: PathBuf::from(sync_dir),
+            min_age: DEFAULT_COMPACTION_MIN_AGE,
+            min_events: DEFAULT_COMPACTION_MIN_EVENTS,
+            retention: RetentionPolicy::default(),
+        }
+    }
+
+    /// Only compact event files older than `min_age`
+    #[must_use]
+    pub fn with_min_age(mut self, min_age: Duration) -> Self {
+        self.min_age = min_age;
+        self
+    }
+
+    /// Only write a segment once at least `min_events` event files can be compacted
+    #[must_use]
+    pub fn with_min_events(mut self, min_events: usize) -> Self {
+        self.min_events = min_events;
+        self
+    }
+
+    /// Set how long compacted events are kept
+    #[must_use]
+    pub fn with_retention(mut self, retention: RetentionPolicy) -> Self {
+        self.retention = retention;
+        self
+    }
+
+    /// Merge the event files accepted by `processed` and older than the minimum age into a
+    /// new segment, then remove them and apply the retention policy
+    pub fn compact<F>(&self, processed: F) -> Result<CompactionReport, Error>
+    where
+        F: Fn(&str) -> bool,
+    {
+        let segments_dir = self.sync_dir.join(SEGMENTS_DIR_NAME);
+        let now = SystemTime::now();
+
+        let mut candidates: Vec<(u32, u32, String, PathBuf)> = vec![];
+        for entry in fs::read_dir(&self.sync_dir)?.flatten() {
+            let Ok(name) = entry.file_name().into_string() else {
+                continue;
+            };
+            let Some(SyncFileKind::Event { client_id, count }) = SyncFileKind::parse(&name) else {
+                continue;
+            };
+            let age = entry
+                .metadata()
+                .and_then(|m| m.modified())
+                .map(|t| now.duration_since(t).unwrap_or_default())
+                .unwrap_or_default();
+
+            if age >= self.min_age && processed(&name) {
+                candidates.push((client_id, count, name, entry.path()));
+            }
+        }
+
+        let mut report = CompactionReport::default();
+
+        if !candidates.is_empty() && candidates.len() >= self.min_events {
+            candidates.sort();
+
+            let mut entries = Vec::with_capacity(candidates.len());
+            let mut counts = read_event_counts(&segments_dir);
+            for (client_id, count, name, path) in &candidates {
+                entries.push(SegmentEntry {
+                    name: name.clone(),
+                    data: fs::read(path)?,
+                });
+                let max = counts.entry(*client_id).or_insert(*count);
+                *max = (*max).max(*count);
+            }
+
+            // the segment and the counts must be on disk before the event files go away
+            write_segment(&segments_dir, &entries)?;
+            let counts_file = segments_dir.join(EVENT_COUNTS_FILE_NAME);
+            let lock_file = counts_file.with_extension("presifuzz_lock");
+            let serialized_counts = serde_json::to_vec(&counts)
+                .map_err(|e| Error::serialize(format!("Unable to serialize event counts: {e}")))?;
+            fs::write(&lock_file, serialized_counts)?;
+            fs::rename(&lock_file, &counts_file)?;
+
+            for (_, _, name, path) in candidates {
+                fs::remove_file(path)?;
+                report.compacted.push(name);
+            }
+        }
+
+        report.removed_segments = self.apply_retention(&segments_dir, now)?;
+        Ok(report)
+    }
+
+    /// Remove the segments that are too old or too many
+    fn apply_retention(&self, segments_dir: &Path, now: SystemTime) -> Result<usize, Error> {
+        let segments = list_segments(segments_dir);
+        let excess = self
+            .retention
+            .max_segments
+            .map_or(0, |max| segments.len().saturating_sub(max));
+
+        let mut removed = 0;
+        for (idx, segment) in segments.iter().enumerate() {
+            let expired = self.retention.max_age.is_some_and(|max_age| {
+                fs::metadata(segment)
+                    .and_then(|m| m.modified())
+                    .is_ok_and(|t| now.duration_since(t).unwrap_or_default() > max_age)
+            });
+
+            if idx < excess || expired {
+                fs::remove_file(segment)?;
+                removed += 1;
+            }
+        }
+
+        Ok(removed)
+    }
+}
+
+/// Reads the events of a sync dir, whether still in raw event files or already compacted
+#[derive(Debug, Clone)]
+pub struct SyncDirReader {
+    sync_dir: PathBuf,
+    /// Names of the segments already read
+    read_segments: HashSet<String>,
+}
+
+impl SyncDirReader {
+    /// Create a reader for `sync_dir`
+    #[must_use]
+    pub fn new(sync_dir: &str) -> Self {
+        Self {
+            sync_dir: PathBuf::from(sync_dir),
+            read_segments: HashSet::new(),
+        }
+    }
+
+    /// Resume from a previous reader, the given segments are not read again
+    #[must_use]
+    pub fn with_read_segments(mut self, read_segments: HashSet<String>) -> Self {
+        self.read_segments = read_segments;
+        self
+    }
+
+    /// Names of the segments already read
+    #[must_use]
+    pub fn read_segments(&self) -> &HashSet<String> {
+        &self.read_segments
+    }
+
+    /// Returns the events not rejected by `skip` (called with the event file name and the id
+    /// of its client), ordered by client and count. Segments are only read once.
+    pub fn pending_events<F>(&mut self, skip: F) -> Vec<SyncEntry>
+    where
+        F: Fn(&str, u32) -> bool,
+    {
+        let mut pending: HashMap<String, SyncEntry> = HashMap::new();
+
+        // the client and count of the event file `name`, if it is neither skipped nor
+        // already pending, so that only the kept files are read
+        let wanted = |pending: &HashMap<String, SyncEntry>, name: &str| match SyncFileKind::parse(name) {
+            Some(SyncFileKind::Event { client_id, count }) if !pending.contains_key(name) && !skip(name, client_id) => {
+                Some((client_id, count))
+            }
+            _ => None,
+        };
+
+        if let Ok(entries) = fs::read_dir(&self.sync_dir) {
+            for entry in entries.flatten() {
+                let Ok(name) = entry.file_name().into_string() else {
+                    continue;
+                };
+                let Some((client_id, count)) = wanted(&pending, &name) else {
+                    continue;
+                };
+                // a raw file may be removed by the compactor in the meantime, it is then
+                // found in the segment listed below
+                if let Ok(data) = fs::read(entry.path()) {
+                    pending.insert(name.clone(), SyncEntry { name, client_id, count, data });
+                }
+            }
+        }
+
+        for segment in list_segments(&self.sync_dir.join(SEGMENTS_DIR_NAME)) {
+            let Some(segment_name) = segment.file_name().and_then(|n| n.to_str()).map(str::to_string) else {
+                continue;
+            };
+            if self.read_segments.contains(&segment_name) {
+                continue;
+            }
+
+            match read_segment(&segment) {
+                Ok(entries) => {
+                    for entry in entries {
+                        if let Some((client_id, count)) = wanted(&pending, &entry.name) {
+                            pending.insert(entry.name.clone(), SyncEntry { name: entry.name, client_id, count, data: entry.data });
+                        }
+                    }
+                }
+                Err(err) => println!("Unable to read segment {:?}: {}", segment, err),
+            }
+            self.read_segments.insert(segment_name);
+        }
+
+        let mut pending: Vec<SyncEntry> = pending.into_values().collect();
+        pending.sort_by_key(|e| (e.client_id, e.count));
+        pending
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashSet, fs, time::Duration};
+
+    use super::{compact_stats, next_event_count, RetentionPolicy, SyncDirCompactor, SyncDirReader};
+
+    #[test]
+    fn compact_and_read_back() {
+        let dir = std::env::temp_dir().join(format!("presifuzz_compaction_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let sync_dir = dir.to_str().unwrap();
+
+        for count in 0..3 {
+            fs::write(dir.join(format!("client_1_{}.event", count)), [count as u8]).unwrap();
+        }
+        fs::write(dir.join("client_2_0.event"), [42]).unwrap();
+
+        let compactor = SyncDirCompactor::new(sync_dir)
+            .with_min_age(Duration::ZERO)
+            .with_min_events(1)
+            .with_retention(RetentionPolicy { max_age: None, max_segments: Some(1) });
+
+        // only the events processed by the broker are compacted
+        let report = compactor.compact(|name| name.starts_with("client_1_")).unwrap();
+        assert_eq!(report.compacted.len(), 3);
+        assert!(!dir.join("client_1_0.event").exists());
+        assert!(dir.join("client_2_0.event").exists());
+        assert_eq!(next_event_count(sync_dir, 1), 3);
+
+        let mut reader = SyncDirReader::new(sync_dir);
+        let imported: HashSet<String> = HashSet::from(["client_1_1.event".to_string()]);
+        let events = reader.pending_events(|name, _| imported.contains(name));
+        let names: Vec<&str> = events.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["client_1_0.event", "client_1_2.event", "client_2_0.event"]);
+        assert_eq!(events[1].data, vec![2]);
+
+        // segments are read once, raw files until imported
+        assert_eq!(reader.pending_events(|_, _| false).len(), 1);
+
+        let (stats, removed) = compact_stats(
+            "{\"UpdateUserStats\":{\"name\":\"a\",\"value\":1}}\n\
+             {\"UpdateUserStats\":{\"name\":\"b\",\"value\":2}}\n\
+             {\"UpdateExecStats\":{\"executions\":10}}\n\
+             {\"UpdateExecStats\":{\"executions\":20}}\n\
+             {\"UpdateUserStats\":{\"name\":\"a\",\"value\":3}}",
+        );
+        assert_eq!(removed, 2);
+        assert_eq!(stats.lines().count(), 3);
+        assert!(stats.contains("\"executions\":20"));
+        assert!(stats.lines().last().unwrap().contains("\"value\":3"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
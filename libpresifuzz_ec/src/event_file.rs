@@ -0,0 +1,214 @@
+// SPDX-FileCopyrightText: 2024 Intel Corporation
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! The on-disk format of the files of the sync dir.
+//!
+//! Every file starts with a fixed-size header (little endian):
+//!
+//! | offset | size | field                                   |
+//! |--------|------|-----------------------------------------|
+//! | 0      | 4    | magic, `PSFZ`                           |
+//! | 4      | 2    | format version                          |
+//! | 6      | 2    | payload encoding, see [`PayloadEncoding`] |
+//! | 8      | 4    | id of the client that wrote the file    |
+//! | 12     | 4    | sequence number of the event            |
+//! | 16     | 8    | payload length                          |
+//! | 24     | 8    | xxh3 hash of the payload                |
+//!
+//! Files are written to a temporary `.presifuzz_lock` file, synced, then renamed into place,
+//! so that readers either see the complete file or nothing. Readers check the hash, catching
+//! files corrupted on the way (e.g. by NFS).
+
+use std::{
+    fs::File,
+    io::Write,
+    path::Path,
+};
+
+use libafl::Error;
+use xxhash_rust::xxh3::xxh3_64;
+
+/// Magic number at the start of every event file
+pub const EVENT_FILE_MAGIC: [u8; 4] = *b"PSFZ";
+/// Current version of the event file format
+pub const EVENT_FILE_VERSION: u16 = 1;
+/// Size of the event file header
+pub const EVENT_FILE_HEADER_SIZE: usize = 32;
+/// Suffix of the files being written
+pub const LOCK_SUFFIX: &str = ".presifuzz_lock";
+
+/// How the payload of an event file is serialized
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PayloadEncoding {
+    /// JSON, one event per line for stats files
+    Json,
+    /// bincode, written by clients built with `serialize_bytes`
+    Bincode,
+}
+
+impl PayloadEncoding {
+    fn to_u16(self) -> u16 {
+        match self {
+            PayloadEncoding::Json => 0,
+            PayloadEncoding::Bincode => 1,
+        }
+    }
+
+    fn from_u16(value: u16) -> Result<Self, Error> {
+        match value {
+            0 => Ok(PayloadEncoding::Json),
+            1 => Ok(PayloadEncoding::Bincode),
+            _ => Err(Error::serialize(format!("Unknown payload encoding {value}"))),
+        }
+    }
+}
+
+/// The header of an event file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EventFileHeader {
+    /// Format version
+    pub version: u16,
+    /// How the payload is serialized
+    pub encoding: PayloadEncoding,
+    /// The id of the client that wrote the file
+    pub client_id: u32,
+    /// The sequence number of the event for this client
+    pub sequence: u32,
+    /// Length of the payload following the header
+    pub payload_len: u64,
+    /// xxh3 hash of the payload
+    pub payload_hash: u64,
+}
+
+impl EventFileHeader {
+    /// The header of `payload`, for the current format version
+    #[must_use]
+    pub fn new(encoding: PayloadEncoding, client_id: u32, sequence: u32, payload: &[u8]) -> Self {
+        Self {
+            version: EVENT_FILE_VERSION,
+            encoding,
+            client_id,
+            sequence,
+            payload_len: payload.len() as u64,
+            payload_hash: xxh3_64(payload),
+        }
+    }
+
+    /// Serialize the header
+    #[must_use]
+    pub fn to_bytes(&self) -> [u8; EVENT_FILE_HEADER_SIZE] {
+        let mut bytes = [0; EVENT_FILE_HEADER_SIZE];
+        bytes[0..4].copy_from_slice(&EVENT_FILE_MAGIC);
+        bytes[4..6].copy_from_slice(&self.version.to_le_bytes());
+        bytes[6..8].copy_from_slice(&self.encoding.to_u16().to_le_bytes());
+        bytes[8..12].copy_from_slice(&self.client_id.to_le_bytes());
+        bytes[12..16].copy_from_slice(&self.sequence.to_le_bytes());
+        bytes[16..24].copy_from_slice(&self.payload_len.to_le_bytes());
+        bytes[24..32].copy_from_slice(&self.payload_hash.to_le_bytes());
+        bytes
+    }
+
+    /// Parse a header, checking the magic and the version
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if !has_header(bytes) {
+            return Err(Error::serialize("Not an event file"));
+        }
+        if bytes.len() < EVENT_FILE_HEADER_SIZE {
+            return Err(Error::serialize("Truncated event file header"));
+        }
+
+        let u16_at = |offset: usize| u16::from_le_bytes(bytes[offset..offset + 2].try_into().unwrap());
+        let u32_at = |offset: usize| u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap());
+        let u64_at = |offset: usize| u64::from_le_bytes(bytes[offset..offset + 8].try_into().unwrap());
+
+        let version = u16_at(4);
+        if version != EVENT_FILE_VERSION {
+            return Err(Error::serialize(format!("Unsupported event file version {version}")));
+        }
+
+        Ok(Self {
+            version,
+            encoding: PayloadEncoding::from_u16(u16_at(6))?,
+            client_id: u32_at(8),
+            sequence: u32_at(12),
+            payload_len: u64_at(16),
+            payload_hash: u64_at(24),
+        })
+    }
+}
+
+/// Whether `bytes` start with an event file header. Files written before the format was
+/// introduced hold the bare payload.
+#[must_use]
+pub fn has_header(bytes: &[u8]) -> bool {
+    bytes.starts_with(&EVENT_FILE_MAGIC)
+}
+
+/// Validate an event file, returning its header and payload
+pub fn read_event_file(bytes: &[u8]) -> Result<(EventFileHeader, &[u8]), Error> {
+    let header = EventFileHeader::from_bytes(bytes)?;
+
+    let payload = &bytes[EVENT_FILE_HEADER_SIZE..];
+    if payload.len() as u64 != header.payload_len {
+        return Err(Error::serialize(format!(
+            "Event file payload is {} bytes, expected {}",
+            payload.len(),
+            header.payload_len
+        )));
+    }
+    if xxh3_64(payload) != header.payload_hash {
+        return Err(Error::serialize("Event file payload does not match its checksum"));
+    }
+
+    Ok((header, payload))
+}
+
+/// Returns the payload of a file of the sync dir, validated against its header if it has one
+pub fn read_payload(bytes: &[u8]) -> Result<(Option<EventFileHeader>, &[u8]), Error> {
+    if has_header(bytes) {
+        let (header, payload) = read_event_file(bytes)?;
+        Ok((Some(header), payload))
+    } else {
+        Ok((None, bytes))
+    }
+}
+
+/// Atomically write an event file: the content is written to a temporary file, synced,
+/// and renamed into place
+pub fn write_event_file(path: &Path, header: &EventFileHeader, payload: &[u8]) -> Result<(), Error> {
+    let mut lock_file = path.as_os_str().to_owned();
+    lock_file.push(LOCK_SUFFIX);
+
+    let mut file = File::create(&lock_file)?;
+    file.write_all(&header.to_bytes())?;
+    file.write_all(payload)?;
+    file.sync_all()?;
+    std::fs::rename(&lock_file, path)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{read_event_file, EventFileHeader, PayloadEncoding, EVENT_FILE_HEADER_SIZE};
+
+    #[test]
+    fn checksum_catches_corruption() {
+        let payload = b"{\"Objective\":{\"objective_size\":1}}";
+        let header = EventFileHeader::new(PayloadEncoding::Json, 3, 42, payload);
+
+        let mut file = header.to_bytes().to_vec();
+        file.extend_from_slice(payload);
+
+        let (read_header, read_payload) = read_event_file(&file).unwrap();
+        assert_eq!(read_header, header);
+        assert_eq!(read_payload, payload);
+
+        // a flipped bit, a truncated file, a bare payload
+        file[EVENT_FILE_HEADER_SIZE + 3] ^= 1;
+        assert!(read_event_file(&file).is_err());
+        assert!(read_event_file(&file[..file.len() - 1]).is_err());
+        assert!(read_event_file(payload).is_err());
+    }
+}
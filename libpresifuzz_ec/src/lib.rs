@@ -1,5 +1,10 @@
+pub mod campaign;
+pub mod compaction;
+pub mod event_file;
 pub mod llmp;
 pub mod manager;
+pub mod tcp;
+pub mod watcher;
 
 use libafl::prelude::Input;
 use core::marker::PhantomData;
@@ -146,3 +151,72 @@ where
 }
 
 
+
+/// Generates the conversion between two event enums sharing the same variants
+macro_rules! convert_event {
+    ($from:ty, $to:ty) => {
+        impl<I> From<$from> for $to
+        where
+            I: Input,
+        {
+            fn from(event: $from) -> Self {
+                type Src<I> = $from;
+                type Dst<I> = $to;
+                match event {
+                    Src::<I>::NewTestcase {
+                        input,
+                        observers_buf,
+                        exit_kind,
+                        corpus_size,
+                        client_config,
+                        time,
+                        executions,
+                        forward_id,
+                    } => Dst::<I>::NewTestcase {
+                        input,
+                        observers_buf,
+                        exit_kind,
+                        corpus_size,
+                        client_config,
+                        time,
+                        executions,
+                        forward_id,
+                    },
+                    Src::<I>::UpdateExecStats { time, executions, phantom } => {
+                        Dst::<I>::UpdateExecStats { time, executions, phantom }
+                    }
+                    Src::<I>::UpdateUserStats { name, value, phantom } => {
+                        Dst::<I>::UpdateUserStats { name, value, phantom }
+                    }
+                    #[cfg(feature = "introspection")]
+                    Src::<I>::UpdatePerfMonitor {
+                        time,
+                        executions,
+                        introspection_monitor,
+                        phantom,
+                    } => Dst::<I>::UpdatePerfMonitor {
+                        time,
+                        executions,
+                        introspection_monitor,
+                        phantom,
+                    },
+                    Src::<I>::Objective { objective_size } => Dst::<I>::Objective { objective_size },
+                    Src::<I>::Log {
+                        severity_level,
+                        message,
+                        phantom,
+                    } => Dst::<I>::Log {
+                        severity_level,
+                        message,
+                        phantom,
+                    },
+                    Src::<I>::CustomBuf { buf, tag } => Dst::<I>::CustomBuf { buf, tag },
+                }
+            }
+        }
+    };
+}
+
+// The events sent over the wire are ours, the ones fired by the fuzzer are LibAFL's
+convert_event!(libafl::events::Event<I>, Event<I>);
+convert_event!(Event<I>, libafl::events::Event<I>);
@@ -2,7 +2,6 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
-// TODO: Launch multiple fuzzer instance in parallel 
 //! The [`Launcher`] launches multiple fuzzer instances in parallel.
 //! Thanks to it, we won't need a `for` loop in a shell script...
 //!
@@ -16,12 +15,34 @@
 //!
 //! On `Unix` systems, the [`Launcher`] will use `fork` if the `fork` feature is used for `LibAFL`.
 //! Else, it will start subsequent nodes with the same commandline, and will set special `env` variables accordingly.
+//!
+//! When `cores` is given, the broker re-executes the current binary once per core, pins each
+//! client to its core and restarts the clients that crash, with an exponential backoff and up
+//! to `max_restarts` times. The clients are killed when the broker stops or receives `SIGINT`
+//! or `SIGTERM`. Without `cores`, clients have to be started by hand with the
+//! `AFL_LAUNCHER_CLIENT` env variable set to their id.
 
 #[cfg(feature = "std")]
 use core::marker::PhantomData;
 use core::{
     fmt::{self, Debug, Formatter},
 };
+#[cfg(feature = "std")]
+use std::{
+    env,
+    fs::{File, OpenOptions},
+    path::Path,
+    process::{self, Child, Command, Stdio},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+#[cfg(feature = "std")]
+use nix::sys::signal::{self, SigHandler, Signal};
 
 #[cfg(feature = "std")]
 use libafl::{
@@ -29,11 +50,13 @@ use libafl::{
         EventConfig,
     },
     monitors::Monitor,
-    state::{HasExecutions, State, HasCorpus},
+    state::{HasExecutions, State, HasCorpus, HasSolutions},
     inputs::{Input},
     Error,
 };
 #[cfg(feature = "std")]
+use libafl_bolts::core_affinity::{CoreId, Cores};
+#[cfg(feature = "std")]
 use typed_builder::TypedBuilder;
 
 use crate::manager::SyncOnDiskRestartingMgr;
@@ -42,6 +65,27 @@ use crate::manager::SyncOnDiskRestartingEventManager;
 
 /// The (internal) `env` that indicates we're running as client.
 const _AFL_LAUNCHER_CLIENT: &str = "AFL_LAUNCHER_CLIENT";
+/// The (internal) `env` that holds the core a spawned client has to be pinned to.
+const _AFL_LAUNCHER_CORE: &str = "_AFL_LAUNCHER_CORE";
+/// The `env` that disables the redirection of the clients output.
+const _LIBAFL_DEBUG_OUTPUT: &str = "LIBAFL_DEBUG_OUTPUT";
+
+/// Default number of times a crashing client is restarted before giving up on it
+pub const DEFAULT_MAX_CLIENT_RESTARTS: usize = 16;
+/// Delay before the first restart of a crashed client, doubled on each restart
+const INITIAL_RESTART_BACKOFF: Duration = Duration::from_secs(1);
+/// Longest delay before the restart of a crashed client
+const MAX_RESTART_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Set when the broker receives `SIGINT` or `SIGTERM`, the supervisor then kills the clients
+/// and exits
+#[cfg(feature = "std")]
+static SHUTDOWN_REQUESTED: AtomicBool = AtomicBool::new(false);
+
+#[cfg(feature = "std")]
+extern "C" fn request_shutdown(_signal: libc::c_int) {
+    SHUTDOWN_REQUESTED.store(true, Ordering::SeqCst);
+}
 
 /// Provides a [`Launcher`], which can be used to launch a fuzzing run on a specified list of cores
 ///
@@ -58,7 +102,7 @@ where
     CF: FnOnce(Option<S>, SyncOnDiskRestartingEventManager<S, I>, u32) -> Result<(), Error>,
     S::Input: 'a,
     MT: Monitor,
-    S: State + 'a + HasCorpus,
+    S: State + 'a + HasCorpus + HasSolutions,
     I: Input,
 {
     /// The monitor instance to use
@@ -67,6 +111,15 @@ where
     configuration: EventConfig,
     #[builder(default = "/tmp/corpus".to_string())]
     sync_dir: String,
+    /// The cores to spawn a client on. If `None`, clients are started by hand.
+    #[builder(default = None)]
+    cores: Option<&'a Cores>,
+    /// Restart the spawned clients when they crash
+    #[builder(default = true)]
+    restart_clients: bool,
+    /// How many times a crashing client is restarted before giving up on it
+    #[builder(default = DEFAULT_MAX_CLIENT_RESTARTS)]
+    max_restarts: usize,
     /// The 'main' function to run for each client forked. This probably shouldn't return
     #[builder(default, setter(strip_option))]
     run_client: Option<CF>,
@@ -85,7 +138,7 @@ impl<CF, MT, S, I> Debug for Launcher<'_, CF, MT, S, I>
 where
     CF: FnOnce(Option<S>, SyncOnDiskRestartingEventManager<S, I>, u32) -> Result<(), Error>,
     MT: Monitor + Clone,
-    S: State + HasCorpus,
+    S: State + HasCorpus + HasSolutions,
     I: Input,
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
@@ -94,6 +147,9 @@ where
             .field("stdout_file", &self.stdout_file)
             .field("stderr_file", &self.stderr_file)
             .field("sync_dir", &self.sync_dir)
+            .field("cores", &self.cores)
+            .field("restart_clients", &self.restart_clients)
+            .field("max_restarts", &self.max_restarts)
             .finish_non_exhaustive()
     }
 }
@@ -103,7 +159,7 @@ impl<'a, CF, MT, S, I> Launcher<'a, CF, MT, S, I>
 where
     CF: FnOnce(Option<S>, SyncOnDiskRestartingEventManager<S, I>, u32) -> Result<(), Error>,
     MT: Monitor + Clone,
-    S: State + HasExecutions + HasCorpus,
+    S: State + HasExecutions + HasCorpus + HasSolutions,
     I: Input,
 {
 
@@ -120,6 +176,11 @@ where
      
                 println!("I am client!! with id {}", core_id);
 
+                if let Ok(core) = std::env::var(_AFL_LAUNCHER_CORE) {
+                    let core: usize = core.parse()?;
+                    CoreId(core).set_affinity()?;
+                }
+
                 // the actual client. do the fuzzing
                 let (state, mgr) = SyncOnDiskRestartingMgr::<MT, S, I>::builder()
                     .kind(SyncOnDiskManagerKind::Client {
@@ -140,13 +201,51 @@ where
                 // before going to the broker loop, spawn n clients
                 println!("I am broker!!.");
 
-                SyncOnDiskRestartingMgr::<MT, S, I>::builder()
+                let supervisor = match self.cores {
+                    Some(cores) => {
+                        let handler = SigHandler::Handler(request_shutdown);
+                        // SAFETY: the handler only stores to an atomic
+                        unsafe {
+                            signal::signal(Signal::SIGINT, handler)
+                                .and_then(|_| signal::signal(Signal::SIGTERM, handler))
+                                .map_err(|err| Error::unknown(format!("Unable to install the signal handlers: {err}")))?;
+                        }
+
+                        let mut supervisor = ClientSupervisor::spawn(
+                            cores,
+                            self.stdout_file,
+                            self.stderr_file,
+                            self.restart_clients,
+                            self.max_restarts,
+                        )?;
+                        let stop = Arc::new(AtomicBool::new(false));
+                        let stop_supervisor = stop.clone();
+                        let handle = thread::spawn(move || {
+                            if supervisor.supervise(&stop_supervisor) {
+                                drop(supervisor);
+                                println!("Interrupted, the clients are stopped");
+                                process::exit(130);
+                            }
+                        });
+                        Some((stop, handle))
+                    }
+                    None => None,
+                };
+
+                let res = SyncOnDiskRestartingMgr::<MT, S, I>::builder()
                     .monitor(Some(self.monitor.clone()))
                     .kind(SyncOnDiskManagerKind::Broker)
                     .configuration(self.configuration)
                     .sync_dir(self.sync_dir.clone())
                     .build()
-                    .launch()?;
+                    .launch();
+
+                // the broker only returns on errors, do not leave the clients behind
+                if let Some((stop, handle)) = supervisor {
+                    stop.store(true, Ordering::SeqCst);
+                    let _ = handle.join();
+                }
+                res?;
             }
             Err(_) => panic!("Env variables are broken, received non-unicode!"),
         };
@@ -155,3 +254,234 @@ where
     }
 }
 
+/// A client process spawned by the [`Launcher`]
+#[cfg(feature = "std")]
+#[derive(Debug)]
+struct ClientProcess {
+    core_id: CoreId,
+    /// `None` while waiting for a restart
+    child: Option<Child>,
+    restarts: usize,
+    restart_at: Instant,
+}
+
+/// Spawns one client per core and restarts them when they crash
+#[cfg(feature = "std")]
+#[derive(Debug)]
+struct ClientSupervisor {
+    clients: Vec<ClientProcess>,
+    stdout_file: Option<String>,
+    stderr_file: Option<String>,
+    restart_clients: bool,
+    max_restarts: usize,
+}
+
+#[cfg(feature = "std")]
+impl ClientSupervisor {
+    /// Spawn a client on each core
+    fn spawn(
+        cores: &Cores,
+        stdout_file: Option<&str>,
+        stderr_file: Option<&str>,
+        restart_clients: bool,
+        max_restarts: usize,
+    ) -> Result<Self, Error> {
+        let mut supervisor = Self {
+            clients: vec![],
+            stdout_file: stdout_file.map(String::from),
+            stderr_file: stderr_file.map(String::from),
+            restart_clients,
+            max_restarts,
+        };
+
+        for core_id in &cores.ids {
+            let child = supervisor.spawn_client(*core_id)?;
+            supervisor.clients.push(ClientProcess {
+                core_id: *core_id,
+                child: Some(child),
+                restarts: 0,
+                restart_at: Instant::now(),
+            });
+        }
+
+        Ok(supervisor)
+    }
+    /// Re-execute the current binary as the client pinned to `core_id`
+    fn spawn_client(&self, core_id: CoreId) -> Result<Child, Error> {
+        let mut command = Command::new(env::current_exe()?);
+        command
+            .args(env::args().skip(1))
+            .env(_AFL_LAUNCHER_CLIENT, core_id.0.to_string())
+            .env(_AFL_LAUNCHER_CORE, core_id.0.to_string());
+
+        if env::var(_LIBAFL_DEBUG_OUTPUT).is_err() {
+            // stderr goes to stdout_file if no stderr_file is given
+            let stdout = Self::client_output(self.stdout_file.as_deref(), core_id)?;
+            let stderr = match self.stderr_file.as_deref() {
+                Some(_) => Self::client_output(self.stderr_file.as_deref(), core_id)?,
+                None => stdout.as_ref().map(File::try_clone).transpose()?,
+            };
+
+            if let Some(stdout) = stdout {
+                command.stdout(Stdio::from(stdout));
+            }
+            if let Some(stderr) = stderr {
+                command.stderr(Stdio::from(stderr));
+            }
+        }
+
+        println!("Spawning client {}", core_id.0);
+        Ok(command.spawn()?)
+    }
+
+    /// Open the output file of a client. Regular files get a per-client suffix,
+    /// special files (e.g. `/dev/null`) are shared by all clients.
+    fn client_output(file: Option<&str>, core_id: CoreId) -> Result<Option<File>, Error> {
+        let Some(file) = file else {
+            return Ok(None);
+        };
+
+        let path = Path::new(file);
+        let shared = path.exists() && !path.is_file();
+        let filename = if shared {
+            file.to_string()
+        } else {
+            format!("{}_{}", file, core_id.0)
+        };
+
+        Ok(Some(OpenOptions::new().create(true).append(true).open(filename)?))
+    }
+
+    /// Wait for the clients, restarting the ones that crashed after a backoff, until they all
+    /// exited or `stop` is set. Returns `true` if the broker received a signal.
+    fn supervise(&mut self, stop: &AtomicBool) -> bool {
+        while !self.clients.is_empty() {
+            if SHUTDOWN_REQUESTED.load(Ordering::SeqCst) {
+                return true;
+            }
+            if stop.load(Ordering::SeqCst) {
+                return false;
+            }
+
+            for index in (0..self.clients.len()).rev() {
+                let client = &mut self.clients[index];
+                let core_id = client.core_id;
+
+                let Some(child) = client.child.as_mut() else {
+                    if Instant::now() >= client.restart_at {
+                        self.restart_client(index);
+                    }
+                    continue;
+                };
+
+                let crashed = match child.try_wait() {
+                    Ok(None) => continue,
+                    Ok(Some(status)) if status.success() => {
+                        println!("Client {} exited", core_id.0);
+                        false
+                    }
+                    Ok(Some(status)) => {
+                        println!("Client {} crashed ({})", core_id.0, status);
+                        true
+                    }
+                    Err(err) => {
+                        println!("Unable to wait for client {}: {}", core_id.0, err);
+                        false
+                    }
+                };
+
+                if !(crashed && self.restart_clients) {
+                    self.clients.remove(index);
+                    continue;
+                }
+                if client.restarts >= self.max_restarts {
+                    println!("Client {} crashed {} times, giving up", core_id.0, client.restarts + 1);
+                    self.clients.remove(index);
+                    continue;
+                }
+
+                client.child = None;
+                client.restart_at = Instant::now() + Self::backoff(client.restarts);
+            }
+
+            thread::sleep(Duration::from_millis(200));
+        }
+
+        false
+    }
+
+    /// Spawn the client at `index` again
+    fn restart_client(&mut self, index: usize) {
+        let core_id = self.clients[index].core_id;
+        match self.spawn_client(core_id) {
+            Ok(child) => {
+                let client = &mut self.clients[index];
+                client.child = Some(child);
+                client.restarts += 1;
+                println!("Client {} restarted ({} restarts)", core_id.0, client.restarts);
+            }
+            Err(err) => {
+                println!("Unable to restart client {}: {}", core_id.0, err);
+                self.clients.remove(index);
+            }
+        }
+    }
+
+    /// The delay before restarting a client that was already restarted `restarts` times
+    fn backoff(restarts: usize) -> Duration {
+        INITIAL_RESTART_BACKOFF
+            .saturating_mul(1 << restarts.min(16))
+            .min(MAX_RESTART_BACKOFF)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Drop for ClientSupervisor {
+    /// Kill the remaining clients and reap them
+    fn drop(&mut self) {
+        for client in &mut self.clients {
+            if let Some(child) = client.child.as_mut() {
+                let _ = child.kill();
+                let _ = child.wait();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        process::Command,
+        time::{Duration, Instant},
+    };
+
+    use libafl_bolts::core_affinity::CoreId;
+    use nix::{sys::signal, unistd::Pid};
+
+    use super::{ClientProcess, ClientSupervisor};
+
+    #[test]
+    fn supervise_clients() {
+        assert_eq!(ClientSupervisor::backoff(0), Duration::from_secs(1));
+        assert_eq!(ClientSupervisor::backoff(3), Duration::from_secs(8));
+        assert_eq!(ClientSupervisor::backoff(40), Duration::from_secs(60));
+
+        // the clients are killed and reaped with the supervisor
+        let child = Command::new("sleep").arg("60").spawn().unwrap();
+        let pid = Pid::from_raw(child.id() as i32);
+        let supervisor = ClientSupervisor {
+            clients: vec![ClientProcess {
+                core_id: CoreId(0),
+                child: Some(child),
+                restarts: 0,
+                restart_at: Instant::now(),
+            }],
+            stdout_file: None,
+            stderr_file: None,
+            restart_clients: true,
+            max_restarts: 1,
+        };
+        drop(supervisor);
+        assert!(signal::kill(pid, None).is_err());
+    }
+}
@@ -8,11 +8,13 @@ use core::{
     marker::PhantomData,
 };
 use std::{
+    collections::{BTreeMap, HashMap, HashSet},
     env,
-    io::{Read, Write},
+    io::Write,
 };
 use std::fs::File;
 use libafl_bolts::{ClientId};
+use libafl::corpus::{Corpus, CorpusId};
 use serde::{de::DeserializeOwned, Deserialize};
 #[cfg(feature = "std")]
 use typed_builder::TypedBuilder;
@@ -25,8 +27,8 @@ use libafl::{
     executors::{Executor, HasObservers},
     fuzzer::{EvaluatorObservers, ExecutionProcessor},
     inputs::{Input, UsesInput},
-    monitors::Monitor,
-    state::{HasExecutions, HasCorpus, HasLastReportTime, HasMetadata, State, UsesState},
+    monitors::{AggregatorOps, Monitor, UserStats, UserStatsValue},
+    state::{HasExecutions, HasCorpus, HasLastReportTime, HasMetadata, HasSolutions, State, UsesState},
     Error,
 };
 use libafl::prelude::ObserversTuple;
@@ -35,10 +37,167 @@ use std::{
     fs,
     time::SystemTime,
 };
-use std::thread;
-use std::time::Duration;
-use std::fs::OpenOptions;
-use std::path::Path;
+use std::time::{Duration, Instant};
+use std::path::{Path, PathBuf};
+
+use crate::campaign::{
+    decode_coverage_map, CampaignTracker, CAMPAIGN_SUMMARY_FILE_NAME, COVERAGE_MAP_TAG_PREFIX,
+    DEFAULT_SUMMARY_INTERVAL,
+};
+use crate::compaction::{self, SyncDirCompactor, SyncDirReader, STATS_COMPACTION_THRESHOLD};
+use crate::event_file::{self, write_event_file, EventFileHeader, PayloadEncoding};
+use crate::watcher::SyncDirWatcher;
+
+/// Default time the broker waits for new files between two liveness checks
+pub const DEFAULT_BROKER_POLL_INTERVAL: Duration = Duration::from_secs(1);
+/// Default time between two full scans of the sync dir
+pub const DEFAULT_BROKER_RESCAN_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Name of the folder, inside the sync dir, holding the saved client states
+pub const STATES_DIR_NAME: &str = "states";
+
+/// Name of the folder, inside the sync dir, where the clients copy their objectives
+pub const CRASHES_DIR_NAME: &str = "crashes";
+/// Name of the input of an objective, inside its folder of the crashes dir
+pub const OBJECTIVE_INPUT_FILE_NAME: &str = "input";
+/// Name of the metadata of an objective, inside its folder of the crashes dir
+pub const OBJECTIVE_METADATA_FILE_NAME: &str = "metadata.json";
+
+/// Default time between two imports of the testcases of the other clients
+pub const DEFAULT_CLIENT_SYNC_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Default time between two writes of the stats file of a client
+pub const DEFAULT_STATS_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Default time between two compactions of the sync dir
+pub const DEFAULT_COMPACTION_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Default time without any activity after which a client is reported as stale
+pub const DEFAULT_STALE_CLIENT_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+
+/// The serialization format of an event file found in the sync dir
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventFileFormat {
+    /// `serde_json`, written by clients built without `serialize_bytes`
+    Json,
+    /// `bincode`, written by clients built with `serialize_bytes`
+    Bincode,
+}
+
+impl EventFileFormat {
+    /// Guess the format of a serialized event from its first bytes
+    #[must_use]
+    pub fn detect(bytes: &[u8]) -> Self {
+        match bytes.iter().find(|b| !b.is_ascii_whitespace()) {
+            Some(b'{') => EventFileFormat::Json,
+            _ => EventFileFormat::Bincode,
+        }
+    }
+}
+
+/// Deserialize an event file of the sync dir. Files with a header are checked against their
+/// checksum and decoded with the encoding it records. For older files, the format is
+/// auto-detected: if the guessed format fails, the other one is tried before giving up.
+pub fn deserialize_event<I>(bytes: &[u8]) -> Result<(Event<I>, EventFileFormat), Error>
+where
+    I: Input,
+{
+    let from_json = |bytes: &[u8]| {
+        serde_json::from_slice::<Event<I>>(bytes)
+            .map_err(|e| Error::serialize(format!("Invalid JSON event: {e}")))
+    };
+    let from_bincode = |bytes: &[u8]| {
+        bincode::deserialize::<Event<I>>(bytes)
+            .map_err(|e| Error::serialize(format!("Invalid bincode event: {e}")))
+    };
+
+    let (header, bytes) = event_file::read_payload(bytes)?;
+    if let Some(header) = header {
+        return match header.encoding {
+            PayloadEncoding::Json => from_json(bytes).map(|e| (e, EventFileFormat::Json)),
+            PayloadEncoding::Bincode => from_bincode(bytes).map(|e| (e, EventFileFormat::Bincode)),
+        };
+    }
+
+    match EventFileFormat::detect(bytes) {
+        EventFileFormat::Json => from_json(bytes)
+            .map(|e| (e, EventFileFormat::Json))
+            .or_else(|err| from_bincode(bytes).map(|e| (e, EventFileFormat::Bincode)).map_err(|_| err)),
+        EventFileFormat::Bincode => from_bincode(bytes)
+            .map(|e| (e, EventFileFormat::Bincode))
+            .or_else(|err| from_json(bytes).map(|e| (e, EventFileFormat::Json)).map_err(|_| err)),
+    }
+}
+
+/// The files written by the clients into the sync dir
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyncFileKind {
+    /// `client_{id}_{count}.event`: an event (e.g. a new testcase) of the client
+    Event {
+        /// The id of the client that fired the event
+        client_id: u32,
+        /// The sequence number of the event for this client
+        count: u32,
+    },
+    /// `client_{id}.event.stats`: the user stats of the client, one JSON event per line
+    Stats {
+        /// The id of the client owning the stats
+        client_id: u32,
+    },
+    /// `client_{id}.event.coverage`: the latest coverage maps of the client, by metric
+    Coverage {
+        /// The id of the client owning the coverage maps
+        client_id: u32,
+    },
+}
+
+impl SyncFileKind {
+    /// Parse the name of a file of the sync dir. Files being written (`.presifuzz_lock`)
+    /// and unknown files are ignored.
+    #[must_use]
+    pub fn parse(filename: &str) -> Option<Self> {
+        let name = filename.strip_prefix("client_")?;
+
+        if let Some(client_id) = name.strip_suffix(".event.stats") {
+            return Some(SyncFileKind::Stats {
+                client_id: client_id.parse().ok()?,
+            });
+        }
+
+        if let Some(client_id) = name.strip_suffix(".event.coverage") {
+            return Some(SyncFileKind::Coverage {
+                client_id: client_id.parse().ok()?,
+            });
+        }
+
+        let (client_id, count) = name.strip_suffix(".event")?.split_once('_')?;
+        Some(SyncFileKind::Event {
+            client_id: client_id.parse().ok()?,
+            count: count.parse().ok()?,
+        })
+    }
+
+    /// The id of the client that wrote the file
+    #[must_use]
+    pub fn client_id(&self) -> u32 {
+        match self {
+            SyncFileKind::Event { client_id, .. }
+            | SyncFileKind::Stats { client_id }
+            | SyncFileKind::Coverage { client_id } => *client_id,
+        }
+    }
+}
+
+/// What the broker knows about the activity of a client
+#[derive(Debug, Clone)]
+pub struct ClientActivity {
+    /// Modification time of the most recent file written by the client
+    pub last_seen: SystemTime,
+    /// Number of events received from the client
+    pub events: u64,
+    /// Whether the client was reported as stale
+    pub stale: bool,
+}
 
 /// An TCP-backed event manager for simple multi-processed fuzzing
 #[derive(Debug)]
@@ -50,6 +209,17 @@ where
 {
     sync_dir: String,
     monitor: MT,
+    clients: HashMap<u32, ClientActivity>,
+    stale_timeout: Duration,
+    poll_interval: Duration,
+    rescan_interval: Duration,
+    use_inotify: bool,
+    /// Modification time and size of the files already processed, by file name
+    processed: HashMap<String, (SystemTime, u64)>,
+    compactor: Option<SyncDirCompactor>,
+    compaction_interval: Duration,
+    campaign: CampaignTracker,
+    summary_interval: Duration,
     phantom: PhantomData<I>,
 }
 
@@ -62,92 +232,279 @@ where
         Ok(Self{
             sync_dir: sync_dir,
             monitor: monitor,
+            clients: HashMap::new(),
+            stale_timeout: DEFAULT_STALE_CLIENT_TIMEOUT,
+            poll_interval: DEFAULT_BROKER_POLL_INTERVAL,
+            rescan_interval: DEFAULT_BROKER_RESCAN_INTERVAL,
+            use_inotify: true,
+            processed: HashMap::new(),
+            compactor: None,
+            compaction_interval: DEFAULT_COMPACTION_INTERVAL,
+            campaign: CampaignTracker::new(),
+            summary_interval: DEFAULT_SUMMARY_INTERVAL,
             phantom: PhantomData,
         })
     }
 
+    /// Set how long the broker waits for new files before checking the clients liveness
+    #[must_use]
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Set how often the whole sync dir is rescanned, to catch files that were not notified
+    /// (e.g. written by remote NFS clients, or when inotify is unavailable)
+    #[must_use]
+    pub fn with_rescan_interval(mut self, rescan_interval: Duration) -> Self {
+        self.rescan_interval = rescan_interval;
+        self
+    }
+
+    /// Enable or disable inotify, without it the sync dir is only polled
+    #[must_use]
+    pub fn with_inotify(mut self, use_inotify: bool) -> Self {
+        self.use_inotify = use_inotify;
+        self
+    }
+
+    /// Set the time without any activity after which a client is reported as stale
+    #[must_use]
+    pub fn with_stale_timeout(mut self, stale_timeout: Duration) -> Self {
+        self.stale_timeout = stale_timeout;
+        self
+    }
+
+    /// Periodically merge the processed event files into segments, see [`SyncDirCompactor`]
+    #[must_use]
+    pub fn with_compaction(mut self, compactor: SyncDirCompactor, compaction_interval: Duration) -> Self {
+        self.compactor = Some(compactor);
+        self.compaction_interval = compaction_interval;
+        self
+    }
+
+    /// Set how often `campaign_summary.json` is written into the sync dir
+    #[must_use]
+    pub fn with_summary_interval(mut self, summary_interval: Duration) -> Self {
+        self.summary_interval = summary_interval;
+        self
+    }
+
+    /// The merged coverage and the figures of the clients
+    pub fn campaign(&self) -> &CampaignTracker {
+        &self.campaign
+    }
+
+    /// The activity of every client seen so far, by client id
+    pub fn clients(&self) -> &HashMap<u32, ClientActivity> {
+        &self.clients
+    }
+
     /// Run in the broker until all clients exit
-    #[tokio::main(flavor = "current_thread")]
-    #[allow(clippy::too_many_lines)]
-    pub async fn broker_loop(&mut self) -> Result<(), Error> {
-        
-        let mut max_time = None;
-        let mut last = None;
-        let client_id = 0;
+    pub fn broker_loop(&mut self) -> Result<(), Error> {
+        let watcher = SyncDirWatcher::new(&self.sync_dir, self.use_inotify);
+        let mut last_rescan: Option<Instant> = None;
+        let mut last_compaction = Instant::now();
+        let mut last_summary = Instant::now();
 
         loop {
-            let in_dir = self.sync_dir.clone();
-            for entry in fs::read_dir(in_dir)? {
-                let entry = entry?;
-                let path = entry.path();
-                let attributes = fs::metadata(&path);
+            if last_rescan.is_none_or(|t| t.elapsed() >= self.rescan_interval) {
+                self.rescan();
+                last_rescan = Some(Instant::now());
+            }
 
-                if attributes.is_err() {
-                    continue;
+            match watcher.wait(self.poll_interval) {
+                Some(names) => {
+                    for name in names {
+                        let path = Path::new(&self.sync_dir).join(name);
+                        self.process_if_new(&path);
+                    }
                 }
+                None => last_rescan = None,
+            }
 
-                let attr = attributes?;
+            self.check_stale_clients();
 
-                if attr.is_file() && attr.len() > 0 {
-                    if let Ok(time) = attr.modified() {
-                        if let Some(l) = last {
-                            if time.duration_since(l).is_err() {
-                                continue;
-                            }
-                        }
-                        max_time = Some(max_time.map_or(time, |t: SystemTime| t.max(time)));
-
-                        // Read the file back and deserialize the Event struct
-        
-                        #[cfg(not(feature = "serialize_bytes"))]
-                        {
-                            let path_str = path.to_str().expect("Failed to convert path to string");
-                            let mut file = File::open(path_str).expect("Failed to event file from corpus directory");
-                            let mut serialized_event = String::new();
-                            file.read_to_string(&mut serialized_event).expect("Failed to read file");
-
-                            // Deserialize the JSON string back into an Event struct
-                            let event: Event<I> = serde_json::from_str(&serialized_event).expect("Failed to deserialize event");
-                            match Self::handle_in_broker(&mut self.monitor, ClientId(client_id), &event).unwrap() {
-                                BrokerEventResult::Forward => {
-                                    println!("Forwarding a new testcase");
-                                }
-                                BrokerEventResult::Handled => (),
-                            };
-                        }
-                        
-                        #[cfg(feature = "serialize_bytes")]
-                        {
-                            // Read the file back and deserialize the Event struct
-                            let path_str = path.to_str().expect("Failed to convert path to string");
-                            let mut file = File::open(path_str).expect("Failed to open file");
-                            let mut serialized_event = Vec::new();
-                            file.read_to_end(&mut serialized_event).expect("Failed to read file");
-
-                            // Deserialize the binary data back into an Event struct
-                            let event: Event<I> = bincode::deserialize(&serialized_event).expect("Failed to deserialize event");
-                            match Self::handle_in_broker(&mut self.monitor, ClientId(client_id), &event).unwrap() {
-                                BrokerEventResult::Forward => {
-                                    println!("Forwarding a new testcase");
-                                }
-                                BrokerEventResult::Handled => (),
-                            };
-                        }
-                    }
+            if last_compaction.elapsed() >= self.compaction_interval {
+                self.compact();
+                last_compaction = Instant::now();
+            }
+
+            if last_summary.elapsed() >= self.summary_interval {
+                let summary_file = Path::new(&self.sync_dir).join(CAMPAIGN_SUMMARY_FILE_NAME);
+                if let Err(err) = self.campaign.write_summary(&summary_file) {
+                    println!("Unable to write {:?}: {}", summary_file, err);
                 }
+                last_summary = Instant::now();
             }
-            last = max_time;
+        }
+    }
 
-            // Wait for 30 seconds
-            let duration = Duration::from_secs(30);
-            println!("Waiting for 30 seconds...");
-            thread::sleep(duration);
+    /// Merge the event files already processed into a segment
+    fn compact(&mut self) {
+        let Some(compactor) = &self.compactor else {
+            return;
+        };
+
+        let processed = &self.processed;
+        match compactor.compact(|name| processed.contains_key(name)) {
+            Ok(report) => {
+                if !report.compacted.is_empty() || report.removed_segments > 0 {
+                    println!(
+                        "Compacted {} events, removed {} old segments",
+                        report.compacted.len(),
+                        report.removed_segments
+                    );
+                }
+                for name in report.compacted {
+                    self.processed.remove(&name);
+                }
+            }
+            Err(err) => println!("Unable to compact {}: {}", self.sync_dir, err),
         }
     }
 
+    /// Process all the files of the sync dir that were not processed yet
+    fn rescan(&mut self) {
+        let entries = match fs::read_dir(&self.sync_dir) {
+            Ok(entries) => entries,
+            Err(err) => {
+                println!("Unable to read sync dir {}: {}", self.sync_dir, err);
+                return;
+            }
+        };
+
+        for entry in entries.flatten() {
+            self.process_if_new(&entry.path());
+        }
+    }
+
+    /// Process a file of the sync dir unless it was already processed. Event files are
+    /// written once, stats files are processed again whenever they change.
+    fn process_if_new(&mut self, path: &Path) {
+        let Some(filename) = path.file_name().and_then(|f| f.to_str()) else {
+            return;
+        };
+
+        if SyncFileKind::parse(filename).is_none() {
+            return;
+        }
+
+        let Ok(attr) = fs::metadata(path) else {
+            return;
+        };
+
+        if !attr.is_file() || attr.len() == 0 {
+            return;
+        }
+
+        let modified = attr.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+        if self.processed.get(filename) == Some(&(modified, attr.len())) {
+            return;
+        }
+
+        // files are renamed into place once complete, an error won't go away by retrying
+        if let Err(err) = self.process_file(path, modified) {
+            println!("Unable to process {:?}: {}", path, err);
+        }
+
+        self.processed.insert(filename.to_string(), (modified, attr.len()));
+    }
+
+    /// Read a file of the sync dir and hand its events to the monitor, on behalf of the client
+    /// that wrote it
+    fn process_file(&mut self, path: &Path, modified: SystemTime) -> Result<(), Error> {
+        let Some(kind) = path.file_name().and_then(|f| f.to_str()).and_then(SyncFileKind::parse) else {
+            return Ok(());
+        };
+
+        let events: Vec<Event<I>> = match kind {
+            SyncFileKind::Event { .. } => {
+                vec![deserialize_event(&fs::read(path)?)?.0]
+            }
+            SyncFileKind::Stats { .. } => {
+                // stats are always written as JSON, one event per line
+                let bytes = fs::read(path)?;
+                let (_, payload) = event_file::read_payload(&bytes)?;
+                String::from_utf8_lossy(payload)
+                    .lines()
+                    .filter(|line| !line.trim().is_empty())
+                    .filter_map(|line| serde_json::from_str(line).ok())
+                    .collect()
+            }
+            SyncFileKind::Coverage { .. } => {
+                let bytes = fs::read(path)?;
+                let (_, payload) = event_file::read_payload(&bytes)?;
+                let maps: BTreeMap<String, Vec<u8>> = bincode::deserialize(payload)
+                    .map_err(|e| Error::serialize(format!("Invalid coverage maps: {e}")))?;
+                for (metric, map) in maps {
+                    self.campaign.merge_coverage(&metric, &decode_coverage_map(&map)?);
+                }
+                vec![]
+            }
+        };
+
+        let client_id = kind.client_id();
+        let activity = self.clients.entry(client_id).or_insert(ClientActivity {
+            last_seen: modified,
+            events: 0,
+            stale: false,
+        });
+        activity.last_seen = activity.last_seen.max(modified);
+        activity.events += events.len() as u64;
+
+        if activity.stale {
+            activity.stale = false;
+            println!("Client {} is alive again", client_id);
+            Self::set_client_status(&mut self.monitor, client_id, "alive");
+        }
+
+        for event in &events {
+            self.campaign.on_event(client_id, event)?;
+            match Self::handle_in_broker(&mut self.monitor, ClientId(client_id), event)? {
+                BrokerEventResult::Forward => {
+                    println!("Forwarding a new testcase");
+                }
+                BrokerEventResult::Handled => (),
+            };
+        }
+
+        Ok(())
+    }
+
+    /// Report the clients that did not write anything for longer than the stale timeout
+    fn check_stale_clients(&mut self) {
+        let now = SystemTime::now();
+
+        for (client_id, activity) in &mut self.clients {
+            let idle = now.duration_since(activity.last_seen).unwrap_or_default();
+
+            if !activity.stale && idle > self.stale_timeout {
+                activity.stale = true;
+                println!(
+                    "Client {} looks dead: no activity for {}s ({} events received)",
+                    client_id,
+                    idle.as_secs(),
+                    activity.events
+                );
+                Self::set_client_status(&mut self.monitor, *client_id, "stale");
+            }
+        }
+    }
+
+    /// Expose the liveness of a client in the monitor, as a user stat
+    fn set_client_status(monitor: &mut MT, client_id: u32, status: &str) {
+        let client_id = ClientId(client_id);
+        monitor.client_stats_insert(client_id);
+        monitor.client_stats_mut_for(client_id).update_user_stats(
+            "status".to_string(),
+            UserStats::new(UserStatsValue::String(status.to_string()), AggregatorOps::None),
+        );
+    }
+
     /// Handle arriving events in the broker
     #[allow(clippy::unnecessary_wraps)]
-    fn handle_in_broker(
+    pub(crate) fn handle_in_broker(
         monitor: &mut MT,
         client_id: ClientId,
         event: &Event<I>,
@@ -247,28 +604,74 @@ where
                 println!("{message}");
                 Ok(BrokerEventResult::Handled)
             }
+            // coverage maps are for the broker only
+            Event::CustomBuf { tag, .. } if tag.starts_with(COVERAGE_MAP_TAG_PREFIX) => {
+                Ok(BrokerEventResult::Handled)
+            }
             Event::CustomBuf { .. } => Ok(BrokerEventResult::Forward),
             //_ => Ok(BrokerEventResult::Forward),
         }
     }
 }
 
+/// What is known about an objective copied to the crashes dir
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ObjectiveMetadata {
+    /// The client that found the objective
+    pub client_id: u32,
+    /// The id of the objective in the solutions of the client
+    pub solution_id: usize,
+    /// Executions of the client when the objective was found
+    pub executions: usize,
+    /// Execution time of the input, if measured
+    pub exec_time_ms: Option<u128>,
+    /// The corpus entry the input was mutated from, if known
+    pub parent_id: Option<usize>,
+    /// When the objective was exported, in seconds since the epoch
+    pub found_at: u64,
+}
+
+/// What a client already read from the sync dir, saved along with its state so that a
+/// restarted client does not import the same events again
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct SyncPosition {
+    /// Names of the event files of the other clients already imported
+    pub imported: HashSet<String>,
+    /// Names of the segments already read
+    pub read_segments: HashSet<String>,
+}
+
 /// An [`EventManager`] that forwards all events to other attached via tcp.
 pub struct SyncOnDiskEventManager<S, I>
 where
-    S: State + HasCorpus,
+    S: State + HasCorpus + HasSolutions,
     I: Input,
 {
     client_id: u32,
     sync_dir: String,
     configuration: EventConfig,
     count: u32,
+    /// Names of the event files of the other clients already imported
+    imported: HashSet<String>,
+    reader: SyncDirReader,
+    /// The content of the stats file, loaded on the first write
+    stats: Option<String>,
+    /// Whether `stats` has lines not written to the stats file yet
+    stats_pending: bool,
+    stats_interval: Duration,
+    last_stats_write: Option<Instant>,
+    /// The latest coverage map of each metric, as sent to the broker
+    coverage: BTreeMap<String, Vec<u8>>,
+    /// Solutions already copied to the crashes dir
+    exported_objectives: HashSet<CorpusId>,
+    sync_interval: Duration,
+    last_sync: Option<Instant>,
     phantom: PhantomData<(S, I)>,
 }
 
 impl<S, I> core::fmt::Debug for SyncOnDiskEventManager<S, I>
 where
-    S: State + HasCorpus,
+    S: State + HasCorpus + HasSolutions,
     I: Input,
 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
@@ -281,7 +684,7 @@ where
 
 impl<S, I> Drop for SyncOnDiskEventManager<S, I>
 where
-    S: State + HasCorpus,
+    S: State + HasCorpus + HasSolutions,
     I: Input,
 {
     fn drop(&mut self) {
@@ -290,31 +693,61 @@ where
 
 impl<S, I> SyncOnDiskEventManager<S, I>
 where
-    S: State + HasExecutions + HasCorpus,
+    S: State + HasExecutions + HasCorpus + HasSolutions,
     I: Input,
 {
     /// Create a manager from a raw TCP client
     pub fn new(client_id: u32, sync_dir: String, configuration: EventConfig) -> Result<Self, Error> {
+        // a restarted client does not overwrite the events it fired before
+        let count = compaction::next_event_count(&sync_dir, client_id);
         Ok(Self{client_id: client_id, 
+            reader: SyncDirReader::new(&sync_dir),
+            stats: None,
+            stats_pending: false,
+            stats_interval: DEFAULT_STATS_INTERVAL,
+            last_stats_write: None,
+            coverage: BTreeMap::new(),
+            exported_objectives: HashSet::new(),
             sync_dir: sync_dir,
             configuration: configuration, 
-            count: 0,
+            count,
+            imported: HashSet::new(),
+            sync_interval: DEFAULT_CLIENT_SYNC_INTERVAL,
+            last_sync: None,
             phantom: PhantomData,
         })
     }
 
+    /// Resume reading the sync dir from the position saved by a previous run
+    #[must_use]
+    pub fn with_position(mut self, position: SyncPosition) -> Self {
+        self.imported = position.imported;
+        self.reader = SyncDirReader::new(&self.sync_dir).with_read_segments(position.read_segments);
+        self
+    }
+
+    /// Set how often the sync dir is scanned for testcases of the other clients
+    #[must_use]
+    pub fn with_sync_interval(mut self, sync_interval: Duration) -> Self {
+        self.sync_interval = sync_interval;
+        self
+    }
+
+    /// Set how often the stats file is rewritten, the stats fired in between are kept in
+    /// memory until the next write
+    #[must_use]
+    pub fn with_stats_interval(mut self, stats_interval: Duration) -> Self {
+        self.stats_interval = stats_interval;
+        self
+    }
+
     pub fn existing_from_env(
         env_name: &str,
         sync_dir: String,
         configuration: EventConfig,
     ) -> Result<Self, Error> {
-        let this_id = str::parse::<u32>(&env::var(env_name)?).unwrap();
-        Ok(Self{client_id: this_id, 
-            sync_dir: sync_dir,
-            configuration: configuration, 
-            count: 0,
-            phantom: PhantomData,
-        })
+        let this_id = str::parse::<u32>(&env::var(env_name)?)?;
+        Self::new(this_id, sync_dir, configuration)
     }
 
     /// Write the client id for a client [`EventManager`] to env vars
@@ -325,17 +758,156 @@ where
 
 impl<S, I> SyncOnDiskEventManager<S, I>
 where
-    S: State + HasCorpus,
+    S: State + HasCorpus + HasSolutions,
     I: Input,
 {
     pub fn send_exiting(&mut self) -> Result<(), Error> {
+        self.flush_stats()
+    }
+
+    /// What was already read from the sync dir
+    #[must_use]
+    pub fn position(&self) -> SyncPosition {
+        SyncPosition {
+            imported: self.imported.clone(),
+            read_segments: self.reader.read_segments().clone(),
+        }
+    }
+
+    /// Write an event to its own file, `client_{id}_{count}.event`
+    fn write_event(&mut self, event: &Event<S::Input>) -> Result<(), Error> {
+        #[cfg(feature = "serialize_bytes")]
+        let (payload, encoding) = (
+            bincode::serialize(event).map_err(|e| Error::serialize(format!("Unable to serialize event: {e}")))?,
+            PayloadEncoding::Bincode,
+        );
+        #[cfg(not(feature = "serialize_bytes"))]
+        let (payload, encoding) = (
+            serde_json::to_vec(event).map_err(|e| Error::serialize(format!("Unable to serialize event: {e}")))?,
+            PayloadEncoding::Json,
+        );
+
+        let event_filename = format!("{}/client_{}_{}.event", self.sync_dir, &self.client_id, self.count);
+        let header = EventFileHeader::new(encoding, self.client_id, self.count, &payload);
+        write_event_file(Path::new(&event_filename), &header, &payload)?;
+
+        self.count += 1;
         Ok(())
     }
+
+    /// Copy the solutions not exported yet to `{sync_dir}/crashes/client_{id}_{solution}/`,
+    /// with their input and an [`ObjectiveMetadata`]. The folder is filled under a temporary
+    /// name and renamed into place.
+    fn export_objectives(&mut self, state: &S) -> Result<(), Error> {
+        let crashes_dir = Path::new(&self.sync_dir).join(CRASHES_DIR_NAME);
+        fs::create_dir_all(&crashes_dir)?;
+
+        let solutions = state.solutions();
+        for id in solutions.ids() {
+            if self.exported_objectives.contains(&id) {
+                continue;
+            }
+
+            let solution_id = usize::from(id);
+            let objective_dir = crashes_dir.join(format!("client_{}_{}", self.client_id, solution_id));
+            if !objective_dir.exists() {
+                let input = solutions.cloned_input_for_id(id)?;
+                let metadata = {
+                    let testcase = solutions.get(id)?.borrow();
+                    ObjectiveMetadata {
+                        client_id: self.client_id,
+                        solution_id,
+                        executions: *testcase.executions(),
+                        exec_time_ms: testcase.exec_time().map(|t| t.as_millis()),
+                        parent_id: testcase.parent_id().map(usize::from),
+                        found_at: SystemTime::now()
+                            .duration_since(SystemTime::UNIX_EPOCH)
+                            .map_or(0, |d| d.as_secs()),
+                    }
+                };
+                let metadata = serde_json::to_string_pretty(&metadata)
+                    .map_err(|e| Error::serialize(format!("Unable to serialize objective metadata: {e}")))?;
+
+                let lock_dir = crashes_dir.join(format!("client_{}_{}{}", self.client_id, solution_id, event_file::LOCK_SUFFIX));
+                if lock_dir.exists() {
+                    fs::remove_dir_all(&lock_dir)?;
+                }
+                fs::create_dir(&lock_dir)?;
+                input.to_file(lock_dir.join(OBJECTIVE_INPUT_FILE_NAME))?;
+                fs::write(lock_dir.join(OBJECTIVE_METADATA_FILE_NAME), metadata)?;
+                fs::rename(&lock_dir, &objective_dir)?;
+            }
+
+            self.exported_objectives.insert(id);
+        }
+
+        Ok(())
+    }
+
+    /// Add a line to the stats of this client, written to the stats file at most once per
+    /// stats interval
+    fn write_stats(&mut self, serialized_event: &str) -> Result<(), Error> {
+        let stats_filename = format!("{}/client_{}.event.stats", self.sync_dir, &self.client_id);
+
+        // pick up the stats of a previous run of this client
+        let stats = self.stats.get_or_insert_with(|| {
+            fs::read(&stats_filename)
+                .ok()
+                .and_then(|bytes| {
+                    event_file::read_payload(&bytes)
+                        .ok()
+                        .and_then(|(_, payload)| String::from_utf8(payload.to_vec()).ok())
+                })
+                .unwrap_or_default()
+        });
+
+        if !stats.is_empty() {
+            stats.push('\n');
+        }
+        stats.push_str(serialized_event);
+
+        // only the latest value of each stat matters, keep the file small
+        if stats.len() > STATS_COMPACTION_THRESHOLD {
+            *stats = compaction::compact_stats(stats).0;
+        }
+        self.stats_pending = true;
+
+        if self.last_stats_write.is_some_and(|t| t.elapsed() < self.stats_interval) {
+            return Ok(());
+        }
+        self.flush_stats()
+    }
+
+    /// Write the pending stats. The whole file is rewritten and renamed into place, readers
+    /// never see it missing or half-written.
+    fn flush_stats(&mut self) -> Result<(), Error> {
+        let Some(stats) = self.stats.as_ref().filter(|_| self.stats_pending) else {
+            return Ok(());
+        };
+        let stats_filename = format!("{}/client_{}.event.stats", self.sync_dir, &self.client_id);
+
+        let header = EventFileHeader::new(PayloadEncoding::Json, self.client_id, self.count, stats.as_bytes());
+        write_event_file(Path::new(&stats_filename), &header, stats.as_bytes())?;
+        self.stats_pending = false;
+        self.last_stats_write = Some(Instant::now());
+        Ok(())
+    }
+
+    /// Replace the coverage maps file of this client, the broker merges them with the maps
+    /// of the other clients
+    fn write_coverage(&mut self) -> Result<(), Error> {
+        let coverage_filename = format!("{}/client_{}.event.coverage", self.sync_dir, &self.client_id);
+
+        let payload = bincode::serialize(&self.coverage)
+            .map_err(|e| Error::serialize(format!("Unable to serialize coverage maps: {e}")))?;
+        let header = EventFileHeader::new(PayloadEncoding::Bincode, self.client_id, self.count, &payload);
+        write_event_file(Path::new(&coverage_filename), &header, &payload)
+    }
 }
 
 impl<S, I> UsesState for SyncOnDiskEventManager<S, I>
 where
-    S: State + HasCorpus,
+    S: State + HasCorpus + HasSolutions,
     I: Input,
 {
     type State = S;
@@ -343,175 +915,43 @@ where
 
 impl<S, I> EventFirer for SyncOnDiskEventManager<S, I>
 where
-    S: State + HasCorpus,
+    S: State + HasCorpus + HasSolutions,
     I: Input,
 {
-    #[cfg(feature = "serialize_bytes")]
     fn fire(
         &mut self,
-        _state: &mut Self::State,
+        state: &mut Self::State,
         event: Event<<Self::State as UsesInput>::Input>,
         // event: &Event<I>,
     ) -> Result<(), Error> {
-        
+        #[cfg(feature = "debug")]
+        println!("sync on dir: {}", self.sync_dir);
+
         match &event {
-            Event::NewTestcase {
-                input: _,
-                client_config: _,
-                exit_kind: _,
-                corpus_size,
-                observers_buf: _,
-                time,
-                executions,
-                forward_id,
-            } => {
-                let serialized_event = bincode::serialize(&event).expect("Failed to serialize event");
-
-                // Write the serialized binary data to a file
-                let event_filename = format!("{}/client_{}_{}.event.presifuzz_lock", self.sync_dir, &self.client_id, self.count);
-                
-                #[cfg(feature = "debug")]
-                println!("sync on dir: {}", self.sync_dir);
-                
-                let mut file = File::create(event_filename).expect("Failed to persist event on systemfile");
-                file.write_all(&serialized_event).expect("Failed to write to file");
-                    
-                let event_filename = format!("{}/client_{}_{}.event.presifuzz_lock", self.sync_dir, &self.client_id, self.count);
-                let new_filename = format!("{}/client_{}_{}.event", self.sync_dir, &self.client_id, self.count);
-                fs::rename(event_filename, new_filename)?;
-
-                self.count += 1;
+            Event::CustomBuf { tag, buf } if tag.starts_with(COVERAGE_MAP_TAG_PREFIX) => {
+                let metric = &tag[COVERAGE_MAP_TAG_PREFIX.len()..];
+                self.coverage.insert(metric.to_string(), buf.clone());
+                self.write_coverage()?;
             },
-            Event::UpdateUserStats {
-                name,
-                value,
-                phantom:_,
-            } => {
-                // println!("UpdateUserStats: {} -> {:?}", name, value);
-                let serialized_event = serde_json::to_string(&event).expect("Failed to serialize event");
-
-                //Write the serialized binary data to a file
-                let new_filename = format!("{}/client_{}.event.stats", self.sync_dir, &self.client_id);
-                let event_filename = format!("{}/client_{}.stats_event.presifuzz_lock", self.sync_dir, &self.client_id);
-
-                #[cfg(feature = "debug")]
-                println!("sync on dir: {}", self.sync_dir);
-    
-                if !Path::new(&new_filename).is_file() {
-                    let mut file = File::create(event_filename).expect("Failed to persist event on systemfile");
-                    file.write_all(serialized_event.as_bytes()).expect("Failed to write to file");
-
-                    let event_filename = format!("{}/client_{}.stats_event.presifuzz_lock", self.sync_dir, &self.client_id);
-                    let new_filename = format!("{}/client_{}.event.stats", self.sync_dir, &self.client_id);
-                    fs::rename(event_filename, new_filename)?;
-                } else {
-                    
-                    let event_filename = format!("{}/client_{}.stats_event.presifuzz_lock", self.sync_dir, &self.client_id);
-                    let new_filename = format!("{}/client_{}.event.stats", self.sync_dir, &self.client_id);
-                    fs::rename(new_filename, event_filename)?;
-
-                    let event_filename = format!("{}/client_{}.stats_event.presifuzz_lock", self.sync_dir, &self.client_id);
-                    let mut file = OpenOptions::new()
-                        .write(true)
-                        .append(true)
-                        .open(event_filename)
-                        .unwrap();
-                   
-                    file.write_all("\n".as_bytes()).expect("Failed to write to file");
-                    file.write_all(serialized_event.as_bytes()).expect("Failed to write to file");
-
-                    let event_filename = format!("{}/client_{}.stats_event.presifuzz_lock", self.sync_dir, &self.client_id);
-                    let new_filename = format!("{}/client_{}.event.stats", self.sync_dir, &self.client_id);
-                    fs::rename(event_filename, new_filename)?;
-                }
+            // the latest values are all the broker needs, they go to the stats file
+            Event::UpdateUserStats { .. } | Event::UpdateExecStats { .. } => {
+                let serialized_event = serde_json::to_string(&event)
+                    .map_err(|e| Error::serialize(format!("Unable to serialize event: {e}")))?;
+                self.write_stats(&serialized_event)?;
             },
-            _ => {}
-        };
-
-        Ok(())
-    }
-
-    #[cfg(not(feature = "serialize_bytes"))]
-    fn fire(
-        &mut self,
-        _state: &mut Self::State,
-        event: Event<<Self::State as UsesInput>::Input>,
-        // event: &Event<I>,
-    ) -> Result<(), Error> {
-        
-        match event {
-            Event::NewTestcase {
-                input: _,
-                client_config: _,
-                exit_kind: _,
-                corpus_size: _,
-                observers_buf: _,
-                time: _,
-                executions: _,
-                forward_id: _,
-            } => {
-                let serialized_event = serde_json::to_string(&event).expect("Failed to serialize event");
-
-                // Write the serialized binary data to a file
-                let event_filename = format!("{}/client_{}_{}.event.presifuzz_lock", self.sync_dir, &self.client_id, self.count);
-
-                #[cfg(feature = "debug")]
-                println!("sync on dir: {}", self.sync_dir);
-
-                let mut file = File::create(event_filename).expect("Failed to persist event on systemfile");
-                file.write_all(serialized_event.as_bytes()).expect("Failed to write to file");
-         
-                let event_filename = format!("{}/client_{}_{}.event.presifuzz_lock", self.sync_dir, &self.client_id, self.count);
-                let new_filename = format!("{}/client_{}_{}.event", self.sync_dir, &self.client_id, self.count);
-                fs::rename(event_filename, new_filename)?;
-
-                self.count += 1;
+            #[cfg(feature = "introspection")]
+            Event::UpdatePerfMonitor { .. } => {
+                let serialized_event = serde_json::to_string(&event)
+                    .map_err(|e| Error::serialize(format!("Unable to serialize event: {e}")))?;
+                self.write_stats(&serialized_event)?;
             },
-            Event::UpdateUserStats {
-                name:_,
-                value:_,
-                phantom:_,
-            } => {
-                // println!("UpdateUserStats: {} -> {:?}", name, value);
-                let serialized_event = serde_json::to_string(&event).expect("Failed to serialize event");
-
-                //Write the serialized binary data to a file
-                let new_filename = format!("{}/client_{}.event.stats", self.sync_dir, &self.client_id);
-                let event_filename = format!("{}/client_{}.stats_event.presifuzz_lock", self.sync_dir, &self.client_id);
-
-                #[cfg(feature = "debug")]
-                println!("sync on dir: {}", self.sync_dir);
-    
-                if !Path::new(&new_filename).is_file() {
-                    let mut file = File::create(event_filename).expect("Failed to persist event on systemfile");
-                    file.write_all(serialized_event.as_bytes()).expect("Failed to write to file");
-
-                    let event_filename = format!("{}/client_{}.stats_event.presifuzz_lock", self.sync_dir, &self.client_id);
-                    let new_filename = format!("{}/client_{}.event.stats", self.sync_dir, &self.client_id);
-                    fs::rename(event_filename, new_filename)?;
-                } else {
-                    
-                    let event_filename = format!("{}/client_{}.stats_event.presifuzz_lock", self.sync_dir, &self.client_id);
-                    let new_filename = format!("{}/client_{}.event.stats", self.sync_dir, &self.client_id);
-                    fs::rename(new_filename, event_filename)?;
-
-                    let event_filename = format!("{}/client_{}.stats_event.presifuzz_lock", self.sync_dir, &self.client_id);
-                    let mut file = OpenOptions::new()
-                        .write(true)
-                        .append(true)
-                        .open(event_filename)
-                        .unwrap();
-                   
-                    file.write_all("\n".as_bytes()).expect("Failed to write to file");
-                    file.write_all(serialized_event.as_bytes()).expect("Failed to write to file");
-
-                    let event_filename = format!("{}/client_{}.stats_event.presifuzz_lock", self.sync_dir, &self.client_id);
-                    let new_filename = format!("{}/client_{}.event.stats", self.sync_dir, &self.client_id);
-                    fs::rename(event_filename, new_filename)?;
-                }
+            Event::Objective { .. } => {
+                self.export_objectives(state)?;
+                self.write_event(&event)?;
             },
-            _ => {}
-        };
+            _ => self.write_event(&event)?,
+        }
+
         Ok(())
     }
 
@@ -522,16 +962,24 @@ where
 
 impl<S, I> EventRestarter for SyncOnDiskEventManager<S, I>
 where
-    S: State + HasCorpus,
+    S: State + HasCorpus + HasSolutions,
     I: Input,
 {
+    /// Events are renamed into place as soon as they are fired, write the pending stats and
+    /// make sure the renames reached the disk before the process goes away
     fn await_restart_safe(&mut self) {
+        if let Err(err) = self.flush_stats() {
+            println!("Unable to write the stats of client {}: {}", self.client_id, err);
+        }
+        if let Err(err) = File::open(&self.sync_dir).and_then(|dir| dir.sync_all()) {
+            println!("Unable to sync {}: {}", self.sync_dir, err);
+        }
     }
 }
 
 impl<E, S, Z, I> EventProcessor<E, Z> for SyncOnDiskEventManager<S, I>
 where
-    S: State + HasExecutions + HasCorpus,
+    S: State + HasExecutions + HasCorpus + HasSolutions,
     E: HasObservers<State = S> + Executor<Self, Z>,
     for<'a> E::Observers: Deserialize<'a>,
     Z: EvaluatorObservers<E::Observers, State = S> + ExecutionProcessor<E::Observers, State = S>,
@@ -539,11 +987,64 @@ where
 {
     fn process(
         &mut self,
-        _fuzzer: &mut Z,
-        _state: &mut Self::State,
-        _executor: &mut E,
+        fuzzer: &mut Z,
+        state: &mut Self::State,
+        executor: &mut E,
     ) -> Result<usize, Error> {
-        Ok(0)
+        if self.last_sync.is_some_and(|t| t.elapsed() < self.sync_interval) {
+            return Ok(0);
+        }
+        self.last_sync = Some(Instant::now());
+
+        let own_id = self.client_id;
+        let imported = &self.imported;
+        let pending = self
+            .reader
+            .pending_events(|name, client_id| client_id == own_id || imported.contains(name));
+
+        let mut handled = 0;
+        for entry in pending {
+            let event: Event<S::Input> = match deserialize_event(&entry.data) {
+                Ok((event, _)) => event,
+                Err(err) => {
+                    println!("Unable to load {}: {}", entry.name, err);
+                    self.imported.insert(entry.name);
+                    continue;
+                }
+            };
+            let name = entry.name;
+            self.imported.insert(name.clone());
+
+            if let Event::NewTestcase {
+                input,
+                client_config,
+                exit_kind,
+                observers_buf,
+                ..
+            } = event
+            {
+                // Same as LLMP: trust the observers of clients running the same configuration,
+                // re-execute the input otherwise
+                match observers_buf {
+                    Some(observers_buf) if self.configuration.match_with(&client_config) => {
+                        let observers: E::Observers = match postcard::from_bytes(&observers_buf) {
+                            Ok(observers) => observers,
+                            Err(err) => {
+                                println!("Unable to deserialize the observers of {}, skipping: {}", name, err);
+                                continue;
+                            }
+                        };
+                        fuzzer.process_execution(state, self, input, &observers, &exit_kind, false)?;
+                    }
+                    _ => {
+                        fuzzer.evaluate_input_with_observers::<E, Self>(state, executor, self, input, false)?;
+                    }
+                }
+                handled += 1;
+            }
+        }
+
+        Ok(handled)
     }
 }
 
@@ -551,21 +1052,21 @@ impl<E, S, Z, I> EventManager<E, Z> for SyncOnDiskEventManager<S, I>
 where
     E: HasObservers<State = S> + Executor<Self, Z>,
     for<'a> E::Observers: Deserialize<'a>,
-    S: State + HasExecutions + HasMetadata + HasLastReportTime + HasCorpus,
+    S: State + HasExecutions + HasMetadata + HasLastReportTime + HasCorpus + HasSolutions,
     Z: EvaluatorObservers<E::Observers, State = S> + ExecutionProcessor<E::Observers, State = S>,
     I: Input,
 {
 }
 
 impl<S, I> ProgressReporter for SyncOnDiskEventManager<S, I> where
-    S: State + HasExecutions + HasMetadata + HasLastReportTime+ HasCorpus,
+    S: State + HasExecutions + HasMetadata + HasLastReportTime+ HasCorpus + HasSolutions,
     I: Input,
 {
 }
 
 impl<S, I> HasEventManagerId for SyncOnDiskEventManager<S, I>
 where
-    S: State + HasCorpus,
+    S: State + HasCorpus + HasSolutions,
     I: Input,
 {
     /// Gets the id assigned to this staterestorer.
@@ -579,16 +1080,21 @@ where
 #[derive(Debug)]
 pub struct SyncOnDiskRestartingEventManager<S, I>
 where
-    S: State + HasCorpus,
+    S: State + HasCorpus + HasSolutions,
     I: Input,
 {
     mgr: SyncOnDiskEventManager<S, I>,
+    /// Save the state every `checkpoint_interval`, so that it survives a kill (e.g. OOM)
+    checkpoint_interval: Option<Duration>,
+    last_checkpoint: Instant,
+    /// The state as of the last `process`, `send_exiting` has no state to save
+    last_state: Option<Vec<u8>>,
 }
 
 #[cfg(feature = "std")]
 impl<S, I> UsesState for SyncOnDiskRestartingEventManager<S, I>
 where
-    S: State + HasCorpus,
+    S: State + HasCorpus + HasSolutions,
     I: Input,
 {
     type State = S;
@@ -597,7 +1103,7 @@ where
 #[cfg(feature = "std")]
 impl<S, I> ProgressReporter for SyncOnDiskRestartingEventManager<S, I>
 where
-    S: State + HasExecutions + HasMetadata + HasLastReportTime + HasCorpus,
+    S: State + HasExecutions + HasMetadata + HasLastReportTime + HasCorpus + HasSolutions,
     I: Input,
 {
 }
@@ -605,7 +1111,7 @@ where
 #[cfg(feature = "std")]
 impl<S, I> EventFirer for SyncOnDiskRestartingEventManager<S, I>
 where
-    S: State + HasCorpus,
+    S: State + HasCorpus + HasSolutions,
     I: Input,
 {
     fn fire(
@@ -632,7 +1138,7 @@ where
 #[cfg(feature = "std")]
 impl<S, I> EventRestarter for SyncOnDiskRestartingEventManager<S, I>
 where
-    S: State + HasExecutions + HasCorpus,
+    S: State + HasExecutions + HasCorpus + HasSolutions,
     I: Input,
 {
     /// The tcp client needs to wait until a broker mapped all pages, before shutting down.
@@ -642,13 +1148,18 @@ where
         self.mgr.await_restart_safe();
     }
 
-    /// Reset the single page (we reuse it over and over from pos 0), then send the current state to the next runner.
-    fn on_restart(&mut self, _state: &mut S) -> Result<(), Error> {
+    /// Save the current state in the sync dir, the next runner reloads it on launch.
+    fn on_restart(&mut self, state: &mut S) -> Result<(), Error> {
+        self.save_state(state)?;
         self.await_restart_safe();
         Ok(())
     }
 
+    /// Save the last state seen by `process`, so that a stopped campaign can be resumed.
     fn send_exiting(&mut self) -> Result<(), Error> {
+        if let Some(serialized_state) = self.last_state.take() {
+            self.write_state(&serialized_state)?;
+        }
         // Also inform the broker that we are about to exit.
         // This way, the broker can clean up the pages, and eventually exit.
         self.mgr.send_exiting()
@@ -660,12 +1171,14 @@ impl<E, S, Z, I> EventProcessor<E, Z> for SyncOnDiskRestartingEventManager<S, I>
 where
     E: HasObservers<State = S> + Executor<SyncOnDiskEventManager<S, I>, Z>,
     for<'a> E::Observers: Deserialize<'a>,
-    S: State + HasExecutions + HasCorpus,
+    S: State + HasExecutions + HasCorpus + HasSolutions,
     Z: EvaluatorObservers<E::Observers, State = S> + ExecutionProcessor<E::Observers>, //CE: CustomEvent<I>,
     I: Input,
 {
     fn process(&mut self, fuzzer: &mut Z, state: &mut S, executor: &mut E) -> Result<usize, Error> {
-        self.mgr.process(fuzzer, state, executor)
+        let handled = self.mgr.process(fuzzer, state, executor)?;
+        self.snapshot_state(state)?;
+        Ok(handled)
     }
 }
 
@@ -674,7 +1187,7 @@ impl<E, S, Z, I> EventManager<E, Z> for SyncOnDiskRestartingEventManager<S, I>
 where
     E: HasObservers<State = S> + Executor<SyncOnDiskEventManager<S, I>, Z>,
     for<'a> E::Observers: Deserialize<'a>,
-    S: State + HasExecutions + HasMetadata + HasLastReportTime + HasCorpus,
+    S: State + HasExecutions + HasMetadata + HasLastReportTime + HasCorpus + HasSolutions,
     Z: EvaluatorObservers<E::Observers, State = S> + ExecutionProcessor<E::Observers>, //CE: CustomEvent<I>,
     I: Input,
 {
@@ -683,7 +1196,7 @@ where
 #[cfg(feature = "std")]
 impl<S, I> HasEventManagerId for SyncOnDiskRestartingEventManager<S, I>
 where
-    S: State + HasCorpus,
+    S: State + HasCorpus + HasSolutions,
     I: Input,
 {
     fn mgr_id(&self) -> EventManagerId {
@@ -700,13 +1213,91 @@ const _ENV_FUZZER_BROKER_CLIENT_INITIAL: &str = "_AFL_ENV_FUZZER_BROKER_CLIENT";
 #[cfg(feature = "std")]
 impl<S, I> SyncOnDiskRestartingEventManager<S, I>
 where
-    S: State + HasCorpus,
+    S: State + HasCorpus + HasSolutions,
     I: Input,
 {
     /// Create a new runner, the executed child doing the actual fuzzing.
     pub fn new(mgr: SyncOnDiskEventManager<S, I>) -> Self {
         Self {
             mgr,
+            checkpoint_interval: None,
+            last_checkpoint: Instant::now(),
+            last_state: None,
+        }
+    }
+
+    /// Also save the state periodically, not only on restart
+    #[must_use]
+    pub fn with_checkpoint_interval(mut self, checkpoint_interval: Option<Duration>) -> Self {
+        self.checkpoint_interval = checkpoint_interval;
+        self
+    }
+
+    /// The file the state of `client_id` is saved to. States are kept out of the top-level
+    /// sync dir, which only holds events.
+    #[must_use]
+    pub fn state_file(sync_dir: &str, client_id: u32) -> PathBuf {
+        Path::new(sync_dir)
+            .join(STATES_DIR_NAME)
+            .join(format!("client_{}.state", client_id))
+    }
+
+    /// Serialize the state next to the events, along with the position of the client in the
+    /// sync dir. The write is atomic.
+    pub fn save_state(&mut self, state: &S) -> Result<(), Error> {
+        let serialized_state = postcard::to_allocvec(&(state, self.mgr.position()))?;
+        self.write_state(&serialized_state)?;
+        // superseded, `send_exiting` must not go back to it
+        self.last_state = None;
+        Ok(())
+    }
+
+    /// Keep the state for `send_exiting`, and write it if the checkpoint interval elapsed
+    fn snapshot_state(&mut self, state: &S) -> Result<(), Error> {
+        let serialized_state = postcard::to_allocvec(&(state, self.mgr.position()))?;
+        if self.checkpoint_interval.is_some_and(|i| self.last_checkpoint.elapsed() >= i) {
+            self.write_state(&serialized_state)?;
+        }
+        self.last_state = Some(serialized_state);
+        Ok(())
+    }
+
+    fn write_state(&mut self, serialized_state: &[u8]) -> Result<(), Error> {
+        let state_file = Self::state_file(&self.mgr.sync_dir, self.mgr.client_id);
+        let lock_file = state_file.with_extension("state.presifuzz_lock");
+
+        if let Some(states_dir) = state_file.parent() {
+            fs::create_dir_all(states_dir)?;
+        }
+
+        let mut file = File::create(&lock_file)?;
+        file.write_all(serialized_state)?;
+        file.sync_all()?;
+        fs::rename(lock_file, state_file)?;
+
+        self.last_checkpoint = Instant::now();
+        Ok(())
+    }
+
+    /// Load the state saved by a previous run of `client_id`, and its position in the sync dir
+    pub fn load_state(sync_dir: &str, client_id: u32) -> Result<Option<(S, SyncPosition)>, Error> {
+        let state_file = Self::state_file(sync_dir, client_id);
+        if !state_file.is_file() {
+            return Ok(None);
+        }
+
+        let serialized_state = fs::read(&state_file)?;
+        match postcard::from_bytes::<(S, SyncPosition)>(&serialized_state) {
+            Ok(saved) => Ok(Some(saved)),
+            // states saved without position, the whole sync dir is read again
+            Err(_) => match postcard::from_bytes(&serialized_state) {
+                Ok(state) => Ok(Some((state, SyncPosition::default()))),
+                Err(err) => {
+                    // e.g. the fuzzer was rebuilt with other observers, start from scratch
+                    println!("Ignoring the saved state {:?}: {}", state_file, err);
+                    Ok(None)
+                }
+            },
         }
     }
 }
@@ -738,7 +1329,7 @@ pub fn setup_restarting_mgr_sync_on_disk<MT, S, I>(
 ) -> Result<(Option<S>, SyncOnDiskRestartingEventManager<S, I>), Error>
 where
     MT: Monitor + Clone,
-    S: State + HasExecutions + HasCorpus,
+    S: State + HasExecutions + HasCorpus + HasSolutions,
     I: Input,
 {
     SyncOnDiskRestartingMgr::builder()
@@ -771,6 +1362,27 @@ where
     /// The type of manager to build
     #[builder(default = SyncOnDiskManagerKind::Broker)]
     kind: SyncOnDiskManagerKind,
+    /// How long the broker waits for new files between two liveness checks
+    #[builder(default = DEFAULT_BROKER_POLL_INTERVAL)]
+    broker_poll_interval: Duration,
+    /// How often the broker rescans the whole sync dir
+    #[builder(default = DEFAULT_BROKER_RESCAN_INTERVAL)]
+    broker_rescan_interval: Duration,
+    /// Time without any activity after which the broker reports a client as stale
+    #[builder(default = DEFAULT_STALE_CLIENT_TIMEOUT)]
+    stale_client_timeout: Duration,
+    /// Use inotify to be notified of new files, instead of only polling the sync dir
+    #[builder(default = true)]
+    use_inotify: bool,
+    /// Save the client state periodically, in addition to restarts
+    #[builder(default = None)]
+    checkpoint_interval: Option<Duration>,
+    /// Let the broker merge the processed event files into segments
+    #[builder(default = None)]
+    compaction: Option<SyncDirCompactor>,
+    /// How often the broker compacts the sync dir
+    #[builder(default = DEFAULT_COMPACTION_INTERVAL)]
+    compaction_interval: Duration,
     #[builder(setter(skip), default = PhantomData)]
     phantom_data: PhantomData<(S, I)>,
 }
@@ -779,7 +1391,7 @@ where
 #[allow(clippy::type_complexity, clippy::too_many_lines)]
 impl<MT, S, I> SyncOnDiskRestartingMgr<MT, S, I>
 where
-    S: State + HasExecutions + HasCorpus,
+    S: State + HasExecutions + HasCorpus + HasSolutions,
     MT: Monitor + Clone,
     I: Input,
 {
@@ -794,22 +1406,230 @@ where
         // Launch broker loop or start Client fuzzer
         match self.kind {
             SyncOnDiskManagerKind::Broker => {
-                let event_broker = SyncOnDiskEventBroker::<S::Input, MT>::new(
-                    self.sync_dir.clone(),
-                    self.monitor.take().unwrap(),
-                )?;
+                let monitor = self
+                    .monitor
+                    .take()
+                    .ok_or_else(|| Error::illegal_argument("The broker needs a monitor"))?;
+                let event_broker = SyncOnDiskEventBroker::<S::Input, MT>::new(self.sync_dir.clone(), monitor)?
+                    .with_poll_interval(self.broker_poll_interval)
+                    .with_rescan_interval(self.broker_rescan_interval)
+                    .with_stale_timeout(self.stale_client_timeout)
+                    .with_inotify(self.use_inotify);
+                let event_broker = match self.compaction.take() {
+                    Some(compactor) => event_broker.with_compaction(compactor, self.compaction_interval),
+                    None => event_broker,
+                };
 
                 broker_things(event_broker)?;
                 unreachable!("The broker may never return normally, only on errors or when shutting down.");
             }
             SyncOnDiskManagerKind::Client { cpu_core } => {
                 // We are a client
-                let mgr = SyncOnDiskEventManager::<S, I>::new(cpu_core.unwrap(), self.sync_dir.clone(), self.configuration)?;
- 
-                let state = None;
-                return Ok((state, SyncOnDiskRestartingEventManager::new(mgr)));
+                let client_id = cpu_core.ok_or_else(|| Error::illegal_argument("A client needs an id"))?;
+                let mut mgr = SyncOnDiskEventManager::<S, I>::new(client_id, self.sync_dir.clone(), self.configuration)?;
+
+                // Reload the state of the previous run of this client, if it saved one
+                let state = match SyncOnDiskRestartingEventManager::<S, I>::load_state(&self.sync_dir, client_id)? {
+                    Some((state, position)) => {
+                        println!("Restored the state of client {}", client_id);
+                        mgr = mgr.with_position(position);
+                        Some(state)
+                    }
+                    None => None,
+                };
+
+                let mgr = SyncOnDiskRestartingEventManager::new(mgr)
+                    .with_checkpoint_interval(self.checkpoint_interval);
+                Ok((state, mgr))
             }
-        };
+        }
     }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use core::{marker::PhantomData, time::Duration};
+    use std::fs;
+
+    use libafl::{
+        corpus::InMemoryCorpus,
+        events::{Event, EventConfig, EventFirer, EventRestarter},
+        executors::ExitKind,
+        inputs::BytesInput,
+        state::{HasExecutions, StdState},
+    };
+    use libafl_bolts::rands::StdRand;
+
+    use super::{
+        deserialize_event, EventFileFormat, SyncFileKind, SyncOnDiskEventManager, SyncOnDiskRestartingEventManager,
+        SyncPosition,
+    };
+    use crate::event_file::{self, EventFileHeader, PayloadEncoding};
+
+    type TestState = StdState<BytesInput, InMemoryCorpus<BytesInput>, StdRand, InMemoryCorpus<BytesInput>>;
+
+    fn new_testcase() -> Event<BytesInput> {
+        Event::NewTestcase {
+            input: BytesInput::new(vec![0x13, 0x00, 0x00, 0x00]),
+            observers_buf: Some(vec![1, 2, 3]),
+            exit_kind: ExitKind::Ok,
+            corpus_size: 1,
+            client_config: EventConfig::from_name("default"),
+            time: Duration::from_secs(1),
+            executions: 42,
+            forward_id: None,
+        }
+    }
+
+    #[test]
+    fn detect_event_format() {
+        let event = new_testcase();
+
+        let json = serde_json::to_vec(&event).unwrap();
+        let (decoded, format) = deserialize_event::<BytesInput>(&json).unwrap();
+        assert_eq!(format, EventFileFormat::Json);
+        assert!(matches!(decoded, Event::NewTestcase { .. }));
+
+        let bin = bincode::serialize(&event).unwrap();
+        let (decoded, format) = deserialize_event::<BytesInput>(&bin).unwrap();
+        assert_eq!(format, EventFileFormat::Bincode);
+        assert!(matches!(decoded, Event::NewTestcase { .. }));
+
+        let stats = Event::<BytesInput>::UpdateExecStats {
+            time: Duration::from_secs(1),
+            executions: 1,
+            phantom: PhantomData,
+        };
+        let bin = bincode::serialize(&stats).unwrap();
+        assert!(matches!(
+            deserialize_event::<BytesInput>(&bin).unwrap().0,
+            Event::UpdateExecStats { .. }
+        ));
+    }
+
+    #[test]
+    fn reject_corrupted_event() {
+        let json = serde_json::to_vec(&new_testcase()).unwrap();
+        assert!(deserialize_event::<BytesInput>(&json[..json.len() / 2]).is_err());
+        assert!(deserialize_event::<BytesInput>(&[0xff; 7]).is_err());
+
+        // files with a header are checked against their checksum
+        let header = EventFileHeader::new(PayloadEncoding::Json, 1, 0, &json);
+        let mut file = header.to_bytes().to_vec();
+        file.extend_from_slice(&json);
+        assert!(deserialize_event::<BytesInput>(&file).is_ok());
+        let last = file.len() - 2;
+        file[last] ^= 0x20;
+        assert!(deserialize_event::<BytesInput>(&file).is_err());
+    }
+
+    #[test]
+    fn parse_sync_file_names() {
+        assert_eq!(
+            SyncFileKind::parse("client_3_42.event"),
+            Some(SyncFileKind::Event { client_id: 3, count: 42 })
+        );
+        assert_eq!(
+            SyncFileKind::parse("client_12.event.stats"),
+            Some(SyncFileKind::Stats { client_id: 12 })
+        );
+        assert_eq!(
+            SyncFileKind::parse("client_5.event.coverage"),
+            Some(SyncFileKind::Coverage { client_id: 5 })
+        );
+        assert_eq!(SyncFileKind::parse("client_3_42.event.presifuzz_lock"), None);
+        assert_eq!(SyncFileKind::parse("campaign_summary.json"), None);
+        assert_eq!(SyncFileKind::parse("client_3.stats_event.presifuzz_lock"), None);
+        assert_eq!(SyncFileKind::parse("quarantine"), None);
+    }
+
+    #[test]
+    fn save_and_restore_position() {
+        let dir = std::env::temp_dir().join(format!("presifuzz_saved_state_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let sync_dir = dir.to_str().unwrap();
+
+        let mut state: TestState = StdState::new(
+            StdRand::with_seed(0),
+            InMemoryCorpus::new(),
+            InMemoryCorpus::new(),
+            &mut (),
+            &mut (),
+        )
+        .unwrap();
+        *state.executions_mut() = 42;
+
+        let position = SyncPosition {
+            imported: ["client_2_0.event".to_string()].into(),
+            read_segments: ["segment_0.segment".to_string()].into(),
+        };
+        let mgr = SyncOnDiskEventManager::<TestState, BytesInput>::new(1, sync_dir.to_string(), EventConfig::AlwaysUnique)
+            .unwrap()
+            .with_position(position.clone());
+        let mut mgr = SyncOnDiskRestartingEventManager::new(mgr);
+        mgr.save_state(&state).unwrap();
+
+        let (restored, restored_position) =
+            SyncOnDiskRestartingEventManager::<TestState, BytesInput>::load_state(sync_dir, 1)
+                .unwrap()
+                .unwrap();
+        assert_eq!(*restored.executions(), 42);
+        assert_eq!(restored_position, position);
+        assert!(SyncOnDiskRestartingEventManager::<TestState, BytesInput>::load_state(sync_dir, 2)
+            .unwrap()
+            .is_none());
+
+        // exiting saves the state last seen by `process`
+        *state.executions_mut() = 43;
+        mgr.snapshot_state(&state).unwrap();
+        mgr.send_exiting().unwrap();
+        let (restored, _) = SyncOnDiskRestartingEventManager::<TestState, BytesInput>::load_state(sync_dir, 1)
+            .unwrap()
+            .unwrap();
+        assert_eq!(*restored.executions(), 43);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn rate_limited_stats() {
+        let dir = std::env::temp_dir().join(format!("presifuzz_stats_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let sync_dir = dir.to_str().unwrap();
+
+        let mut state: TestState = StdState::new(
+            StdRand::with_seed(0),
+            InMemoryCorpus::new(),
+            InMemoryCorpus::new(),
+            &mut (),
+            &mut (),
+        )
+        .unwrap();
+        let mut mgr = SyncOnDiskEventManager::<TestState, BytesInput>::new(1, sync_dir.to_string(), EventConfig::AlwaysUnique)
+            .unwrap()
+            .with_stats_interval(Duration::from_secs(3600));
+        let stats_lines = || {
+            let bytes = fs::read(dir.join("client_1.event.stats")).unwrap();
+            event_file::read_payload(&bytes).unwrap().1.split(|b| *b == b'\n').count()
+        };
+
+        for executions in 1..=3 {
+            let event = Event::UpdateExecStats {
+                time: Duration::from_secs(executions as u64),
+                executions,
+                phantom: PhantomData,
+            };
+            mgr.fire(&mut state, event).unwrap();
+        }
+        // the first stats are written, the next ones wait for the interval
+        assert_eq!(stats_lines(), 1);
+
+        mgr.send_exiting().unwrap();
+        assert_eq!(stats_lines(), 3);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
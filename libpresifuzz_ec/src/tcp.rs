@@ -0,0 +1,692 @@
+// SPDX-FileCopyrightText: 2024 Intel Corporation
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! TCP-backed event manager and broker, for clusters without a shared filesystem.
+//!
+//! Clients and broker exchange [`crate::Event`]s as frames: the payload length and the id of
+//! the sending client (both `u32` little endian), followed by the postcard-serialized event.
+//! On connection, the client sends its id (or [`UNDEFINED_CLIENT_ID`]) and the broker answers
+//! with the id it is known by, or [`UNDEFINED_CLIENT_ID`] when a client with that id is still
+//! connected. New testcases are forwarded to all the other clients; a client reconnecting
+//! with its id gets the testcases it missed in the meantime.
+
+use core::marker::PhantomData;
+use std::{
+    collections::HashMap,
+    io::{ErrorKind, Read, Write},
+    net::{Shutdown, SocketAddr, TcpListener, TcpStream, ToSocketAddrs},
+    path::PathBuf,
+    sync::Arc,
+    thread,
+    time::{Duration, Instant},
+};
+
+use libafl::{
+    events::{
+        BrokerEventResult, EventConfig, EventFirer, EventManager, EventManagerId, EventProcessor,
+        EventRestarter, HasEventManagerId, ProgressReporter,
+    },
+    executors::{Executor, HasObservers},
+    fuzzer::{EvaluatorObservers, ExecutionProcessor},
+    inputs::{Input, UsesInput},
+    monitors::Monitor,
+    state::{HasExecutions, HasLastReportTime, HasMetadata, State, UsesState},
+    Error,
+};
+use libafl_bolts::ClientId;
+use serde::Deserialize;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    sync::{broadcast, broadcast::error::RecvError, mpsc, Mutex},
+    task::{spawn, JoinHandle},
+};
+
+use crate::{
+    campaign::{CampaignTracker, DEFAULT_SUMMARY_INTERVAL},
+    manager::SyncOnDiskEventBroker,
+    Event,
+};
+
+/// The id a client sends when it wants the broker to pick one
+pub const UNDEFINED_CLIENT_ID: u32 = u32::MAX;
+/// Frames larger than this are rejected
+pub const MAX_FRAME_SIZE: u32 = 64 << 20;
+/// Default number of connection attempts before a client gives up
+pub const DEFAULT_RECONNECT_ATTEMPTS: usize = 30;
+/// Default time between two connection attempts
+pub const DEFAULT_RECONNECT_DELAY: Duration = Duration::from_secs(1);
+
+/// Size of the frame header: payload length and sender id
+const FRAME_HEADER_SIZE: usize = 8;
+
+/// Serialize `event` as a frame sent on behalf of `sender`
+pub fn encode_frame<I>(sender: u32, event: &Event<I>) -> Result<Vec<u8>, Error>
+where
+    I: Input,
+{
+    let payload = postcard::to_allocvec(event)?;
+    let len = u32::try_from(payload.len())
+        .ok()
+        .filter(|len| *len <= MAX_FRAME_SIZE)
+        .ok_or_else(|| Error::illegal_argument(format!("Event of {} bytes is too large", payload.len())))?;
+
+    let mut frame = Vec::with_capacity(FRAME_HEADER_SIZE + payload.len());
+    frame.extend_from_slice(&len.to_le_bytes());
+    frame.extend_from_slice(&sender.to_le_bytes());
+    frame.extend_from_slice(&payload);
+    Ok(frame)
+}
+
+/// Split the complete frames off the front of `buf`, returning their sender and payload.
+/// An incomplete trailing frame is left in `buf`.
+pub fn decode_frames(buf: &mut Vec<u8>) -> Result<Vec<(u32, Vec<u8>)>, Error> {
+    let mut frames = vec![];
+    let mut offset = 0;
+
+    while buf.len() - offset >= FRAME_HEADER_SIZE {
+        let len = u32::from_le_bytes(buf[offset..offset + 4].try_into().unwrap());
+        let sender = u32::from_le_bytes(buf[offset + 4..offset + 8].try_into().unwrap());
+        if len > MAX_FRAME_SIZE {
+            return Err(Error::illegal_state(format!("Frame of {} bytes is too large", len)));
+        }
+
+        let end = offset + FRAME_HEADER_SIZE + len as usize;
+        if buf.len() < end {
+            break;
+        }
+
+        frames.push((sender, buf[offset + FRAME_HEADER_SIZE..end].to_vec()));
+        offset = end;
+    }
+
+    buf.drain(..offset);
+    Ok(frames)
+}
+
+/// Tries to create (synchronously) a [`TcpListener`] that is `nonblocking` (for later use in tokio).
+/// Will error if the port is already in use (or other errors occur)
+fn create_nonblocking_listener<A: ToSocketAddrs>(addr: A) -> Result<TcpListener, Error> {
+    let listener = TcpListener::bind(addr)?;
+    listener.set_nonblocking(true)?;
+    Ok(listener)
+}
+
+/// The connections of a client known by the broker
+struct BrokerClient {
+    reader: JoinHandle<()>,
+    writer: JoinHandle<()>,
+    /// Frames to forward to the client, kept across reconnections
+    forwarded: Arc<Mutex<broadcast::Receiver<Arc<Vec<u8>>>>>,
+}
+
+/// A TCP broker handing the events of the clients to the monitor and forwarding their
+/// new testcases to the other clients
+#[derive(Debug)]
+pub struct TcpEventBroker<I, MT>
+where
+    I: Input,
+    MT: Monitor,
+{
+    monitor: MT,
+    /// A `nonblocking` [`TcpListener`] converted to a tokio listener in [`Self::broker_loop()`].
+    listener: Option<TcpListener>,
+    campaign: CampaignTracker,
+    /// Where to write the campaign summary, if anywhere
+    summary_file: Option<PathBuf>,
+    summary_interval: Duration,
+    phantom: PhantomData<I>,
+}
+
+impl<I, MT> TcpEventBroker<I, MT>
+where
+    I: Input,
+    MT: Monitor,
+{
+    /// Create a TCP broker, listening on the given address
+    pub fn new<A: ToSocketAddrs>(addr: A, monitor: MT) -> Result<Self, Error> {
+        Ok(Self {
+            monitor,
+            listener: Some(create_nonblocking_listener(addr)?),
+            campaign: CampaignTracker::new(),
+            summary_file: None,
+            summary_interval: DEFAULT_SUMMARY_INTERVAL,
+            phantom: PhantomData,
+        })
+    }
+
+    /// Write the campaign summary to `summary_file` every `summary_interval`
+    #[must_use]
+    pub fn with_campaign_summary(mut self, summary_file: PathBuf, summary_interval: Duration) -> Self {
+        self.summary_file = Some(summary_file);
+        self.summary_interval = summary_interval;
+        self
+    }
+
+    /// The address the broker listens on, e.g. to find the port picked for `127.0.0.1:0`
+    pub fn local_addr(&self) -> Result<SocketAddr, Error> {
+        self.listener
+            .as_ref()
+            .ok_or_else(|| Error::illegal_state("The broker loop already took the listener"))?
+            .local_addr()
+            .map_err(Error::from)
+    }
+
+    /// Run in the broker until an error occurs
+    #[tokio::main(flavor = "current_thread")]
+    pub async fn broker_loop(&mut self) -> Result<(), Error> {
+        let (tx_bc, _) = broadcast::channel::<Arc<Vec<u8>>>(65536);
+        let (tx, mut rx_mpsc) = mpsc::channel::<(u32, Vec<u8>)>(65536);
+
+        let listener = self
+            .listener
+            .take()
+            .ok_or_else(|| Error::illegal_state("Listener has already been used / was none"))?;
+        let listener = tokio::net::TcpListener::from_std(listener)?;
+
+        let mut last_summary = Instant::now();
+
+        let tx_forward = tx_bc.clone();
+        spawn(async move {
+            let mut clients: HashMap<u32, BrokerClient> = HashMap::new();
+
+            loop {
+                let socket = match listener.accept().await {
+                    Ok((socket, _)) => socket,
+                    Err(err) => {
+                        println!("Unable to accept a client: {}", err);
+                        continue;
+                    }
+                };
+                let (mut read, mut write) = tokio::io::split(socket);
+
+                // Protocol: the client sends its id, or UNDEFINED_CLIENT_ID to get a new one
+                let mut id_buf = [0; 4];
+                if read.read_exact(&mut id_buf).await.is_err() {
+                    continue;
+                }
+                let client_id = match u32::from_le_bytes(id_buf) {
+                    UNDEFINED_CLIENT_ID => (0..UNDEFINED_CLIENT_ID)
+                        .find(|id| !clients.contains_key(id))
+                        .unwrap_or(UNDEFINED_CLIENT_ID),
+                    client_id => client_id,
+                };
+
+                // an id is only taken over once its previous connection is closed, a new
+                // client asking for the id of a running one is turned away
+                if clients.get(&client_id).is_some_and(|client| !client.reader.is_finished()) {
+                    println!("Client {} is already connected, rejecting the new connection", client_id);
+                    let _ = write.write_all(&UNDEFINED_CLIENT_ID.to_le_bytes()).await;
+                    continue;
+                }
+
+                // Only one connection per client: a reconnecting client replaces the old one,
+                // the testcases forwarded meanwhile are waiting in its receiver
+                let forwarded = match clients.remove(&client_id) {
+                    Some(old) => {
+                        println!("Client {} reconnected", client_id);
+                        old.reader.abort();
+                        old.writer.abort();
+                        old.forwarded
+                    }
+                    None => Arc::new(Mutex::new(tx_forward.subscribe())),
+                };
+
+                if write.write_all(&client_id.to_le_bytes()).await.is_err() {
+                    continue;
+                }
+
+                let tx_inner = tx.clone();
+                let reader = spawn(async move {
+                    let mut header = [0; FRAME_HEADER_SIZE];
+                    loop {
+                        // The socket is closed, the client is restarting
+                        if read.read_exact(&mut header).await.is_err() {
+                            return;
+                        }
+
+                        let len = u32::from_le_bytes(header[..4].try_into().unwrap());
+                        if len > MAX_FRAME_SIZE {
+                            println!("Client {} sent a frame of {} bytes, disconnecting", client_id, len);
+                            return;
+                        }
+
+                        let mut payload = vec![0; len as usize];
+                        if read.read_exact(&mut payload).await.is_err() {
+                            return;
+                        }
+
+                        if tx_inner.send((client_id, payload)).await.is_err() {
+                            return;
+                        }
+                    }
+                });
+
+                let forwarded_inner = forwarded.clone();
+                let writer = spawn(async move {
+                    let mut forwarded = forwarded_inner.lock().await;
+                    loop {
+                        let frame = match forwarded.recv().await {
+                            Ok(frame) => frame,
+                            Err(RecvError::Lagged(num)) => {
+                                println!("Client {} lagged, skipping {} events", client_id, num);
+                                continue;
+                            }
+                            Err(RecvError::Closed) => return,
+                        };
+
+                        // Do not send a client its own testcases back
+                        if frame[4..FRAME_HEADER_SIZE] == client_id.to_le_bytes() {
+                            continue;
+                        }
+
+                        if write.write_all(&frame).await.is_err() {
+                            return;
+                        }
+                    }
+                });
+
+                clients.insert(
+                    client_id,
+                    BrokerClient {
+                        reader,
+                        writer,
+                        forwarded,
+                    },
+                );
+            }
+        });
+
+        while let Some((client_id, payload)) = rx_mpsc.recv().await {
+            let event: Event<I> = match postcard::from_bytes(&payload) {
+                Ok(event) => event,
+                Err(err) => {
+                    println!("Invalid event from client {}: {}", client_id, err);
+                    continue;
+                }
+            };
+
+            let mut event: libafl::events::Event<I> = event.into();
+            self.campaign.on_event(client_id, &event)?;
+            let result = SyncOnDiskEventBroker::<I, MT>::handle_in_broker(
+                &mut self.monitor,
+                ClientId(client_id),
+                &event,
+            )?;
+
+            let forward = matches!(event, libafl::events::Event::NewTestcase { .. })
+                || matches!(result, BrokerEventResult::Forward);
+            if forward {
+                if let libafl::events::Event::NewTestcase { forward_id, .. } = &mut event {
+                    forward_id.get_or_insert(ClientId(client_id));
+                }
+                let frame = encode_frame(client_id, &Event::from(event))?;
+                // nobody is listening until the first client connected
+                let _ = tx_bc.send(Arc::new(frame));
+            }
+
+            if let Some(summary_file) = &self.summary_file {
+                if last_summary.elapsed() >= self.summary_interval {
+                    if let Err(err) = self.campaign.write_summary(summary_file) {
+                        println!("Unable to write {:?}: {}", summary_file, err);
+                    }
+                    last_summary = Instant::now();
+                }
+            }
+        }
+
+        Err(Error::shutting_down())
+    }
+}
+
+/// A connection to the [`TcpEventBroker`], reconnecting when the broker goes away
+#[derive(Debug)]
+pub struct TcpEventClient {
+    addrs: Vec<SocketAddr>,
+    tcp: TcpStream,
+    client_id: u32,
+    /// Bytes received but not forming a complete frame yet
+    buf: Vec<u8>,
+    reconnect_attempts: usize,
+    reconnect_delay: Duration,
+}
+
+impl TcpEventClient {
+    /// Connect to the broker at `addr` as `client_id`, or as a new client if
+    /// [`UNDEFINED_CLIENT_ID`]
+    pub fn connect<A: ToSocketAddrs>(addr: A, client_id: u32) -> Result<Self, Error> {
+        let addrs: Vec<SocketAddr> = addr.to_socket_addrs()?.collect();
+        let (tcp, client_id) = Self::handshake(&addrs, client_id)?;
+
+        Ok(Self {
+            addrs,
+            tcp,
+            client_id,
+            buf: vec![],
+            reconnect_attempts: DEFAULT_RECONNECT_ATTEMPTS,
+            reconnect_delay: DEFAULT_RECONNECT_DELAY,
+        })
+    }
+
+    /// Set how many times, and how often, the client tries to reconnect to the broker
+    #[must_use]
+    pub fn with_reconnect(mut self, reconnect_attempts: usize, reconnect_delay: Duration) -> Self {
+        self.reconnect_attempts = reconnect_attempts;
+        self.reconnect_delay = reconnect_delay;
+        self
+    }
+
+    /// The id the broker knows this client by
+    #[must_use]
+    pub fn client_id(&self) -> u32 {
+        self.client_id
+    }
+
+    fn handshake(addrs: &[SocketAddr], client_id: u32) -> Result<(TcpStream, u32), Error> {
+        let mut tcp = TcpStream::connect(addrs)?;
+        tcp.set_nodelay(true)?;
+
+        let mut id_buf = client_id.to_le_bytes();
+        tcp.write_all(&id_buf)?;
+        tcp.read_exact(&mut id_buf)?;
+
+        match u32::from_le_bytes(id_buf) {
+            UNDEFINED_CLIENT_ID => Err(Error::illegal_state(format!("Client {client_id} is already connected"))),
+            client_id => Ok((tcp, client_id)),
+        }
+    }
+
+    /// Open a new connection to the broker, keeping our id
+    pub fn reconnect(&mut self) -> Result<(), Error> {
+        let mut last_err = None;
+        // the broker hands our id over once the previous connection is closed
+        let _ = self.tcp.shutdown(Shutdown::Both);
+
+        for attempt in 0..self.reconnect_attempts.max(1) {
+            if attempt > 0 {
+                thread::sleep(self.reconnect_delay);
+            }
+
+            match Self::handshake(&self.addrs, self.client_id) {
+                Ok((tcp, client_id)) => {
+                    self.tcp = tcp;
+                    self.client_id = client_id;
+                    // a frame cut by the disconnection is lost
+                    self.buf.clear();
+                    return Ok(());
+                }
+                Err(err) => last_err = Some(err),
+            }
+        }
+
+        Err(last_err.unwrap_or_else(|| Error::unknown("Unable to reconnect to the broker")))
+    }
+
+    /// Send an event to the broker, reconnecting once if the connection was lost
+    pub fn send<I>(&mut self, event: &Event<I>) -> Result<(), Error>
+    where
+        I: Input,
+    {
+        let frame = encode_frame(self.client_id, event)?;
+
+        if self.tcp.write_all(&frame).is_err() {
+            self.reconnect()?;
+            self.tcp.write_all(&frame)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the events forwarded by the broker since the last call, without blocking
+    pub fn recv<I>(&mut self) -> Result<Vec<(ClientId, Event<I>)>, Error>
+    where
+        I: Input,
+    {
+        let mut disconnected = false;
+        let mut chunk = [0_u8; 4096];
+
+        self.tcp.set_nonblocking(true)?;
+        loop {
+            match self.tcp.read(&mut chunk) {
+                Ok(0) => {
+                    disconnected = true;
+                    break;
+                }
+                Ok(n) => self.buf.extend_from_slice(&chunk[..n]),
+                Err(e) if e.kind() == ErrorKind::WouldBlock => break,
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(_) => {
+                    disconnected = true;
+                    break;
+                }
+            }
+        }
+        self.tcp.set_nonblocking(false)?;
+
+        let mut events = vec![];
+        for (sender, payload) in decode_frames(&mut self.buf)? {
+            match postcard::from_bytes(&payload) {
+                Ok(event) => events.push((ClientId(sender), event)),
+                Err(err) => println!("Invalid event from client {}: {}", sender, err),
+            }
+        }
+
+        if disconnected {
+            self.reconnect()?;
+        }
+
+        Ok(events)
+    }
+}
+
+/// An [`EventManager`] exchanging events with the other clients through a [`TcpEventBroker`]
+#[derive(Debug)]
+pub struct TcpEventManager<S>
+where
+    S: State,
+{
+    client: TcpEventClient,
+    /// The configuration defines this specific fuzzer.
+    /// A node will not re-use the observer values sent over TCP
+    /// from nodes with other configurations.
+    configuration: EventConfig,
+    phantom: PhantomData<S>,
+}
+
+impl<S> TcpEventManager<S>
+where
+    S: State,
+{
+    /// Create a manager connected to the broker at `addr`
+    pub fn new<A: ToSocketAddrs>(addr: A, client_id: u32, configuration: EventConfig) -> Result<Self, Error> {
+        Ok(Self::with_client(TcpEventClient::connect(addr, client_id)?, configuration))
+    }
+
+    /// Create a manager from an existing connection
+    #[must_use]
+    pub fn with_client(client: TcpEventClient, configuration: EventConfig) -> Self {
+        Self {
+            client,
+            configuration,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<S> UsesState for TcpEventManager<S>
+where
+    S: State,
+{
+    type State = S;
+}
+
+impl<S> EventFirer for TcpEventManager<S>
+where
+    S: State,
+{
+    fn fire(
+        &mut self,
+        _state: &mut Self::State,
+        event: libafl::events::Event<<Self::State as UsesInput>::Input>,
+    ) -> Result<(), Error> {
+        self.client.send(&Event::from(event))
+    }
+
+    fn configuration(&self) -> EventConfig {
+        self.configuration
+    }
+}
+
+impl<S> EventRestarter for TcpEventManager<S> where S: State {}
+
+impl<E, S, Z> EventProcessor<E, Z> for TcpEventManager<S>
+where
+    S: State + HasExecutions,
+    E: HasObservers<State = S> + Executor<Self, Z>,
+    for<'a> E::Observers: Deserialize<'a>,
+    Z: EvaluatorObservers<E::Observers, State = S> + ExecutionProcessor<E::Observers, State = S>,
+{
+    fn process(&mut self, fuzzer: &mut Z, state: &mut Self::State, executor: &mut E) -> Result<usize, Error> {
+        let mut handled = 0;
+
+        for (_, event) in self.client.recv::<S::Input>()? {
+            if let Event::NewTestcase {
+                input,
+                client_config,
+                exit_kind,
+                observers_buf,
+                ..
+            } = event
+            {
+                // trust the observers of clients running the same configuration,
+                // re-execute the input otherwise
+                match observers_buf {
+                    Some(observers_buf) if self.configuration.match_with(&client_config) => {
+                        let observers: E::Observers = match postcard::from_bytes(&observers_buf) {
+                            Ok(observers) => observers,
+                            Err(err) => {
+                                println!("Unable to deserialize the observers of a testcase, skipping: {}", err);
+                                continue;
+                            }
+                        };
+                        fuzzer.process_execution(state, self, input, &observers, &exit_kind, false)?;
+                    }
+                    _ => {
+                        fuzzer.evaluate_input_with_observers::<E, Self>(state, executor, self, input, false)?;
+                    }
+                }
+                handled += 1;
+            }
+        }
+
+        Ok(handled)
+    }
+}
+
+impl<E, S, Z> EventManager<E, Z> for TcpEventManager<S>
+where
+    E: HasObservers<State = S> + Executor<Self, Z>,
+    for<'a> E::Observers: Deserialize<'a>,
+    S: State + HasExecutions + HasMetadata + HasLastReportTime,
+    Z: EvaluatorObservers<E::Observers, State = S> + ExecutionProcessor<E::Observers, State = S>,
+{
+}
+
+impl<S> ProgressReporter for TcpEventManager<S> where
+    S: State + HasExecutions + HasMetadata + HasLastReportTime
+{
+}
+
+impl<S> HasEventManagerId for TcpEventManager<S>
+where
+    S: State,
+{
+    fn mgr_id(&self) -> EventManagerId {
+        EventManagerId(self.client.client_id() as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::mpsc,
+        thread,
+        time::{Duration, Instant},
+    };
+
+    use libafl::{
+        events::EventConfig,
+        executors::ExitKind,
+        inputs::{BytesInput, HasBytesVec},
+        monitors::NopMonitor,
+    };
+    use libafl_bolts::ClientId;
+
+    use super::{TcpEventBroker, TcpEventClient, UNDEFINED_CLIENT_ID};
+    use crate::Event;
+
+    fn new_testcase(bytes: &[u8]) -> Event<BytesInput> {
+        Event::NewTestcase {
+            input: BytesInput::new(bytes.to_vec()),
+            observers_buf: None,
+            exit_kind: ExitKind::Ok,
+            corpus_size: 1,
+            client_config: EventConfig::AlwaysUnique,
+            time: Duration::ZERO,
+            executions: 1,
+            forward_id: None,
+        }
+    }
+
+    fn recv_one(client: &mut TcpEventClient) -> (ClientId, Event<BytesInput>) {
+        let start = Instant::now();
+        while start.elapsed() < Duration::from_secs(5) {
+            if let Some(event) = client.recv().unwrap().pop() {
+                return event;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+        panic!("No event received");
+    }
+
+    #[test]
+    fn forward_testcases_across_reconnects() {
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            let mut broker = TcpEventBroker::<BytesInput, _>::new("127.0.0.1:0", NopMonitor::new()).unwrap();
+            tx.send(broker.local_addr().unwrap()).unwrap();
+            let _ = broker.broker_loop();
+        });
+        let addr = rx.recv().unwrap();
+
+        let mut client1 = TcpEventClient::connect(addr, 1).unwrap();
+        let mut client2 = TcpEventClient::connect(addr, 2)
+            .unwrap()
+            .with_reconnect(50, Duration::from_millis(20));
+
+        // the id of a connected client is not given to another one
+        assert!(TcpEventClient::connect(addr, 1).is_err());
+        let client3 = TcpEventClient::connect(addr, UNDEFINED_CLIENT_ID).unwrap();
+        assert_eq!(client3.client_id(), 0);
+        assert!(TcpEventClient::connect(addr, 0).is_err());
+
+        client1.send(&new_testcase(b"abc")).unwrap();
+        match recv_one(&mut client2) {
+            (sender, Event::NewTestcase { input, forward_id, .. }) => {
+                assert_eq!(sender, ClientId(1));
+                assert_eq!(forward_id, Some(ClientId(1)));
+                assert_eq!(input.bytes(), b"abc");
+            }
+            _ => panic!("Expected a new testcase"),
+        }
+
+        // a client keeps its id and its testcases across reconnections
+        client2.reconnect().unwrap();
+        assert_eq!(client2.client_id(), 2);
+        client1.send(&new_testcase(b"def")).unwrap();
+        assert!(matches!(recv_one(&mut client2).1, Event::NewTestcase { input, .. } if input.bytes() == b"def"));
+
+        // testcases are not sent back to their client
+        assert!(client1.recv::<BytesInput>().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,135 @@
+// SPDX-FileCopyrightText: 2024 Intel Corporation
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Change notification for the sync dir: inotify when available, polling otherwise.
+//! inotify does not see files written by remote NFS clients, so callers should still
+//! rescan the directory from time to time.
+
+use std::{os::unix::io::AsRawFd, thread, time::Duration};
+
+use nix::{
+    errno::Errno,
+    poll::{poll, PollFd, PollFlags},
+    sys::inotify::{AddWatchFlags, InitFlags, Inotify},
+};
+
+/// Watches the sync dir for new or updated files
+#[derive(Debug)]
+pub enum SyncDirWatcher {
+    /// Files are reported as soon as they are renamed into place or closed after writing
+    Inotify(Inotify),
+    /// No notification, the whole directory has to be rescanned
+    Polling,
+}
+
+impl SyncDirWatcher {
+    /// Watch `sync_dir`, falling back to polling if inotify is disabled or unavailable
+    #[must_use]
+    pub fn new(sync_dir: &str, use_inotify: bool) -> Self {
+        if !use_inotify {
+            return SyncDirWatcher::Polling;
+        }
+
+        let inotify = match Inotify::init(InitFlags::IN_NONBLOCK | InitFlags::IN_CLOEXEC) {
+            Ok(inotify) => inotify,
+            Err(err) => {
+                println!("inotify is not available ({}), polling {}", err, sync_dir);
+                return SyncDirWatcher::Polling;
+            }
+        };
+
+        let mask = AddWatchFlags::IN_MOVED_TO | AddWatchFlags::IN_CLOSE_WRITE;
+        match inotify.add_watch(sync_dir, mask) {
+            Ok(_) => SyncDirWatcher::Inotify(inotify),
+            Err(err) => {
+                println!("Unable to watch {} ({}), polling instead", sync_dir, err);
+                let _ = nix::unistd::close(inotify.as_raw_fd());
+                SyncDirWatcher::Polling
+            }
+        }
+    }
+
+    /// Wait up to `timeout` for changes. Returns the names of the files that changed,
+    /// or `None` if the caller has to rescan the whole directory.
+    pub fn wait(&self, timeout: Duration) -> Option<Vec<String>> {
+        let inotify = match self {
+            SyncDirWatcher::Inotify(inotify) => inotify,
+            SyncDirWatcher::Polling => {
+                thread::sleep(timeout);
+                return None;
+            }
+        };
+
+        let mut fds = [PollFd::new(inotify.as_raw_fd(), PollFlags::POLLIN)];
+        let timeout_ms = i32::try_from(timeout.as_millis()).unwrap_or(i32::MAX);
+        match poll(&mut fds, timeout_ms) {
+            Ok(0) | Err(Errno::EINTR) => return Some(vec![]),
+            Ok(_) => {}
+            Err(_) => return None,
+        }
+
+        let mut names = vec![];
+        loop {
+            match inotify.read_events() {
+                Ok(events) => {
+                    for event in events {
+                        if event.mask.contains(AddWatchFlags::IN_Q_OVERFLOW) {
+                            return None;
+                        }
+                        if let Some(name) = event.name.and_then(|n| n.into_string().ok()) {
+                            names.push(name);
+                        }
+                    }
+                }
+                Err(Errno::EAGAIN) => break,
+                Err(_) => return None,
+            }
+        }
+
+        names.sort();
+        names.dedup();
+        Some(names)
+    }
+}
+
+impl Drop for SyncDirWatcher {
+    fn drop(&mut self) {
+        if let SyncDirWatcher::Inotify(inotify) = self {
+            let _ = nix::unistd::close(inotify.as_raw_fd());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, time::Duration};
+
+    use super::SyncDirWatcher;
+
+    #[test]
+    fn notify_renamed_files() {
+        let dir = std::env::temp_dir().join(format!("presifuzz_watch_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let sync_dir = dir.to_str().unwrap();
+
+        let watcher = SyncDirWatcher::new(sync_dir, true);
+        if let SyncDirWatcher::Polling = watcher {
+            // inotify is not available in this environment
+            fs::remove_dir_all(&dir).unwrap();
+            return;
+        }
+
+        assert_eq!(watcher.wait(Duration::from_millis(10)), Some(vec![]));
+
+        fs::write(dir.join("client_1_0.event.presifuzz_lock"), b"{}").unwrap();
+        fs::rename(dir.join("client_1_0.event.presifuzz_lock"), dir.join("client_1_0.event")).unwrap();
+
+        let names = watcher.wait(Duration::from_secs(1)).unwrap();
+        assert!(names.contains(&"client_1_0.event".to_string()));
+
+        assert_eq!(SyncDirWatcher::new(sync_dir, false).wait(Duration::ZERO), None);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
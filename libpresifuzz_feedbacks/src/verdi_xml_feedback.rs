@@ -31,6 +31,7 @@ use libafl::inputs::Input;
 use libafl::prelude::MapFeedbackMetadata;
 use libafl::state::HasMetadata;
 
+use libpresifuzz_ec::campaign::coverage_map_event;
 use libpresifuzz_observers::verdi_xml_observer::VerdiXMLMapObserver as VerdiObserver;
 
 /// Nop feedback that annotates execution time in the new testcase, if any
@@ -131,6 +132,9 @@ where
                 },
             )?;
 
+            // Share the whole map, the broker merges the maps of all the clients
+            manager.fire(state, coverage_map_event(self.name(), &self.history))?;
+
             // Save scrore into state
             manager.fire(
                 state,
@@ -0,0 +1,283 @@
+// SPDX-FileCopyrightText: 2024 Intel Corporation
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Crossover between corpus entries at instruction boundaries.
+//!
+//! The [`ISASpliceMutator`] replaces the tail of the input with the tail of another
+//! testcase, the [`ISACrossoverInsertMutator`] inserts a range of instructions of another
+//! testcase. The other testcase is decoded like the input, so instructions are never cut.
+//! When the moved instructions would land on a different 4-byte alignment, a `c.nop` is
+//! inserted so that 4-byte instructions keep their alignment; without the C extension in
+//! the profile, the mutation is skipped instead.
+
+use libafl::{
+    corpus::Corpus,
+    inputs::{HasBytesVec, UsesInput},
+    mutators::MutationResult,
+    random_corpus_id,
+    state::{HasCorpus, HasMaxSize, HasRand},
+    Error,
+};
+use libafl_bolts::{rands::Rand, HasLen, Named};
+use libpresifuzz_riscv::instruction::Instruction;
+use libpresifuzz_riscv::profile::CpuProfile;
+
+use crate::{byte_len, cuts_bounded_loop, ISAInput, ISAMutator};
+
+/// Encoding of `c.nop`
+const C_NOP: u16 = 0x0001;
+
+/// Decodes the other testcases, with the instructions of a profile if any
+#[derive(Debug, Default)]
+struct OtherTestcase {
+    profile: Option<CpuProfile>,
+}
+
+impl OtherTestcase {
+    fn decode(&self, bytes: &[u8]) -> ISAInput {
+        match &self.profile {
+            Some(profile) => ISAInput::new_with_profile(bytes, profile),
+            None => ISAInput::new(bytes),
+        }
+    }
+
+    /// The instructions of a random corpus entry other than the current one
+    fn instructions<S>(&self, state: &mut S) -> Result<Option<Vec<Instruction>>, Error>
+    where
+        S: HasCorpus + HasRand,
+        <S as UsesInput>::Input: HasBytesVec,
+    {
+        if state.corpus().count() == 0 {
+            return Ok(None);
+        }
+        let idx = random_corpus_id!(state.corpus(), state.rand_mut());
+        if state.corpus().current().is_some_and(|cur| cur == idx) {
+            return Ok(None);
+        }
+
+        let mut other_testcase = state.corpus().get(idx)?.borrow_mut();
+        let other = other_testcase.load_input(state.corpus())?;
+        let instructions = self.decode(other.bytes()).instructions;
+
+        Ok((!instructions.is_empty()).then_some(instructions))
+    }
+
+    /// Move `chunk`, found at byte offset `from`, to byte offset `to`: prepend a `c.nop`
+    /// when the 4-byte alignment differs, and append one when `pad_end` and the chunk is
+    /// not a multiple of 4 bytes. `None` when a `c.nop` is needed but not in the profile.
+    fn align(&self, mut chunk: Vec<Instruction>, from: usize, to: usize, pad_end: bool) -> Option<Vec<Instruction>> {
+        let mut nop = None;
+        let mut c_nop = || -> Option<Instruction> {
+            if nop.is_none() {
+                nop = self.decode(&C_NOP.to_le_bytes()).instructions.pop();
+            }
+            nop.clone()
+        };
+
+        if from % 4 != to % 4 {
+            chunk.insert(0, c_nop()?);
+        }
+        if pad_end && !byte_len(&chunk).is_multiple_of(4) {
+            chunk.push(c_nop()?);
+        }
+        Some(chunk)
+    }
+}
+
+/// Replaces the tail of the input with the tail of another testcase
+#[derive(Debug, Default)]
+pub struct ISASpliceMutator {
+    other: OtherTestcase,
+}
+
+impl<S> ISAMutator<S> for ISASpliceMutator
+where
+    S: HasRand + HasMaxSize + HasCorpus,
+    <S as UsesInput>::Input: HasBytesVec,
+{
+    fn mutate(
+        &mut self,
+        state: &mut S,
+        input: &mut ISAInput,
+        _stage_idx: i32,
+    ) -> Result<MutationResult, Error> {
+        if input.instructions.is_empty() {
+            return Ok(MutationResult::Skipped);
+        }
+        let Some(other) = self.other.instructions(state)? else {
+            return Ok(MutationResult::Skipped);
+        };
+
+        let split_at = state.rand_mut().below(input.instructions.len() as u64) as usize;
+        let other_split_at = state.rand_mut().below(other.len() as u64) as usize;
+        if cuts_bounded_loop(&input.instructions, split_at..input.instructions.len()) {
+            return Ok(MutationResult::Skipped);
+        }
+
+        let from = byte_len(&other[..other_split_at]);
+        let to = byte_len(&input.instructions[..split_at]);
+        let Some(tail) = self.other.align(other[other_split_at..].to_vec(), from, to, false) else {
+            return Ok(MutationResult::Skipped);
+        };
+
+        if to + byte_len(&tail) > state.max_size() {
+            return Ok(MutationResult::Skipped);
+        }
+
+        input.instructions.splice(split_at.., tail);
+
+        Ok(MutationResult::Mutated)
+    }
+}
+
+impl Named for ISASpliceMutator {
+    fn name(&self) -> &str {
+        "ISASpliceMutator"
+    }
+}
+
+impl ISASpliceMutator {
+    /// Creates a new [`ISASpliceMutator`]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Decode the other testcases with the instructions of `profile` only
+    #[must_use]
+    pub fn with_profile(profile: CpuProfile) -> Self {
+        Self {
+            other: OtherTestcase { profile: Some(profile) },
+        }
+    }
+}
+
+/// Inserts a range of instructions of another testcase into the input
+#[derive(Debug, Default)]
+pub struct ISACrossoverInsertMutator {
+    other: OtherTestcase,
+}
+
+impl<S> ISAMutator<S> for ISACrossoverInsertMutator
+where
+    S: HasRand + HasMaxSize + HasCorpus,
+    <S as UsesInput>::Input: HasBytesVec,
+{
+    fn mutate(
+        &mut self,
+        state: &mut S,
+        input: &mut ISAInput,
+        _stage_idx: i32,
+    ) -> Result<MutationResult, Error> {
+        let Some(other) = self.other.instructions(state)? else {
+            return Ok(MutationResult::Skipped);
+        };
+
+        let start = state.rand_mut().below(other.len() as u64) as usize;
+        let end = state.rand_mut().between(start as u64 + 1, other.len() as u64) as usize;
+        let idx = state.rand_mut().below(input.instructions.len() as u64 + 1) as usize;
+        if cuts_bounded_loop(&input.instructions, idx..idx) {
+            return Ok(MutationResult::Skipped);
+        }
+
+        let from = byte_len(&other[..start]);
+        let to = byte_len(&input.instructions[..idx]);
+        let Some(chunk) = self.other.align(other[start..end].to_vec(), from, to, true) else {
+            return Ok(MutationResult::Skipped);
+        };
+
+        if input.len() + byte_len(&chunk) > state.max_size() {
+            return Ok(MutationResult::Skipped);
+        }
+
+        input.instructions.splice(idx..idx, chunk);
+
+        Ok(MutationResult::Mutated)
+    }
+}
+
+impl Named for ISACrossoverInsertMutator {
+    fn name(&self) -> &str {
+        "ISACrossoverInsertMutator"
+    }
+}
+
+impl ISACrossoverInsertMutator {
+    /// Creates a new [`ISACrossoverInsertMutator`]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Decode the other testcases with the instructions of `profile` only
+    #[must_use]
+    pub fn with_profile(profile: CpuProfile) -> Self {
+        Self {
+            other: OtherTestcase { profile: Some(profile) },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use libafl::{
+        corpus::{Corpus, InMemoryCorpus, Testcase},
+        inputs::BytesInput,
+        mutators::MutationResult,
+        state::{HasCorpus, HasMaxSize, StdState},
+    };
+    use libafl_bolts::rands::StdRand;
+
+    use super::{ISACrossoverInsertMutator, ISASpliceMutator};
+    use crate::{ISAInput, ISAMutator};
+
+    /// Whether every 4-byte instruction is 4-byte aligned
+    fn aligned(input: &ISAInput) -> bool {
+        let mut offset = 0usize;
+        input.instructions.iter().all(|insn| {
+            let ok = insn.length == 2 || offset.is_multiple_of(4);
+            offset += insn.length;
+            ok
+        })
+    }
+
+    #[test]
+    fn crossover_keeps_alignment() {
+        // c.addi x1,1 / c.nop / add x1,x2,x3 / c.addi x2,1 / c.addi x2,1 / sub x4,x5,x6
+        let words: Vec<u8> = [
+            &0x0085u16.to_le_bytes()[..],
+            &0x0001u16.to_le_bytes()[..],
+            &0x003100b3u32.to_le_bytes()[..],
+            &0x0105u16.to_le_bytes()[..],
+            &0x0105u16.to_le_bytes()[..],
+            &0x40628233u32.to_le_bytes()[..],
+        ]
+        .concat();
+        let program = ISAInput::new(&words);
+        assert!(aligned(&program));
+
+        let mut state = StdState::new(
+            StdRand::with_seed(0),
+            InMemoryCorpus::<BytesInput>::new(),
+            InMemoryCorpus::new(),
+            &mut (),
+            &mut (),
+        )
+        .unwrap();
+        state.corpus_mut().add(Testcase::new(BytesInput::new(words.clone()))).unwrap();
+        state.set_max_size(1024);
+
+        let mut splice = ISASpliceMutator::new();
+        let mut insert = ISACrossoverInsertMutator::new();
+        let mut mutated = 0;
+        for _ in 0..100 {
+            let mut input = program.clone();
+            mutated += usize::from(splice.mutate(&mut state, &mut input, 0).unwrap() == MutationResult::Mutated);
+            assert!(aligned(&input));
+            mutated += usize::from(insert.mutate(&mut state, &mut input, 0).unwrap() == MutationResult::Mutated);
+            assert!(aligned(&input));
+        }
+        assert!(mutated > 100);
+    }
+}
@@ -0,0 +1,470 @@
+// SPDX-FileCopyrightText: 2024 Intel Corporation
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Windows of instructions with controlled data dependencies.
+//!
+//! Pipeline hazards (RAW chains, load-use stalls, store-to-load forwarding) rarely arise
+//! from independent random instructions. The [`DependencyMutator`] inserts, or writes over,
+//! a window of instructions following a [`DependencyPattern`], with a chosen chain length,
+//! distance between producer and consumer, and address aliasing between stores and loads.
+//! Loads and stores are only generated with memory regions, their base register is set up
+//! by the address prologue of the [`MemoryAccessMutator`].
+
+use libafl::{
+    mutators::MutationResult,
+    state::{HasMaxSize, HasRand},
+    Error,
+};
+use libafl_bolts::{rands::Rand, HasLen, Named};
+use libpresifuzz_riscv::instruction::{Instruction, OperandKind};
+use libpresifuzz_riscv::profile::CpuProfile;
+
+use crate::memory::{MemoryAccessConf, MemoryAccessMutator, BASE_REGISTERS};
+use crate::operands::OperandDictionary;
+use crate::{cuts_bounded_loop, ISAInput, ISAMutator};
+
+/// Bits of the `rs2` field, the data register of stores
+const RS2: (u32, u32) = (20, 24);
+
+/// The dependency pattern of a generated window
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DependencyPattern {
+    /// Each instruction reads the result of the previous one
+    RawChain,
+    /// A load followed by a chain of instructions using the loaded value
+    LoadUse,
+    /// A store followed by a load of the same, or a nearby, address, then a load-use chain
+    StoreToLoad,
+}
+
+/// Shape of the windows generated by the [`DependencyMutator`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct DependencyMutatorConf {
+    /// Number of dependent instructions of a window (at least 2)
+    pub max_chain_length: usize,
+    /// Number of instructions from a producer to its consumer, 1 is back-to-back. Independent
+    /// instructions fill the gap.
+    pub max_distance: usize,
+    /// Probability that a load reads the address of the preceding store
+    pub alias_rate: f32,
+}
+
+impl Default for DependencyMutatorConf {
+    fn default() -> Self {
+        Self {
+            max_chain_length: 6,
+            max_distance: 3,
+            alias_rate: 0.75,
+        }
+    }
+}
+
+/// Inserts or rewrites a window of dependent instructions, see the module documentation
+#[derive(Debug)]
+pub struct DependencyMutator {
+    conf: DependencyMutatorConf,
+    dictionary: OperandDictionary,
+    memory: Option<MemoryAccessMutator>,
+    profile: CpuProfile,
+    alu: Vec<&'static Instruction>,
+    loads: Vec<&'static Instruction>,
+    stores: Vec<&'static Instruction>,
+}
+
+impl Default for DependencyMutator {
+    fn default() -> Self {
+        Self::with_profile(CpuProfile::default())
+    }
+}
+
+impl<S> ISAMutator<S> for DependencyMutator
+where
+    S: HasRand + HasMaxSize,
+{
+    fn mutate(
+        &mut self,
+        state: &mut S,
+        input: &mut ISAInput,
+        _stage_idx: i32,
+    ) -> Result<MutationResult, Error> {
+        let patterns = self.patterns();
+        if patterns.is_empty() {
+            return Ok(MutationResult::Skipped);
+        }
+        let pattern = state.rand_mut().choose(patterns);
+        let window = self.window(state.rand_mut(), pattern);
+
+        // write over the instructions at idx, or insert before them
+        let idx = state.rand_mut().below(input.instructions.len() as u64 + 1) as usize;
+        let overwritten = if state.rand_mut().below(2) == 0 {
+            window.len().min(input.instructions.len() - idx)
+        } else {
+            0
+        };
+
+        if cuts_bounded_loop(&input.instructions, idx..idx + overwritten) {
+            return Ok(MutationResult::Skipped);
+        }
+
+        let removed: usize = input.instructions[idx..idx + overwritten].iter().map(|insn| insn.length).sum();
+        let added: usize = window.iter().map(|insn| insn.length).sum();
+        if input.len() - removed + added > state.max_size() {
+            return Ok(MutationResult::Skipped);
+        }
+
+        input.instructions.splice(idx..idx + overwritten, window);
+
+        Ok(MutationResult::Mutated)
+    }
+}
+
+impl Named for DependencyMutator {
+    fn name(&self) -> &str {
+        "DependencyMutator"
+    }
+}
+
+impl DependencyMutator {
+    /// Creates a new [`DependencyMutator`] generating instructions of every table
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only generate instructions of `profile`
+    #[must_use]
+    pub fn with_profile(profile: CpuProfile) -> Self {
+        let alu = profile
+            .instructions()
+            .filter(|meta| {
+                meta.is_integer_alu()
+                    && meta.operand_kinds.contains(&OperandKind::DestReg)
+                    && meta.operand_kinds.contains(&OperandKind::SrcReg)
+            })
+            .collect();
+        let loads = profile.instructions().filter(|meta| meta.is_load()).collect();
+        let stores = profile.instructions().filter(|meta| meta.is_store()).collect();
+
+        Self {
+            conf: DependencyMutatorConf::default(),
+            dictionary: OperandDictionary::default(),
+            memory: None,
+            profile,
+            alu,
+            loads,
+            stores,
+        }
+    }
+
+    /// Also generate load-use and store-to-load windows, accessing the memory of `conf`
+    #[must_use]
+    pub fn with_memory(mut self, conf: MemoryAccessConf) -> Self {
+        self.memory = Some(MemoryAccessMutator::new(conf).with_profile(self.profile.clone()));
+        self
+    }
+
+    /// Set the shape of the generated windows
+    #[must_use]
+    pub fn with_conf(mut self, conf: DependencyMutatorConf) -> Self {
+        self.conf = conf;
+        self
+    }
+
+    /// The patterns the profile has instructions, and the mutator memory regions, for
+    fn patterns(&self) -> Vec<DependencyPattern> {
+        let mut patterns = vec![];
+        if self.alu.is_empty() {
+            return patterns;
+        }
+        patterns.push(DependencyPattern::RawChain);
+        if self.memory.is_some() && !self.loads.is_empty() {
+            patterns.push(DependencyPattern::LoadUse);
+            if !self.stores.is_empty() {
+                patterns.push(DependencyPattern::StoreToLoad);
+            }
+        }
+        patterns
+    }
+
+    /// Generate a window of instructions following `pattern`. Without memory regions, or
+    /// when the access cannot reach them, a RAW chain is generated instead.
+    pub fn window<R: Rand>(&self, rand: &mut R, pattern: DependencyPattern) -> Vec<Instruction> {
+        let length = rand.between(2, self.conf.max_chain_length.max(2) as u64) as usize;
+        let distance = rand.between(1, self.conf.max_distance.max(1) as u64) as usize;
+
+        let mut window = vec![];
+        let mut reserved = vec![];
+        match pattern {
+            DependencyPattern::RawChain => {
+                self.chain(rand, None, length, distance, &mut reserved, &mut window);
+            }
+            DependencyPattern::LoadUse => {
+                let base = Self::free_register(rand, &reserved);
+                reserved.push(base);
+                let offset = Self::offset(rand);
+                let loaded = self.load(rand, base, offset, &mut reserved, &mut window);
+                let size = window[0].access_size().unwrap_or(1);
+                let Some(prologue) = self.prologue(rand, base, size, offset) else {
+                    return self.window(rand, DependencyPattern::RawChain);
+                };
+                window.splice(0..0, prologue);
+                self.fill(rand, distance - 1, &reserved, &mut window);
+                self.chain(rand, Some(loaded), length - 1, distance, &mut reserved, &mut window);
+            }
+            DependencyPattern::StoreToLoad => {
+                let base = Self::free_register(rand, &reserved);
+                reserved.push(base);
+                let offset = Self::offset(rand);
+                let size = self.store(rand, base, offset, &mut reserved, &mut window);
+                let Some(prologue) = self.prologue(rand, base, size, offset) else {
+                    return self.window(rand, DependencyPattern::RawChain);
+                };
+                window.splice(0..0, prologue);
+                self.fill(rand, distance - 1, &reserved, &mut window);
+
+                let load_offset = if rand.below(1000) < (self.conf.alias_rate * 1000.0) as u64 {
+                    // same address, or a partial overlap
+                    offset + rand.below(u64::from(size)) as i32
+                } else {
+                    // the next or previous access
+                    if rand.below(2) == 0 { offset + size as i32 } else { offset - size as i32 }
+                };
+                let loaded = self.load(rand, base, load_offset, &mut reserved, &mut window);
+                self.fill(rand, distance - 1, &reserved, &mut window);
+                self.chain(rand, Some(loaded), length - 1, distance, &mut reserved, &mut window);
+            }
+        }
+        window
+    }
+
+    /// The instructions pointing `base` to memory for an access of `size` bytes at
+    /// `base + offset`
+    fn prologue<R: Rand>(&self, rand: &mut R, base: u32, size: u32, offset: i32) -> Option<Vec<Instruction>> {
+        self.memory.as_ref()?.address_prologue(rand, base, u64::from(size), offset)
+    }
+
+    /// Append `length` ALU instructions, each reading the result of the previous one
+    /// (`start` for the first one), `distance` instructions apart
+    fn chain<R: Rand>(
+        &self,
+        rand: &mut R,
+        start: Option<u32>,
+        length: usize,
+        distance: usize,
+        reserved: &mut Vec<u32>,
+        window: &mut Vec<Instruction>,
+    ) {
+        let mut previous = start;
+        for i in 0..length {
+            if i > 0 {
+                self.fill(rand, distance - 1, reserved, window);
+            }
+            let dest = Self::free_register(rand, reserved);
+            reserved.push(dest);
+
+            let meta = rand.choose(self.alu.iter().copied());
+            let mut insn = self.instantiate(rand, meta, dest);
+            if let Some(source) = previous {
+                let sources: Vec<usize> = (0..insn.operands.len())
+                    .filter(|idx| insn.operand_kind(*idx) == OperandKind::SrcReg)
+                    .collect();
+                insn.set_operand_value(rand.choose(sources), source);
+            }
+            window.push(insn);
+            previous = Some(dest);
+        }
+    }
+
+    /// Append `count` ALU instructions independent from the `reserved` registers
+    fn fill<R: Rand>(&self, rand: &mut R, count: usize, reserved: &[u32], window: &mut Vec<Instruction>) {
+        for _ in 0..count {
+            let meta = rand.choose(self.alu.iter().copied());
+            let dest = Self::free_register(rand, reserved);
+            let mut insn = self.instantiate(rand, meta, dest);
+            for idx in 0..insn.operands.len() {
+                if insn.operand_kind(idx) == OperandKind::SrcReg {
+                    insn.set_operand_value(idx, Self::free_register(rand, reserved));
+                }
+            }
+            window.push(insn);
+        }
+    }
+
+    /// Append a load from `base + offset`, returns the loaded register
+    fn load<R: Rand>(&self, rand: &mut R, base: u32, offset: i32, reserved: &mut Vec<u32>, window: &mut Vec<Instruction>) -> u32 {
+        let dest = Self::free_register(rand, reserved);
+        reserved.push(dest);
+
+        let meta = rand.choose(self.loads.iter().copied());
+        let mut insn = self.instantiate(rand, meta, dest);
+        insn.set_base_register(base);
+        insn.set_memory_offset(offset);
+        window.push(insn);
+        dest
+    }
+
+    /// Append a store to `base + offset`, returns the number of bytes written
+    fn store<R: Rand>(&self, rand: &mut R, base: u32, offset: i32, reserved: &mut Vec<u32>, window: &mut Vec<Instruction>) -> u32 {
+        let data = Self::free_register(rand, reserved);
+        reserved.push(data);
+
+        let meta = rand.choose(self.stores.iter().copied());
+        let mut insn = self.instantiate(rand, meta, 0);
+        insn.set_base_register(base);
+        insn.set_memory_offset(offset);
+        if let Some(idx) = insn.operand_index(RS2.0, RS2.1) {
+            insn.set_operand_value(idx, data);
+        }
+        let size = insn.access_size().unwrap_or(1);
+        window.push(insn);
+        size
+    }
+
+    /// An instance of `meta` writing `dest`, other operands are drawn from the dictionary
+    fn instantiate<R: Rand>(&self, rand: &mut R, meta: &Instruction, dest: u32) -> Instruction {
+        let mut insn = self.dictionary.instantiate(rand, meta);
+        for idx in 0..insn.operands.len() {
+            if insn.operand_kind(idx) == OperandKind::DestReg {
+                insn.set_operand_value(idx, dest);
+            }
+        }
+        insn
+    }
+
+    /// A register other than `ra`, `sp`, `gp`, `tp` and the `reserved` ones, see
+    /// [`BASE_REGISTERS`]
+    fn free_register<R: Rand>(rand: &mut R, reserved: &[u32]) -> u32 {
+        let free: Vec<u32> = BASE_REGISTERS.map(|reg| reg as u32).filter(|reg| !reserved.contains(reg)).collect();
+        if free.is_empty() {
+            return rand.between(*BASE_REGISTERS.start(), *BASE_REGISTERS.end()) as u32;
+        }
+        rand.choose(free)
+    }
+
+    /// A memory offset, aligned on 8 bytes
+    fn offset<R: Rand>(rand: &mut R) -> i32 {
+        (rand.below(64) as i32 - 32) * 8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use libafl::{
+        corpus::InMemoryCorpus,
+        inputs::BytesInput,
+        mutators::MutationResult,
+        state::{HasMaxSize, StdState},
+    };
+    use libafl_bolts::{rands::StdRand, HasLen};
+    use libpresifuzz_riscv::instruction::OperandKind;
+    use libpresifuzz_riscv::profile::CpuProfile;
+
+    use super::{DependencyMutator, DependencyMutatorConf, DependencyPattern};
+    use crate::memory::{MemoryAccessConf, MemoryRegion};
+    use crate::{ISAInput, ISAMutator, LOOP_COUNTER};
+
+    fn memory() -> MemoryAccessConf {
+        MemoryAccessConf {
+            regions: vec![MemoryRegion::new("scratch", 0x8000_2000, 0x1000).unwrap()],
+            illegal_rate: 0.0,
+            page_table: None,
+        }
+    }
+
+    #[test]
+    fn dependency_windows() {
+        let profile = CpuProfile::from_isa_string("rv64im").unwrap();
+        let conf = DependencyMutatorConf {
+            max_chain_length: 4,
+            max_distance: 1,
+            alias_rate: 1.0,
+        };
+        let mutator = DependencyMutator::with_profile(profile.clone())
+            .with_conf(conf.clone())
+            .with_memory(memory());
+        let mut rand = StdRand::with_seed(0);
+
+        for _ in 0..100 {
+            // the registers of the ELF template harness and the loop counter are never written
+            for pattern in [DependencyPattern::RawChain, DependencyPattern::LoadUse, DependencyPattern::StoreToLoad] {
+                let window = mutator.window(&mut rand, pattern);
+                assert!(window
+                    .iter()
+                    .flat_map(|insn| insn.registers(OperandKind::DestReg))
+                    .all(|reg| (5..LOOP_COUNTER).contains(&reg)));
+            }
+
+            // back-to-back: every instruction reads the result of the previous one
+            let window = mutator.window(&mut rand, DependencyPattern::RawChain);
+            assert!((2..=4).contains(&window.len()));
+            for pair in window.windows(2) {
+                let dest = pair[0].registers(OperandKind::DestReg)[0];
+                assert!(pair[1].registers(OperandKind::SrcReg).contains(&dest));
+            }
+
+            // the base register is set up by a prologue before the store
+            let window = mutator.window(&mut rand, DependencyPattern::StoreToLoad);
+            let idx = window.iter().position(|insn| insn.is_store()).unwrap();
+            let base = window[idx].registers(OperandKind::SrcReg)[0];
+            assert_eq!(window[0].mnemonic, "LUI");
+            assert!((5..32).contains(&base));
+            assert!(window[..idx].iter().all(|insn| insn.registers(OperandKind::DestReg) == vec![base]));
+
+            // the load reads the bytes written by the store, and feeds the next instruction
+            let (store, load) = (&window[idx], &window[idx + 1]);
+            assert!(load.is_load());
+            assert_eq!(base, load.registers(OperandKind::SrcReg)[0]);
+            let delta = load.memory_offset().unwrap() - store.memory_offset().unwrap();
+            assert!(delta >= 0 && delta < store.access_size().unwrap() as i32);
+            let loaded = load.registers(OperandKind::DestReg)[0];
+            assert!(window[idx + 2].registers(OperandKind::SrcReg).contains(&loaded));
+        }
+
+        // without memory, no access is generated
+        let mutator = DependencyMutator::with_profile(profile).with_conf(conf);
+        for _ in 0..20 {
+            let window = mutator.window(&mut rand, DependencyPattern::LoadUse);
+            assert!(window.iter().all(|insn| !insn.is_load() && !insn.is_store()));
+        }
+    }
+
+    #[test]
+    fn dependency_mutate() {
+        let profile = CpuProfile::from_isa_string("rv64im").unwrap();
+        let mut mutator = DependencyMutator::with_profile(profile).with_memory(memory());
+        let mut state = StdState::new(
+            StdRand::with_seed(0),
+            InMemoryCorpus::<BytesInput>::new(),
+            InMemoryCorpus::new(),
+            &mut (),
+            &mut (),
+        )
+        .unwrap();
+
+        // addi x1, x1, 1; addi x2, x2, 2
+        let words = [0x0010_8093u32.to_le_bytes(), 0x0021_0113u32.to_le_bytes()].concat();
+        let program = ISAInput::new(&words);
+
+        let max_size = program.len() + 32;
+        state.set_max_size(max_size);
+        let mut appended = false;
+        for _ in 0..200 {
+            let mut input = program.clone();
+            if mutator.mutate(&mut state, &mut input, 0).unwrap() == MutationResult::Mutated {
+                assert!(input.len() <= max_size);
+                appended |= input.instructions.len() > 2 && input.instructions[..2] == program.instructions[..];
+            }
+        }
+        // inserting or writing over at the end of the input only appends
+        assert!(appended);
+
+        // an empty input is written over at its end
+        state.set_max_size(1024);
+        for _ in 0..20 {
+            let mut input = ISAInput::new(&[]);
+            assert_eq!(mutator.mutate(&mut state, &mut input, 0).unwrap(), MutationResult::Mutated);
+            assert!(!input.instructions.is_empty());
+        }
+    }
+}
@@ -2,7 +2,7 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 use libafl::inputs::Input;
-use std::{rc::Rc, string::String, vec::Vec};
+use std::{ops::Range, rc::Rc, string::String, vec::Vec};
 use core::{
     cell::RefCell,
     convert::From,
@@ -15,8 +15,9 @@ use libafl::{
     inputs::HasBytesVec,
     mutators::{MutationResult},
 };
-use libpresifuzz_riscv::instruction::Instruction;
+use libpresifuzz_riscv::instruction::{Instruction, OperandKind};
 use libpresifuzz_riscv::cpu_profile::INSTRUCTIONS;
+use libpresifuzz_riscv::profile::CpuProfile;
 
 // type Instruction = Instruction;
 
@@ -82,8 +83,27 @@ impl ISAInput {
         Self::new(raw_input)
     }
 
+    /// Decode an input made of instructions of `profile`
+    #[must_use]
+    pub fn from_input_with_profile<I: HasBytesVec + Clone>(input: &mut I, profile: &CpuProfile) -> Self {
+        Self::new_with_profile(input.bytes(), profile)
+    }
+
     #[must_use]
     pub fn new(raw_input: &[u8]) -> Self {
+        Self::decode(raw_input, |insn| INSTRUCTIONS.iter().find(|meta| (insn & meta.mmatch) == meta.mask))
+    }
+
+    /// Decode raw bytes, only recognizing the instructions of `profile`
+    #[must_use]
+    pub fn new_with_profile(raw_input: &[u8], profile: &CpuProfile) -> Self {
+        Self::decode(raw_input, |insn| profile.decode(insn))
+    }
+
+    fn decode<'a, F>(raw_input: &[u8], lookup: F) -> Self
+    where
+        F: Fn(u32) -> Option<&'a Instruction>,
+    {
         let mut k = 0;
         let mut testcase_insns: Vec<Instruction> = vec![];
         let len = raw_input.len();
@@ -119,25 +139,17 @@ impl ISAInput {
             };
 
 
-            for meta in INSTRUCTIONS.iter()  {
-                let insn_mask = meta.mask;
-                let insn_match = meta.mmatch;
- 
-                if (insn & insn_match) == insn_mask {
-
-                    let insn_mnemonic = meta.mnemonic.clone();
-
-                    testcase_insns.push(Instruction{
-                        instruction: insn as u64, 
-                        length: insn_length, 
-                        mask: insn_mask, 
-                        mmatch: insn_match,
-                        mnemonic: insn_mnemonic,
-                        extension: meta.extension.clone(),
-                        operands: meta.operands.clone(),
-                        });
-                    break;
-                }
+            if let Some(meta) = lookup(insn) {
+                testcase_insns.push(Instruction{
+                    instruction: insn as u64, 
+                    length: insn_length, 
+                    mask: meta.mask, 
+                    mmatch: meta.mmatch,
+                    mnemonic: meta.mnemonic.clone(),
+                    extension: meta.extension.clone(),
+                    operands: meta.operands.clone(),
+                    operand_kinds: meta.operand_kinds.clone(),
+                    });
             }
         }
         return Self { instructions: testcase_insns };
@@ -174,6 +186,63 @@ impl ISAInput {
     }
 }
 
+/// The number of bytes of `instructions`
+pub(crate) fn byte_len(instructions: &[Instruction]) -> usize {
+    instructions.iter().map(|insn| insn.length).sum()
+}
+
+/// Register counting down the bounded loops, `t6`: the mutators never write it and leave
+/// the instructions using it alone
+pub const LOOP_COUNTER: u32 = 31;
+
+/// Whether `insn` reads or writes [`LOOP_COUNTER`], e.g. the initialisation, the decrement
+/// or the branch of a bounded loop
+pub(crate) fn uses_loop_counter(insn: &Instruction) -> bool {
+    insn.registers(OperandKind::DestReg).contains(&LOOP_COUNTER)
+        || insn.registers(OperandKind::SrcReg).contains(&LOOP_COUNTER)
+}
+
+/// The indices of the counter initialisation and of the backward branch of the bounded
+/// loops of `instructions`, as made by the branch mutator and the [`template`] loops
+fn bounded_loops(instructions: &[Instruction]) -> Vec<(usize, usize)> {
+    let mut loops = vec![];
+    let mut init = None;
+    for (idx, insn) in instructions.iter().enumerate() {
+        let mut sources = insn.registers(OperandKind::SrcReg);
+        sources.sort_unstable();
+        if insn.registers(OperandKind::DestReg).contains(&LOOP_COUNTER) && sources == [0] {
+            init = Some(idx);
+        } else if insn.major_opcode() == 0x63 && sources == [0, LOOP_COUNTER] {
+            if let Some(init) = init.take() {
+                loops.push((init, idx));
+            }
+        }
+    }
+    loops
+}
+
+/// Whether replacing `instructions[range]`, or inserting at `range.start` when it is
+/// empty, would cut a bounded loop: the offset of its branch would no longer land after
+/// the initialisation of the counter and before its decrement
+pub(crate) fn cuts_bounded_loop(instructions: &[Instruction], range: Range<usize>) -> bool {
+    bounded_loops(instructions).into_iter().any(|(init, branch)| {
+        if range.is_empty() {
+            init < range.start && range.start <= branch
+        } else {
+            range.start <= branch && init < range.end && !(range.start <= init && branch < range.end)
+        }
+    })
+}
+
+/// Write the register below [`LOOP_COUNTER`] instead of it
+pub(crate) fn spare_loop_counter(insn: &mut Instruction) {
+    for idx in 0..insn.operands.len() {
+        if insn.operand_kind(idx) == OperandKind::DestReg && insn.operand_value(idx) == LOOP_COUNTER {
+            insn.set_operand_value(idx, LOOP_COUNTER - 1);
+        }
+    }
+}
+
 
 
 
@@ -205,6 +274,60 @@ pub trait ISAMutator<S>: Named {
     ) -> Result<(), Error> {
         Ok(())
     }
+
+    /// Relative probability of scheduling this mutator, see [`Weighted`]
+    #[inline]
+    fn weight(&self) -> f64 {
+        1.0
+    }
+}
+
+/// An [`ISAMutator`] scheduled with an explicit weight, instead of being listed several times
+#[derive(Debug)]
+pub struct Weighted<M> {
+    mutator: M,
+    weight: f64,
+}
+
+impl<M> Weighted<M> {
+    /// Schedule `mutator` with `weight`, relative to the weight 1 of plain mutators
+    #[must_use]
+    pub fn new(mutator: M, weight: f64) -> Self {
+        Self { mutator, weight }
+    }
+}
+
+impl<M: Named> Named for Weighted<M> {
+    fn name(&self) -> &str {
+        self.mutator.name()
+    }
+}
+
+impl<M, S> ISAMutator<S> for Weighted<M>
+where
+    M: ISAMutator<S>,
+{
+    fn mutate(
+        &mut self,
+        state: &mut S,
+        input: &mut ISAInput,
+        stage_idx: i32,
+    ) -> Result<MutationResult, Error> {
+        self.mutator.mutate(state, input, stage_idx)
+    }
+
+    fn post_exec(
+        &mut self,
+        state: &mut S,
+        stage_idx: i32,
+        corpus_idx: Option<CorpusId>,
+    ) -> Result<(), Error> {
+        self.mutator.post_exec(state, stage_idx, corpus_idx)
+    }
+
+    fn weight(&self) -> f64 {
+        self.weight
+    }
 }
 
 use libafl_bolts::{tuples::HasConstLen, Named};
@@ -281,6 +404,9 @@ pub trait ISAMutatorsTuple<S>: HasConstLen {
 
     /// Gets all names of the wrapped [`Mutator`]`s`.
     fn names(&self) -> Vec<&str>;
+
+    /// Gets the weights of the wrapped [`Mutator`]`s`.
+    fn weights(&self) -> Vec<f64>;
 }
 
 impl<S> ISAMutatorsTuple<S> for () {
@@ -330,6 +456,11 @@ impl<S> ISAMutatorsTuple<S> for () {
     fn names(&self) -> Vec<&str> {
         Vec::new()
     }
+
+    #[inline]
+    fn weights(&self) -> Vec<f64> {
+        Vec::new()
+    }
 }
 
 impl<Head, Tail, S> ISAMutatorsTuple<S> for (Head, Tail)
@@ -396,8 +527,36 @@ where
         ret.insert(0, self.0.name());
         ret
     }
+
+    fn weights(&self) -> Vec<f64> {
+        let mut ret = self.1.weights();
+        ret.insert(0, self.0.weight());
+        ret
+    }
 }
 
+pub mod crossover;
+pub mod dependency;
+pub mod memory;
+pub mod operands;
+pub mod privilege;
 pub mod riscv_isa;
 pub mod scheduled;
+pub mod template;
+
 
+#[cfg(test)]
+mod tests {
+    use super::ISAInput;
+
+    #[test]
+    fn decode_extensions() {
+        // fadd.s f1,f2,f3 / amoadd.w x1,x2,(x3) / csrrw x1,mstatus,x2 / fence.i / vadd.vv v1,v2,v3 / add.uw x1,x2,x3
+        let words: [u32; 6] = [0x003100d3, 0x0021a0af, 0x300110f3, 0x0000100f, 0x022180d7, 0x083100bb];
+        let bytes: Vec<u8> = words.iter().flat_map(|w| w.to_le_bytes()).collect();
+
+        let input = ISAInput::new(&bytes);
+        let mnemonics: Vec<&str> = input.instructions.iter().map(|i| i.mnemonic.as_str()).collect();
+        assert_eq!(mnemonics, vec!["FADD_S", "AMOADD_W", "CSRRW", "FENCE_I", "VADD_VV", "ADD_UW"]);
+    }
+}
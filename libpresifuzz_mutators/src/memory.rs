@@ -0,0 +1,418 @@
+// SPDX-FileCopyrightText: 2024 Intel Corporation
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Loads and stores to legal memory.
+//!
+//! Random loads and stores mostly fault on their first access, and simulation time goes to
+//! trap handlers. The [`MemoryAccessMutator`] emits an address-setup prologue (`lui`/`addi`
+//! into a base register) before each access, so that it lands in one of the configured
+//! [`MemoryRegion`]s, e.g. the writable sections of the ELF template. An opt-in ratio of
+//! the accesses is deliberately illegal: just out of a region, misaligned, or to the null
+//! page.
+
+use libafl::{
+    mutators::MutationResult,
+    state::{HasMaxSize, HasRand},
+    Error,
+};
+use libafl_bolts::{rands::Rand, HasLen, Named};
+use libpresifuzz_riscv::cpu_profile::{addi, lui, slli, srli};
+use libpresifuzz_riscv::elf::ELF;
+use libpresifuzz_riscv::instruction::Instruction;
+use libpresifuzz_riscv::profile::CpuProfile;
+
+use crate::operands::OperandDictionary;
+use crate::{cuts_bounded_loop, ISAInput, ISAMutator};
+
+/// Base registers of the generated accesses, `ra`, `sp`, `gp`, `tp` and the loop counter
+/// `t6` are left alone
+pub(crate) const BASE_REGISTERS: std::ops::RangeInclusive<u64> = 5..=30;
+
+/// A range of memory the test cases may access
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemoryRegion {
+    /// Name of the region, e.g. its ELF section
+    pub name: String,
+    /// First address of the region
+    pub start: u64,
+    /// Size of the region in bytes
+    pub size: u64,
+}
+
+impl MemoryRegion {
+    /// Creates a new [`MemoryRegion`]. The region must hold at least a doubleword and end
+    /// below 4 GiB, the reach of a `lui`/`addi` pair.
+    pub fn new(name: &str, start: u64, size: u64) -> Result<Self, Error> {
+        if size < 8 {
+            return Err(Error::illegal_argument(format!("Memory region {name} is smaller than 8 bytes")));
+        }
+        if start.checked_add(size).is_none_or(|end| end > 1 << 32) {
+            return Err(Error::illegal_argument(format!(
+                "Memory region {name} ({start:#x}, {size:#x} bytes) does not fit below 4 GiB"
+            )));
+        }
+        Ok(Self {
+            name: name.to_string(),
+            start,
+            size,
+        })
+    }
+
+    /// The writable sections of an ELF template that can be used as memory regions
+    pub fn from_elf(template_file: &str) -> Result<Vec<Self>, Error> {
+        Ok(ELF::writable_regions(template_file)?
+            .into_iter()
+            .filter_map(|(name, start, size)| Self::new(&name, start, size).ok())
+            .collect())
+    }
+
+    /// Whether `address` is in the region
+    #[must_use]
+    pub fn contains(&self, address: u64) -> bool {
+        address >= self.start && address - self.start < self.size
+    }
+}
+
+/// The memory regions and the illegal access ratio of the [`MemoryAccessMutator`]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MemoryAccessConf {
+    /// Legal memory regions
+    pub regions: Vec<MemoryRegion>,
+    /// Probability that an access is deliberately illegal, 0 by default
+    pub illegal_rate: f32,
+    /// A 4 KiB page outside of the regions, holding the page tables of the
+    /// [`crate::privilege::PrivilegeMutator`]
+    pub page_table: Option<u64>,
+}
+
+impl MemoryAccessConf {
+    /// Read the memory regions from the `fuzzer` section of a `config.yml`:
+    ///
+    /// ```yaml
+    /// fuzzer:
+    ///   memory_regions:
+    ///     - name: scratchpad
+    ///       start: 0x80002000
+    ///       size: 0x1000
+    ///   illegal_access_rate: 0.05
+    ///   page_table: 0x80004000
+    /// ```
+    ///
+    /// Without `memory_regions` the configuration has no region, see
+    /// [`MemoryRegion::from_elf`], and without `page_table` no page is set aside, see
+    /// [`MemoryAccessConf::with_reserved_page_table`].
+    pub fn from_config(config: &serde_yaml::Value) -> Result<Self, Error> {
+        let fuzzer = &config["fuzzer"];
+
+        let mut regions = vec![];
+        if let Some(entries) = fuzzer["memory_regions"].as_sequence() {
+            for (i, entry) in entries.iter().enumerate() {
+                let name = entry["name"].as_str().map_or_else(|| format!("region{i}"), str::to_string);
+                let start = Self::parse_integer(&entry["start"])
+                    .ok_or_else(|| Error::illegal_argument(format!("Memory region {name} has no valid start")))?;
+                let size = Self::parse_integer(&entry["size"])
+                    .ok_or_else(|| Error::illegal_argument(format!("Memory region {name} has no valid size")))?;
+                regions.push(MemoryRegion::new(&name, start, size)?);
+            }
+        }
+
+        let illegal_rate = fuzzer["illegal_access_rate"].as_f64().unwrap_or(0.0) as f32;
+        if !(0.0..=1.0).contains(&illegal_rate) {
+            return Err(Error::illegal_argument(format!("Invalid illegal_access_rate {illegal_rate}")));
+        }
+
+        let page_table = match &fuzzer["page_table"] {
+            serde_yaml::Value::Null => None,
+            value => Some(
+                Self::parse_integer(value).ok_or_else(|| Error::illegal_argument("Invalid page_table address"))?,
+            ),
+        };
+        if let Some(page) = page_table {
+            if !page.is_multiple_of(0x1000)
+                || page >= 1 << 32
+                || regions.iter().any(|region| region.start < page + 0x1000 && page < region.start + region.size)
+            {
+                return Err(Error::illegal_argument(format!(
+                    "Page table {page:#x} is not a 4 KiB page below 4 GiB outside of the memory regions"
+                )));
+            }
+        }
+
+        Ok(Self {
+            regions,
+            illegal_rate,
+            page_table,
+        })
+    }
+
+    /// Set a page aside for the page tables when none is: the last 4 KiB page of the
+    /// largest region able to spare one is taken out of it
+    #[must_use]
+    pub fn with_reserved_page_table(mut self) -> Self {
+        if self.page_table.is_some() {
+            return self;
+        }
+        let last_page = |region: &MemoryRegion| ((region.start + region.size) & !0xfff).checked_sub(0x1000);
+        let largest = self
+            .regions
+            .iter_mut()
+            .filter(|region| last_page(region).is_some_and(|page| page >= region.start + 8))
+            .max_by_key(|region| region.size);
+        if let Some(region) = largest {
+            let page = last_page(region).unwrap();
+            region.size = page - region.start;
+            self.page_table = Some(page);
+        }
+        self
+    }
+
+    /// An integer, or a string holding a decimal or `0x` hexadecimal integer
+    fn parse_integer(value: &serde_yaml::Value) -> Option<u64> {
+        value.as_u64().or_else(|| {
+            let text = value.as_str()?.trim();
+            match text.strip_prefix("0x") {
+                Some(hex) => u64::from_str_radix(hex, 16).ok(),
+                None => text.parse().ok(),
+            }
+        })
+    }
+}
+
+/// Inserts loads and stores to legal memory, see the module documentation
+#[derive(Debug)]
+pub struct MemoryAccessMutator {
+    conf: MemoryAccessConf,
+    profile: CpuProfile,
+    dictionary: OperandDictionary,
+    accesses: Vec<&'static Instruction>,
+}
+
+impl<S> ISAMutator<S> for MemoryAccessMutator
+where
+    S: HasRand + HasMaxSize,
+{
+    fn mutate(
+        &mut self,
+        state: &mut S,
+        input: &mut ISAInput,
+        _stage_idx: i32,
+    ) -> Result<MutationResult, Error> {
+        let Some(sequence) = self.sequence(state.rand_mut()) else {
+            return Ok(MutationResult::Skipped);
+        };
+
+        let added: usize = sequence.iter().map(|insn| insn.length).sum();
+        if input.len() + added > state.max_size() {
+            return Ok(MutationResult::Skipped);
+        }
+
+        let idx = state.rand_mut().below(input.instructions.len() as u64 + 1) as usize;
+        if cuts_bounded_loop(&input.instructions, idx..idx) {
+            return Ok(MutationResult::Skipped);
+        }
+        input.instructions.splice(idx..idx, sequence);
+
+        Ok(MutationResult::Mutated)
+    }
+}
+
+impl Named for MemoryAccessMutator {
+    fn name(&self) -> &str {
+        "MemoryAccessMutator"
+    }
+}
+
+impl MemoryAccessMutator {
+    /// Creates a new [`MemoryAccessMutator`] accessing the memory of `conf`
+    #[must_use]
+    pub fn new(conf: MemoryAccessConf) -> Self {
+        Self {
+            conf,
+            profile: CpuProfile::default(),
+            dictionary: OperandDictionary::default(),
+            accesses: Self::accesses(&CpuProfile::default()),
+        }
+    }
+
+    /// Only generate loads and stores of `profile`, and address prologues for its width
+    #[must_use]
+    pub fn with_profile(mut self, profile: CpuProfile) -> Self {
+        self.accesses = Self::accesses(&profile);
+        self.profile = profile;
+        self
+    }
+
+    /// Generate an address prologue followed by a load or store. Returns `None` without
+    /// memory region or load and store instructions.
+    pub fn sequence<R: Rand>(&self, rand: &mut R) -> Option<Vec<Instruction>> {
+        if self.conf.regions.is_empty() || self.accesses.is_empty() {
+            return None;
+        }
+
+        let meta = rand.choose(self.accesses.iter().copied());
+        let mut access = self.dictionary.instantiate(rand, meta);
+        let size = u64::from(access.access_size()?);
+
+        let address = self.address(rand, size);
+
+        // a small offset in the access, the prologue loads the rest of the address
+        let mut offset = (rand.below(16) as i64 - 8) * size as i64;
+        if !(0..=0xffff_ffff).contains(&(address as i64 - offset)) {
+            offset = 0;
+        }
+        let base_address = (address as i64 - offset) as u64;
+        let base = rand.between(*BASE_REGISTERS.start(), *BASE_REGISTERS.end()) as u32;
+
+        access.set_base_register(base);
+        access.set_memory_offset(offset as i32);
+
+        let mut sequence = self.prologue(base, base_address);
+        sequence.push(access);
+        Some(sequence)
+    }
+
+    /// The prologue of an access of `size` bytes at `base + offset`: the instructions loading
+    /// into `base` an address such that the access lands in a region, or is illegal in the
+    /// configured ratio. Returns `None` without memory region or when the offset cannot reach.
+    pub fn address_prologue<R: Rand>(&self, rand: &mut R, base: u32, size: u64, offset: i32) -> Option<Vec<Instruction>> {
+        if self.conf.regions.is_empty() {
+            return None;
+        }
+
+        let base_address = self.address(rand, size) as i64 - i64::from(offset);
+        if !(0..=0xffff_ffff).contains(&base_address) {
+            return None;
+        }
+        Some(self.prologue(base, base_address as u64))
+    }
+
+    /// A legal address, or an illegal one in the configured ratio
+    fn address<R: Rand>(&self, rand: &mut R, size: u64) -> u64 {
+        let illegal = rand.below(1000) < (self.conf.illegal_rate * 1000.0) as u64;
+        if illegal {
+            self.illegal_address(rand, size)
+        } else {
+            self.legal_address(rand, size)
+        }
+    }
+
+    /// The instructions loading `address`, below 4 GiB, into `base`
+    fn prologue(&self, base: u32, address: u64) -> Vec<Instruction> {
+        let hi = ((address + 0x800) >> 12) as u32 & 0xfffff;
+        let lo = address as u32 & 0xfff;
+
+        let mut prologue = vec![self.emit(lui(base, hi)), self.emit(addi(base, base, lo))];
+
+        // lui sign-extends on RV64, clear the upper bits
+        let value = i64::from((hi << 12) as i32) + i64::from(((lo << 20) as i32) >> 20);
+        if self.profile.xlen() != Some(32) && value as u64 != address {
+            prologue.push(self.emit(slli(base, base, 32)));
+            prologue.push(self.emit(srli(base, base, 32)));
+        }
+        prologue
+    }
+
+    /// An aligned address of one of the regions
+    fn legal_address<R: Rand>(&self, rand: &mut R, size: u64) -> u64 {
+        let region = rand.choose(&self.conf.regions);
+        let aligned_start = (region.start + size - 1) & !(size - 1);
+        let slots = (region.start + region.size).saturating_sub(aligned_start) / size;
+        if slots == 0 {
+            return region.start;
+        }
+        aligned_start + rand.below(slots) * size
+    }
+
+    /// An address just out of a region, misaligned, or in the null page
+    fn illegal_address<R: Rand>(&self, rand: &mut R, size: u64) -> u64 {
+        let region = rand.choose(&self.conf.regions);
+        match rand.below(4) {
+            0 => region.start + region.size,
+            1 => region.start.wrapping_sub(size) & 0xffff_ffff,
+            2 if size > 1 => self.legal_address(rand, size) + 1,
+            _ => rand.below(0x1000) & !(size - 1),
+        }
+    }
+
+    /// `insn` with the metadata of the profile, as if decoded from the input
+    fn emit(&self, insn: Instruction) -> Instruction {
+        match self.profile.decode(insn.instruction as u32) {
+            Some(meta) => Instruction {
+                instruction: insn.instruction,
+                ..meta.clone()
+            },
+            None => insn,
+        }
+    }
+
+    fn accesses(profile: &CpuProfile) -> Vec<&'static Instruction> {
+        profile.instructions().filter(|meta| meta.is_load() || meta.is_store()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use libafl_bolts::rands::StdRand;
+    use libpresifuzz_riscv::instruction::Instruction;
+
+    use super::{MemoryAccessConf, MemoryAccessMutator, MemoryRegion};
+
+    /// The address accessed by a generated sequence
+    fn accessed_address(sequence: &[Instruction]) -> u64 {
+        let mut base: i64 = 0;
+        for insn in sequence {
+            let imm = (insn.instruction >> 20) as u32;
+            match insn.mnemonic.as_str() {
+                "LUI" => base = i64::from((insn.instruction as u32 & 0xfffff000) as i32),
+                "ADDI" => base += i64::from(((imm << 20) as i32) >> 20),
+                "SLLI" => base <<= imm & 0x3f,
+                "SRLI" => base = ((base as u64) >> (imm & 0x3f)) as i64,
+                _ => return base.wrapping_add(i64::from(insn.memory_offset().unwrap())) as u64,
+            }
+        }
+        unreachable!()
+    }
+
+    #[test]
+    fn legal_accesses() {
+        let region = MemoryRegion::new("scratch", 0x8000_2000, 0x100).unwrap();
+        let mut conf = MemoryAccessConf {
+            regions: vec![region.clone()],
+            illegal_rate: 0.0,
+            page_table: None,
+        };
+        let mut rand = StdRand::with_seed(0);
+
+        let mutator = MemoryAccessMutator::new(conf.clone());
+        for _ in 0..200 {
+            let sequence = mutator.sequence(&mut rand).unwrap();
+            let access = sequence.last().unwrap();
+            let address = accessed_address(&sequence);
+            assert!(region.contains(address), "{address:#x}");
+            assert_eq!(address % u64::from(access.access_size().unwrap()), 0);
+        }
+
+        conf.illegal_rate = 1.0;
+        let mutator = MemoryAccessMutator::new(conf);
+        for _ in 0..200 {
+            let sequence = mutator.sequence(&mut rand).unwrap();
+            let size = u64::from(sequence.last().unwrap().access_size().unwrap());
+            let address = accessed_address(&sequence);
+            assert!(!region.contains(address) || address % size != 0, "{address:#x}");
+        }
+
+        assert!(MemoryRegion::new("high", 0xffff_f000, 0x2000).is_err());
+
+        // the page tables stay out of the regions
+        let config = |page_table: &str| {
+            let yaml = format!(
+                "fuzzer:\n  memory_regions:\n    - {{ name: scratch, start: 0x80002000, size: 0x2000 }}\n  page_table: {page_table}\n"
+            );
+            MemoryAccessConf::from_config(&serde_yaml::from_str(&yaml).unwrap())
+        };
+        assert_eq!(config("0x80004000").unwrap().page_table, Some(0x8000_4000));
+        assert!(config("0x80003000").is_err());
+        assert!(config("0x80004800").is_err());
+    }
+}
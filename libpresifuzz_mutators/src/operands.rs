@@ -0,0 +1,243 @@
+// SPDX-FileCopyrightText: 2024 Intel Corporation
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Operand values chosen after the kind of the field they are written to.
+//!
+//! Registers are preferably reused from the surrounding instructions to create data
+//! dependencies, immediates are biased towards boundary values (0, -1, min/max, page
+//! offsets), and CSR numbers and memory addresses are drawn from dictionaries.
+
+use libafl_bolts::rands::Rand;
+use libpresifuzz_riscv::instruction::{Instruction, OperandKind};
+
+use crate::LOOP_COUNTER;
+
+/// CSRs worth accessing: floating-point and vector state, counters, supervisor and
+/// machine trap setup and handling, address translation, PMP, triggers and debug
+pub const INTERESTING_CSRS: &[u32] = &[
+    0x001, 0x002, 0x003, // fflags, frm, fcsr
+    0x008, 0xc20, 0xc21, 0xc22, // vstart, vl, vtype, vlenb
+    0xc00, 0xc01, 0xc02, // cycle, time, instret
+    0x100, 0x104, 0x105, 0x106, // sstatus, sie, stvec, scounteren
+    0x140, 0x141, 0x142, 0x143, 0x144, // sscratch, sepc, scause, stval, sip
+    0x180, // satp
+    0x300, 0x301, 0x302, 0x303, 0x304, 0x305, 0x306, // mstatus, misa, medeleg, mideleg, mie, mtvec, mcounteren
+    0x340, 0x341, 0x342, 0x343, 0x344, // mscratch, mepc, mcause, mtval, mip
+    0x3a0, 0x3b0, // pmpcfg0, pmpaddr0
+    0xb00, 0xb02, // mcycle, minstret
+    0x7a0, 0x7a1, 0x7b0, 0x7b1, // tselect, tdata1, dcsr, dpc
+    0xf11, 0xf12, 0xf13, 0xf14, // mvendorid, marchid, mimpid, mhartid
+];
+
+/// Addresses worth accessing on usual RISC-V platforms: null page, debug module, boot ROM,
+/// CLINT, PLIC, UART and the start of DRAM
+pub const INTERESTING_ADDRESSES: &[u64] = &[
+    0x0000_0000, 0x0000_0800, 0x0000_1000, 0x0001_0000, 0x0200_0000, 0x0200_bff8, 0x0c00_0000,
+    0x1000_0000, 0x8000_0000, 0x8000_1000, 0x8000_0ff8, 0xffff_f000,
+];
+
+/// Valid rounding modes: rne, rtz, rdn, rup, rmm and dyn
+const ROUNDING_MODES: &[u32] = &[0, 1, 2, 3, 4, 7];
+
+/// The registers read and written around the instruction being mutated
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RegisterUsage {
+    /// Registers written by the preceding instructions
+    pub written: Vec<u32>,
+    /// Registers read by the following instructions
+    pub read: Vec<u32>,
+}
+
+impl RegisterUsage {
+    /// The registers used around `instructions[idx]`
+    #[must_use]
+    pub fn around(instructions: &[Instruction], idx: usize) -> Self {
+        let idx = idx.min(instructions.len());
+        Self {
+            written: instructions[..idx]
+                .iter()
+                .flat_map(|insn| insn.registers(OperandKind::DestReg))
+                .collect(),
+            read: instructions[idx..]
+                .iter()
+                .skip(1)
+                .flat_map(|insn| insn.registers(OperandKind::SrcReg))
+                .collect(),
+        }
+    }
+}
+
+/// The dictionaries operand values are drawn from, see the module documentation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OperandDictionary {
+    csrs: Vec<u32>,
+    addresses: Vec<u64>,
+}
+
+impl Default for OperandDictionary {
+    fn default() -> Self {
+        Self {
+            csrs: INTERESTING_CSRS.to_vec(),
+            addresses: INTERESTING_ADDRESSES.to_vec(),
+        }
+    }
+}
+
+impl OperandDictionary {
+    /// Creates a new [`OperandDictionary`] with the default dictionaries
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replace the CSR numbers of the dictionary
+    #[must_use]
+    pub fn with_csrs(mut self, csrs: Vec<u32>) -> Self {
+        self.csrs = csrs;
+        self
+    }
+
+    /// Replace the memory addresses of the dictionary, e.g. with the memory map of the core
+    #[must_use]
+    pub fn with_addresses(mut self, addresses: Vec<u64>) -> Self {
+        self.addresses = addresses;
+        self
+    }
+
+    /// The CSR numbers of the dictionary
+    #[must_use]
+    pub fn csrs(&self) -> &[u32] {
+        &self.csrs
+    }
+
+    /// The memory addresses of the dictionary
+    #[must_use]
+    pub fn addresses(&self) -> &[u64] {
+        &self.addresses
+    }
+
+    /// A value for operand `idx` of `meta`, truncated to the width of the field. Written
+    /// registers are never the [`LOOP_COUNTER`].
+    pub fn generate<R: Rand>(&self, rand: &mut R, meta: &Instruction, idx: usize, usage: &RegisterUsage) -> u32 {
+        let (lsb, msb) = meta.operands[idx];
+        let nb_bits = msb - lsb + 1;
+        let field_mask = u32::MAX >> (32 - nb_bits);
+        let random = rand.below(1u64 << nb_bits) as u32;
+
+        let value = match meta.operand_kind(idx) {
+            OperandKind::SrcReg => Self::reuse_register(rand, &usage.written, nb_bits, 2).unwrap_or(random),
+            OperandKind::DestReg => match Self::reuse_register(rand, &usage.read, nb_bits, 4).unwrap_or(random) {
+                LOOP_COUNTER => LOOP_COUNTER - 1,
+                register => register,
+            },
+            OperandKind::SignedImm | OperandKind::UnsignedImm => match rand.below(3) {
+                0 => rand.choose(boundary_values(nb_bits)),
+                1 => self.address_part(rand, meta, idx).unwrap_or(random),
+                _ => random,
+            },
+            OperandKind::Shamt if rand.below(2) == 0 => rand.choose(boundary_values(nb_bits)),
+            OperandKind::Csr if !self.csrs.is_empty() && rand.below(4) != 0 => rand.choose(self.csrs.iter().copied()),
+            OperandKind::RoundingMode if rand.below(8) != 0 => rand.choose(ROUNDING_MODES.iter().copied()),
+            _ => random,
+        };
+
+        value & field_mask
+    }
+
+    /// An instance of `meta`, every operand drawn with [`OperandDictionary::generate`]
+    pub fn instantiate<R: Rand>(&self, rand: &mut R, meta: &Instruction) -> Instruction {
+        let mut insn = meta.clone();
+        insn.instruction = u64::from(meta.mask);
+        let usage = RegisterUsage::default();
+        for idx in 0..insn.operands.len() {
+            let value = self.generate(rand, &insn, idx, &usage);
+            insn.set_operand_value(idx, value);
+        }
+        insn
+    }
+
+    /// One of `registers` encodable in a field of `nb_bits`, with probability `1/ratio`
+    fn reuse_register<R: Rand>(rand: &mut R, registers: &[u32], nb_bits: u32, ratio: u64) -> Option<u32> {
+        // 3-bit fields of compressed instructions encode x8-x15
+        let candidates: Vec<u32> = if nb_bits == 3 {
+            registers.iter().filter(|reg| (8..16).contains(*reg)).map(|reg| reg - 8).collect()
+        } else {
+            registers.to_vec()
+        };
+
+        if candidates.is_empty() || rand.below(ratio) != 0 {
+            return None;
+        }
+        Some(rand.choose(candidates))
+    }
+
+    /// The part of a dictionary address operand `idx` can hold: the upper 20 bits for
+    /// `lui`/`auipc`, the lower 12 bits for a whole 12-bit immediate
+    fn address_part<R: Rand>(&self, rand: &mut R, meta: &Instruction, idx: usize) -> Option<u32> {
+        if self.addresses.is_empty() {
+            return None;
+        }
+        let address = rand.choose(self.addresses.iter().copied());
+
+        let (lsb, msb) = meta.operands[idx];
+        match (meta.operand_kind(idx), msb - lsb + 1) {
+            // rounded so that a sign-extended addi of the low part lands on the address
+            (OperandKind::UnsignedImm, 20) => Some((address.wrapping_add(0x800) >> 12) as u32),
+            (OperandKind::SignedImm, 12) => Some((address & 0xfff) as u32),
+            _ => None,
+        }
+    }
+}
+
+/// Boundary values of a field of `nb_bits`: 0, 1, -1 (all ones), signed min and max, and
+/// page offsets for 12-bit fields
+#[must_use]
+pub fn boundary_values(nb_bits: u32) -> Vec<u32> {
+    let all_ones = u32::MAX >> (32 - nb_bits);
+    let sign_bit = 1 << (nb_bits - 1);
+    let mut values = vec![0, 1, all_ones, sign_bit, sign_bit - 1];
+    if nb_bits == 12 {
+        values.extend([0x004, 0x008, 0x7f8, 0x7fc, 0xff8, 0xffc]);
+    }
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use libafl_bolts::rands::StdRand;
+    use libpresifuzz_riscv::cpu_profile::{addi, csrrw, lui};
+    use libpresifuzz_riscv::instruction::OperandKind;
+
+    use super::{boundary_values, OperandDictionary, RegisterUsage};
+
+    #[test]
+    fn typed_operands() {
+        // addi x5, x6, 0 ; csrrw x0, mstatus, x7 ; lui x9, 0
+        let program = vec![addi(5, 6, 0), csrrw(0, 7, 0x300), lui(9, 0)];
+        assert_eq!(program[1].operand_kinds, vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Csr]);
+        assert_eq!(program[1].operand_value(2), 0x300);
+
+        let usage = RegisterUsage::around(&program, 1);
+        assert_eq!(usage, RegisterUsage { written: vec![5], read: vec![] });
+
+        let dictionary = OperandDictionary::new().with_csrs(vec![0x341]).with_addresses(vec![0x8000_0ff8]);
+        let mut rand = StdRand::with_seed(0);
+        let (mut reused, mut csrs, mut upper, mut boundary) = (0, 0, 0, 0);
+        for _ in 0..1000 {
+            reused += usize::from(dictionary.generate(&mut rand, &program[1], 1, &usage) == 5);
+            csrs += usize::from(dictionary.generate(&mut rand, &program[1], 2, &usage) == 0x341);
+            upper += usize::from(dictionary.generate(&mut rand, &program[2], 1, &usage) == 0x80001);
+
+            let imm = dictionary.generate(&mut rand, &program[0], 2, &usage);
+            assert!(imm < 1 << 12);
+            boundary += usize::from(boundary_values(12).contains(&imm));
+        }
+        assert!(reused > 400 && csrs > 700 && upper > 250 && boundary > 300);
+
+        let mut insn = program[0].clone();
+        insn.set_operand_value(0, 0x3f);
+        assert_eq!(insn.registers(OperandKind::DestReg), vec![0x1f]);
+        assert_eq!(insn.registers(OperandKind::SrcReg), vec![6]);
+    }
+}
@@ -0,0 +1,265 @@
+// SPDX-FileCopyrightText: 2024 Intel Corporation
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Privilege-mode and trap scaffolding.
+//!
+//! Random instructions rarely leave M-mode, delegate a trap or enable address translation:
+//! the [`PrivilegeMutator`] inserts the well-formed sequences doing it, the [`Macro`]s of
+//! the program templates. A scaffold is one of:
+//!
+//! - a switch to U-mode or S-mode, `mstatus.MPP` then `mret`, after a PMP entry granting
+//!   the low 4 GiB so that the following code can run,
+//! - the delegation of exceptions to S-mode through `medeleg`, with an S-mode handler,
+//! - an Sv39 page table, in the page set aside by the [`MemoryAccessConf`], enabled in
+//!   `satp`, then a switch to the U-mode or S-mode its pages are mapped for,
+//! - a machine timer interrupt.
+//!
+//! The scaffolds never write `mtvec`: M-mode traps stay with the handler of the ELF
+//! template, or of the program template, which skips the trapping instruction, disables
+//! the interrupts it takes, and ends the test after a few traps.
+
+use libafl::{
+    mutators::MutationResult,
+    state::{HasMaxSize, HasRand},
+    Error,
+};
+use libafl_bolts::{rands::Rand, HasLen, Named};
+use libpresifuzz_riscv::cpu_profile::c_nop;
+use libpresifuzz_riscv::instruction::Instruction;
+use libpresifuzz_riscv::profile::CpuProfile;
+
+use crate::memory::MemoryAccessConf;
+use crate::template::{Encoder, Macro, DEFAULT_CLINT, DELEGABLE_EXCEPTIONS};
+use crate::{byte_len, cuts_bounded_loop, ISAInput, ISAMutator};
+
+/// PMP entry of the mode switches
+const PMP_ENTRY: u32 = 0;
+/// Bounds of the delay of the timer interrupts, in timer ticks
+const TIMER_DELTAS: (u64, u64) = (16, 4096);
+
+/// Inserts privilege-mode and trap scaffolding, see the module documentation
+#[derive(Debug)]
+pub struct PrivilegeMutator {
+    conf: MemoryAccessConf,
+    profile: CpuProfile,
+    clint: u64,
+}
+
+impl<S> ISAMutator<S> for PrivilegeMutator
+where
+    S: HasRand + HasMaxSize,
+{
+    fn mutate(
+        &mut self,
+        state: &mut S,
+        input: &mut ISAInput,
+        _stage_idx: i32,
+    ) -> Result<MutationResult, Error> {
+        let macros = self.scaffold(state.rand_mut());
+        if macros.is_empty() {
+            return Ok(MutationResult::Skipped);
+        }
+
+        let idx = state.rand_mut().below(input.instructions.len() as u64 + 1) as usize;
+        if cuts_bounded_loop(&input.instructions, idx..idx) {
+            return Ok(MutationResult::Skipped);
+        }
+        let offset = byte_len(&input.instructions[..idx]);
+        let mut sequence: Vec<Instruction> = vec![];
+        for m in &macros {
+            sequence.extend(m.instructions(&self.profile, offset + byte_len(&sequence)));
+        }
+
+        // keep the alignment of the following instructions
+        let mut added = byte_len(&sequence);
+        if !added.is_multiple_of(4) {
+            if !self.profile.supports("c") {
+                return Ok(MutationResult::Skipped);
+            }
+            sequence.push(Encoder::new(&self.profile).emit(c_nop(0, 0)));
+            added += 2;
+        }
+
+        if input.len() + added > state.max_size() {
+            return Ok(MutationResult::Skipped);
+        }
+
+        input.instructions.splice(idx..idx, sequence);
+
+        Ok(MutationResult::Mutated)
+    }
+}
+
+impl Named for PrivilegeMutator {
+    fn name(&self) -> &str {
+        "PrivilegeMutator"
+    }
+}
+
+impl PrivilegeMutator {
+    /// Creates a new [`PrivilegeMutator`], placing page tables in the memory of `conf`
+    #[must_use]
+    pub fn new(conf: MemoryAccessConf) -> Self {
+        Self {
+            conf,
+            profile: CpuProfile::default(),
+            clint: DEFAULT_CLINT,
+        }
+    }
+
+    /// Only generate scaffolds supported by `profile`
+    #[must_use]
+    pub fn with_profile(mut self, profile: CpuProfile) -> Self {
+        self.profile = profile;
+        self
+    }
+
+    /// Arm the timer through the CLINT at `clint`
+    #[must_use]
+    pub fn with_clint(mut self, clint: u64) -> Self {
+        self.clint = clint;
+        self
+    }
+
+    /// The macros of a random scaffold, empty when the picked scaffold is not supported
+    pub fn scaffold<R: Rand>(&self, rand: &mut R) -> Vec<Macro> {
+        let grant = Macro::PmpRegion {
+            index: PMP_ENTRY,
+            start: 0,
+            size: 1 << 32,
+            permissions: "rwx".to_string(),
+        };
+        match rand.below(5) {
+            0 => vec![grant, Macro::SwitchToUserMode],
+            1 => vec![grant, Macro::SwitchToSupervisorMode],
+            2 => vec![Macro::DelegateExceptions {
+                mask: rand.next() & DELEGABLE_EXCEPTIONS,
+            }],
+            3 => match self.page_table_root() {
+                // translation only applies below M-mode, the U bit follows the target mode
+                Some(root) => {
+                    let user = rand.below(2) == 0;
+                    let switch = if user {
+                        Macro::SwitchToUserMode
+                    } else {
+                        Macro::SwitchToSupervisorMode
+                    };
+                    vec![grant, Macro::Sv39PageTable { root, user }, switch]
+                }
+                None => vec![],
+            },
+            _ => vec![Macro::ArmTimer {
+                delta: rand.between(TIMER_DELTAS.0, TIMER_DELTAS.1),
+                clint: self.clint,
+            }],
+        }
+    }
+
+    /// The page set aside for the page tables, none on RV32
+    fn page_table_root(&self) -> Option<u64> {
+        if self.profile.xlen() == Some(32) {
+            return None;
+        }
+        self.conf.page_table
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use libafl::{
+        corpus::InMemoryCorpus,
+        inputs::BytesInput,
+        mutators::MutationResult,
+        state::{HasMaxSize, StdState},
+    };
+    use libafl_bolts::rands::StdRand;
+    use libpresifuzz_riscv::cpu_profile::{addi, c_nop};
+    use libpresifuzz_riscv::profile::CpuProfile;
+
+    use super::PrivilegeMutator;
+    use crate::memory::{MemoryAccessConf, MemoryRegion};
+    use crate::template::Macro;
+    use crate::{ISAInput, ISAMutator};
+
+    #[test]
+    fn privilege_scaffolds() {
+        let profile = CpuProfile::from_isa_string("rv64gc").unwrap();
+        let conf = MemoryAccessConf {
+            regions: vec![MemoryRegion::new("scratch", 0x9000_0800, 0x2000).unwrap()],
+            illegal_rate: 0.0,
+            page_table: None,
+        }
+        .with_reserved_page_table();
+        // the page tables are out of reach of the generated accesses
+        assert_eq!(conf.page_table, Some(0x9000_1000));
+        assert_eq!(conf.regions[0].size, 0x800);
+        let mut state = StdState::new(
+            StdRand::with_seed(0),
+            InMemoryCorpus::<BytesInput>::new(),
+            InMemoryCorpus::new(),
+            &mut (),
+            &mut (),
+        )
+        .unwrap();
+        state.set_max_size(1 << 16);
+
+        // the S-mode handler is 4-byte aligned wherever the delegation is
+        for offset in [0, 2] {
+            let delegation = Macro::DelegateExceptions { mask: u64::MAX };
+            let sequence = delegation.instructions(&profile, offset);
+            let auipc = sequence.iter().position(|insn| insn.mnemonic == "AUIPC").unwrap();
+            let at: usize = offset + sequence[..auipc].iter().map(|insn| insn.length).sum::<usize>();
+            let handler = at + (sequence[auipc + 1].instruction >> 20) as usize;
+            assert_eq!(handler % 4, 0);
+            assert!(sequence.iter().any(|insn| insn.mnemonic == "SRET"));
+        }
+
+        let mut mutator = PrivilegeMutator::new(conf).with_profile(profile.clone());
+        let mut input = ISAInput::new_from_parsed_data(vec![c_nop(0, 0), addi(5, 5, 1), c_nop(0, 0)]);
+        let mut mutated = 0;
+        for _ in 0..50 {
+            if mutator.mutate(&mut state, &mut input, 0).unwrap() == MutationResult::Mutated {
+                mutated += 1;
+            }
+        }
+        assert!(mutated > 40);
+
+        // every scaffold decodes with the profile, and leaves mtvec to the ELF template
+        let mut bytes = vec![];
+        input.unparse(&mut bytes);
+        let program = ISAInput::new_with_profile(&bytes, &profile);
+        assert_eq!(program.instructions.len(), input.instructions.len());
+        for insn in &program.instructions {
+            assert!(!insn.mnemonic.starts_with("CSRR") || insn.instruction >> 20 != 0x305);
+        }
+        for mnemonic in ["MRET", "SRET", "SFENCE_VMA"] {
+            assert!(program.instructions.iter().any(|insn| insn.mnemonic == mnemonic), "{mnemonic}");
+        }
+
+        // the pages are mapped for the mode switched to
+        let mut rand = StdRand::with_seed(0);
+        let mut tables = 0;
+        for _ in 0..50 {
+            let scaffold = mutator.scaffold(&mut rand);
+            if let Some(Macro::Sv39PageTable { root, user }) = scaffold.get(1) {
+                assert_eq!(*root, 0x9000_1000);
+                let switch = if *user { Macro::SwitchToUserMode } else { Macro::SwitchToSupervisorMode };
+                assert_eq!(scaffold.last(), Some(&switch));
+                tables += 1;
+            }
+        }
+        assert!(tables > 0);
+
+        // no page table on RV32
+        let rv32 = PrivilegeMutator::new(MemoryAccessConf::default())
+            .with_profile(CpuProfile::from_isa_string("rv32gc").unwrap());
+        let mut rand = StdRand::with_seed(1);
+        for _ in 0..50 {
+            assert!(!rv32
+                .scaffold(&mut rand)
+                .iter()
+                .any(|m| matches!(m, Macro::Sv39PageTable { .. })));
+        }
+    }
+}
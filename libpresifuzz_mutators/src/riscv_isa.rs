@@ -8,17 +8,24 @@ use libafl_bolts::{
 };
 use libafl::{
     // inputs::BytesInput,
+    inputs::{HasBytesVec, UsesInput},
     mutators::{MutationResult},
     state::{HasCorpus, HasMaxSize, HasRand},
     state::{HasMetadata},
     Error,
 };
 use libafl_bolts::HasLen;
-use crate::ISAMutator;
+use crate::{cuts_bounded_loop, spare_loop_counter, uses_loop_counter, ISAMutator};
 use crate::ISAInput;
+use crate::Weighted;
+use crate::crossover::{ISACrossoverInsertMutator, ISASpliceMutator};
+use crate::dependency::DependencyMutator;
+use crate::memory::{MemoryAccessConf, MemoryAccessMutator};
+use crate::operands::{OperandDictionary, RegisterUsage};
+use crate::privilege::PrivilegeMutator;
 
 use libpresifuzz_riscv::instruction::Instruction;
-use libpresifuzz_riscv::cpu_profile;
+use libpresifuzz_riscv::profile::CpuProfile;
 
 #[derive(Default, Debug)]
 pub struct InstDeleteMutator;
@@ -38,6 +45,9 @@ where
         }
 
         let idx = state.rand_mut().below(input.instructions.len() as u64) as usize;
+        if cuts_bounded_loop(&input.instructions, idx..idx + 1) {
+            return Ok(MutationResult::Skipped);
+        }
         input.instructions.remove(idx);
 
         Ok(MutationResult::Mutated)
@@ -58,8 +68,12 @@ impl InstDeleteMutator {
     }
 }
 
+/// Regenerates the operands of an instruction according to their kind, see
+/// [`OperandDictionary`]
 #[derive(Default, Debug)]
-pub struct OperandMutator;
+pub struct OperandMutator {
+    dictionary: OperandDictionary,
+}
 impl<S> ISAMutator<S> for OperandMutator
 where
     S: HasRand  + HasMaxSize,
@@ -78,21 +92,18 @@ where
         }
 
         let idx = state.rand_mut().below(input.instructions.len() as u64) as usize;
-        let meta = &mut input.instructions[idx]; 
-
-        let mut inst : u32 = 0;
-        let mask: u32 = meta.mask;
-        inst |= mask;
-        for operand in meta.operands.clone().into_iter() {
-
-            let nb_bits = operand.1-operand.0 + 1;
-            let op_max = ((1<<(1-1))-1)^((1<<nb_bits)-1);
-            
-            let op_value = state.rand_mut().below(op_max) as u32;
+        if uses_loop_counter(&input.instructions[idx]) {
+            return Ok(MutationResult::Skipped);
+        }
+        let usage = RegisterUsage::around(&input.instructions, idx);
 
-            inst |= build_operand(op_value, operand.0, operand.1);
+        let mut meta = input.instructions[idx].clone();
+        meta.instruction = u64::from(meta.mask);
+        for operand in 0..meta.operands.len() {
+            let op_value = self.dictionary.generate(state.rand_mut(), &meta, operand, &usage);
+            meta.set_operand_value(operand, op_value);
         }
-        meta.instruction = inst as u64;
+        input.instructions[idx] = meta;
 
         Ok(MutationResult::Mutated)
     }
@@ -107,12 +118,20 @@ impl Named for OperandMutator {
 impl OperandMutator {
     #[must_use]
     pub fn new() -> Self {
-        Self
+        Self::default()
+    }
+
+    /// Draw CSR numbers and memory addresses from `dictionary`
+    #[must_use]
+    pub fn with_dictionary(dictionary: OperandDictionary) -> Self {
+        Self { dictionary }
     }
 }
 
 #[derive(Default, Debug)]
-pub struct AppendInstMutator;
+pub struct AppendInstMutator {
+    profile: CpuProfile,
+}
 impl<S> ISAMutator<S> for AppendInstMutator
 where
     S: HasRand  + HasMaxSize,
@@ -130,9 +149,9 @@ where
             return Ok(MutationResult::Skipped);
         }
             
-        let target_idx = state.rand_mut().below(cpu_profile::INSTRUCTIONS.len() as u64) as usize;
-
-        let meta = &cpu_profile::INSTRUCTIONS[target_idx];
+        let Some(meta) = self.profile.random_instruction(state.rand_mut()) else {
+            return Ok(MutationResult::Skipped);
+        };
 
         let mut inst : u32 = 0;
         let mask: u32 = meta.mask;
@@ -147,7 +166,7 @@ where
             inst |= build_operand(op_value, operand.0, operand.1);
         }
 
-        let mutation = Instruction{
+        let mut mutation = Instruction{
             instruction: inst as u64,
             length: meta.length,
             mask: meta.mask,
@@ -155,10 +174,15 @@ where
             mnemonic: meta.mnemonic.clone(),
             extension: meta.extension.clone(),
             operands: meta.operands.clone(),
+            operand_kinds: meta.operand_kinds.clone(),
         };
+        spare_loop_counter(&mut mutation);
 
         // randomly insert mutant
         let idx = state.rand_mut().below(input.instructions.len() as u64) as usize;
+        if cuts_bounded_loop(&input.instructions, idx..idx) {
+            return Ok(MutationResult::Skipped);
+        }
 
         input.instructions.insert(idx, mutation.clone());
 
@@ -176,13 +200,21 @@ impl AppendInstMutator {
     /// Creates a a new [`LoadStoreMutator`].
     #[must_use]
     pub fn new() -> Self {
-        Self
+        Self::default()
+    }
+
+    /// Only generate instructions of `profile`
+    #[must_use]
+    pub fn with_profile(profile: CpuProfile) -> Self {
+        Self { profile }
     }
 }
 
 
 #[derive(Default, Debug)]
-pub struct OpcodeMutator;
+pub struct OpcodeMutator {
+    profile: CpuProfile,
+}
 impl<S> ISAMutator<S> for OpcodeMutator
 where
     S: HasRand  + HasMaxSize,
@@ -200,9 +232,9 @@ where
             return Ok(MutationResult::Skipped);
         }
             
-        let target_idx = state.rand_mut().below(cpu_profile::INSTRUCTIONS.len() as u64) as usize;
-
-        let meta = &cpu_profile::INSTRUCTIONS[target_idx];
+        let Some(meta) = self.profile.random_instruction(state.rand_mut()) else {
+            return Ok(MutationResult::Skipped);
+        };
 
         let mut inst : u32 = 0;
         let mask: u32 = meta.mask;
@@ -217,7 +249,7 @@ where
             inst |= build_operand(op_value, operand.0, operand.1);
         }
 
-        let mutation = Instruction{
+        let mut mutation = Instruction{
             instruction: inst as u64,
             length: meta.length,
             mask: meta.mask,
@@ -225,12 +257,17 @@ where
             mnemonic: meta.mnemonic.clone(),
             extension: meta.extension.clone(),
             operands: meta.operands.clone(),
+            operand_kinds: meta.operand_kinds.clone(),
         };
+        spare_loop_counter(&mut mutation);
 
         // randomly insert mutant
         // let mut from = state.rand_mut().choose(0..input.instructions.len()) as usize;
         let idx = state.rand_mut().below(input.instructions.len() as u64) as usize;
 
+        if uses_loop_counter(&input.instructions[idx]) {
+            return Ok(MutationResult::Skipped);
+        }
         if input.instructions[idx].length == mutation.length {
             input.instructions.remove(idx);
             input.instructions.insert(idx, mutation.clone());
@@ -238,7 +275,7 @@ where
             // search for for match 
             let mut skipped = true;
             for i in 0..input.instructions.len() {
-                if input.instructions[i].length == mutation.length {
+                if input.instructions[i].length == mutation.length && !uses_loop_counter(&input.instructions[i]) {
                     input.instructions.remove(i);
                     input.instructions.insert(i, mutation.clone());
                     skipped = false;
@@ -265,7 +302,13 @@ impl OpcodeMutator {
     /// Creates a a new [`LoadStoreMutator`].
     #[must_use]
     pub fn new() -> Self {
-        Self
+        Self::default()
+    }
+
+    /// Only generate instructions of `profile`
+    #[must_use]
+    pub fn with_profile(profile: CpuProfile) -> Self {
+        Self { profile }
     }
 }
 
@@ -277,31 +320,107 @@ use crate::ISAMutatorsTuple;
 pub fn riscv_mutations<S>() -> impl ISAMutatorsTuple<S>
 where
     S: HasRand + HasMetadata + HasMaxSize + HasCorpus,
+    <S as UsesInput>::Input: HasBytesVec,
     // I: HasBytesVec + Clone,
+{
+    riscv_mutations_with_profile(&CpuProfile::default())
+}
+
+/// The RISC-V mutations, only generating instructions of `profile`
+#[must_use]
+pub fn riscv_mutations_with_profile<S>(profile: &CpuProfile) -> impl ISAMutatorsTuple<S>
+where
+    S: HasRand + HasMetadata + HasMaxSize + HasCorpus,
+    <S as UsesInput>::Input: HasBytesVec,
+{
+    tuple_list!(
+        Weighted::new(InstDeleteMutator::new(), 4.0),
+        OpcodeMutator::with_profile(profile.clone()),
+        Weighted::new(OperandMutator::new(), 8.0),
+        Weighted::new(AppendInstMutator::with_profile(profile.clone()), 9.0),
+    )
+}
+
+/// The RISC-V mutations of [`riscv_mutations_with_profile`], inserting windows of dependent
+/// instructions through the [`DependencyMutator`]
+#[must_use]
+pub fn riscv_mutations_with_dependencies<S>(profile: &CpuProfile) -> impl ISAMutatorsTuple<S>
+where
+    S: HasRand + HasMetadata + HasMaxSize + HasCorpus,
+    <S as UsesInput>::Input: HasBytesVec,
+{
+    tuple_list!(
+        Weighted::new(InstDeleteMutator::new(), 4.0),
+        OpcodeMutator::with_profile(profile.clone()),
+        Weighted::new(OperandMutator::new(), 8.0),
+        Weighted::new(AppendInstMutator::with_profile(profile.clone()), 9.0),
+        Weighted::new(DependencyMutator::with_profile(profile.clone()), 2.0),
+    )
+}
+
+/// The RISC-V mutations of [`riscv_mutations_with_profile`], combining the input with other
+/// corpus entries through the [`ISASpliceMutator`] and the [`ISACrossoverInsertMutator`]
+#[must_use]
+pub fn riscv_mutations_with_crossover<S>(profile: &CpuProfile) -> impl ISAMutatorsTuple<S>
+where
+    S: HasRand + HasMetadata + HasMaxSize + HasCorpus,
+    <S as UsesInput>::Input: HasBytesVec,
 {
     tuple_list!(
-        InstDeleteMutator::new(),
-        InstDeleteMutator::new(),
-        InstDeleteMutator::new(),
-        InstDeleteMutator::new(),
-        OpcodeMutator::new(),
-        OperandMutator::new(),
-        OperandMutator::new(),
-        OperandMutator::new(),
-        OperandMutator::new(),
-        OperandMutator::new(),
-        OperandMutator::new(),
-        OperandMutator::new(),
-        OperandMutator::new(),
-        AppendInstMutator::new(),
-        AppendInstMutator::new(),
-        AppendInstMutator::new(),
-        AppendInstMutator::new(),
-        AppendInstMutator::new(),
-        AppendInstMutator::new(),
-        AppendInstMutator::new(),
-        AppendInstMutator::new(),
-        AppendInstMutator::new(),
+        Weighted::new(InstDeleteMutator::new(), 4.0),
+        OpcodeMutator::with_profile(profile.clone()),
+        Weighted::new(OperandMutator::new(), 8.0),
+        Weighted::new(AppendInstMutator::with_profile(profile.clone()), 9.0),
+        Weighted::new(ISASpliceMutator::with_profile(profile.clone()), 2.0),
+        Weighted::new(ISACrossoverInsertMutator::with_profile(profile.clone()), 2.0),
     )
 }
 
+/// The RISC-V mutations of [`riscv_mutations_with_crossover`] and
+/// [`riscv_mutations_with_dependencies`], with loads and stores to the memory regions of
+/// `memory`, also in dependency windows, and privilege scaffolds with page tables in a page
+/// the accesses never target, see [`MemoryAccessConf::with_reserved_page_table`]
+#[must_use]
+pub fn riscv_mutations_with_memory<S>(profile: &CpuProfile, memory: &MemoryAccessConf) -> impl ISAMutatorsTuple<S>
+where
+    S: HasRand + HasMetadata + HasMaxSize + HasCorpus,
+    <S as UsesInput>::Input: HasBytesVec,
+{
+    let memory = memory.clone().with_reserved_page_table();
+    tuple_list!(
+        Weighted::new(MemoryAccessMutator::new(memory.clone()).with_profile(profile.clone()), 3.0),
+        PrivilegeMutator::new(memory.clone()).with_profile(profile.clone()),
+        Weighted::new(InstDeleteMutator::new(), 4.0),
+        OpcodeMutator::with_profile(profile.clone()),
+        Weighted::new(OperandMutator::new(), 8.0),
+        Weighted::new(AppendInstMutator::with_profile(profile.clone()), 9.0),
+        Weighted::new(DependencyMutator::with_profile(profile.clone()).with_memory(memory.clone()), 2.0),
+        Weighted::new(ISASpliceMutator::with_profile(profile.clone()), 2.0),
+        Weighted::new(ISACrossoverInsertMutator::with_profile(profile.clone()), 2.0),
+    )
+}
+
+
+#[cfg(test)]
+mod tests {
+    use libafl::{corpus::InMemoryCorpus, inputs::BytesInput, state::StdState};
+    use libafl_bolts::rands::StdRand;
+    use libpresifuzz_riscv::profile::CpuProfile;
+
+    use super::{riscv_mutations, riscv_mutations_with_crossover, riscv_mutations_with_dependencies};
+    use crate::ISAMutatorsTuple;
+
+    type State = StdState<BytesInput, InMemoryCorpus<BytesInput>, StdRand, InMemoryCorpus<BytesInput>>;
+
+    #[test]
+    fn opt_in_mutations() {
+        // the crossover and dependency mutators are only scheduled when asked for
+        let baseline = ISAMutatorsTuple::<State>::weights(&riscv_mutations::<State>());
+        assert_eq!(baseline, vec![4.0, 1.0, 8.0, 9.0]);
+        let profile = CpuProfile::default();
+        let crossover = ISAMutatorsTuple::<State>::weights(&riscv_mutations_with_crossover::<State>(&profile));
+        assert_eq!(crossover, [baseline.clone(), vec![2.0, 2.0]].concat());
+        let dependencies = ISAMutatorsTuple::<State>::weights(&riscv_mutations_with_dependencies::<State>(&profile));
+        assert_eq!(dependencies, [baseline, vec![2.0]].concat());
+    }
+}
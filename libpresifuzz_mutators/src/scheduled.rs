@@ -7,23 +7,33 @@
 // use alloc::{string::String, vec::Vec};
 use core::{
     fmt::{self, Debug},
-    marker::PhantomData, panic,
+    marker::PhantomData,
 };
 
+use std::collections::BTreeMap;
+
 use libafl_bolts::{
     rands::Rand,
     Named,
 };
-use libpresifuzz_riscv::{dasm::RiscvInstructions, disas::{DisasDataTable}};
+use serde::{Deserialize, Serialize};
+use libpresifuzz_riscv::{
+    cpu_profile::addi,
+    dasm::{native_dasm::NativeDasm, RiscvInstructions},
+    disas::{CofiTarget, CofiType, DisasDataTable},
+    instruction::OperandKind,
+    profile::CpuProfile,
+};
 
 use libafl::prelude::HasBytesVec;
 use libafl::prelude::HasMaxSize;
 
 use libafl::{
+    corpus::CorpusId,
     mutators::{
         MutationResult, Mutator,
     },
-    state::{HasRand},
+    state::{HasMetadata, HasRand},
     Error,
 };
 use crate::ISAInput;
@@ -44,6 +54,7 @@ where
 
 
 use crate::MutationId;
+use crate::LOOP_COUNTER;
 
 
 /// A [`Mutator`] scheduling multiple [`Mutator`]s for an input.
@@ -57,7 +68,24 @@ where
     /// Get the next mutation to apply
     fn schedule(&self, state: &mut S, input: &I) -> MutationId;
 
-    fn branch_mutator(&self, input: ISAInput, state: &mut S) -> ISAInput;
+    /// Insert branches and jumps into `input`, see [`BranchMutatorConf`]
+    fn branch_mutator(&self, input: &mut ISAInput, state: &mut S) -> MutationResult;
+
+    /// The instructions of the core under test, every known instruction if `None`
+    fn cpu_profile(&self) -> Option<&CpuProfile> {
+        None
+    }
+
+    /// Decode raw bytes with the profile of the core under test
+    fn decode(&self, raw_input: &[u8]) -> ISAInput {
+        match self.cpu_profile() {
+            Some(profile) => ISAInput::new_with_profile(raw_input, profile),
+            None => ISAInput::new(raw_input),
+        }
+    }
+
+    /// Called with the mutations that changed the input, before it is executed
+    fn applied(&mut self, _mutations: Vec<MutationId>) {}
 
     /// New default implementation for mutate.
     /// Implementations must forward `mutate()` to this method
@@ -72,9 +100,10 @@ where
         I: HasBytesVec + Clone,
         S: HasRand,
     {
-        let mut isa_input = ISAInput::from_input(input); 
+        let mut isa_input = self.decode(input.bytes()); 
 
         let mut r = MutationResult::Skipped;
+        let mut applied = Vec::new();
         let num = self.iterations(state, input);
         for _ in 0..num {
             let idx = self.schedule(state, input);
@@ -83,11 +112,15 @@ where
                 .get_and_mutate(idx, state, &mut isa_input, stage_idx)?;
             if outcome == MutationResult::Mutated {
                 r = MutationResult::Mutated;
+                applied.push(idx);
             }
         }
+        self.applied(applied);
 
         /* branch mutator */
-        isa_input = self.branch_mutator(isa_input, state);
+        if self.branch_mutator(&mut isa_input, state) == MutationResult::Mutated {
+            r = MutationResult::Mutated;
+        }
 
         // transform back the testcase_insns into bytes
         isa_input.unparse(input.bytes_mut());
@@ -98,8 +131,187 @@ where
     
 }
 
+/// How the branch mutator treats branches to a previous instruction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoopPolicy {
+    /// Never branch backwards
+    Forbid,
+    /// Branch backwards with random conditions, possibly looping forever
+    Unbounded,
+    /// Make loops of at most `max_iterations` iterations: the [`LOOP_COUNTER`] is set
+    /// before the target, decremented before the branch, and the branch is a
+    /// `blt x0, t6`. At most one loop per mutation, around instructions not writing it.
+    Bounded { max_iterations: u32 },
+}
+
+/// Configuration of the branch mutator of the [`StdISAScheduledMutator`]
+#[derive(Debug, Clone, PartialEq)]
 pub struct BranchMutatorConf {
-    pub rate: f32, /* */
+    /// Share of the instructions getting a branch inserted before them
+    pub rate: f32,
+    /// Address of the first instruction of the input, e.g. the payload of the ELF template
+    pub base_address: u64,
+    /// The branch and jump types to insert, and their weights. The compressed ones are
+    /// left out when the profile has no `c` extension.
+    pub cofi_types: Vec<(CofiType, f64)>,
+    /// Largest distance in bytes to a following target
+    pub max_forward: u64,
+    /// Largest distance in bytes to a previous target
+    pub max_backward: u64,
+    /// The loops created by backward branches
+    pub loops: LoopPolicy,
+}
+
+impl BranchMutatorConf {
+    /// Creates a new [`BranchMutatorConf`] inserting branches at `rate`, of every type,
+    /// with bounded loops counted in the [`LOOP_COUNTER`]
+    #[must_use]
+    pub fn new(rate: f32) -> Self {
+        Self {
+            rate,
+            base_address: 0x1000,
+            cofi_types: [
+                CofiType::CBEQZ,
+                CofiType::CBNEZ,
+                CofiType::BEQ,
+                CofiType::BNE,
+                CofiType::BLT,
+                CofiType::BGE,
+                CofiType::BLTU,
+                CofiType::BGEU,
+                CofiType::J,
+                CofiType::CJ,
+                CofiType::JAL,
+            ]
+            .into_iter()
+            .map(|cofi_type| (cofi_type, 1.0))
+            .collect(),
+            max_forward: 1024,
+            max_backward: 256,
+            loops: LoopPolicy::Bounded { max_iterations: 8 },
+        }
+    }
+
+    /// Check the rate, the weights, the distances and the loop policy
+    pub fn validate(&self) -> Result<(), Error> {
+        if !(self.rate > 0.0 && self.rate <= 1.0) {
+            return Err(Error::illegal_argument(format!("Invalid branch rate {}", self.rate)));
+        }
+        if self.cofi_types.iter().any(|(_, weight)| !weight.is_finite() || *weight < 0.0)
+            || self.cofi_types.iter().map(|(_, weight)| weight).sum::<f64>() <= 0.0
+        {
+            return Err(Error::illegal_argument("Invalid branch type weights"));
+        }
+        if self.max_forward < self.max_backward {
+            return Err(Error::illegal_argument(format!(
+                "Largest forward distance {} below the largest backward distance {}",
+                self.max_forward, self.max_backward
+            )));
+        }
+        match self.loops {
+            LoopPolicy::Bounded { max_iterations } if max_iterations == 0 || max_iterations >= 1 << 11 => Err(
+                Error::illegal_argument(format!("Invalid iteration count {max_iterations}")),
+            ),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Share of the previous probabilities kept by each update of the adaptive schedule
+const ADAPTIVE_INERTIA: f64 = 0.5;
+/// Share of the probabilities always spread after the declared weights, so that no
+/// mutation starves
+const ADAPTIVE_EXPLORATION: f64 = 0.1;
+
+/// How often the mutations of a [`StdISAScheduledMutator`] were executed and found new
+/// coverage
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MutationStats {
+    /// Executions of inputs changed by the mutation
+    pub uses: u64,
+    /// Executions that added the input to the corpus
+    pub finds: u64,
+}
+
+impl MutationStats {
+    fn record(&mut self, found: bool) {
+        self.uses += 1;
+        self.finds += u64::from(found);
+    }
+}
+
+/// The [`MutationStats`] of the scheduled mutations, by mutator name
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct ISAMutationStatsMetadata {
+    /// Statistics since the start of the campaign
+    pub mutations: BTreeMap<String, MutationStats>,
+}
+
+libafl_bolts::impl_serdeany!(ISAMutationStatsMetadata);
+
+/// MOpt-style schedule: every `period` executions, the probabilities move towards the
+/// mutations that found new coverage during the period
+#[derive(Debug, Clone)]
+struct AdaptiveSchedule {
+    period: u64,
+    executions: u64,
+    stats: Vec<MutationStats>,
+}
+
+impl AdaptiveSchedule {
+    fn new(period: u64, nb_mutations: usize) -> Self {
+        Self {
+            period: period.max(1),
+            executions: 0,
+            stats: vec![MutationStats::default(); nb_mutations],
+        }
+    }
+
+    /// Record an execution, returns the new probabilities at the end of a period
+    fn record(&mut self, applied: &[MutationId], found: bool, weights: &[f64], probabilities: &[f64]) -> Option<Vec<f64>> {
+        for idx in applied {
+            self.stats[idx.0].record(found);
+        }
+        self.executions += 1;
+        if self.executions < self.period {
+            return None;
+        }
+
+        // smoothed towards the average find rate, so that unused mutations are not ruled out
+        let uses: u64 = self.stats.iter().map(|stats| stats.uses).sum();
+        let finds: u64 = self.stats.iter().map(|stats| stats.finds).sum();
+        let prior = (finds as f64 + 1.0) / (uses as f64 + 1.0);
+        let efficiency: Vec<f64> = weights
+            .iter()
+            .zip(&self.stats)
+            .map(|(weight, stats)| weight * (stats.finds as f64 + prior) / (stats.uses as f64 + 1.0))
+            .collect();
+
+        let target = normalize(&efficiency);
+        let base = normalize(weights);
+        let updated: Vec<f64> = probabilities
+            .iter()
+            .zip(target.iter().zip(&base))
+            .map(|(old, (target, base))| {
+                let target = (1.0 - ADAPTIVE_EXPLORATION) * target + ADAPTIVE_EXPLORATION * base;
+                ADAPTIVE_INERTIA * old + (1.0 - ADAPTIVE_INERTIA) * target
+            })
+            .collect();
+
+        self.executions = 0;
+        self.stats.iter_mut().for_each(|stats| *stats = MutationStats::default());
+        Some(normalize(&updated))
+    }
+}
+
+/// Scale `weights` to probabilities, uniform when they are all zero
+fn normalize(weights: &[f64]) -> Vec<f64> {
+    let total: f64 = weights.iter().sum();
+    if total > 0.0 {
+        weights.iter().map(|weight| weight / total).collect()
+    } else {
+        vec![1.0 / weights.len() as f64; weights.len()]
+    }
 }
 
 /// A [`Mutator`] that schedules one of the embedded mutations on each call.
@@ -113,6 +325,11 @@ where
     max_stack_pow: u64,
     phantom: PhantomData<(I, S)>,
     branch_mutator_conf: Option<BranchMutatorConf>,
+    profile: Option<CpuProfile>,
+    weights: Vec<f64>,
+    probabilities: Vec<f64>,
+    adaptive: Option<AdaptiveSchedule>,
+    applied: Vec<MutationId>,
 }
 
 impl<I, MT, S> Debug for StdISAScheduledMutator<I, MT, S>
@@ -143,7 +360,7 @@ where
 impl<I, MT, S> Mutator<I, S> for StdISAScheduledMutator<I, MT, S>
 where
     MT: ISAMutatorsTuple<S>,
-    S: HasRand + HasMaxSize,
+    S: HasRand + HasMaxSize + HasMetadata,
     I: HasBytesVec + Clone,
 {
     #[inline]
@@ -156,6 +373,35 @@ where
     {
         self.scheduled_mutate(state, input, stage_idx)
     }
+
+    /// Credit the mutations applied to the executed input, `corpus_idx` is set when the
+    /// input found new coverage
+    fn post_exec(
+        &mut self,
+        state: &mut S,
+        stage_idx: i32,
+        corpus_idx: Option<CorpusId>,
+    ) -> Result<(), Error> {
+        let applied = core::mem::take(&mut self.applied);
+        let found = corpus_idx.is_some();
+
+        if !state.has_metadata::<ISAMutationStatsMetadata>() {
+            state.add_metadata(ISAMutationStatsMetadata::default());
+        }
+        let names = self.mutations.names();
+        let metadata = state.metadata_mut::<ISAMutationStatsMetadata>()?;
+        for idx in &applied {
+            metadata.mutations.entry(names[idx.0].to_string()).or_default().record(found);
+        }
+
+        if let Some(adaptive) = &mut self.adaptive {
+            if let Some(probabilities) = adaptive.record(&applied, found, &self.weights, &self.probabilities) {
+                self.probabilities = probabilities;
+            }
+        }
+
+        self.mutations.post_exec_all(state, stage_idx, corpus_idx)
+    }
 }
 
 impl<I, MT, S> ComposedByMutations<MT, S> for StdISAScheduledMutator<I, MT, S>
@@ -188,111 +434,43 @@ where
         1 << (1 + state.rand_mut().below(self.max_stack_pow))
     }
 
-    /// Get the next mutation to apply
+    /// Get the next mutation to apply, drawn after the scheduling probabilities
     fn schedule(&self, state: &mut S, _: &I) -> MutationId {
         debug_assert!(!self.mutations().is_empty());
-        state.rand_mut().below(self.mutations().len() as u64).into()
+        let draw = (state.rand_mut().next() >> 11) as f64 / (1u64 << 53) as f64;
+        let mut cumulated = 0.0;
+        for (idx, probability) in self.probabilities.iter().enumerate() {
+            cumulated += probability;
+            if draw < cumulated {
+                return idx.into();
+            }
+        }
+        // rounding errors, fall back on the last schedulable mutation
+        self.probabilities.iter().rposition(|p| *p > 0.0).unwrap_or(0).into()
     }
 
-    fn branch_mutator(&self, input: ISAInput, state: &mut S) -> ISAInput
-    where
-        S: HasRand,
-    {
-        match &self.branch_mutator_conf {
-            Some(conf) => {
-                let mut tmp_data = Vec::new();
-                input.unparse(&mut tmp_data);
-                let riscv_ins = RiscvInstructions::from_le(tmp_data);
-                //println!("riscv_ins -> {:?}", riscv_ins);
-                let mut disas_data_table = DisasDataTable::from_riscv_ins(&riscv_ins, 0x1000).unwrap();
-                //println!("len: {}\n", disas_data_table.len());
-                
-                /*
-                println!("==============");
-                for (_id, addr, ins) in disas_data_table.into_iter() {
-                    println!("0x{:x} {:?}", addr, ins);
-                }
-                */
-
-                let instruction_lens = disas_data_table.len() as u64;
-                let range = state.rand_mut().between(0, disas_data_table.len() as u64);
-                for i in 0..(range as f32 *conf.rate) as usize {
-
-                    let target_index = state.rand_mut().between(0, (instruction_lens + i as u64) - 1) as usize;
-                    let target_id = disas_data_table.get_id_by_index(target_index).unwrap();
-
-                    let cofi_index = state.rand_mut().between(0, (instruction_lens + i as u64) - 1) as usize;
-
-                    //let cofi_type = state.rand_mut().
-                    //.choose(CofiType);
-
-                    let reg1 = state.rand_mut().between(0, 31) as u8;
-                    let reg2 = state.rand_mut().between(0, 31) as u8;
-
-                    //println!("ADD at {} to {} / {}", cofi_index, target_index, instruction_lens);
-
-                    match state.rand_mut().between(0, 10) {
-                        0 => {
-                            disas_data_table.add_branch_instruction_by_index(cofi_index, libpresifuzz_riscv::disas::CofiTarget::TargetID(target_id), reg1%8, 0, libpresifuzz_riscv::disas::CofiType::CBEQZ).unwrap();
-                        },
-                        1 => {
-                            disas_data_table.add_branch_instruction_by_index(cofi_index, libpresifuzz_riscv::disas::CofiTarget::TargetID(target_id), reg1%8, 0, libpresifuzz_riscv::disas::CofiType::CBNEZ).unwrap();
-                        },
-
-                        /* InstructionType::CondBranchRelativeCMP */
-
-                        2 => {
-                            disas_data_table.add_branch_instruction_by_index(cofi_index, libpresifuzz_riscv::disas::CofiTarget::TargetID(target_id), reg1, reg2, libpresifuzz_riscv::disas::CofiType::BEQ).unwrap();
-                        },
-                        3 => {
-                            disas_data_table.add_branch_instruction_by_index(cofi_index, libpresifuzz_riscv::disas::CofiTarget::TargetID(target_id), reg1, reg2, libpresifuzz_riscv::disas::CofiType::BNE).unwrap();
-                        },
-                        4 => {
-                            disas_data_table.add_branch_instruction_by_index(cofi_index, libpresifuzz_riscv::disas::CofiTarget::TargetID(target_id), reg1, reg2, libpresifuzz_riscv::disas::CofiType::BLT).unwrap();
-                        },
-                        5 => {
-                            disas_data_table.add_branch_instruction_by_index(cofi_index, libpresifuzz_riscv::disas::CofiTarget::TargetID(target_id), reg1, reg2, libpresifuzz_riscv::disas::CofiType::BGE).unwrap();
-                        },
-                        6 => {
-                            disas_data_table.add_branch_instruction_by_index(cofi_index, libpresifuzz_riscv::disas::CofiTarget::TargetID(target_id), reg1, reg2, libpresifuzz_riscv::disas::CofiType::BLTU).unwrap();
-                        },
-                        7 => {
-                            disas_data_table.add_branch_instruction_by_index(cofi_index, libpresifuzz_riscv::disas::CofiTarget::TargetID(target_id), reg1, reg2, libpresifuzz_riscv::disas::CofiType::BGEU).unwrap();
-                        },
-
-                        /* InstructionType::BranchRelativeStore */
-                        8 => {
-                            disas_data_table.add_branch_instruction_by_index(cofi_index, libpresifuzz_riscv::disas::CofiTarget::TargetID(target_id), 0, 0, libpresifuzz_riscv::disas::CofiType::J).unwrap();
-                        },
-                        9 => {
-                            disas_data_table.add_branch_instruction_by_index(cofi_index, libpresifuzz_riscv::disas::CofiTarget::TargetID(target_id), 0, 0, libpresifuzz_riscv::disas::CofiType::CJ).unwrap();
-                        },
-                        10 => {
-                            disas_data_table.add_branch_instruction_by_index(cofi_index, libpresifuzz_riscv::disas::CofiTarget::TargetID(target_id), 0, 0, libpresifuzz_riscv::disas::CofiType::JAL).unwrap();
-                        },
-                        _ => {panic!("???")}
-                    }
-                }
-                
-
-                //println!("riscv_ins -> {:?}", riscv_ins);
-
-                /*
-                println!("==============");
-                for (_id, addr, ins) in disas_data_table.into_iter() {
-                    println!("0x{:x} {:?}", addr, ins);
-                }
-                */
+    fn applied(&mut self, mutations: Vec<MutationId>) {
+        self.applied = mutations;
+    }
 
-                let new = disas_data_table.serialize_safe().serialize();
-                ISAInput::new(&new)
+    fn branch_mutator(&self, input: &mut ISAInput, state: &mut S) -> MutationResult {
+        let Some(conf) = &self.branch_mutator_conf else {
+            return MutationResult::Skipped;
+        };
+        match self.insert_branches(conf, input, state) {
+            Ok(Some(mutated)) => {
+                *input = mutated;
+                MutationResult::Mutated
             }
-            None => {
-                input
-            },
+            // disassembly and encoding failures leave the input alone
+            Ok(None) | Err(_) => MutationResult::Skipped,
         }
     }
 
+    fn cpu_profile(&self) -> Option<&CpuProfile> {
+        self.profile.as_ref()
+    }
+
 }
 
 impl<I, MT, S> StdISAScheduledMutator<I, MT, S>
@@ -302,35 +480,209 @@ where
 {
     /// Create a new [`StdISAScheduledMutator`] instance specifying mutations
     pub fn new(mutations: MT) -> Self {
-        StdISAScheduledMutator {
-            name: format!("StdISAScheduledMutator[{}]", mutations.names().join(", ")),
-            mutations,
-            max_stack_pow: 7,
-            phantom: PhantomData,
-            branch_mutator_conf: None, 
-        }
+        Self::with_max_stack_pow(mutations, 7)
     }
 
     /// Create a new [`StdISAScheduledMutator`] instance specifying mutations and the maximun number of iterations
     pub fn with_max_stack_pow(mutations: MT, max_stack_pow: u64) -> Self {
+        let weights = mutations.weights();
         StdISAScheduledMutator {
             name: format!("StdISAScheduledMutator[{}]", mutations.names().join(", ")),
             mutations,
             max_stack_pow,
             phantom: PhantomData,
             branch_mutator_conf: None, 
+            profile: None,
+            probabilities: normalize(&weights),
+            weights,
+            adaptive: None,
+            applied: Vec::new(),
         }
     }
 
-    /// Create a new [`StdISAScheduledMutator`] instance specifying mutations and the maximun number of iterations
-    pub fn with_max_stack_pow_and_branch_mutator(mutations: MT, max_stack_pow: u64, branch_mutator_conf: BranchMutatorConf) -> Self {
-        StdISAScheduledMutator {
-            name: format!("StdISAScheduledMutator[{}]", mutations.names().join(", ")),
-            mutations,
-            max_stack_pow,
-            phantom: PhantomData,
-            branch_mutator_conf: Some(branch_mutator_conf), 
+    /// Create a new [`StdISAScheduledMutator`] instance specifying mutations, the maximun number of iterations
+    /// and the branch mutator, whose configuration is checked by [`BranchMutatorConf::validate`]
+    pub fn with_max_stack_pow_and_branch_mutator(
+        mutations: MT,
+        max_stack_pow: u64,
+        branch_mutator_conf: BranchMutatorConf,
+    ) -> Result<Self, Error> {
+        branch_mutator_conf.validate()?;
+        let mut mutator = Self::with_max_stack_pow(mutations, max_stack_pow);
+        mutator.branch_mutator_conf = Some(branch_mutator_conf);
+        Ok(mutator)
+    }
+
+    /// The input with the branches of `conf`, `None` when none could be inserted
+    fn insert_branches(
+        &self,
+        conf: &BranchMutatorConf,
+        input: &ISAInput,
+        state: &mut S,
+    ) -> Result<Option<ISAInput>, Error> {
+        let profile = self.profile.clone().unwrap_or_default();
+        let types: Vec<(CofiType, f64)> = conf
+            .cofi_types
+            .iter()
+            .copied()
+            .filter(|(cofi_type, weight)| {
+                *weight > 0.0 && (!cofi_type.is_compressed() || self.profile.is_none() || profile.supports("c"))
+            })
+            .collect();
+        if types.is_empty() || input.instructions.is_empty() {
+            return Ok(None);
+        }
+
+        let mut bytes = Vec::new();
+        input.unparse(&mut bytes);
+        let riscv_ins = RiscvInstructions::from_le(bytes);
+        let dasm = Box::new(NativeDasm::with_profile(profile));
+        let mut table = DisasDataTable::from_riscv_ins_with_dasm(&riscv_ins, conf.base_address, dasm)?;
+        if table.len() != input.instructions.len() {
+            return Ok(None);
+        }
+
+        let mut inserted = 0;
+        // ids of the counter initialisation, of the first instruction and of the branch of the
+        // bounded loop
+        let mut bounded_loop = None;
+        let count = (state.rand_mut().below(table.len() as u64 + 1) as f32 * conf.rate) as usize;
+        for _ in 0..count {
+            let target_index = state.rand_mut().below(table.len() as u64) as usize;
+            let cofi_index = state.rand_mut().below(table.len() as u64 + 1) as usize;
+            // only the branches of the loop body may jump after the initialisation of the counter
+            if let Some((init_id, start_id, branch_id)) = bounded_loop {
+                let (Some(init), Some(start), Some(branch)) = (
+                    table.get_index_of(&init_id),
+                    table.get_index_of(&start_id),
+                    table.get_index_of(&branch_id),
+                ) else {
+                    return Ok(None);
+                };
+                if (init + 1..=branch).contains(&target_index) && !(start + 1..=branch).contains(&cofi_index) {
+                    continue;
+                }
+            }
+            let target_id = table.get_id_by_index(target_index)?;
+            let target = CofiTarget::TargetID(target_id);
+            let address = table.get_address_by_index(cofi_index)?;
+            let target_address = table.get_address_by_index(target_index)?;
+            let cofi_type = Self::pick_cofi_type(state.rand_mut(), &types);
+
+            if target_index < cofi_index {
+                if address - target_address > conf.max_backward.min(cofi_type.reach()) {
+                    continue;
+                }
+                match conf.loops {
+                    LoopPolicy::Forbid => continue,
+                    LoopPolicy::Unbounded => {
+                        let (reg1, reg2) = Self::cofi_registers(state.rand_mut(), cofi_type);
+                        table.add_branch_instruction_by_index(cofi_index, target, reg1, reg2, cofi_type)?;
+                    }
+                    LoopPolicy::Bounded { max_iterations } => {
+                        // the indices of the table are the ones of the input until the first insertion
+                        if inserted > 0
+                            || input.instructions[target_index..cofi_index]
+                                .iter()
+                                .any(|insn| insn.registers(OperandKind::DestReg).contains(&LOOP_COUNTER))
+                        {
+                            continue;
+                        }
+                        let iterations = state.rand_mut().between(1, u64::from(max_iterations)) as u32;
+                        let counter = LOOP_COUNTER;
+                        let decrement = addi(counter, counter, (-1i32) as u32).instruction as u32;
+                        table.add_instruction_at_index(cofi_index, decrement)?;
+                        table.add_branch_instruction_by_index(cofi_index + 1, target, 0, counter as u8, CofiType::BLT)?;
+                        let branch_id = table.get_id_by_index(cofi_index + 1)?;
+                        let init_id =
+                            table.add_instruction_at_index(target_index, addi(counter, 0, iterations).instruction as u32)?;
+                        bounded_loop = Some((init_id, target_id, branch_id));
+                    }
+                }
+            } else {
+                if target_address - address > conf.max_forward.min(cofi_type.reach() - 4) {
+                    continue;
+                }
+                let (reg1, reg2) = Self::cofi_registers(state.rand_mut(), cofi_type);
+                table.add_branch_instruction_by_index(cofi_index, target, reg1, reg2, cofi_type)?;
+            }
+            inserted += 1;
+        }
+        if inserted == 0 {
+            return Ok(None);
+        }
+
+        // encoding failures drop instructions, do not keep a shifted program
+        let size: usize = table.values().map(|insn| insn.size as usize).sum();
+        let new = table.serialize_safe().serialize();
+        if new.len() != size || new.len() > state.max_size() {
+            return Ok(None);
+        }
+        Ok(Some(match &self.profile {
+            Some(profile) => ISAInput::new_with_profile(&new, profile),
+            None => ISAInput::new(&new),
+        }))
+    }
+
+    fn pick_cofi_type<R: Rand>(rand: &mut R, types: &[(CofiType, f64)]) -> CofiType {
+        let total: f64 = types.iter().map(|(_, weight)| weight).sum();
+        let mut target = (rand.next() >> 11) as f64 / (1u64 << 53) as f64 * total;
+        for (cofi_type, weight) in types {
+            if target < *weight {
+                return *cofi_type;
+            }
+            target -= weight;
+        }
+        // rounding, fall back on the last type
+        types[types.len() - 1].0
+    }
+
+    /// Random registers of a branch, `x8`-`x15` for the compressed ones
+    fn cofi_registers<R: Rand>(rand: &mut R, cofi_type: CofiType) -> (u8, u8) {
+        match cofi_type {
+            CofiType::CBEQZ | CofiType::CBNEZ => (rand.below(8) as u8, 0),
+            CofiType::J | CofiType::CJ => (0, 0),
+            _ => (rand.below(32) as u8, rand.below(32) as u8),
+        }
+    }
+
+    /// Decode the inputs with the instructions of `profile` only
+    #[must_use]
+    pub fn with_profile(mut self, profile: CpuProfile) -> Self {
+        self.profile = Some(profile);
+        self
+    }
+
+    /// Schedule the mutations with `weights`, one per mutation, instead of the weights
+    /// they declare
+    pub fn with_weights(mut self, weights: Vec<f64>) -> Result<Self, Error> {
+        if weights.len() != self.mutations.len() {
+            return Err(Error::illegal_argument(format!(
+                "Expected {} weights, got {}",
+                self.mutations.len(),
+                weights.len()
+            )));
+        }
+        if weights.iter().any(|weight| !weight.is_finite() || *weight < 0.0) || weights.iter().sum::<f64>() <= 0.0 {
+            return Err(Error::illegal_argument(format!("Invalid mutation weights {:?}", weights)));
         }
+        self.probabilities = normalize(&weights);
+        self.weights = weights;
+        Ok(self)
+    }
+
+    /// Adapt the scheduling probabilities to the mutations finding new coverage, every
+    /// `period` executions
+    #[must_use]
+    pub fn with_adaptive_schedule(mut self, period: u64) -> Self {
+        self.adaptive = Some(AdaptiveSchedule::new(period, self.mutations.len()));
+        self
+    }
+
+    /// The current scheduling probabilities, by mutation index
+    #[must_use]
+    pub fn probabilities(&self) -> &[f64] {
+        &self.probabilities
     }
 
 
@@ -376,3 +728,256 @@ pub mod pybind {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use libafl::{
+        corpus::{CorpusId, InMemoryCorpus},
+        inputs::BytesInput,
+        mutators::{MutationResult, Mutator},
+        state::{HasMetadata, StdState},
+    };
+    use libafl_bolts::{rands::StdRand, tuples::tuple_list};
+    use libpresifuzz_riscv::{
+        cpu_profile::addi, dasm::native_dasm::NativeDasm, disas::CofiType, instruction::OperandKind, profile::CpuProfile,
+    };
+
+    use super::{BranchMutatorConf, ISAMutationStatsMetadata, ISAScheduledMutator, LoopPolicy, StdISAScheduledMutator};
+    use crate::dependency::DependencyMutator;
+    use crate::memory::{MemoryAccessConf, MemoryAccessMutator, MemoryRegion};
+    use crate::{ISAInput, ISAMutator, LOOP_COUNTER};
+    use crate::riscv_isa::{InstDeleteMutator, OpcodeMutator, OperandMutator};
+    use crate::Weighted;
+
+    #[test]
+    fn weighted_and_adaptive_schedule() {
+        let mut state = StdState::new(
+            StdRand::with_seed(0),
+            InMemoryCorpus::<BytesInput>::new(),
+            InMemoryCorpus::new(),
+            &mut (),
+            &mut (),
+        )
+        .unwrap();
+        let input = BytesInput::new(vec![]);
+
+        let mutations = tuple_list!(InstDeleteMutator::new(), Weighted::new(OperandMutator::new(), 3.0));
+        let mut scheduler: StdISAScheduledMutator<BytesInput, _, _> = StdISAScheduledMutator::new(mutations);
+        assert_eq!(scheduler.probabilities(), &[0.25, 0.75]);
+
+        scheduler = scheduler.with_weights(vec![0.0, 1.0]).unwrap();
+        assert!((0..100).all(|_| scheduler.schedule(&mut state, &input).0 == 1));
+        assert!(scheduler.with_weights(vec![1.0]).is_err());
+
+        let mutations = tuple_list!(InstDeleteMutator::new(), OperandMutator::new());
+        let mut scheduler: StdISAScheduledMutator<BytesInput, _, _> =
+            StdISAScheduledMutator::new(mutations).with_adaptive_schedule(100);
+        for _ in 0..1000 {
+            let idx = scheduler.schedule(&mut state, &input);
+            scheduler.applied(vec![idx]);
+            let found = (idx.0 == 1).then(|| CorpusId::from(0usize));
+            scheduler.post_exec(&mut state, 0, found).unwrap();
+        }
+        assert!(scheduler.probabilities()[1] > 0.8);
+
+        let stats = &state.metadata::<ISAMutationStatsMetadata>().unwrap().mutations;
+        assert_eq!(stats["OperandMutator"].uses, stats["OperandMutator"].finds);
+        assert_eq!(stats["InstDeleteMutator"].finds, 0);
+        assert_eq!(stats.values().map(|s| s.uses).sum::<u64>(), 1000);
+    }
+
+    /// The offsets of the branch targets of `input`, relative to its first instruction
+    fn branch_targets(input: &ISAInput, dasm: &NativeDasm) -> Vec<(usize, i64)> {
+        let mut targets = vec![];
+        let mut offset = 0;
+        for insn in &input.instructions {
+            let (_, args) = dasm.disassemble(insn.instruction as u32).unwrap();
+            let args = args.unwrap_or_default();
+            if let Some((_, distance)) = args.split_once("pc ") {
+                let distance: i64 = distance.replace(' ', "").parse().unwrap();
+                targets.push((offset, offset as i64 + distance));
+            }
+            offset += insn.length;
+        }
+        targets
+    }
+
+    #[test]
+    fn branch_mutator_conf() {
+        let mut state = StdState::new(
+            StdRand::with_seed(0),
+            InMemoryCorpus::<BytesInput>::new(),
+            InMemoryCorpus::new(),
+            &mut (),
+            &mut (),
+        )
+        .unwrap();
+        let profile = CpuProfile::from_isa_string("rv64gc").unwrap();
+        let dasm = NativeDasm::with_profile(profile.clone());
+        let program = ISAInput::new_from_parsed_data((0..16).map(|_| addi(5, 5, 1)).collect());
+
+        assert!(BranchMutatorConf::new(1.5).validate().is_err());
+        let mut conf = BranchMutatorConf::new(1.0);
+        conf.loops = LoopPolicy::Bounded { max_iterations: 0 };
+        assert!(conf.validate().is_err());
+        for broken in [
+            BranchMutatorConf::new(0.0),
+            BranchMutatorConf { cofi_types: vec![], ..BranchMutatorConf::new(1.0) },
+            BranchMutatorConf { cofi_types: vec![(CofiType::BEQ, 0.0)], ..BranchMutatorConf::new(1.0) },
+            BranchMutatorConf { max_forward: 64, ..BranchMutatorConf::new(1.0) },
+        ] {
+            assert!(broken.validate().is_err());
+        }
+        assert!(StdISAScheduledMutator::<
+            BytesInput,
+            _,
+            StdState<BytesInput, InMemoryCorpus<BytesInput>, StdRand, InMemoryCorpus<BytesInput>>,
+        >::with_max_stack_pow_and_branch_mutator(
+            tuple_list!(InstDeleteMutator::new()),
+            1,
+            BranchMutatorConf::new(0.0),
+        )
+        .is_err());
+
+        let scheduler: StdISAScheduledMutator<BytesInput, _, _> =
+            StdISAScheduledMutator::with_max_stack_pow_and_branch_mutator(
+                tuple_list!(InstDeleteMutator::new()),
+                1,
+                BranchMutatorConf::new(1.0),
+            )
+            .unwrap()
+            .with_profile(profile.clone());
+        let mut loops = 0;
+        for _ in 0..50 {
+            let mut input = program.clone();
+            if scheduler.branch_mutator(&mut input, &mut state) != MutationResult::Mutated {
+                continue;
+            }
+            let boundaries: Vec<usize> = input
+                .instructions
+                .iter()
+                .scan(0, |offset, insn| {
+                    *offset += insn.length;
+                    Some(*offset - insn.length)
+                })
+                .collect();
+            let targets = branch_targets(&input, &dasm);
+            for &(at, target) in &targets {
+                assert!(boundaries.contains(&(target as usize)), "{target} in {input:?}");
+                if target <= at as i64 {
+                    // no branch from outside of the loop skips the initialisation of the counter
+                    let body = target..=at as i64;
+                    assert!(targets
+                        .iter()
+                        .all(|(from, to)| body.contains(&(*from as i64)) || !body.contains(to)));
+
+                    // a bounded loop: counter set before the target, decremented before the branch
+                    loops += 1;
+                    let idx = boundaries.iter().position(|b| *b == at).unwrap();
+                    let (_, branch) = dasm.disassemble(input.instructions[idx].instruction as u32).unwrap();
+                    assert_eq!(branch.as_deref().unwrap().split(", ").take(2).collect::<Vec<_>>(), ["x0", "x31"]);
+                }
+            }
+        }
+        assert!(loops > 0);
+
+        // no backward branch when loops are forbidden
+        let mut conf = BranchMutatorConf::new(1.0);
+        conf.loops = LoopPolicy::Forbid;
+        let scheduler: StdISAScheduledMutator<BytesInput, _, _> =
+            StdISAScheduledMutator::with_max_stack_pow_and_branch_mutator(tuple_list!(InstDeleteMutator::new()), 1, conf)
+                .unwrap()
+                .with_profile(profile);
+        for _ in 0..50 {
+            let mut input = program.clone();
+            scheduler.branch_mutator(&mut input, &mut state);
+            assert!(branch_targets(&input, &dasm).iter().all(|(at, target)| *target > *at as i64));
+        }
+    }
+
+    /// Whether `input` runs to its end, only following the bounded loops: the other
+    /// branches fall through
+    fn bounded_loops_end(input: &ISAInput, dasm: &NativeDasm) -> bool {
+        let targets = branch_targets(input, dasm);
+        let offsets: Vec<usize> = input
+            .instructions
+            .iter()
+            .scan(0, |offset, insn| {
+                *offset += insn.length;
+                Some(*offset - insn.length)
+            })
+            .collect();
+        let mut counter = 0i32;
+        let mut pc = 0;
+        for _ in 0..10_000 {
+            let Some(idx) = offsets.iter().position(|offset| *offset == pc) else {
+                return pc == offsets.last().unwrap() + input.instructions.last().unwrap().length;
+            };
+            let insn = &input.instructions[idx];
+            pc += insn.length;
+            if insn.registers(OperandKind::DestReg).contains(&LOOP_COUNTER) {
+                // only the initialisation and the decrement write the counter
+                let imm = (insn.instruction as u32 as i32) >> 20;
+                match insn.registers(OperandKind::SrcReg)[..] {
+                    [0] if insn.mnemonic == "ADDI" => counter = imm,
+                    [LOOP_COUNTER] if insn.mnemonic == "ADDI" && imm == -1 => counter -= 1,
+                    _ => return false,
+                }
+            } else if insn.mnemonic == "BLT" && insn.registers(OperandKind::SrcReg) == [0, LOOP_COUNTER] && counter > 0 {
+                let (_, target) = targets.iter().find(|(at, _)| *at == offsets[idx]).unwrap();
+                pc = *target as usize;
+            }
+        }
+        false
+    }
+
+    #[test]
+    fn mutated_bounded_loop() {
+        let mut state = StdState::new(
+            StdRand::with_seed(0),
+            InMemoryCorpus::<BytesInput>::new(),
+            InMemoryCorpus::new(),
+            &mut (),
+            &mut (),
+        )
+        .unwrap();
+        let profile = CpuProfile::from_isa_string("rv64g").unwrap();
+        let dasm = NativeDasm::with_profile(profile.clone());
+        let memory = MemoryAccessConf {
+            regions: vec![MemoryRegion::new("scratch", 0x8000_2000, 0x1000).unwrap()],
+            illegal_rate: 0.0,
+            page_table: None,
+        };
+        let scheduler: StdISAScheduledMutator<BytesInput, _, _> =
+            StdISAScheduledMutator::with_max_stack_pow_and_branch_mutator(
+                tuple_list!(InstDeleteMutator::new()),
+                1,
+                BranchMutatorConf::new(1.0),
+            )
+            .unwrap()
+            .with_profile(profile.clone());
+        let mut operand = OperandMutator::new();
+        let mut opcode = OpcodeMutator::with_profile(profile.clone());
+        let mut dependency = DependencyMutator::with_profile(profile.clone()).with_memory(memory.clone());
+        let mut access = MemoryAccessMutator::new(memory).with_profile(profile);
+
+        let program = ISAInput::new_from_parsed_data((0..16).map(|_| addi(5, 5, 1)).collect());
+        let mut loops = 0;
+        for _ in 0..20 {
+            let mut input = program.clone();
+            scheduler.branch_mutator(&mut input, &mut state);
+            if branch_targets(&input, &dasm).iter().all(|(at, target)| *target > *at as i64) {
+                continue;
+            }
+            loops += 1;
+            for _ in 0..100 {
+                operand.mutate(&mut state, &mut input, 0).unwrap();
+                opcode.mutate(&mut state, &mut input, 0).unwrap();
+                dependency.mutate(&mut state, &mut input, 0).unwrap();
+                access.mutate(&mut state, &mut input, 0).unwrap();
+                assert!(bounded_loops_end(&input, &dasm), "{input:?}");
+            }
+        }
+        assert!(loops > 0);
+    }
+}
@@ -0,0 +1,1148 @@
+// SPDX-FileCopyrightText: 2024 Intel Corporation
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Structured test programs generated from a template.
+//!
+//! A [`ProgramTemplate`] is a list of named blocks (e.g. `csr_setup`, `init`,
+//! `trap_handler`, `loop`), each made of items: a number of random instructions drawn
+//! from weighted [`InstructionClass`]es, or a parameterised [`Macro`] such as "enable
+//! interrupts" or "switch to U-mode". A block can be repeated by a bounded loop, or
+//! installed as the trap handler through `mtvec`.
+//!
+//! The [`TemplateGenerator`] creates seeds from a template. Every block starts with a
+//! marker, the HINT `addi x0, x0, 0x100 + index`, so that the [`TemplateBlockMutator`] can
+//! find the blocks of a program and regenerate one of them at a time.
+//!
+//! Macros use `t0`-`t2` as scratch registers, loops count with the [`LOOP_COUNTER`], which
+//! the mutators never write.
+//!
+//! Like the handler of the ELF template (`testcase.S`), the generated trap handlers count
+//! the traps and end the test in a `wfi` loop after [`TRAP_LIMIT`] of them.
+
+use std::collections::BTreeMap;
+
+use libafl::{
+    generators::Generator,
+    inputs::BytesInput,
+    mutators::MutationResult,
+    state::{HasMaxSize, HasRand},
+    Error,
+};
+use libafl_bolts::{rands::Rand, HasLen, Named};
+use libpresifuzz_riscv::cpu_profile::{
+    add, addi, andi, auipc, c_nop, csrrc, csrrci, csrrs, csrrsi, csrrw, ld, lhu, lw, mret, sd, sfence_vma, sll, sret,
+    sw, wfi,
+};
+pub use libpresifuzz_riscv::asm::load_immediate;
+use libpresifuzz_riscv::dasm::gen::{gen_branch_instruction, gen_jal_instruction};
+use libpresifuzz_riscv::instruction::Instruction;
+use libpresifuzz_riscv::profile::CpuProfile;
+use serde::{Deserialize, Serialize};
+
+use crate::memory::{MemoryAccessConf, MemoryAccessMutator};
+use crate::operands::OperandDictionary;
+use crate::{byte_len, cuts_bounded_loop, ISAInput, ISAMutator, LOOP_COUNTER};
+
+/// Immediate of the marker of the first block
+pub const BLOCK_MARKER_BASE: u32 = 0x100;
+
+/// Scratch registers of the macros
+const T0: u32 = 5;
+const T1: u32 = 6;
+const T2: u32 = 7;
+/// Number of traps after which the generated trap handlers end the test, as the handler
+/// of the ELF template
+pub const TRAP_LIMIT: u32 = 10;
+/// `addi x0, x0, 0`, the initial value of the trap counters
+const NOP: u32 = 0x13;
+
+const CSR_SIE: u32 = 0x104;
+const CSR_STVEC: u32 = 0x105;
+const CSR_SEPC: u32 = 0x141;
+const CSR_SCAUSE: u32 = 0x142;
+const CSR_SATP: u32 = 0x180;
+const CSR_MSTATUS: u32 = 0x300;
+const CSR_MEDELEG: u32 = 0x302;
+const CSR_MIE: u32 = 0x304;
+const CSR_MTVEC: u32 = 0x305;
+const CSR_MEPC: u32 = 0x341;
+const CSR_MCAUSE: u32 = 0x342;
+const CSR_PMPCFG0: u32 = 0x3a0;
+const CSR_PMPADDR0: u32 = 0x3b0;
+
+/// `mstatus.MIE`
+const MSTATUS_MIE: u32 = 1 << 3;
+/// `mstatus.MPP`
+const MSTATUS_MPP: u64 = 3 << 11;
+/// Supervisor mode in `mstatus.MPP`
+const MSTATUS_MPP_S: u64 = 1 << 11;
+/// `mie.MSIE`, `mie.MTIE` and `mie.MEIE`
+const MIE_MACHINE: u64 = (1 << 3) | (1 << 7) | (1 << 11);
+/// `mie.MTIE`
+const MIE_MTIE: u64 = 1 << 7;
+/// NAPOT address matching in a `pmpcfg` entry
+const PMP_NAPOT: u64 = 3 << 3;
+/// The exceptions [`Macro::DelegateExceptions`] delegates: misaligned instructions,
+/// breakpoints, misaligned loads, misaligned stores, access faults on loads and stores,
+/// U-mode ecalls and store page faults. The S-mode handler cannot raise them itself.
+pub const DELEGABLE_EXCEPTIONS: u64 = (1 << 0) | (1 << 3) | (1 << 4) | (1 << 5) | (1 << 6) | (1 << 7) | (1 << 8) | (1 << 15);
+/// Sv39 translation in `satp.MODE`
+const SATP_SV39: u64 = 8 << 60;
+/// Valid, readable, writable, executable, accessed and dirty leaf PTE
+const PTE_RWX: u64 = 0xcf;
+/// User-accessible PTE
+const PTE_USER: u64 = 1 << 4;
+/// Number of identity mapped 1 GiB pages of [`Macro::Sv39PageTable`]
+const SV39_GIGAPAGES: u64 = 4;
+/// Default base address of the CLINT, as on the Spike, CVA6 and Chipyard SoCs
+pub const DEFAULT_CLINT: u64 = 0x0200_0000;
+const CLINT_MTIMECMP: u64 = 0x4000;
+const CLINT_MTIME: u64 = 0xbff8;
+
+/// A family of instructions the random items of a block draw from
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum InstructionClass {
+    /// Integer computations on registers and immediates
+    Alu,
+    /// Loads and stores, to the legal memory regions when configured
+    Memory,
+    /// CSR accesses
+    Csr,
+    /// Single and double precision floating-point instructions
+    Float,
+    /// Atomic memory operations
+    Atomic,
+    /// Compressed instructions
+    Compressed,
+    /// Vector instructions
+    Vector,
+    /// Any instruction of the profile
+    Any,
+}
+
+impl InstructionClass {
+    /// Whether `meta` belongs to the class. Control transfers are left to the branch
+    /// mutator and the macros, they never belong to a class.
+    #[must_use]
+    pub fn matches(self, meta: &Instruction) -> bool {
+        let extension = meta.extension.split_once('_').map_or("", |(_, ext)| ext);
+        let member = match self {
+            Self::Alu => meta.is_integer_alu(),
+            Self::Memory => meta.is_load() || meta.is_store(),
+            Self::Csr => extension == "zicsr",
+            Self::Float => extension == "f" || extension == "d",
+            Self::Atomic => extension == "a",
+            Self::Compressed => meta.length == 2,
+            Self::Vector => extension == "v",
+            Self::Any => true,
+        };
+        member && !Self::is_control_transfer(meta)
+    }
+
+    fn is_control_transfer(meta: &Instruction) -> bool {
+        if meta.length == 2 {
+            return matches!(
+                meta.mnemonic.as_str(),
+                "C_J" | "C_JAL" | "C_JR" | "C_JALR" | "C_BEQZ" | "C_BNEZ" | "C_EBREAK"
+            );
+        }
+        // branches, jal, jalr, and the SYSTEM instructions other than CSR accesses
+        matches!(meta.major_opcode(), 0x63 | 0x67 | 0x6f)
+            || (meta.major_opcode() == 0x73 && (meta.mask >> 12) & 0x7 == 0)
+    }
+}
+
+/// A well-formed sequence of instructions, see the module documentation for the
+/// registers it uses
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Macro {
+    /// Enable the machine software, timer and external interrupts
+    EnableInterrupts,
+    /// Clear `mstatus.MIE`
+    DisableInterrupts,
+    /// `mret` to the next instruction in U-mode
+    SwitchToUserMode,
+    /// `mret` to the next instruction in S-mode
+    SwitchToSupervisorMode,
+    /// Configure PMP entry `index` to match the naturally aligned power-of-two region at
+    /// `start`, with `permissions` among `r`, `w` and `x`
+    PmpRegion {
+        index: u32,
+        start: u64,
+        size: u64,
+        permissions: String,
+    },
+    /// Write `value` to `csr`
+    WriteCsr { csr: u32, value: u64 },
+    /// Delegate the exceptions of `mask` among [`DELEGABLE_EXCEPTIONS`] to S-mode through
+    /// `medeleg`, and install an S-mode handler returning after the trapping instruction
+    DelegateExceptions { mask: u64 },
+    /// Build an Sv39 page table at `root`, 4 KiB aligned, identity mapping the low 4 GiB
+    /// with 1 GiB pages, U-mode pages when `user`, and enable translation in `satp`.
+    /// Nothing on RV32.
+    Sv39PageTable {
+        root: u64,
+        #[serde(default)]
+        user: bool,
+    },
+    /// Raise a machine timer interrupt `delta` ticks from now, through the CLINT at
+    /// `clint`, and enable it. The interrupt stays pending until `mtimecmp` is written
+    /// again: the generated trap handlers disable it in `mie` once taken, the handler of
+    /// the ELF template ends the test after [`TRAP_LIMIT`] traps.
+    ArmTimer {
+        delta: u64,
+        #[serde(default = "default_clint")]
+        clint: u64,
+    },
+}
+
+fn default_clint() -> u64 {
+    DEFAULT_CLINT
+}
+
+impl Macro {
+    /// Check the parameters of the macro
+    pub fn validate(&self) -> Result<(), Error> {
+        match self {
+            Self::PmpRegion {
+                index,
+                start,
+                size,
+                permissions,
+            } => {
+                if *index >= 16 {
+                    return Err(Error::illegal_argument(format!("Invalid PMP entry {index}")));
+                }
+                if *size < 8 || !size.is_power_of_two() || !start.is_multiple_of(*size) {
+                    return Err(Error::illegal_argument(format!(
+                        "PMP region {start:#x}+{size:#x} is not a naturally aligned power of two"
+                    )));
+                }
+                if !permissions.chars().all(|c| "rwx".contains(c)) {
+                    return Err(Error::illegal_argument(format!("Invalid PMP permissions {permissions}")));
+                }
+                Ok(())
+            }
+            Self::WriteCsr { csr, .. } if *csr >= 1 << 12 => {
+                Err(Error::illegal_argument(format!("Invalid CSR {csr:#x}")))
+            }
+            Self::Sv39PageTable { root, .. } if !root.is_multiple_of(0x1000) || *root >= 1 << 32 => Err(
+                Error::illegal_argument(format!("Page table root {root:#x} is not 4 KiB aligned below 4 GiB")),
+            ),
+            Self::ArmTimer { clint, .. } if *clint >= 1 << 32 => {
+                Err(Error::illegal_argument(format!("Invalid CLINT address {clint:#x}")))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// The instructions of the macro for `profile`, starting at byte `offset` of the
+    /// program
+    #[must_use]
+    pub fn instructions(&self, profile: &CpuProfile, offset: usize) -> Vec<Instruction> {
+        let encoder = Encoder::new(profile);
+        let rv32 = profile.xlen() == Some(32);
+        let mut instructions = vec![];
+        match self {
+            Self::EnableInterrupts => {
+                instructions.extend(encoder.load_immediate(T0, MIE_MACHINE));
+                instructions.push(csrrs(0, T0, CSR_MIE));
+                instructions.push(csrrsi(0, CSR_MSTATUS, MSTATUS_MIE));
+            }
+            Self::DisableInterrupts => instructions.push(csrrci(0, CSR_MSTATUS, MSTATUS_MIE)),
+            Self::SwitchToUserMode | Self::SwitchToSupervisorMode => {
+                instructions.extend(encoder.load_immediate(T0, MSTATUS_MPP));
+                instructions.push(csrrc(0, T0, CSR_MSTATUS));
+                if *self == Self::SwitchToSupervisorMode {
+                    instructions.extend(encoder.load_immediate(T0, MSTATUS_MPP_S));
+                    instructions.push(csrrs(0, T0, CSR_MSTATUS));
+                }
+                // mepc: the instruction after mret
+                instructions.push(auipc(T0, 0));
+                instructions.push(addi(T0, T0, 16));
+                instructions.push(csrrw(0, T0, CSR_MEPC));
+                instructions.push(mret());
+            }
+            Self::PmpRegion {
+                index,
+                start,
+                size,
+                permissions,
+            } => {
+                let address = (start | ((size >> 1) - 1)) >> 2;
+                let mut config = PMP_NAPOT;
+                for (bit, permission) in ['r', 'w', 'x'].into_iter().enumerate() {
+                    if permissions.contains(permission) {
+                        config |= 1 << bit;
+                    }
+                }
+                // RV32 packs 4 entries per pmpcfg register, RV64 8 in the even ones
+                let (cfg_csr, shift) = if rv32 {
+                    (CSR_PMPCFG0 + index / 4, (index % 4) * 8)
+                } else {
+                    (CSR_PMPCFG0 + (index / 8) * 2, (index % 8) * 8)
+                };
+
+                instructions.extend(encoder.load_immediate(T0, address));
+                instructions.push(csrrw(0, T0, CSR_PMPADDR0 + index));
+                instructions.extend(encoder.load_immediate(T0, 0xff << shift));
+                instructions.push(csrrc(0, T0, cfg_csr));
+                instructions.extend(encoder.load_immediate(T0, config << shift));
+                instructions.push(csrrs(0, T0, cfg_csr));
+            }
+            Self::WriteCsr { csr, value } => {
+                instructions.extend(encoder.load_immediate(T0, *value));
+                instructions.push(csrrw(0, T0, *csr));
+            }
+            Self::DelegateExceptions { mask } => {
+                instructions.extend(encoder.load_immediate(T0, mask & DELEGABLE_EXCEPTIONS));
+                instructions.push(csrrw(0, T0, CSR_MEDELEG));
+                let handler_offset = offset + byte_len(&instructions);
+                instructions.extend(encoder.trap_handler(TrapMode::Supervisor, vec![], handler_offset));
+            }
+            Self::Sv39PageTable { .. } if rv32 => {}
+            Self::Sv39PageTable { root, user } => {
+                // zero the table, then one leaf PTE per gigapage
+                instructions.extend(encoder.load_immediate(T0, *root));
+                instructions.extend(encoder.load_immediate(T1, root + 0x1000));
+                instructions.push(sd(0, T0, 0, 0));
+                instructions.push(addi(T0, T0, 8));
+                if let Ok(branch) = gen_branch_instruction(T0 as u8, T1 as u8, -8, 1, true) {
+                    instructions.push(encoder.encoded(branch));
+                }
+                instructions.extend(encoder.load_immediate(T0, *root));
+                let flags = if *user { PTE_RWX | PTE_USER } else { PTE_RWX };
+                for gigapage in 0..SV39_GIGAPAGES {
+                    instructions.extend(encoder.load_immediate(T1, (gigapage << 28) | flags));
+                    instructions.push(sd(0, T0, T1, (gigapage * 8) as u32));
+                }
+                instructions.extend(encoder.load_immediate(T0, SATP_SV39 | (root >> 12)));
+                instructions.push(csrrw(0, T0, CSR_SATP));
+                instructions.push(sfence_vma(0, 0));
+            }
+            Self::ArmTimer { delta, clint } => {
+                if rv32 {
+                    // raise the low word first, so that no interrupt is raised early
+                    instructions.extend(encoder.load_immediate(T0, clint + CLINT_MTIMECMP));
+                    instructions.push(addi(T1, 0, (-1i32) as u32));
+                    instructions.push(sw(0, T0, T1, 0));
+                    instructions.extend(encoder.load_immediate(T0, clint + CLINT_MTIME));
+                    instructions.push(lw(T1, T0, 0));
+                    instructions.push(lw(T2, T0, 4));
+                    instructions.extend(encoder.load_immediate(T0, *delta));
+                    instructions.push(add(T1, T1, T0));
+                    instructions.extend(encoder.load_immediate(T0, clint + CLINT_MTIMECMP));
+                    instructions.push(sw(0, T0, T2, 4));
+                    instructions.push(sw(0, T0, T1, 0));
+                } else {
+                    instructions.extend(encoder.load_immediate(T0, clint + CLINT_MTIME));
+                    instructions.push(ld(T1, T0, 0));
+                    instructions.extend(encoder.load_immediate(T0, *delta));
+                    instructions.push(add(T1, T1, T0));
+                    instructions.extend(encoder.load_immediate(T0, clint + CLINT_MTIMECMP));
+                    instructions.push(sd(0, T0, T1, 0));
+                }
+                instructions.extend(encoder.load_immediate(T0, MIE_MTIE));
+                instructions.push(csrrs(0, T0, CSR_MIE));
+                instructions.push(csrrsi(0, CSR_MSTATUS, MSTATUS_MIE));
+            }
+        }
+        instructions.into_iter().map(|insn| encoder.emit(insn)).collect()
+    }
+}
+
+/// Random instructions of a block
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct InstructionItem {
+    /// Minimal number of instructions
+    pub min: u64,
+    /// Maximal number of instructions
+    pub max: u64,
+    /// Weight of each class, every instruction of the profile when empty
+    #[serde(default)]
+    pub classes: BTreeMap<InstructionClass, f64>,
+}
+
+/// An item of a [`TemplateBlock`]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BlockItem {
+    /// Random instructions
+    Instructions(InstructionItem),
+    /// A macro
+    Macro(Macro),
+}
+
+/// A named block of a [`ProgramTemplate`]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TemplateBlock {
+    /// Name of the block, e.g. `init`
+    pub name: String,
+    /// Run the block this number of times, in a loop
+    #[serde(default)]
+    pub repeat: Option<u32>,
+    /// Install the block as the trap handler: it is skipped in the program flow, and
+    /// returns to the instruction after the trapping one
+    #[serde(default)]
+    pub trap_handler: bool,
+    /// The items of the block, in order, written `macro: ...` or `instructions: ...`
+    #[serde(with = "serde_yaml::with::singleton_map_recursive")]
+    pub items: Vec<BlockItem>,
+}
+
+/// The blocks of the generated programs, see the module documentation
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ProgramTemplate {
+    /// The blocks, in program order
+    pub blocks: Vec<TemplateBlock>,
+}
+
+impl Default for ProgramTemplate {
+    /// CSR setup, init, trap handler, loop and tail blocks of random instructions
+    fn default() -> Self {
+        let instructions = |min, max, classes: &[(InstructionClass, f64)]| {
+            BlockItem::Instructions(InstructionItem {
+                min,
+                max,
+                classes: classes.iter().copied().collect(),
+            })
+        };
+        let block = |name: &str, items| TemplateBlock {
+            name: name.to_string(),
+            repeat: None,
+            trap_handler: false,
+            items,
+        };
+
+        Self {
+            blocks: vec![
+                block("csr_setup", vec![instructions(1, 4, &[(InstructionClass::Csr, 1.0)])]),
+                block(
+                    "init",
+                    vec![instructions(4, 8, &[(InstructionClass::Alu, 3.0), (InstructionClass::Memory, 1.0)])],
+                ),
+                TemplateBlock {
+                    trap_handler: true,
+                    ..block("trap_handler", vec![instructions(0, 4, &[(InstructionClass::Alu, 1.0)])])
+                },
+                TemplateBlock {
+                    repeat: Some(4),
+                    ..block("loop", vec![instructions(4, 12, &[])])
+                },
+                block("tail", vec![instructions(4, 8, &[])]),
+            ],
+        }
+    }
+}
+
+impl ProgramTemplate {
+    /// Read the template from the `generator` section of a `config.yml`, the default
+    /// template without `blocks`:
+    ///
+    /// ```yaml
+    /// generator:
+    ///   blocks:
+    ///     - name: init
+    ///       items:
+    ///         - macro: enable_interrupts
+    ///         - instructions: { min: 4, max: 8, classes: { alu: 3, memory: 1 } }
+    ///     - name: loop
+    ///       repeat: 4
+    ///       items:
+    ///         - instructions: { min: 4, max: 12 }
+    /// ```
+    pub fn from_config(config: &serde_yaml::Value) -> Result<Self, Error> {
+        let generator = &config["generator"];
+        if generator["blocks"].is_null() {
+            return Ok(Self::default());
+        }
+        let template: Self = serde_yaml::from_value(generator.clone())
+            .map_err(|e| Error::serialize(format!("Invalid generator template: {e}")))?;
+        template.validate()?;
+        Ok(template)
+    }
+
+    /// Check the blocks of the template
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.blocks.is_empty() || self.blocks.len() > 0x700 {
+            return Err(Error::illegal_argument(format!(
+                "Invalid number of template blocks {}",
+                self.blocks.len()
+            )));
+        }
+        for block in &self.blocks {
+            if block.repeat.is_some_and(|n| n == 0 || n >= 1 << 11) {
+                return Err(Error::illegal_argument(format!("Invalid repeat count of block {}", block.name)));
+            }
+            for item in &block.items {
+                match item {
+                    BlockItem::Instructions(item) if item.min > item.max => {
+                        return Err(Error::illegal_argument(format!(
+                            "Invalid instruction count in block {}",
+                            block.name
+                        )));
+                    }
+                    BlockItem::Instructions(item)
+                        if item.classes.values().any(|weight| !weight.is_finite() || *weight < 0.0) =>
+                    {
+                        return Err(Error::illegal_argument(format!("Invalid class weight in block {}", block.name)));
+                    }
+                    BlockItem::Macro(m) => m.validate()?,
+                    BlockItem::Instructions(_) => {}
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Generates programs from a [`ProgramTemplate`]
+#[derive(Debug)]
+pub struct TemplateGenerator {
+    template: ProgramTemplate,
+    profile: CpuProfile,
+    dictionary: OperandDictionary,
+    memory: Option<MemoryAccessMutator>,
+    candidates: BTreeMap<InstructionClass, Vec<&'static Instruction>>,
+    max_size: usize,
+}
+
+impl<S> Generator<BytesInput, S> for TemplateGenerator
+where
+    S: HasRand,
+{
+    fn generate(&mut self, state: &mut S) -> Result<BytesInput, Error> {
+        let program = ISAInput::new_from_parsed_data(self.program(state.rand_mut()));
+        let mut bytes = vec![];
+        program.unparse(&mut bytes);
+        Ok(BytesInput::new(bytes))
+    }
+}
+
+impl TemplateGenerator {
+    /// Creates a new [`TemplateGenerator`] of programs up to `max_size` bytes
+    #[must_use]
+    pub fn new(template: ProgramTemplate, max_size: usize) -> Self {
+        Self::with_profile(template, max_size, CpuProfile::default())
+    }
+
+    /// Only generate the instructions of `profile`
+    #[must_use]
+    pub fn with_profile(template: ProgramTemplate, max_size: usize, profile: CpuProfile) -> Self {
+        let candidates = [
+            InstructionClass::Alu,
+            InstructionClass::Memory,
+            InstructionClass::Csr,
+            InstructionClass::Float,
+            InstructionClass::Atomic,
+            InstructionClass::Compressed,
+            InstructionClass::Vector,
+            InstructionClass::Any,
+        ]
+        .into_iter()
+        .map(|class| {
+            let instructions = profile
+                .extensions()
+                .iter()
+                .filter(|ext| ext.weight > 0.0)
+                .flat_map(|ext| ext.instructions.iter().copied())
+                .filter(|meta| class.matches(meta))
+                .collect();
+            (class, instructions)
+        })
+        .collect();
+
+        Self {
+            template,
+            profile,
+            dictionary: OperandDictionary::default(),
+            memory: None,
+            candidates,
+            max_size,
+        }
+    }
+
+    /// Direct the loads and stores to the memory of `conf`
+    #[must_use]
+    pub fn with_memory(mut self, conf: MemoryAccessConf) -> Self {
+        self.memory = Some(MemoryAccessMutator::new(conf).with_profile(self.profile.clone()));
+        self
+    }
+
+    /// The template of the generated programs
+    #[must_use]
+    pub fn template(&self) -> &ProgramTemplate {
+        &self.template
+    }
+
+    /// A program made of the blocks of the template, the last blocks are left out when
+    /// the program would exceed the maximal size
+    pub fn program<R: Rand>(&self, rand: &mut R) -> Vec<Instruction> {
+        let mut program: Vec<Instruction> = vec![];
+        let mut size = 0;
+        for idx in 0..self.template.blocks.len() {
+            let block = self.block(rand, idx, size);
+            let block_size = byte_len(&block);
+            if size + block_size > self.max_size {
+                break;
+            }
+            size += block_size;
+            program.extend(block);
+        }
+        program
+    }
+
+    /// Block `idx` of the template, starting at byte `offset` of the program
+    pub fn block<R: Rand>(&self, rand: &mut R, idx: usize, offset: usize) -> Vec<Instruction> {
+        let block = &self.template.blocks[idx];
+        let encoder = Encoder::new(&self.profile);
+
+        // the marker, then the trap handler prologue or the loop counter
+        let mut body_offset = offset + 4;
+        if block.trap_handler {
+            body_offset += Encoder::trap_handler_prologue(body_offset);
+        } else if block.repeat.is_some() {
+            body_offset += 4;
+        }
+
+        let mut body = vec![];
+        for item in &block.items {
+            match item {
+                BlockItem::Instructions(item) => {
+                    for _ in 0..rand.between(item.min, item.max) {
+                        body.extend(self.random_instructions(rand, &item.classes));
+                    }
+                }
+                BlockItem::Macro(m) => body.extend(m.instructions(&self.profile, body_offset + byte_len(&body))),
+            }
+        }
+
+        let mut instructions = vec![encoder.emit(addi(0, 0, BLOCK_MARKER_BASE + idx as u32))];
+        if block.trap_handler {
+            instructions.extend(encoder.trap_handler(TrapMode::Machine, body, offset + 4));
+        } else if let Some(iterations) = block.repeat {
+            instructions.extend(self.repeat(body, iterations));
+        } else {
+            instructions.extend(body);
+        }
+        instructions
+    }
+
+    /// The blocks of `instructions`: the index of their marker and the index of their
+    /// template block
+    #[must_use]
+    pub fn blocks(&self, instructions: &[Instruction]) -> Vec<(usize, usize)> {
+        instructions
+            .iter()
+            .enumerate()
+            .filter(|(_, insn)| insn.length == 4 && insn.instruction & 0xfffff == 0x13)
+            .filter_map(|(pos, insn)| {
+                let idx = ((insn.instruction >> 20) as u32).checked_sub(BLOCK_MARKER_BASE)? as usize;
+                (idx < self.template.blocks.len()).then_some((pos, idx))
+            })
+            .collect()
+    }
+
+    /// One random instruction of one of `classes`, or a legal memory access sequence
+    fn random_instructions<R: Rand>(&self, rand: &mut R, classes: &BTreeMap<InstructionClass, f64>) -> Vec<Instruction> {
+        let class = Self::pick_class(rand, classes);
+
+        if class == InstructionClass::Memory {
+            if let Some(sequence) = self.memory.as_ref().and_then(|memory| memory.sequence(rand)) {
+                return sequence;
+            }
+        }
+
+        let candidates = &self.candidates[&class];
+        if candidates.is_empty() {
+            return vec![];
+        }
+        let meta = rand.choose(candidates.iter().copied());
+        vec![self.dictionary.instantiate(rand, meta)]
+    }
+
+    fn pick_class<R: Rand>(rand: &mut R, classes: &BTreeMap<InstructionClass, f64>) -> InstructionClass {
+        let total: f64 = classes.values().sum();
+        if total <= 0.0 {
+            return InstructionClass::Any;
+        }
+        let mut target = (rand.below(1 << 20) as f64 / f64::from(1 << 20)) * total;
+        for (class, weight) in classes {
+            if target < *weight {
+                return *class;
+            }
+            target -= weight;
+        }
+        // rounding, fall back on the last class with a weight
+        classes
+            .iter()
+            .rev()
+            .find(|(_, weight)| **weight > 0.0)
+            .map_or(InstructionClass::Any, |(class, _)| *class)
+    }
+
+    /// `body` run `iterations` times, counted down in the loop counter. `body` is run once
+    /// when too large for a branch back to its start.
+    fn repeat(&self, body: Vec<Instruction>, iterations: u32) -> Vec<Instruction> {
+        let encoder = Encoder::new(&self.profile);
+        let back = -((byte_len(&body) + 4) as i32);
+        let Ok(branch) = gen_branch_instruction(LOOP_COUNTER as u8, 0, back, 1, true) else {
+            return body;
+        };
+
+        let mut instructions = vec![encoder.emit(addi(LOOP_COUNTER, 0, iterations))];
+        instructions.extend(body);
+        instructions.push(encoder.emit(addi(LOOP_COUNTER, LOOP_COUNTER, (-1i32) as u32)));
+        instructions.push(encoder.encoded(branch));
+        instructions
+    }
+}
+
+/// The privilege level a trap handler runs in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TrapMode {
+    Machine,
+    Supervisor,
+}
+
+/// Encodes instructions with the metadata of a profile
+pub(crate) struct Encoder<'a> {
+    profile: &'a CpuProfile,
+}
+
+impl<'a> Encoder<'a> {
+    pub(crate) fn new(profile: &'a CpuProfile) -> Self {
+        Self { profile }
+    }
+
+    /// The number of bytes between `offset` and a trap handler installed there, see
+    /// [`Encoder::trap_handler`]
+    pub(crate) fn trap_handler_prologue(offset: usize) -> usize {
+        // auipc, addi, csrrw and jal, then a c.nop when the handler would be misaligned
+        if (offset + 16).is_multiple_of(4) {
+            16
+        } else {
+            18
+        }
+    }
+
+    /// Install `body`, starting at byte `offset`, as the trap handler of `mode`: point
+    /// `mtvec` or `stvec` to it and jump over it. The handler counts the traps in a word
+    /// after it and ends the test after [`TRAP_LIMIT`] of them. Otherwise it returns after
+    /// the trapping instruction, or to the interrupted one with the interrupt disabled.
+    /// `body` alone when it is too large to jump over.
+    pub(crate) fn trap_handler(&self, mode: TrapMode, body: Vec<Instruction>, offset: usize) -> Vec<Instruction> {
+        let (tvec, epc, cause, ie, ret) = match mode {
+            TrapMode::Machine => (CSR_MTVEC, CSR_MEPC, CSR_MCAUSE, CSR_MIE, mret()),
+            TrapMode::Supervisor => (CSR_STVEC, CSR_SEPC, CSR_SCAUSE, CSR_SIE, sret()),
+        };
+        let prologue = Self::trap_handler_prologue(offset);
+        // from the auipc to the counter, a nop that is never executed
+        let counter_offset: u32 = 92;
+        let (Ok(limit), Ok(exception), Ok(compressed), Ok(halt)) = (
+            gen_branch_instruction(T2 as u8, T1 as u8, 64, 7, true),
+            gen_branch_instruction(T1 as u8, 0, 20, 5, true),
+            gen_branch_instruction(T1 as u8, T2 as u8, 8, 1, true),
+            gen_jal_instruction(0, -4, true),
+        ) else {
+            return body;
+        };
+
+        // keep the counter 4-byte aligned
+        let mut handler = body.clone();
+        if !byte_len(&handler).is_multiple_of(4) {
+            handler.push(self.emit(c_nop(0, 0)));
+        }
+        handler.extend(
+            [
+                auipc(T0, 0),
+                lw(T2, T0, counter_offset),
+                addi(T2, T2, 1),
+                sw(counter_offset >> 5, T0, T2, counter_offset & 0x1f),
+                addi(T1, 0, NOP + TRAP_LIMIT),
+                self.encoded(limit),
+                csrrs(T1, 0, cause),
+                self.encoded(exception),
+                // interrupts resume the interrupted instruction, without the interrupt
+                addi(T2, 0, 1),
+                sll(T2, T2, T1),
+                csrrc(0, T2, ie),
+                ret.clone(),
+                csrrs(T0, 0, epc),
+                lhu(T1, T0, 0),
+                andi(T1, T1, 3),
+                addi(T0, T0, 2),
+                addi(T2, 0, 3),
+                self.encoded(compressed),
+                addi(T0, T0, 2),
+                csrrw(0, T0, epc),
+                ret,
+                wfi(),
+                self.encoded(halt),
+                addi(0, 0, 0),
+            ]
+            .into_iter()
+            .map(|insn| self.emit(insn)),
+        );
+
+        let Ok(jump) = gen_jal_instruction(0, (prologue - 12 + byte_len(&handler)) as i32, true) else {
+            return body;
+        };
+        let mut instructions: Vec<Instruction> = [
+            auipc(T0, 0),
+            addi(T0, T0, prologue as u32),
+            csrrw(0, T0, tvec),
+            self.encoded(jump),
+        ]
+        .into_iter()
+        .map(|insn| self.emit(insn))
+        .collect();
+        if !prologue.is_multiple_of(4) {
+            instructions.push(self.emit(c_nop(0, 0)));
+        }
+        instructions.extend(handler);
+        instructions
+    }
+
+    /// The instructions loading `value`, truncated to the width of the profile, into `rd`
+    pub(crate) fn load_immediate(&self, rd: u32, value: u64) -> Vec<Instruction> {
+        let value = if self.profile.xlen() == Some(32) {
+            i64::from(value as u32 as i32)
+        } else {
+            value as i64
+        };
+        load_immediate(rd, value).into_iter().map(|insn| self.emit(insn)).collect()
+    }
+
+    /// The instruction encoded by `word`, e.g. by the encoders of `dasm::gen`
+    pub(crate) fn encoded(&self, (word, length): (u32, usize)) -> Instruction {
+        self.emit(Instruction {
+            instruction: u64::from(word),
+            length,
+            ..Instruction::default()
+        })
+    }
+
+    /// `insn` with the metadata of the profile, as if decoded from the input
+    pub(crate) fn emit(&self, insn: Instruction) -> Instruction {
+        match self.profile.decode(insn.instruction as u32) {
+            Some(meta) => Instruction {
+                instruction: insn.instruction,
+                ..meta.clone()
+            },
+            None => insn,
+        }
+    }
+}
+
+/// Regenerates one block of a program created by a [`TemplateGenerator`]
+#[derive(Debug)]
+pub struct TemplateBlockMutator {
+    generator: TemplateGenerator,
+}
+
+impl<S> ISAMutator<S> for TemplateBlockMutator
+where
+    S: HasRand + HasMaxSize,
+{
+    fn mutate(
+        &mut self,
+        state: &mut S,
+        input: &mut ISAInput,
+        _stage_idx: i32,
+    ) -> Result<MutationResult, Error> {
+        let blocks = self.generator.blocks(&input.instructions);
+        if blocks.is_empty() {
+            return Ok(MutationResult::Skipped);
+        }
+
+        let pick = state.rand_mut().below(blocks.len() as u64) as usize;
+        let (start, idx) = blocks[pick];
+        let end = blocks.get(pick + 1).map_or(input.instructions.len(), |(pos, _)| *pos);
+        if cuts_bounded_loop(&input.instructions, start..end) {
+            return Ok(MutationResult::Skipped);
+        }
+
+        let offset = byte_len(&input.instructions[..start]);
+        let old_size = byte_len(&input.instructions[start..end]);
+        let mut block = self.generator.block(state.rand_mut(), idx, offset);
+
+        // keep the alignment of the following instructions
+        if !(byte_len(&block) + 4 - old_size % 4).is_multiple_of(4) {
+            if !self.generator.profile.supports("c") {
+                return Ok(MutationResult::Skipped);
+            }
+            block.push(Encoder::new(&self.generator.profile).emit(c_nop(0, 0)));
+        }
+
+        if input.len() - old_size + byte_len(&block) > state.max_size() {
+            return Ok(MutationResult::Skipped);
+        }
+
+        input.instructions.splice(start..end, block);
+
+        Ok(MutationResult::Mutated)
+    }
+}
+
+impl Named for TemplateBlockMutator {
+    fn name(&self) -> &str {
+        "TemplateBlockMutator"
+    }
+}
+
+impl TemplateBlockMutator {
+    /// Creates a new [`TemplateBlockMutator`] regenerating the blocks of `generator`
+    #[must_use]
+    pub fn new(generator: TemplateGenerator) -> Self {
+        Self { generator }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use libafl::{
+        corpus::InMemoryCorpus,
+        generators::Generator,
+        inputs::{BytesInput, HasBytesVec},
+        mutators::MutationResult,
+        state::{HasMaxSize, HasRand, StdState},
+    };
+    use libafl_bolts::rands::StdRand;
+    use std::collections::BTreeMap;
+
+    use libpresifuzz_riscv::cpu_profile::{addi, c_nop};
+    use libpresifuzz_riscv::instruction::Instruction;
+    use libpresifuzz_riscv::profile::CpuProfile;
+
+    use super::{
+        load_immediate, BlockItem, Encoder, Macro, ProgramTemplate, TemplateBlockMutator, TemplateGenerator,
+        TrapMode, CSR_MCAUSE, CSR_MEPC, CSR_MIE, CSR_MTVEC, CSR_SCAUSE, CSR_SEPC, CSR_SIE, CSR_STVEC, MIE_MACHINE,
+        MIE_MTIE, TRAP_LIMIT,
+    };
+    use crate::{byte_len, ISAInput, ISAMutator};
+
+    /// How [`Hart::run`] stopped
+    #[derive(Debug, PartialEq)]
+    enum End {
+        /// At this address, after an `mret`/`sret` or on the stop address
+        At(u64),
+        /// On `wfi`
+        Halt,
+    }
+
+    /// Runs the few RV64 instructions of the trap handlers, the program being at address 0
+    struct Hart {
+        regs: [u64; 32],
+        csrs: BTreeMap<u32, u64>,
+        memory: Vec<u8>,
+    }
+
+    impl Hart {
+        fn new(program: &[Instruction]) -> Self {
+            let mut memory = vec![];
+            for insn in program {
+                memory.extend(&insn.instruction.to_le_bytes()[..insn.length]);
+            }
+            Self {
+                regs: [0; 32],
+                csrs: BTreeMap::new(),
+                memory,
+            }
+        }
+
+        fn csr(&self, csr: u32) -> u64 {
+            self.csrs.get(&csr).copied().unwrap_or(0)
+        }
+
+        fn load(&self, address: u64, len: usize) -> u64 {
+            let mut bytes = [0; 8];
+            bytes[..len].copy_from_slice(&self.memory[address as usize..address as usize + len]);
+            u64::from_le_bytes(bytes)
+        }
+
+        fn run(&mut self, mut pc: u64, stop: u64) -> End {
+            for _ in 0..1000 {
+                if pc == stop {
+                    return End::At(pc);
+                }
+                let word = self.load(pc, 4) as u32;
+                if word & 3 != 3 {
+                    assert_eq!(word & 0xffff, 1, "only c.nop is compressed");
+                    pc += 2;
+                    continue;
+                }
+                let (rd, rs1, rs2) = ((word >> 7) & 31, (word >> 15) & 31, (word >> 20) & 31);
+                let (a, b) = (self.regs[rs1 as usize], self.regs[rs2 as usize]);
+                let imm = i64::from(word as i32 >> 20) as u64;
+                let mut next = pc + 4;
+                let value = match (word & 0x7f, (word >> 12) & 7) {
+                    (0x17, _) => Some(pc.wrapping_add(i64::from((word & 0xffff_f000) as i32) as u64)),
+                    (0x13, 0) => Some(a.wrapping_add(imm)),
+                    (0x13, 7) => Some(a & imm),
+                    (0x33, 1) => Some(a << (b & 63)),
+                    (0x03, 2) => Some(i64::from(self.load(a.wrapping_add(imm), 4) as i32) as u64),
+                    (0x03, 5) => Some(self.load(a.wrapping_add(imm), 2)),
+                    (0x23, 2) => {
+                        let offset = i64::from(((word as i32 >> 25) << 5) | ((word >> 7) & 31) as i32) as u64;
+                        let at = a.wrapping_add(offset) as usize;
+                        self.memory[at..at + 4].copy_from_slice(&(b as u32).to_le_bytes());
+                        None
+                    }
+                    (0x63, funct3) => {
+                        let offset = ((word >> 31) << 12) | (((word >> 7) & 1) << 11) | (((word >> 25) & 0x3f) << 5) | (((word >> 8) & 0xf) << 1);
+                        let taken = match funct3 {
+                            0 => a == b,
+                            1 => a != b,
+                            5 => a as i64 >= b as i64,
+                            7 => a >= b,
+                            _ => unreachable!(),
+                        };
+                        if taken {
+                            next = pc.wrapping_add(i64::from(((offset << 19) as i32) >> 19) as u64);
+                        }
+                        None
+                    }
+                    (0x6f, _) => {
+                        let offset = ((word >> 31) << 20) | (((word >> 12) & 0xff) << 12) | (((word >> 20) & 1) << 11) | (((word >> 21) & 0x3ff) << 1);
+                        next = pc.wrapping_add(i64::from(((offset << 11) as i32) >> 11) as u64);
+                        Some(pc + 4)
+                    }
+                    (0x73, 0) => match word {
+                        0x3020_0073 => return End::At(self.csr(CSR_MEPC)),
+                        0x1020_0073 => return End::At(self.csr(CSR_SEPC)),
+                        0x1050_0073 => return End::Halt,
+                        _ => unreachable!(),
+                    },
+                    (0x73, funct3 @ 1..=3) => {
+                        let csr = word >> 20;
+                        let old = self.csr(csr);
+                        let new = [a, old | a, old & !a][funct3 as usize - 1];
+                        self.csrs.insert(csr, new);
+                        Some(old)
+                    }
+                    _ => panic!("unexpected instruction {word:08x}"),
+                };
+                if let (Some(value), true) = (value, rd != 0) {
+                    self.regs[rd as usize] = value;
+                }
+                pc = next;
+            }
+            panic!("the handler does not end");
+        }
+    }
+
+    /// The value loaded by a `load_immediate` sequence
+    fn loaded(value: i64) -> i64 {
+        let mut reg: i64 = 0;
+        for insn in load_immediate(5, value) {
+            let word = insn.instruction as u32;
+            match insn.mnemonic.as_str() {
+                "lui" => reg = i64::from((word & 0xfffff000) as i32),
+                "addi" => reg = reg.wrapping_add(i64::from((word as i32) >> 20)),
+                "slli" => reg <<= (word >> 20) & 0x3f,
+                _ => unreachable!(),
+            }
+        }
+        reg
+    }
+
+    #[test]
+    fn trap_handlers() {
+        let profile = CpuProfile::from_isa_string("rv64gc").unwrap();
+        let encoder = Encoder::new(&profile);
+        for (mode, tvec, epc, cause, ie) in [
+            (TrapMode::Machine, CSR_MTVEC, CSR_MEPC, CSR_MCAUSE, CSR_MIE),
+            (TrapMode::Supervisor, CSR_STVEC, CSR_SEPC, CSR_SCAUSE, CSR_SIE),
+        ] {
+            let mut program = encoder.trap_handler(mode, vec![encoder.emit(c_nop(0, 0))], 0);
+            let trapping = byte_len(&program) as u64;
+            program.extend([encoder.emit(addi(5, 5, 1)), encoder.emit(c_nop(0, 0))]);
+            let mut hart = Hart::new(&program);
+
+            // the handler is installed and jumped over
+            assert_eq!(hart.run(0, trapping), End::At(trapping));
+            let handler = hart.csr(tvec);
+            assert_eq!((handler, handler % 4), (16, 0));
+
+            let mut trap = |hart: &mut Hart, mcause: u64, at: u64| {
+                hart.csrs.insert(cause, mcause);
+                hart.csrs.insert(epc, at);
+                hart.run(handler, u64::MAX)
+            };
+            // exceptions return after the trapping instruction
+            assert_eq!(trap(&mut hart, 2, trapping), End::At(trapping + 4));
+            assert_eq!(trap(&mut hart, 2, trapping + 4), End::At(trapping + 6));
+
+            // a timer interrupt resumes the interrupted instruction, and is not taken again
+            hart.csrs.insert(ie, MIE_MACHINE);
+            assert_eq!(trap(&mut hart, (1 << 63) | 7, trapping), End::At(trapping));
+            assert_eq!(hart.csr(ie), MIE_MACHINE & !MIE_MTIE);
+
+            // the test ends on the last trap
+            for _ in 3..TRAP_LIMIT - 1 {
+                assert_eq!(trap(&mut hart, 2, trapping), End::At(trapping + 4));
+            }
+            assert_eq!(trap(&mut hart, 2, trapping), End::Halt);
+        }
+    }
+
+    #[test]
+    fn template_programs() {
+        for value in [0, 1, -1, 0x7ff, 0x800, 0x7fff_ffff, 0x8000_0000, -0x8000_0000, 0x8000_0ff8, 0x1234_5678_9abc_def0] {
+            assert_eq!(loaded(value), value);
+        }
+
+        let config: serde_yaml::Value = serde_yaml::from_str(
+            "generator:\n  blocks:\n    - name: setup\n      items:\n        - macro: enable_interrupts\n        - macro: { pmp_region: { index: 0, start: 0x80000000, size: 0x1000, permissions: rwx } }\n        - macro: switch_to_user_mode\n    - name: loop\n      repeat: 3\n      items:\n        - instructions: { min: 2, max: 6, classes: { alu: 1 } }\n",
+        )
+        .unwrap();
+        let template = ProgramTemplate::from_config(&config).unwrap();
+        assert_eq!(template.blocks[1].repeat, Some(3));
+        assert!(matches!(template.blocks[0].items[2], BlockItem::Macro(Macro::SwitchToUserMode)));
+
+        let bad: serde_yaml::Value = serde_yaml::from_str(
+            "generator:\n  blocks:\n    - name: setup\n      items:\n        - macro: { pmp_region: { index: 0, start: 0x80000100, size: 0x1000, permissions: rwx } }\n",
+        )
+        .unwrap();
+        assert!(ProgramTemplate::from_config(&bad).is_err());
+
+        let profile = CpuProfile::from_isa_string("rv64gc").unwrap();
+        let mut generator = TemplateGenerator::with_profile(ProgramTemplate::default(), 4096, profile.clone());
+        let mut state = StdState::new(
+            StdRand::with_seed(0),
+            InMemoryCorpus::<BytesInput>::new(),
+            InMemoryCorpus::new(),
+            &mut (),
+            &mut (),
+        )
+        .unwrap();
+        state.set_max_size(4096);
+
+        for _ in 0..20 {
+            let seed = generator.generate(&mut state).unwrap();
+            // every generated instruction decodes with the profile
+            let program = ISAInput::new_with_profile(seed.bytes(), &profile);
+            let mut bytes = vec![];
+            program.unparse(&mut bytes);
+            assert_eq!(&bytes, seed.bytes());
+
+            let blocks = generator.blocks(&program.instructions);
+            assert_eq!(blocks.iter().map(|(_, idx)| *idx).collect::<Vec<_>>(), vec![0, 1, 2, 3, 4]);
+            for mnemonic in ["MRET", "WFI"] {
+                assert!(program.instructions.iter().any(|insn| insn.mnemonic == mnemonic), "{mnemonic}");
+            }
+        }
+
+        let generator = TemplateGenerator::with_profile(template, 4096, profile.clone());
+        let mut program = ISAInput::new_from_parsed_data(generator.program(state.rand_mut()));
+        let mut mutator = TemplateBlockMutator::new(generator);
+        for _ in 0..20 {
+            assert_eq!(mutator.mutate(&mut state, &mut program, 0).unwrap(), MutationResult::Mutated);
+            assert_eq!(mutator.generator.blocks(&program.instructions).len(), 2);
+        }
+    }
+}
@@ -0,0 +1,878 @@
+// SPDX-FileCopyrightText: 2024 Intel Corporation
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! An assembler for the instructions of a [`CpuProfile`], the counterpart of the
+//! `NativeDasm`.
+//!
+//! The syntax is the one of Spike and objdump: one instruction per line, `x` or ABI
+//! register names, decimal or `0x` immediates, `imm(reg)` memory operands, and CSRs by
+//! name or number. Comments start with `#`, labels end with `:`. Branch and jump targets
+//! are labels or `pc + offset`. Floating-point rounding modes are an optional last
+//! argument (`dyn` by default), atomic orderings the `.aq`, `.rl` or `.aqrl` suffixes.
+//!
+//! The usual pseudo-instructions are expanded: `nop`, `li`, `la`, `mv`, `not`, `neg`,
+//! `negw`, `sext.w`, `seqz`, `snez`, `sltz`, `sgtz`, the `beqz` and `bgt` families, `j`,
+//! `jr`, `ret`, `call`, `tail`, `csrr`, `csrw`, `csrs`, `csrc` and their immediate
+//! variants, `fmv`, `fneg` and `fabs`. Instructions are not compressed implicitly, and
+//! the vector instructions are not supported.
+
+use std::collections::HashMap;
+
+use libafl::Error;
+
+use crate::cpu_profile::{addi, lui, slli};
+use crate::dasm::gen::{gen_branch_instruction, gen_cbeqz_instruction, gen_cj_instruction, gen_jal_instruction};
+use crate::dasm::native_dasm::{CSR_NAMES, FPR_NAMES};
+use crate::dasm::{RiscvInstruction, RiscvInstructions};
+use crate::instruction::Instruction;
+use crate::profile::CpuProfile;
+
+/// ABI names of the integer registers
+const XPR_NAMES: [&str; 32] = [
+    "zero", "ra", "sp", "gp", "tp", "t0", "t1", "t2", "s0", "s1", "a0", "a1", "a2", "a3", "a4", "a5", "a6", "a7", "s2",
+    "s3", "s4", "s5", "s6", "s7", "s8", "s9", "s10", "s11", "t3", "t4", "t5", "t6",
+];
+
+/// Names of the floating-point rounding modes, by encoding
+const ROUNDING_MODES: [&str; 8] = ["rne", "rtz", "rdn", "rup", "rmm", "", "", "dyn"];
+
+/// An instruction to encode, once the labels are known
+#[derive(Debug)]
+enum Op {
+    /// An instruction of the tables and its arguments
+    Insn(String, Vec<String>),
+    /// An encoded instruction and its length
+    Encoded(u32, usize),
+    /// `auipc rd` then `addi rd, rd` (`link` is `None`) or `jalr link, rd`, to `target`
+    PcRel { rd: u32, link: Option<u32>, target: String },
+}
+
+impl Op {
+    fn size(&self) -> usize {
+        match self {
+            Self::Insn(mnemonic, _) if mnemonic.starts_with("c.") => 2,
+            Self::Insn(..) => 4,
+            Self::Encoded(_, length) => *length,
+            Self::PcRel { .. } => 8,
+        }
+    }
+
+    fn insn(mnemonic: &str, args: &[&str]) -> Self {
+        Self::Insn(mnemonic.to_string(), args.iter().map(|arg| (*arg).to_string()).collect())
+    }
+}
+
+/// Assembles text into the instructions of a [`CpuProfile`], see the module documentation
+#[derive(Debug, Default)]
+pub struct Assembler {
+    profile: CpuProfile,
+}
+
+impl Assembler {
+    /// Creates a new [`Assembler`] accepting every known instruction, for RV64
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only accept the instructions of `profile`
+    #[must_use]
+    pub fn with_profile(profile: CpuProfile) -> Self {
+        Self { profile }
+    }
+
+    /// Assemble `source`, the offsets of the labels are relative to its first instruction
+    pub fn assemble(&self, source: &str) -> Result<RiscvInstructions, Error> {
+        let mut labels: HashMap<String, usize> = HashMap::new();
+        let mut ops: Vec<(usize, Op)> = vec![];
+        let mut offset = 0;
+
+        for (idx, line) in source.lines().enumerate() {
+            let error = |msg: String| Error::illegal_argument(format!("line {}: {msg}", idx + 1));
+            let mut line = line.split('#').next().unwrap_or_default().trim();
+
+            while let Some((label, rest)) = line.split_once(':') {
+                let label = label.trim();
+                if label.is_empty() || !label.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.') {
+                    break;
+                }
+                if labels.insert(label.to_string(), offset).is_some() {
+                    return Err(error(format!("duplicate label {label}")));
+                }
+                line = rest.trim();
+            }
+            if line.is_empty() {
+                continue;
+            }
+
+            let (mnemonic, args) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+            let args: Vec<&str> = if args.trim().is_empty() {
+                vec![]
+            } else {
+                args.split(',').map(str::trim).collect()
+            };
+            for op in self.expand(&mnemonic.to_lowercase(), &args).map_err(error)? {
+                offset += op.size();
+                ops.push((idx + 1, op));
+            }
+        }
+
+        let mut instructions = RiscvInstructions::new();
+        let mut pc = 0;
+        for (line, op) in ops {
+            let error = |msg: String| Error::illegal_argument(format!("line {line}: {msg}"));
+            let words = match &op {
+                Op::Insn(mnemonic, args) => vec![self.encode(mnemonic, args, pc, &labels).map_err(error)?],
+                Op::Encoded(word, _) => vec![*word],
+                Op::PcRel { rd, link, target } => {
+                    let offset = Self::target(target, pc, &labels).map_err(error)?;
+                    if !(-0x8000_0800..0x7fff_f800).contains(&offset) {
+                        return Err(error(format!("{target} is out of range")));
+                    }
+                    let hi = ((offset + 0x800) >> 12) as u32 & 0xfffff;
+                    let lo = ((offset << 52) >> 52) as u32 & 0xfff;
+                    let second = match link {
+                        Some(link) => 0x67 | (link << 7) | (rd << 15) | (lo << 20),
+                        None => 0x13 | (rd << 7) | (rd << 15) | (lo << 20),
+                    };
+                    vec![0x17 | (rd << 7) | (hi << 12), second]
+                }
+            };
+            for word in words {
+                instructions.push(RiscvInstruction::from_be32(word));
+            }
+            pc += op.size();
+        }
+        Ok(instructions)
+    }
+
+    /// The instructions of `mnemonic`, pseudo-instructions expanded
+    fn expand(&self, mnemonic: &str, args: &[&str]) -> Result<Vec<Op>, String> {
+        let arity = |count: usize| {
+            if args.len() == count {
+                Ok(())
+            } else {
+                Err(format!("{mnemonic} takes {count} arguments"))
+            }
+        };
+        let op = match mnemonic {
+            "nop" => Op::insn("addi", &["x0", "x0", "0"]),
+            "li" => {
+                arity(2)?;
+                let rd = xreg(args[0])?;
+                let value = imm(args[1])?;
+                let value = if self.profile.xlen() == Some(32) {
+                    if !(-0x8000_0000..=0xffff_ffff).contains(&value) {
+                        return Err(format!("{value} does not fit in 32 bits"));
+                    }
+                    i64::from(value as u32 as i32)
+                } else {
+                    value
+                };
+                return Ok(load_immediate(rd, value)
+                    .into_iter()
+                    .map(|insn| Op::Encoded(insn.instruction as u32, insn.length))
+                    .collect());
+            }
+            "la" | "call" | "tail" => {
+                let (rd, link, target) = match mnemonic {
+                    "la" => {
+                        arity(2)?;
+                        (xreg(args[0])?, None, args[1])
+                    }
+                    "call" => {
+                        arity(1)?;
+                        (1, Some(1), args[0])
+                    }
+                    _ => {
+                        arity(1)?;
+                        (6, Some(0), args[0])
+                    }
+                };
+                Op::PcRel {
+                    rd,
+                    link,
+                    target: target.to_string(),
+                }
+            }
+            "mv" | "sext.w" => {
+                arity(2)?;
+                Op::insn(if mnemonic == "mv" { "addi" } else { "addiw" }, &[args[0], args[1], "0"])
+            }
+            "not" => {
+                arity(2)?;
+                Op::insn("xori", &[args[0], args[1], "-1"])
+            }
+            "neg" | "negw" => {
+                arity(2)?;
+                Op::insn(if mnemonic == "neg" { "sub" } else { "subw" }, &[args[0], "x0", args[1]])
+            }
+            "seqz" => {
+                arity(2)?;
+                Op::insn("sltiu", &[args[0], args[1], "1"])
+            }
+            "snez" | "sgtz" => {
+                arity(2)?;
+                Op::insn(if mnemonic == "snez" { "sltu" } else { "slt" }, &[args[0], "x0", args[1]])
+            }
+            "sltz" => {
+                arity(2)?;
+                Op::insn("slt", &[args[0], args[1], "x0"])
+            }
+            "beqz" | "bnez" | "bltz" | "bgez" => {
+                arity(2)?;
+                let branch = format!("b{}", &mnemonic[1..mnemonic.len() - 1]);
+                Op::insn(&branch, &[args[0], "x0", args[1]])
+            }
+            "blez" | "bgtz" => {
+                arity(2)?;
+                Op::insn(if mnemonic == "blez" { "bge" } else { "blt" }, &["x0", args[0], args[1]])
+            }
+            "bgt" | "ble" | "bgtu" | "bleu" => {
+                arity(3)?;
+                let branch = match mnemonic {
+                    "bgt" => "blt",
+                    "ble" => "bge",
+                    "bgtu" => "bltu",
+                    _ => "bgeu",
+                };
+                Op::insn(branch, &[args[1], args[0], args[2]])
+            }
+            "j" => {
+                arity(1)?;
+                Op::insn("jal", &["x0", args[0]])
+            }
+            "jr" => {
+                arity(1)?;
+                Op::insn("jalr", &["x0", args[0]])
+            }
+            "ret" => Op::insn("jalr", &["x0", "0(ra)"]),
+            "csrr" => {
+                arity(2)?;
+                Op::insn("csrrs", &[args[0], args[1], "x0"])
+            }
+            "csrw" | "csrs" | "csrc" | "csrwi" | "csrsi" | "csrci" => {
+                arity(2)?;
+                Op::insn(&format!("csrr{}", &mnemonic[3..]), &["x0", args[0], args[1]])
+            }
+            "fmv.s" | "fmv.d" | "fneg.s" | "fneg.d" | "fabs.s" | "fabs.d" => {
+                arity(2)?;
+                let (name, precision) = mnemonic.split_at(mnemonic.len() - 2);
+                let sign = match name {
+                    "fmv" => "fsgnj",
+                    "fneg" => "fsgnjn",
+                    _ => "fsgnjx",
+                };
+                Op::insn(&format!("{sign}{precision}"), &[args[0], args[1], args[1]])
+            }
+            _ => Op::insn(mnemonic, args),
+        };
+        Ok(vec![op])
+    }
+
+    /// The instruction of the profile named `mnemonic`, the RV32 or RV64 variant if needed
+    fn lookup(&self, mnemonic: &str) -> Result<&'static Instruction, String> {
+        let name = mnemonic.to_uppercase().replace('.', "_");
+        [name.clone(), format!("{name}_RV32"), format!("{name}_RV64")]
+            .iter()
+            .find_map(|name| self.profile.instructions().find(|meta| meta.mnemonic == *name))
+            .ok_or_else(|| format!("unknown instruction {mnemonic}"))
+    }
+
+    /// Encode the instruction `mnemonic` at offset `pc`
+    fn encode(&self, mnemonic: &str, args: &[String], pc: usize, labels: &HashMap<String, usize>) -> Result<u32, String> {
+        let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+        // atomic memory orderings
+        let mut ordering = 0;
+        let mut name = mnemonic;
+        if ["amo", "lr.", "sc."].iter().any(|prefix| mnemonic.starts_with(prefix)) {
+            for (suffix, bits) in [(".aqrl", 3), (".aq", 2), (".rl", 1)] {
+                if let Some(stripped) = mnemonic.strip_suffix(suffix) {
+                    name = stripped;
+                    ordering = bits << 25;
+                    break;
+                }
+            }
+        }
+
+        let meta = self.lookup(name)?;
+        if meta.length == 2 {
+            return Self::compressed(meta, name, &args, pc, labels);
+        }
+        if meta.extension.ends_with("_v") {
+            return Err(format!("vector instruction {mnemonic} is not supported"));
+        }
+
+        let has_field = |lsb: u32| meta.operands.iter().any(|(start, _)| *start == lsb);
+        let arity = |counts: &[usize]| {
+            if counts.contains(&args.len()) {
+                Ok(())
+            } else {
+                Err(format!("{mnemonic} takes {counts:?} arguments"))
+            }
+        };
+        let (rd, rs1, rs2, rs3) = (|r: u32| r << 7, |r: u32| r << 15, |r: u32| r << 20, |r: u32| r << 27);
+        let w = meta.mask | ordering;
+
+        let word = match w & 0x7f {
+            // lui, auipc
+            0x37 | 0x17 => {
+                arity(&[2])?;
+                let value = imm(args[1])?;
+                if !(-0x8_0000..=0xf_ffff).contains(&value) {
+                    return Err(format!("{value} does not fit in 20 bits"));
+                }
+                w | rd(xreg(args[0])?) | ((value as u32 & 0xfffff) << 12)
+            }
+            0x6f => {
+                arity(&[1, 2])?;
+                let (link, target) = if args.len() == 1 { (1, args[0]) } else { (xreg(args[0])?, args[1]) };
+                let offset = Self::target(target, pc, labels)?;
+                let offset = i32::try_from(offset).map_err(|_| format!("{target} is out of range"))?;
+                gen_jal_instruction(link as u8, offset, true).map_err(|err| err.to_string())?.0
+            }
+            0x67 => {
+                arity(&[1, 2, 3])?;
+                let (link, base, offset) = match args.len() {
+                    1 => {
+                        let (offset, base) = mem_or_reg(args[0])?;
+                        (1, base, offset)
+                    }
+                    2 => {
+                        let (offset, base) = mem_or_reg(args[1])?;
+                        (xreg(args[0])?, base, offset)
+                    }
+                    _ => (xreg(args[0])?, xreg(args[1])?, imm(args[2])?),
+                };
+                w | rd(link) | rs1(base) | (signed(offset, 12)? << 20)
+            }
+            0x63 => {
+                arity(&[3])?;
+                let offset = Self::target(args[2], pc, labels)?;
+                let offset = i32::try_from(offset).map_err(|_| format!("{} is out of range", args[2]))?;
+                let funct3 = ((w >> 12) & 7) as u8;
+                gen_branch_instruction(xreg(args[0])? as u8, xreg(args[1])? as u8, offset, funct3, true)
+                    .map_err(|err| err.to_string())?
+                    .0
+            }
+            0x03 | 0x07 => {
+                arity(&[2])?;
+                let (offset, base) = mem(args[1])?;
+                let dest = if w & 0x7f == 0x03 { xreg(args[0])? } else { freg(args[0])? };
+                w | rd(dest) | rs1(base) | (signed(offset, 12)? << 20)
+            }
+            0x23 | 0x27 => {
+                arity(&[2])?;
+                let (offset, base) = mem(args[1])?;
+                let src = if w & 0x7f == 0x23 { xreg(args[0])? } else { freg(args[0])? };
+                let offset = signed(offset, 12)?;
+                w | rs2(src) | rs1(base) | ((offset & 31) << 7) | ((offset >> 5) << 25)
+            }
+            0x13 | 0x1b | 0x33 | 0x3b => {
+                let shift = meta.operands.iter().find(|(start, end)| *start == 20 && *end < 31);
+                if !has_field(20) {
+                    arity(&[2])?;
+                    w | rd(xreg(args[0])?) | rs1(xreg(args[1])?)
+                } else if w & 0x7f == 0x33 || w & 0x7f == 0x3b {
+                    arity(&[3])?;
+                    w | rd(xreg(args[0])?) | rs1(xreg(args[1])?) | rs2(xreg(args[2])?)
+                } else if let Some((start, end)) = shift {
+                    arity(&[3])?;
+                    let shamt = imm(args[2])?;
+                    if !(0..1 << (end - start + 1)).contains(&shamt) {
+                        return Err(format!("invalid shift amount {shamt}"));
+                    }
+                    w | rd(xreg(args[0])?) | rs1(xreg(args[1])?) | ((shamt as u32) << 20)
+                } else {
+                    arity(&[3])?;
+                    w | rd(xreg(args[0])?) | rs1(xreg(args[1])?) | (signed(imm(args[2])?, 12)? << 20)
+                }
+            }
+            0x2f => {
+                let (dest, src, address) = if name.starts_with("lr.") {
+                    arity(&[2])?;
+                    (args[0], None, args[1])
+                } else {
+                    arity(&[3])?;
+                    (args[0], Some(args[1]), args[2])
+                };
+                let (offset, base) = mem(address)?;
+                if offset != 0 {
+                    return Err(format!("{mnemonic} takes no offset"));
+                }
+                w | rd(xreg(dest)?) | rs1(base) | src.map_or(Ok(0), |src| xreg(src).map(rs2))?
+            }
+            0x73 if (w >> 12) & 7 == 0 => {
+                // ecall, mret, sfence.vma, ... with optional register arguments
+                let fields: Vec<u32> = [15, 20].into_iter().filter(|lsb| has_field(*lsb)).collect();
+                if args.len() > fields.len() {
+                    return Err(format!("{mnemonic} takes {} arguments", fields.len()));
+                }
+                let mut word = w;
+                for (arg, lsb) in args.iter().zip(fields) {
+                    word |= xreg(arg)? << lsb;
+                }
+                word
+            }
+            0x73 => {
+                arity(&[3])?;
+                let source = if (w >> 12) & 7 >= 4 {
+                    let uimm = imm(args[2])?;
+                    if !(0..32).contains(&uimm) {
+                        return Err(format!("{uimm} does not fit in 5 bits"));
+                    }
+                    uimm as u32
+                } else {
+                    xreg(args[2])?
+                };
+                w | rd(xreg(args[0])?) | (csr(args[1])? << 20) | rs1(source)
+            }
+            0x0f if name == "fence" => {
+                let (pred, succ) = match args.len() {
+                    0 => (0xf, 0xf),
+                    2 => (fence_set(args[0])?, fence_set(args[1])?),
+                    _ => return Err(format!("{mnemonic} takes [0, 2] arguments")),
+                };
+                w | (pred << 24) | (succ << 20)
+            }
+            0x0f => {
+                arity(&[0])?;
+                w
+            }
+            0x43 | 0x47 | 0x4b | 0x4f => {
+                arity(&[4, 5])?;
+                let rm = rounding_mode(args.get(4))?;
+                w | rd(freg(args[0])?) | rs1(freg(args[1])?) | rs2(freg(args[2])?) | rs3(freg(args[3])?) | (rm << 12)
+            }
+            0x53 => {
+                let parts: Vec<&str> = name.split('.').collect();
+                let integer = |part: Option<&&str>| part.is_some_and(|part| matches!(*part, "w" | "wu" | "l" | "lu" | "x"));
+                let int_rd = matches!(parts[0], "feq" | "flt" | "fle" | "fclass")
+                    || (matches!(parts[0], "fcvt" | "fmv") && integer(parts.get(1)));
+                let int_rs1 = matches!(parts[0], "fcvt" | "fmv") && integer(parts.get(2));
+                let count = if has_field(20) { 3 } else { 2 };
+                if has_field(12) {
+                    arity(&[count, count + 1])?;
+                } else {
+                    arity(&[count])?;
+                }
+                let dest = if int_rd { xreg(args[0])? } else { freg(args[0])? };
+                let src = if int_rs1 { xreg(args[1])? } else { freg(args[1])? };
+                let mut word = w | rd(dest) | rs1(src);
+                if count == 3 {
+                    word |= rs2(freg(args[2])?);
+                }
+                if has_field(12) {
+                    word |= rounding_mode(args.get(count))? << 12;
+                }
+                word
+            }
+            _ => return Err(format!("{mnemonic} is not supported")),
+        };
+        Ok(word)
+    }
+
+    /// Encode the compressed instruction `name`
+    fn compressed(
+        meta: &Instruction,
+        name: &str,
+        args: &[&str],
+        pc: usize,
+        labels: &HashMap<String, usize>,
+    ) -> Result<u32, String> {
+        let arity = |count: usize| {
+            if args.len() == count {
+                Ok(())
+            } else {
+                Err(format!("{name} takes {count} arguments"))
+            }
+        };
+        // an immediate, multiple of `scale`, in `min..=max`
+        let scaled = |arg: &str, scale: i64, min: i64, max: i64| -> Result<u32, String> {
+            let value = imm(arg)?;
+            if value % scale != 0 || !(min..=max).contains(&value) {
+                return Err(format!("invalid immediate {value} for {name}"));
+            }
+            Ok(value as u32)
+        };
+        let imm6 = |value: u32| (((value >> 5) & 1) << 12) | ((value & 31) << 2);
+        let bits = |value: u32, msb: u32, lsb: u32, at: u32| ((value >> lsb) & ((1 << (msb - lsb + 1)) - 1)) << at;
+        // a stack-pointer-relative memory operand
+        let sp_offset = |arg: &str| -> Result<String, String> {
+            let (offset, base) = mem(arg)?;
+            if base != 2 {
+                return Err(format!("{name} only addresses sp"));
+            }
+            Ok(offset.to_string())
+        };
+        let w = meta.mask;
+
+        let word = match name {
+            "c.nop" | "c.ebreak" => {
+                arity(0)?;
+                w
+            }
+            "c.addi" | "c.addiw" | "c.li" => {
+                arity(2)?;
+                w | (xreg(args[0])? << 7) | imm6(scaled(args[1], 1, -32, 31)?)
+            }
+            "c.andi" => {
+                arity(2)?;
+                w | (creg(args[0])? << 7) | imm6(scaled(args[1], 1, -32, 31)?)
+            }
+            "c.lui" => {
+                arity(2)?;
+                // the upper bits of a sign-extended 6-bit immediate
+                let value = imm(args[1])?;
+                let value = if value >= 0xfffe0 { value - 0x10_0000 } else { value };
+                w | (xreg(args[0])? << 7) | imm6(scaled(&value.to_string(), 1, -32, 31)?)
+            }
+            "c.addi16sp" => {
+                arity(2)?;
+                if xreg(args[0])? != 2 {
+                    return Err(format!("{name} only writes sp"));
+                }
+                let value = scaled(args[1], 16, -512, 496)?;
+                w | bits(value, 9, 9, 12) | bits(value, 4, 4, 6) | bits(value, 6, 6, 5) | bits(value, 8, 7, 3) | bits(value, 5, 5, 2)
+            }
+            "c.addi4spn" => {
+                arity(3)?;
+                if xreg(args[1])? != 2 {
+                    return Err(format!("{name} only reads sp"));
+                }
+                let value = scaled(args[2], 4, 0, 1020)?;
+                w | (creg(args[0])? << 2) | bits(value, 2, 2, 6) | bits(value, 3, 3, 5) | bits(value, 5, 4, 11) | bits(value, 9, 6, 7)
+            }
+            "c.slli" | "c.srli" | "c.srai" => {
+                arity(2)?;
+                let max = if meta.mnemonic.ends_with("_RV32") { 31 } else { 63 };
+                let reg = if name == "c.slli" { xreg(args[0])? } else { creg(args[0])? };
+                w | (reg << 7) | imm6(scaled(args[1], 1, 0, max)?)
+            }
+            "c.sub" | "c.xor" | "c.or" | "c.and" | "c.subw" | "c.addw" => {
+                arity(2)?;
+                w | (creg(args[0])? << 7) | (creg(args[1])? << 2)
+            }
+            "c.lw" | "c.sw" | "c.ld" | "c.sd" => {
+                arity(2)?;
+                let (offset, base) = mem(args[1])?;
+                let base = creg(&format!("x{base}"))?;
+                let value = if name.ends_with('w') {
+                    let value = scaled(&offset.to_string(), 4, 0, 124)?;
+                    bits(value, 5, 3, 10) | bits(value, 2, 2, 6) | bits(value, 6, 6, 5)
+                } else {
+                    let value = scaled(&offset.to_string(), 8, 0, 248)?;
+                    bits(value, 5, 3, 10) | bits(value, 7, 6, 5)
+                };
+                w | (creg(args[0])? << 2) | (base << 7) | value
+            }
+            "c.lwsp" | "c.ldsp" => {
+                arity(2)?;
+                let offset = sp_offset(args[1])?;
+                let value = if name == "c.lwsp" {
+                    let value = scaled(&offset, 4, 0, 252)?;
+                    bits(value, 5, 5, 12) | bits(value, 4, 2, 4) | bits(value, 7, 6, 2)
+                } else {
+                    let value = scaled(&offset, 8, 0, 504)?;
+                    bits(value, 5, 5, 12) | bits(value, 4, 3, 5) | bits(value, 8, 6, 2)
+                };
+                w | (xreg(args[0])? << 7) | value
+            }
+            "c.swsp" | "c.sdsp" => {
+                arity(2)?;
+                let offset = sp_offset(args[1])?;
+                let value = if name == "c.swsp" {
+                    let value = scaled(&offset, 4, 0, 252)?;
+                    bits(value, 5, 2, 9) | bits(value, 7, 6, 7)
+                } else {
+                    let value = scaled(&offset, 8, 0, 504)?;
+                    bits(value, 5, 3, 10) | bits(value, 8, 6, 7)
+                };
+                w | (xreg(args[0])? << 2) | value
+            }
+            "c.j" | "c.jal" => {
+                arity(1)?;
+                let offset = Self::target(args[0], pc, labels)?;
+                let offset = i16::try_from(offset).map_err(|_| format!("{} is out of range", args[0]))?;
+                w | (u32::from(gen_cj_instruction(offset, true).map_err(|err| err.to_string())?.0) & 0x1ffc)
+            }
+            "c.beqz" | "c.bnez" => {
+                arity(2)?;
+                let offset = Self::target(args[1], pc, labels)?;
+                let offset = i16::try_from(offset).map_err(|_| format!("{} is out of range", args[1]))?;
+                let encoded = gen_cbeqz_instruction(creg(args[0])? as u8, offset, true).map_err(|err| err.to_string())?;
+                w | (u32::from(encoded.0) & 0x1ffc)
+            }
+            "c.jr" | "c.jalr" => {
+                arity(1)?;
+                w | (xreg(args[0])? << 7)
+            }
+            "c.mv" | "c.add" => {
+                arity(2)?;
+                w | (xreg(args[0])? << 7) | (xreg(args[1])? << 2)
+            }
+            _ => return Err(format!("{name} is not supported")),
+        };
+        Ok(word)
+    }
+
+    /// The offset from `pc` of `target`, a label or `pc + offset`
+    fn target(target: &str, pc: usize, labels: &HashMap<String, usize>) -> Result<i64, String> {
+        if let Some(offset) = target.strip_prefix("pc") {
+            let offset: String = offset.chars().filter(|c| !c.is_whitespace()).collect();
+            return match offset.strip_prefix('+') {
+                _ if offset.is_empty() => Ok(0),
+                Some(offset) => imm(offset),
+                None => imm(&offset),
+            };
+        }
+        labels
+            .get(target)
+            .map(|address| *address as i64 - pc as i64)
+            .ok_or_else(|| format!("unknown label {target}"))
+    }
+}
+
+/// The `lui`/`addi`/`slli` sequence loading the sign-extended `value` into `rd`
+#[must_use]
+pub fn load_immediate(rd: u32, value: i64) -> Vec<Instruction> {
+    let lo = ((value << 52) >> 52) as i32;
+    // lui sign-extends its result, keep below the upper bound of lui + addi
+    if (-0x8000_0000..=0x7fff_f7ff).contains(&value) {
+        let hi = ((value + 0x800) >> 12) as u32 & 0xfffff;
+        return match (hi, lo) {
+            (0, _) => vec![addi(rd, 0, lo as u32)],
+            (_, 0) => vec![lui(rd, hi)],
+            _ => vec![lui(rd, hi), addi(rd, rd, lo as u32)],
+        };
+    }
+
+    let hi = (value - i64::from(lo)) >> 12;
+    let shift = 12 + hi.trailing_zeros();
+    let mut instructions = load_immediate(rd, hi >> hi.trailing_zeros());
+    instructions.push(slli(rd, rd, shift));
+    if lo != 0 {
+        instructions.push(addi(rd, rd, lo as u32));
+    }
+    instructions
+}
+
+/// An integer register, by `x` or ABI name
+fn xreg(arg: &str) -> Result<u32, String> {
+    let arg = arg.trim();
+    if arg == "fp" {
+        return Ok(8);
+    }
+    if let Some(idx) = XPR_NAMES.iter().position(|name| *name == arg) {
+        return Ok(idx as u32);
+    }
+    arg.strip_prefix('x')
+        .and_then(|idx| idx.parse::<u32>().ok())
+        .filter(|idx| *idx < 32)
+        .ok_or_else(|| format!("invalid register {arg}"))
+}
+
+/// A register of the compressed instructions, `x8` to `x15`
+fn creg(arg: &str) -> Result<u32, String> {
+    match xreg(arg)? {
+        reg @ 8..=15 => Ok(reg - 8),
+        _ => Err(format!("{arg} is not one of x8-x15")),
+    }
+}
+
+/// A floating-point register, by `f` or ABI name
+fn freg(arg: &str) -> Result<u32, String> {
+    let arg = arg.trim();
+    if let Some(idx) = FPR_NAMES.iter().position(|name| *name == arg) {
+        return Ok(idx as u32);
+    }
+    arg.strip_prefix('f')
+        .and_then(|idx| idx.parse::<u32>().ok())
+        .filter(|idx| *idx < 32)
+        .ok_or_else(|| format!("invalid floating-point register {arg}"))
+}
+
+/// A decimal, `0x` hexadecimal or `0b` binary immediate, possibly negative
+fn imm(arg: &str) -> Result<i64, String> {
+    let arg = arg.trim();
+    let (negative, digits) = match arg.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, arg),
+    };
+    let value = if let Some(hex) = digits.strip_prefix("0x") {
+        u64::from_str_radix(hex, 16)
+    } else if let Some(binary) = digits.strip_prefix("0b") {
+        u64::from_str_radix(binary, 2)
+    } else {
+        digits.parse::<u64>()
+    }
+    .map_err(|_| format!("invalid immediate {arg}"))? as i64;
+    Ok(if negative { value.wrapping_neg() } else { value })
+}
+
+/// `value` as a `bits`-bit signed field
+fn signed(value: i64, bits: u32) -> Result<u32, String> {
+    let bound = 1i64 << (bits - 1);
+    if !(-bound..bound).contains(&value) {
+        return Err(format!("{value} does not fit in {bits} bits"));
+    }
+    Ok(value as u32 & ((1 << bits) - 1))
+}
+
+/// The offset and base register of `imm(reg)` or `(reg)`
+fn mem(arg: &str) -> Result<(i64, u32), String> {
+    let (offset, base) = arg
+        .trim()
+        .strip_suffix(')')
+        .and_then(|arg| arg.split_once('('))
+        .ok_or_else(|| format!("invalid memory operand {arg}"))?;
+    let offset = if offset.trim().is_empty() { 0 } else { imm(offset)? };
+    Ok((offset, xreg(base)?))
+}
+
+/// A memory operand, or a register without offset
+fn mem_or_reg(arg: &str) -> Result<(i64, u32), String> {
+    if arg.contains('(') {
+        mem(arg)
+    } else {
+        Ok((0, xreg(arg)?))
+    }
+}
+
+/// A CSR, by name or number
+fn csr(arg: &str) -> Result<u32, String> {
+    if let Some((number, _)) = CSR_NAMES.iter().find(|(_, name)| *name == arg) {
+        return Ok(*number);
+    }
+    match imm(arg) {
+        Ok(number) if (0..0x1000).contains(&number) => Ok(number as u32),
+        _ => Err(format!("invalid CSR {arg}")),
+    }
+}
+
+/// The bits of a fence set such as `iorw`, or `0`
+fn fence_set(arg: &str) -> Result<u32, String> {
+    if arg == "0" {
+        return Ok(0);
+    }
+    arg.chars().try_fold(0, |set, c| match "iorw".find(c) {
+        Some(idx) => Ok(set | (8 >> idx)),
+        None => Err(format!("invalid fence set {arg}")),
+    })
+}
+
+/// The encoding of a rounding mode, `dyn` when absent
+fn rounding_mode(arg: Option<&&str>) -> Result<u32, String> {
+    match arg {
+        None => Ok(7),
+        Some(arg) => ROUNDING_MODES
+            .iter()
+            .position(|name| !name.is_empty() && name == arg)
+            .map(|idx| idx as u32)
+            .ok_or_else(|| format!("invalid rounding mode {arg}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    use super::{load_immediate, Assembler};
+    use crate::cpu_profile::{addi, c_addi, sd};
+    use crate::dasm::native_dasm::NativeDasm;
+    use crate::instruction::OperandKind;
+    use crate::profile::CpuProfile;
+
+    /// The little-endian words of `source`
+    fn words(assembler: &Assembler, source: &str) -> Vec<u32> {
+        let bytes = assembler.assemble(source).unwrap().serialize();
+        let mut words = vec![];
+        let mut k = 0;
+        while k < bytes.len() {
+            if bytes[k] & 3 == 3 {
+                words.push(u32::from_le_bytes([bytes[k], bytes[k + 1], bytes[k + 2], bytes[k + 3]]));
+                k += 4;
+            } else {
+                words.push(u32::from(u16::from_le_bytes([bytes[k], bytes[k + 1]])));
+                k += 2;
+            }
+        }
+        words
+    }
+
+    #[test]
+    fn assemble() {
+        let profile = CpuProfile::from_isa_string("rv64gc").unwrap();
+        let assembler = Assembler::with_profile(profile.clone());
+
+        let program = "
+            start:  addi a0, a0, 1      # a comment
+                    c.addi s0, -3
+                    sd ra, -8(sp)
+                    li t0, 0x80000000
+            loop:   bnez t0, done
+                    j loop
+            done:   ret
+        ";
+        let expected = [
+            addi(10, 10, 1).instruction as u32,
+            c_addi(8, 0x1d, 1).instruction as u32,
+            sd(0x7f, 2, 1, 0x18).instruction as u32,
+        ];
+        let program = words(&assembler, program);
+        assert_eq!(&program[..3], &expected);
+        let li: Vec<u32> = load_immediate(5, 0x8000_0000).iter().map(|insn| insn.instruction as u32).collect();
+        assert_eq!(&program[3..5], &li);
+        assert_eq!(program[5], 0x0002_9463);
+        assert_eq!(program[6], 0xffdf_f06f);
+        assert_eq!(program[7], 0x0000_8067);
+
+        assert_eq!(words(&assembler, "amoadd.w.aqrl a0, a1, (a2)"), [0x06b6_252f]);
+        assert_eq!(words(&assembler, "csrw mtvec, t0"), [0x3052_9073]);
+        assert_eq!(words(&assembler, "fadd.d fa0, fa1, fa2, rtz"), [0x02c5_9553]);
+        assert_eq!(words(&assembler, "call f\nf: nop").len(), 3);
+
+        for source in ["addi a0, a0", "addi a0, a0, 2048", "c.addi a0", "beqz a0, nowhere", "x: nop\nx: nop", "vadd.vv v1, v2, v3"] {
+            assert!(assembler.assemble(source).is_err(), "{source}");
+        }
+        assert!(Assembler::with_profile(CpuProfile::from_isa_string("rv32i").unwrap())
+            .assemble("c.nop")
+            .is_err());
+
+        // the output of the disassembler assembles back, where it prints every field
+        for isa in ["rv64gc", "rv32gc_zba_zbb_zbs"] {
+            let profile = CpuProfile::from_isa_string(isa).unwrap();
+            let assembler = Assembler::with_profile(profile.clone());
+            let dasm = NativeDasm::with_profile(profile.clone());
+            let mut rng = StdRng::seed_from_u64(0);
+            let mut checked = 0;
+            for _ in 0..20000 {
+                let word: u32 = if rng.gen_bool(0.5) { rng.gen::<u32>() | 3 } else { rng.gen::<u16>() as u32 };
+                let Some(meta) = profile
+                    .instructions()
+                    .filter(|meta| word & meta.mmatch == meta.mask && (meta.length == 4) == (word & 3 == 3))
+                    .max_by_key(|meta| meta.mmatch.count_ones())
+                else {
+                    continue;
+                };
+                let Some((mnemonic, args)) = dasm.disassemble(word) else {
+                    continue;
+                };
+                if meta.extension.ends_with("_v")
+                    || meta.operand_kinds.iter().any(|kind| matches!(kind, OperandKind::Other | OperandKind::RoundingMode))
+                    || (meta.length == 4 && word & 0x7f == 0x0f)
+                    || (meta.length == 4 && word & 0x7f == 0x73 && (word >> 12) & 7 == 0 && mnemonic != "sfence.vma")
+                {
+                    continue;
+                }
+                let source = format!("{mnemonic} {}", args.unwrap_or_default());
+                let word = if meta.length == 2 { word & 0xffff } else { word };
+                assert_eq!(words(&assembler, &source), [word], "{source}");
+                checked += 1;
+            }
+            assert!(checked > 1000);
+        }
+    }
+}
@@ -1,4 +1,3 @@
-
 // SPDX-FileCopyrightText: 2022 Intel Corporation
 //
 // SPDX-License-Identifier: Apache-2.0
@@ -8,8 +7,9 @@
 * Please, do not change this file directly but instead look at presifuzz/riscv-opcodes
 * This file contains helper functions to assemble all supported riscv instructions
 */
+#![allow(clippy::all)]
 use libafl::prelude::HasRand;
-use crate::instruction::Instruction;
+use crate::instruction::{Instruction, OperandKind};
 
 use libafl_bolts::{
     rands::Rand,
@@ -36,17 +36,69 @@ where
 }
 
 pub const ALL_RISCV_INSTR: &'static [&'static str] = &[
-"add","addi","addiw","addw","and","andi","auipc","beq","bge","bgeu",
-"blt","bltu","bne","c_add","c_addi","c_addi16sp","c_addi4spn","c_addiw","c_addw","c_and",
-"c_andi","c_beqz","c_bnez","c_ebreak","c_j","c_jal","c_jalr","c_jr","c_ld","c_ldsp",
-"c_li","c_lui","c_lw","c_lwsp","c_mv","c_nop","c_or","c_sd","c_sdsp","c_slli",
-"c_slli_rv32","c_srai","c_srai_rv32","c_srli","c_srli_rv32","c_sub","c_subw","c_sw","c_swsp","c_xor",
-"div","divu","divuw","divw","ebreak","ecall","fence","fence_tso","jal","jalr",
-"lb","lbu","ld","lh","lhu","lui","lw","lwu","mul","mulh",
-"mulhsu","mulhu","mulw","or","ori","pause","rem","remu","remuw","remw",
-"sb","sbreak","scall","sd","sh","sll","slli","slli_rv32","slliw","sllw",
-"slt","slti","sltiu","sltu","sra","srai","srai_rv32","sraiw","sraw","srl",
-"srli","srli_rv32","srliw","srlw","sub","subw","sw","xor","xori",];
+"add","add_uw","addi","addiw","addw","amoadd_d","amoadd_w","amoand_d","amoand_w","amomax_d",
+"amomax_w","amomaxu_d","amomaxu_w","amomin_d","amomin_w","amominu_d","amominu_w","amoor_d","amoor_w","amoswap_d",
+"amoswap_w","amoxor_d","amoxor_w","and","andi","andn","auipc","bclr","bclri","bclri_rv32",
+"beq","bext","bexti","bexti_rv32","bge","bgeu","binv","binvi","binvi_rv32","blt",
+"bltu","bne","bset","bseti","bseti_rv32","c_add","c_addi","c_addi16sp","c_addi4spn","c_addiw",
+"c_addw","c_and","c_andi","c_beqz","c_bnez","c_ebreak","c_j","c_jal","c_jalr","c_jr",
+"c_ld","c_ldsp","c_li","c_lui","c_lw","c_lwsp","c_mv","c_nop","c_or","c_sd",
+"c_sdsp","c_slli","c_slli_rv32","c_srai","c_srai_rv32","c_srli","c_srli_rv32","c_sub","c_subw","c_sw",
+"c_swsp","c_xor","clz","clzw","cpop","cpopw","csrrc","csrrci","csrrs","csrrsi",
+"csrrw","csrrwi","ctz","ctzw","div","divu","divuw","divw","ebreak","ecall",
+"fadd_d","fadd_s","fclass_d","fclass_s","fcvt_d_l","fcvt_d_lu","fcvt_d_s","fcvt_d_w","fcvt_d_wu","fcvt_l_d",
+"fcvt_l_s","fcvt_lu_d","fcvt_lu_s","fcvt_s_d","fcvt_s_l","fcvt_s_lu","fcvt_s_w","fcvt_s_wu","fcvt_w_d","fcvt_w_s",
+"fcvt_wu_d","fcvt_wu_s","fdiv_d","fdiv_s","fence","fence_i","fence_tso","feq_d","feq_s","fld",
+"fle_d","fle_s","flt_d","flt_s","flw","fmadd_d","fmadd_s","fmax_d","fmax_s","fmin_d",
+"fmin_s","fmsub_d","fmsub_s","fmul_d","fmul_s","fmv_d_x","fmv_w_x","fmv_x_d","fmv_x_w","fnmadd_d",
+"fnmadd_s","fnmsub_d","fnmsub_s","fsd","fsgnj_d","fsgnj_s","fsgnjn_d","fsgnjn_s","fsgnjx_d","fsgnjx_s",
+"fsqrt_d","fsqrt_s","fsub_d","fsub_s","fsw","jal","jalr","lb","lbu","ld",
+"lh","lhu","lr_d","lr_w","lui","lw","lwu","max","maxu","min",
+"minu","mret","mul","mulh","mulhsu","mulhu","mulw","or","orc_b","ori",
+"orn","pause","rem","remu","remuw","remw","rev8","rev8_rv32","rol","rolw",
+"ror","rori","rori_rv32","roriw","rorw","sb","sbreak","sc_d","sc_w","scall",
+"sd","sext_b","sext_h","sfence_vma","sh","sh1add","sh1add_uw","sh2add","sh2add_uw","sh3add",
+"sh3add_uw","sll","slli","slli_rv32","slli_uw","slliw","sllw","slt","slti","sltiu",
+"sltu","sra","srai","srai_rv32","sraiw","sraw","sret","srl","srli","srli_rv32",
+"srliw","srlw","sub","subw","sw","vaadd_vv","vaadd_vx","vaaddu_vv","vaaddu_vx","vadc_vim",
+"vadc_vvm","vadc_vxm","vadd_vi","vadd_vv","vadd_vx","vand_vi","vand_vv","vand_vx","vasub_vv","vasub_vx",
+"vasubu_vv","vasubu_vx","vcompress_vm","vcpop_m","vdiv_vv","vdiv_vx","vdivu_vv","vdivu_vx","vfadd_vf","vfadd_vv",
+"vfclass_v","vfcvt_f_x_v","vfcvt_f_xu_v","vfcvt_rtz_x_f_v","vfcvt_rtz_xu_f_v","vfcvt_x_f_v","vfcvt_xu_f_v","vfdiv_vf","vfdiv_vv","vfirst_m",
+"vfmacc_vf","vfmacc_vv","vfmadd_vf","vfmadd_vv","vfmax_vf","vfmax_vv","vfmerge_vfm","vfmin_vf","vfmin_vv","vfmsac_vf",
+"vfmsac_vv","vfmsub_vf","vfmsub_vv","vfmul_vf","vfmul_vv","vfmv_f_s","vfmv_s_f","vfmv_v_f","vfncvt_f_f_w","vfncvt_f_x_w",
+"vfncvt_f_xu_w","vfncvt_rod_f_f_w","vfncvt_rtz_x_f_w","vfncvt_rtz_xu_f_w","vfncvt_x_f_w","vfncvt_xu_f_w","vfnmacc_vf","vfnmacc_vv","vfnmadd_vf","vfnmadd_vv",
+"vfnmsac_vf","vfnmsac_vv","vfnmsub_vf","vfnmsub_vv","vfrdiv_vf","vfrec7_v","vfredmax_vs","vfredmin_vs","vfredosum_vs","vfredusum_vs",
+"vfrsqrt7_v","vfrsub_vf","vfsgnj_vf","vfsgnj_vv","vfsgnjn_vf","vfsgnjn_vv","vfsgnjx_vf","vfsgnjx_vv","vfslide1down_vf","vfslide1up_vf",
+"vfsqrt_v","vfsub_vf","vfsub_vv","vfwadd_vf","vfwadd_vv","vfwadd_wf","vfwadd_wv","vfwcvt_f_f_v","vfwcvt_f_x_v","vfwcvt_f_xu_v",
+"vfwcvt_rtz_x_f_v","vfwcvt_rtz_xu_f_v","vfwcvt_x_f_v","vfwcvt_xu_f_v","vfwmacc_vf","vfwmacc_vv","vfwmsac_vf","vfwmsac_vv","vfwmul_vf","vfwmul_vv",
+"vfwnmacc_vf","vfwnmacc_vv","vfwnmsac_vf","vfwnmsac_vv","vfwredosum_vs","vfwredusum_vs","vfwsub_vf","vfwsub_vv","vfwsub_wf","vfwsub_wv",
+"vid_v","viota_m","vl1re16_v","vl1re32_v","vl1re64_v","vl1re8_v","vl2re16_v","vl2re32_v","vl2re64_v","vl2re8_v",
+"vl4re16_v","vl4re32_v","vl4re64_v","vl4re8_v","vl8re16_v","vl8re32_v","vl8re64_v","vl8re8_v","vle16_v","vle16ff_v",
+"vle32_v","vle32ff_v","vle64_v","vle64ff_v","vle8_v","vle8ff_v","vlm_v","vloxei16_v","vloxei32_v","vloxei64_v",
+"vloxei8_v","vlse16_v","vlse32_v","vlse64_v","vlse8_v","vluxei16_v","vluxei32_v","vluxei64_v","vluxei8_v","vmacc_vv",
+"vmacc_vx","vmadc_vi","vmadc_vim","vmadc_vv","vmadc_vvm","vmadc_vx","vmadc_vxm","vmadd_vv","vmadd_vx","vmand_mm",
+"vmandn_mm","vmax_vv","vmax_vx","vmaxu_vv","vmaxu_vx","vmerge_vim","vmerge_vvm","vmerge_vxm","vmfeq_vf","vmfeq_vv",
+"vmfge_vf","vmfgt_vf","vmfle_vf","vmfle_vv","vmflt_vf","vmflt_vv","vmfne_vf","vmfne_vv","vmin_vv","vmin_vx",
+"vminu_vv","vminu_vx","vmnand_mm","vmnor_mm","vmor_mm","vmorn_mm","vmsbc_vv","vmsbc_vvm","vmsbc_vx","vmsbc_vxm",
+"vmsbf_m","vmseq_vi","vmseq_vv","vmseq_vx","vmsgt_vi","vmsgt_vx","vmsgtu_vi","vmsgtu_vx","vmsif_m","vmsle_vi",
+"vmsle_vv","vmsle_vx","vmsleu_vi","vmsleu_vv","vmsleu_vx","vmslt_vv","vmslt_vx","vmsltu_vv","vmsltu_vx","vmsne_vi",
+"vmsne_vv","vmsne_vx","vmsof_m","vmul_vv","vmul_vx","vmulh_vv","vmulh_vx","vmulhsu_vv","vmulhsu_vx","vmulhu_vv",
+"vmulhu_vx","vmv1r_v","vmv2r_v","vmv4r_v","vmv8r_v","vmv_s_x","vmv_v_i","vmv_v_v","vmv_v_x","vmv_x_s",
+"vmxnor_mm","vmxor_mm","vnclip_wi","vnclip_wv","vnclip_wx","vnclipu_wi","vnclipu_wv","vnclipu_wx","vnmsac_vv","vnmsac_vx",
+"vnmsub_vv","vnmsub_vx","vnsra_wi","vnsra_wv","vnsra_wx","vnsrl_wi","vnsrl_wv","vnsrl_wx","vor_vi","vor_vv",
+"vor_vx","vredand_vs","vredmax_vs","vredmaxu_vs","vredmin_vs","vredminu_vs","vredor_vs","vredsum_vs","vredxor_vs","vrem_vv",
+"vrem_vx","vremu_vv","vremu_vx","vrgather_vi","vrgather_vv","vrgather_vx","vrgatherei16_vv","vrsub_vi","vrsub_vx","vs1r_v",
+"vs2r_v","vs4r_v","vs8r_v","vsadd_vi","vsadd_vv","vsadd_vx","vsaddu_vi","vsaddu_vv","vsaddu_vx","vsbc_vvm",
+"vsbc_vxm","vse16_v","vse32_v","vse64_v","vse8_v","vsetivli","vsetvl","vsetvli","vsext_vf2","vsext_vf4",
+"vsext_vf8","vslide1down_vx","vslide1up_vx","vslidedown_vi","vslidedown_vx","vslideup_vi","vslideup_vx","vsll_vi","vsll_vv","vsll_vx",
+"vsm_v","vsmul_vv","vsmul_vx","vsoxei16_v","vsoxei32_v","vsoxei64_v","vsoxei8_v","vsra_vi","vsra_vv","vsra_vx",
+"vsrl_vi","vsrl_vv","vsrl_vx","vsse16_v","vsse32_v","vsse64_v","vsse8_v","vssra_vi","vssra_vv","vssra_vx",
+"vssrl_vi","vssrl_vv","vssrl_vx","vssub_vv","vssub_vx","vssubu_vv","vssubu_vx","vsub_vv","vsub_vx","vsuxei16_v",
+"vsuxei32_v","vsuxei64_v","vsuxei8_v","vwadd_vv","vwadd_vx","vwadd_wv","vwadd_wx","vwaddu_vv","vwaddu_vx","vwaddu_wv",
+"vwaddu_wx","vwmacc_vv","vwmacc_vx","vwmaccsu_vv","vwmaccsu_vx","vwmaccu_vv","vwmaccu_vx","vwmaccus_vx","vwmul_vv","vwmul_vx",
+"vwmulsu_vv","vwmulsu_vx","vwmulu_vv","vwmulu_vx","vwredsum_vs","vwredsumu_vs","vwsub_vv","vwsub_vx","vwsub_wv","vwsub_wx",
+"vwsubu_vv","vwsubu_vx","vwsubu_wv","vwsubu_wx","vxor_vi","vxor_vv","vxor_vx","vzext_vf2","vzext_vf4","vzext_vf8",
+"wfi","xnor","xor","xori","zext_h","zext_h_rv32",];
 
 /* Start of the helper function section */
 pub fn add(rd: u32,rs1: u32,rs2: u32) -> Instruction
@@ -66,6 +118,27 @@ pub fn add(rd: u32,rs1: u32,rs2: u32) -> Instruction
         extension: String::from("rv_i"),
         mnemonic: String::from("add"),
         operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn add_uw(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x800003b;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x800003b,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv64_zba"),
+        mnemonic: String::from("add_uw"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
     };
 }
 pub fn addi(rd: u32,rs1: u32,imm12: u32) -> Instruction
@@ -85,6 +158,7 @@ pub fn addi(rd: u32,rs1: u32,imm12: u32) -> Instruction
         extension: String::from("rv_i"),
         mnemonic: String::from("addi"),
         operands: vec![(7,11), (15,19), (20,31), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
     };
 }
 pub fn addiw(rd: u32,rs1: u32,imm12: u32) -> Instruction
@@ -104,6 +178,7 @@ pub fn addiw(rd: u32,rs1: u32,imm12: u32) -> Instruction
         extension: String::from("rv64_i"),
         mnemonic: String::from("addiw"),
         operands: vec![(7,11), (15,19), (20,31), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
     };
 }
 pub fn addw(rd: u32,rs1: u32,rs2: u32) -> Instruction
@@ -123,850 +198,933 @@ pub fn addw(rd: u32,rs1: u32,rs2: u32) -> Instruction
         extension: String::from("rv64_i"),
         mnemonic: String::from("addw"),
         operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
     };
 }
-pub fn and(rd: u32,rs1: u32,rs2: u32) -> Instruction
+pub fn amoadd_d(rd: u32,rs1: u32,rs2: u32,aqrl: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x7033;
+    let mask: u32 = 0x302f;
     inst |= mask;
     inst |= build_operand(rd, 7, 11);
     inst |= build_operand(rs1, 15, 19);
     inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(aqrl, 25, 26);
 
     return Instruction{
         instruction: inst as u64,
         length: 4,
-        mask: 0x7033,
-        mmatch: 0xfe00707f,
-        extension: String::from("rv_i"),
-        mnemonic: String::from("and"),
-        operands: vec![(7,11), (15,19), (20,24), ],
+        mask: 0x302f,
+        mmatch: 0xf800707f,
+        extension: String::from("rv64_a"),
+        mnemonic: String::from("amoadd_d"),
+        operands: vec![(7,11), (15,19), (20,24), (25,26), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::Other, ],
     };
 }
-pub fn andi(rd: u32,rs1: u32,imm12: u32) -> Instruction
+pub fn amoadd_w(rd: u32,rs1: u32,rs2: u32,aqrl: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x7013;
+    let mask: u32 = 0x202f;
     inst |= mask;
     inst |= build_operand(rd, 7, 11);
     inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(imm12, 20, 31);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(aqrl, 25, 26);
 
     return Instruction{
         instruction: inst as u64,
         length: 4,
-        mask: 0x7013,
-        mmatch: 0x707f,
-        extension: String::from("rv_i"),
-        mnemonic: String::from("andi"),
-        operands: vec![(7,11), (15,19), (20,31), ],
+        mask: 0x202f,
+        mmatch: 0xf800707f,
+        extension: String::from("rv_a"),
+        mnemonic: String::from("amoadd_w"),
+        operands: vec![(7,11), (15,19), (20,24), (25,26), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::Other, ],
     };
 }
-pub fn auipc(rd: u32,imm20: u32) -> Instruction
+pub fn amoand_d(rd: u32,rs1: u32,rs2: u32,aqrl: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x17;
+    let mask: u32 = 0x6000302f;
     inst |= mask;
     inst |= build_operand(rd, 7, 11);
-    inst |= build_operand(imm20, 12, 31);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(aqrl, 25, 26);
 
     return Instruction{
         instruction: inst as u64,
         length: 4,
-        mask: 0x17,
-        mmatch: 0x7f,
-        extension: String::from("rv_i"),
-        mnemonic: String::from("auipc"),
-        operands: vec![(7,11), (12,31), ],
+        mask: 0x6000302f,
+        mmatch: 0xf800707f,
+        extension: String::from("rv64_a"),
+        mnemonic: String::from("amoand_d"),
+        operands: vec![(7,11), (15,19), (20,24), (25,26), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::Other, ],
     };
 }
-pub fn beq(bimm12hi: u32,rs1: u32,rs2: u32,bimm12lo: u32) -> Instruction
+pub fn amoand_w(rd: u32,rs1: u32,rs2: u32,aqrl: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x63;
+    let mask: u32 = 0x6000202f;
     inst |= mask;
-    inst |= build_operand(bimm12hi, 25, 31);
+    inst |= build_operand(rd, 7, 11);
     inst |= build_operand(rs1, 15, 19);
     inst |= build_operand(rs2, 20, 24);
-    inst |= build_operand(bimm12lo, 7, 11);
+    inst |= build_operand(aqrl, 25, 26);
 
     return Instruction{
         instruction: inst as u64,
         length: 4,
-        mask: 0x63,
-        mmatch: 0x707f,
-        extension: String::from("rv_i"),
-        mnemonic: String::from("beq"),
-        operands: vec![(25,31), (15,19), (20,24), (7,11), ],
+        mask: 0x6000202f,
+        mmatch: 0xf800707f,
+        extension: String::from("rv_a"),
+        mnemonic: String::from("amoand_w"),
+        operands: vec![(7,11), (15,19), (20,24), (25,26), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::Other, ],
     };
 }
-pub fn bge(bimm12hi: u32,rs1: u32,rs2: u32,bimm12lo: u32) -> Instruction
+pub fn amomax_d(rd: u32,rs1: u32,rs2: u32,aqrl: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x5063;
+    let mask: u32 = 0xa000302f;
     inst |= mask;
-    inst |= build_operand(bimm12hi, 25, 31);
+    inst |= build_operand(rd, 7, 11);
     inst |= build_operand(rs1, 15, 19);
     inst |= build_operand(rs2, 20, 24);
-    inst |= build_operand(bimm12lo, 7, 11);
+    inst |= build_operand(aqrl, 25, 26);
 
     return Instruction{
         instruction: inst as u64,
         length: 4,
-        mask: 0x5063,
-        mmatch: 0x707f,
-        extension: String::from("rv_i"),
-        mnemonic: String::from("bge"),
-        operands: vec![(25,31), (15,19), (20,24), (7,11), ],
+        mask: 0xa000302f,
+        mmatch: 0xf800707f,
+        extension: String::from("rv64_a"),
+        mnemonic: String::from("amomax_d"),
+        operands: vec![(7,11), (15,19), (20,24), (25,26), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::Other, ],
     };
 }
-pub fn bgeu(bimm12hi: u32,rs1: u32,rs2: u32,bimm12lo: u32) -> Instruction
+pub fn amomax_w(rd: u32,rs1: u32,rs2: u32,aqrl: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x7063;
+    let mask: u32 = 0xa000202f;
     inst |= mask;
-    inst |= build_operand(bimm12hi, 25, 31);
+    inst |= build_operand(rd, 7, 11);
     inst |= build_operand(rs1, 15, 19);
     inst |= build_operand(rs2, 20, 24);
-    inst |= build_operand(bimm12lo, 7, 11);
+    inst |= build_operand(aqrl, 25, 26);
 
     return Instruction{
         instruction: inst as u64,
         length: 4,
-        mask: 0x7063,
-        mmatch: 0x707f,
-        extension: String::from("rv_i"),
-        mnemonic: String::from("bgeu"),
-        operands: vec![(25,31), (15,19), (20,24), (7,11), ],
+        mask: 0xa000202f,
+        mmatch: 0xf800707f,
+        extension: String::from("rv_a"),
+        mnemonic: String::from("amomax_w"),
+        operands: vec![(7,11), (15,19), (20,24), (25,26), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::Other, ],
     };
 }
-pub fn blt(bimm12hi: u32,rs1: u32,rs2: u32,bimm12lo: u32) -> Instruction
+pub fn amomaxu_d(rd: u32,rs1: u32,rs2: u32,aqrl: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x4063;
+    let mask: u32 = 0xe000302f;
     inst |= mask;
-    inst |= build_operand(bimm12hi, 25, 31);
+    inst |= build_operand(rd, 7, 11);
     inst |= build_operand(rs1, 15, 19);
     inst |= build_operand(rs2, 20, 24);
-    inst |= build_operand(bimm12lo, 7, 11);
+    inst |= build_operand(aqrl, 25, 26);
 
     return Instruction{
         instruction: inst as u64,
         length: 4,
-        mask: 0x4063,
-        mmatch: 0x707f,
-        extension: String::from("rv_i"),
-        mnemonic: String::from("blt"),
-        operands: vec![(25,31), (15,19), (20,24), (7,11), ],
+        mask: 0xe000302f,
+        mmatch: 0xf800707f,
+        extension: String::from("rv64_a"),
+        mnemonic: String::from("amomaxu_d"),
+        operands: vec![(7,11), (15,19), (20,24), (25,26), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::Other, ],
     };
 }
-pub fn bltu(bimm12hi: u32,rs1: u32,rs2: u32,bimm12lo: u32) -> Instruction
+pub fn amomaxu_w(rd: u32,rs1: u32,rs2: u32,aqrl: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x6063;
+    let mask: u32 = 0xe000202f;
     inst |= mask;
-    inst |= build_operand(bimm12hi, 25, 31);
+    inst |= build_operand(rd, 7, 11);
     inst |= build_operand(rs1, 15, 19);
     inst |= build_operand(rs2, 20, 24);
-    inst |= build_operand(bimm12lo, 7, 11);
+    inst |= build_operand(aqrl, 25, 26);
 
     return Instruction{
         instruction: inst as u64,
         length: 4,
-        mask: 0x6063,
-        mmatch: 0x707f,
-        extension: String::from("rv_i"),
-        mnemonic: String::from("bltu"),
-        operands: vec![(25,31), (15,19), (20,24), (7,11), ],
+        mask: 0xe000202f,
+        mmatch: 0xf800707f,
+        extension: String::from("rv_a"),
+        mnemonic: String::from("amomaxu_w"),
+        operands: vec![(7,11), (15,19), (20,24), (25,26), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::Other, ],
     };
 }
-pub fn bne(bimm12hi: u32,rs1: u32,rs2: u32,bimm12lo: u32) -> Instruction
+pub fn amomin_d(rd: u32,rs1: u32,rs2: u32,aqrl: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x1063;
+    let mask: u32 = 0x8000302f;
     inst |= mask;
-    inst |= build_operand(bimm12hi, 25, 31);
+    inst |= build_operand(rd, 7, 11);
     inst |= build_operand(rs1, 15, 19);
     inst |= build_operand(rs2, 20, 24);
-    inst |= build_operand(bimm12lo, 7, 11);
+    inst |= build_operand(aqrl, 25, 26);
 
     return Instruction{
         instruction: inst as u64,
         length: 4,
-        mask: 0x1063,
-        mmatch: 0x707f,
-        extension: String::from("rv_i"),
-        mnemonic: String::from("bne"),
-        operands: vec![(25,31), (15,19), (20,24), (7,11), ],
+        mask: 0x8000302f,
+        mmatch: 0xf800707f,
+        extension: String::from("rv64_a"),
+        mnemonic: String::from("amomin_d"),
+        operands: vec![(7,11), (15,19), (20,24), (25,26), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::Other, ],
     };
 }
-pub fn c_add(rd_rs1_n0: u32,c_rs2_n0: u32) -> Instruction
+pub fn amomin_w(rd: u32,rs1: u32,rs2: u32,aqrl: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x9002;
+    let mask: u32 = 0x8000202f;
     inst |= mask;
-    inst |= build_operand(rd_rs1_n0, 7, 11);
-    inst |= build_operand(c_rs2_n0, 2, 6);
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(aqrl, 25, 26);
 
     return Instruction{
         instruction: inst as u64,
-        length: 2,
-        mask: 0x9002,
-        mmatch: 0xf003,
-        extension: String::from("rv_c"),
-        mnemonic: String::from("c_add"),
-        operands: vec![(7,11), (2,6), ],
+        length: 4,
+        mask: 0x8000202f,
+        mmatch: 0xf800707f,
+        extension: String::from("rv_a"),
+        mnemonic: String::from("amomin_w"),
+        operands: vec![(7,11), (15,19), (20,24), (25,26), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::Other, ],
     };
 }
-pub fn c_addi(rd_rs1_n0: u32,c_nzimm6lo: u32,c_nzimm6hi: u32) -> Instruction
+pub fn amominu_d(rd: u32,rs1: u32,rs2: u32,aqrl: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x1;
+    let mask: u32 = 0xc000302f;
     inst |= mask;
-    inst |= build_operand(rd_rs1_n0, 7, 11);
-    inst |= build_operand(c_nzimm6lo, 2, 6);
-    inst |= build_operand(c_nzimm6hi, 12, 12);
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(aqrl, 25, 26);
 
     return Instruction{
         instruction: inst as u64,
-        length: 2,
-        mask: 0x1,
-        mmatch: 0xe003,
-        extension: String::from("rv_c"),
-        mnemonic: String::from("c_addi"),
-        operands: vec![(7,11), (2,6), (12,12), ],
+        length: 4,
+        mask: 0xc000302f,
+        mmatch: 0xf800707f,
+        extension: String::from("rv64_a"),
+        mnemonic: String::from("amominu_d"),
+        operands: vec![(7,11), (15,19), (20,24), (25,26), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::Other, ],
     };
 }
-pub fn c_addi16sp(c_nzimm10hi: u32,c_nzimm10lo: u32) -> Instruction
+pub fn amominu_w(rd: u32,rs1: u32,rs2: u32,aqrl: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x6101;
+    let mask: u32 = 0xc000202f;
     inst |= mask;
-    inst |= build_operand(c_nzimm10hi, 12, 12);
-    inst |= build_operand(c_nzimm10lo, 2, 6);
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(aqrl, 25, 26);
 
     return Instruction{
         instruction: inst as u64,
-        length: 2,
-        mask: 0x6101,
-        mmatch: 0xef83,
-        extension: String::from("rv_c"),
-        mnemonic: String::from("c_addi16sp"),
-        operands: vec![(12,12), (2,6), ],
+        length: 4,
+        mask: 0xc000202f,
+        mmatch: 0xf800707f,
+        extension: String::from("rv_a"),
+        mnemonic: String::from("amominu_w"),
+        operands: vec![(7,11), (15,19), (20,24), (25,26), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::Other, ],
     };
 }
-pub fn c_addi4spn(rd_p: u32,c_nzuimm10: u32) -> Instruction
+pub fn amoor_d(rd: u32,rs1: u32,rs2: u32,aqrl: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x0;
+    let mask: u32 = 0x4000302f;
     inst |= mask;
-    inst |= build_operand(rd_p, 2, 4);
-    inst |= build_operand(c_nzuimm10, 5, 12);
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(aqrl, 25, 26);
 
     return Instruction{
         instruction: inst as u64,
-        length: 2,
-        mask: 0x0,
-        mmatch: 0xe003,
-        extension: String::from("rv_c"),
-        mnemonic: String::from("c_addi4spn"),
-        operands: vec![(2,4), (5,12), ],
+        length: 4,
+        mask: 0x4000302f,
+        mmatch: 0xf800707f,
+        extension: String::from("rv64_a"),
+        mnemonic: String::from("amoor_d"),
+        operands: vec![(7,11), (15,19), (20,24), (25,26), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::Other, ],
     };
 }
-pub fn c_addiw(rd_rs1_n0: u32,c_imm6lo: u32,c_imm6hi: u32) -> Instruction
+pub fn amoor_w(rd: u32,rs1: u32,rs2: u32,aqrl: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x2001;
+    let mask: u32 = 0x4000202f;
     inst |= mask;
-    inst |= build_operand(rd_rs1_n0, 7, 11);
-    inst |= build_operand(c_imm6lo, 2, 6);
-    inst |= build_operand(c_imm6hi, 12, 12);
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(aqrl, 25, 26);
 
     return Instruction{
         instruction: inst as u64,
-        length: 2,
-        mask: 0x2001,
-        mmatch: 0xe003,
-        extension: String::from("rv64_c"),
-        mnemonic: String::from("c_addiw"),
-        operands: vec![(7,11), (2,6), (12,12), ],
+        length: 4,
+        mask: 0x4000202f,
+        mmatch: 0xf800707f,
+        extension: String::from("rv_a"),
+        mnemonic: String::from("amoor_w"),
+        operands: vec![(7,11), (15,19), (20,24), (25,26), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::Other, ],
     };
 }
-pub fn c_addw(rd_rs1_p: u32,rs2_p: u32) -> Instruction
+pub fn amoswap_d(rd: u32,rs1: u32,rs2: u32,aqrl: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x9c21;
+    let mask: u32 = 0x800302f;
     inst |= mask;
-    inst |= build_operand(rd_rs1_p, 7, 9);
-    inst |= build_operand(rs2_p, 2, 4);
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(aqrl, 25, 26);
 
     return Instruction{
         instruction: inst as u64,
-        length: 2,
-        mask: 0x9c21,
-        mmatch: 0xfc63,
-        extension: String::from("rv64_c"),
-        mnemonic: String::from("c_addw"),
-        operands: vec![(7,9), (2,4), ],
+        length: 4,
+        mask: 0x800302f,
+        mmatch: 0xf800707f,
+        extension: String::from("rv64_a"),
+        mnemonic: String::from("amoswap_d"),
+        operands: vec![(7,11), (15,19), (20,24), (25,26), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::Other, ],
     };
 }
-pub fn c_and(rd_rs1_p: u32,rs2_p: u32) -> Instruction
+pub fn amoswap_w(rd: u32,rs1: u32,rs2: u32,aqrl: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x8c61;
+    let mask: u32 = 0x800202f;
     inst |= mask;
-    inst |= build_operand(rd_rs1_p, 7, 9);
-    inst |= build_operand(rs2_p, 2, 4);
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(aqrl, 25, 26);
 
     return Instruction{
         instruction: inst as u64,
-        length: 2,
-        mask: 0x8c61,
-        mmatch: 0xfc63,
-        extension: String::from("rv_c"),
-        mnemonic: String::from("c_and"),
-        operands: vec![(7,9), (2,4), ],
+        length: 4,
+        mask: 0x800202f,
+        mmatch: 0xf800707f,
+        extension: String::from("rv_a"),
+        mnemonic: String::from("amoswap_w"),
+        operands: vec![(7,11), (15,19), (20,24), (25,26), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::Other, ],
     };
 }
-pub fn c_andi(rd_rs1_p: u32,c_imm6hi: u32,c_imm6lo: u32) -> Instruction
+pub fn amoxor_d(rd: u32,rs1: u32,rs2: u32,aqrl: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x8801;
+    let mask: u32 = 0x2000302f;
     inst |= mask;
-    inst |= build_operand(rd_rs1_p, 7, 9);
-    inst |= build_operand(c_imm6hi, 12, 12);
-    inst |= build_operand(c_imm6lo, 2, 6);
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(aqrl, 25, 26);
 
     return Instruction{
         instruction: inst as u64,
-        length: 2,
-        mask: 0x8801,
-        mmatch: 0xec03,
-        extension: String::from("rv_c"),
-        mnemonic: String::from("c_andi"),
-        operands: vec![(7,9), (12,12), (2,6), ],
+        length: 4,
+        mask: 0x2000302f,
+        mmatch: 0xf800707f,
+        extension: String::from("rv64_a"),
+        mnemonic: String::from("amoxor_d"),
+        operands: vec![(7,11), (15,19), (20,24), (25,26), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::Other, ],
     };
 }
-pub fn c_beqz(rs1_p: u32,c_bimm9lo: u32,c_bimm9hi: u32) -> Instruction
+pub fn amoxor_w(rd: u32,rs1: u32,rs2: u32,aqrl: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0xc001;
+    let mask: u32 = 0x2000202f;
     inst |= mask;
-    inst |= build_operand(rs1_p, 7, 9);
-    inst |= build_operand(c_bimm9lo, 2, 6);
-    inst |= build_operand(c_bimm9hi, 10, 12);
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(aqrl, 25, 26);
 
     return Instruction{
         instruction: inst as u64,
-        length: 2,
-        mask: 0xc001,
-        mmatch: 0xe003,
-        extension: String::from("rv_c"),
-        mnemonic: String::from("c_beqz"),
-        operands: vec![(7,9), (2,6), (10,12), ],
+        length: 4,
+        mask: 0x2000202f,
+        mmatch: 0xf800707f,
+        extension: String::from("rv_a"),
+        mnemonic: String::from("amoxor_w"),
+        operands: vec![(7,11), (15,19), (20,24), (25,26), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::Other, ],
     };
 }
-pub fn c_bnez(rs1_p: u32,c_bimm9lo: u32,c_bimm9hi: u32) -> Instruction
+pub fn and(rd: u32,rs1: u32,rs2: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0xe001;
+    let mask: u32 = 0x7033;
     inst |= mask;
-    inst |= build_operand(rs1_p, 7, 9);
-    inst |= build_operand(c_bimm9lo, 2, 6);
-    inst |= build_operand(c_bimm9hi, 10, 12);
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
 
     return Instruction{
         instruction: inst as u64,
-        length: 2,
-        mask: 0xe001,
-        mmatch: 0xe003,
-        extension: String::from("rv_c"),
-        mnemonic: String::from("c_bnez"),
-        operands: vec![(7,9), (2,6), (10,12), ],
+        length: 4,
+        mask: 0x7033,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_i"),
+        mnemonic: String::from("and"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
     };
 }
-pub fn c_ebreak() -> Instruction
+pub fn andi(rd: u32,rs1: u32,imm12: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x9002;
+    let mask: u32 = 0x7013;
     inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(imm12, 20, 31);
 
     return Instruction{
         instruction: inst as u64,
-        length: 2,
-        mask: 0x9002,
-        mmatch: 0xffff,
-        extension: String::from("rv_c"),
-        mnemonic: String::from("c_ebreak"),
-        operands: vec![],
+        length: 4,
+        mask: 0x7013,
+        mmatch: 0x707f,
+        extension: String::from("rv_i"),
+        mnemonic: String::from("andi"),
+        operands: vec![(7,11), (15,19), (20,31), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
     };
 }
-pub fn c_j(c_imm12: u32) -> Instruction
+pub fn andn(rd: u32,rs1: u32,rs2: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0xa001;
+    let mask: u32 = 0x40007033;
     inst |= mask;
-    inst |= build_operand(c_imm12, 2, 12);
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
 
     return Instruction{
         instruction: inst as u64,
-        length: 2,
-        mask: 0xa001,
-        mmatch: 0xe003,
-        extension: String::from("rv_c"),
-        mnemonic: String::from("c_j"),
-        operands: vec![(2,12), ],
+        length: 4,
+        mask: 0x40007033,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_zbb"),
+        mnemonic: String::from("andn"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
     };
 }
-pub fn c_jal(c_imm12: u32) -> Instruction
+pub fn auipc(rd: u32,imm20: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x2001;
+    let mask: u32 = 0x17;
     inst |= mask;
-    inst |= build_operand(c_imm12, 2, 12);
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(imm20, 12, 31);
 
     return Instruction{
         instruction: inst as u64,
-        length: 2,
-        mask: 0x2001,
-        mmatch: 0xe003,
-        extension: String::from("rv32_c"),
-        mnemonic: String::from("c_jal"),
-        operands: vec![(2,12), ],
+        length: 4,
+        mask: 0x17,
+        mmatch: 0x7f,
+        extension: String::from("rv_i"),
+        mnemonic: String::from("auipc"),
+        operands: vec![(7,11), (12,31), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::UnsignedImm, ],
     };
 }
-pub fn c_jalr(c_rs1_n0: u32) -> Instruction
+pub fn bclr(rd: u32,rs1: u32,rs2: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x9002;
+    let mask: u32 = 0x48001033;
     inst |= mask;
-    inst |= build_operand(c_rs1_n0, 7, 11);
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
 
     return Instruction{
         instruction: inst as u64,
-        length: 2,
-        mask: 0x9002,
-        mmatch: 0xf07f,
-        extension: String::from("rv_c"),
-        mnemonic: String::from("c_jalr"),
-        operands: vec![(7,11), ],
+        length: 4,
+        mask: 0x48001033,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_zbs"),
+        mnemonic: String::from("bclr"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
     };
 }
-pub fn c_jr(rs1_n0: u32) -> Instruction
+pub fn bclri(rd: u32,rs1: u32,shamtd: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x8002;
+    let mask: u32 = 0x48001013;
     inst |= mask;
-    inst |= build_operand(rs1_n0, 7, 11);
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(shamtd, 20, 25);
 
     return Instruction{
         instruction: inst as u64,
-        length: 2,
-        mask: 0x8002,
-        mmatch: 0xf07f,
-        extension: String::from("rv_c"),
-        mnemonic: String::from("c_jr"),
-        operands: vec![(7,11), ],
+        length: 4,
+        mask: 0x48001013,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv64_zbs"),
+        mnemonic: String::from("bclri"),
+        operands: vec![(7,11), (15,19), (20,25), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Shamt, ],
     };
 }
-pub fn c_ld(rd_p: u32,rs1_p: u32,c_uimm8lo: u32,c_uimm8hi: u32) -> Instruction
+pub fn bclri_rv32(rd: u32,rs1: u32,shamtw: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x6000;
+    let mask: u32 = 0x48001013;
     inst |= mask;
-    inst |= build_operand(rd_p, 2, 4);
-    inst |= build_operand(rs1_p, 7, 9);
-    inst |= build_operand(c_uimm8lo, 5, 6);
-    inst |= build_operand(c_uimm8hi, 10, 12);
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(shamtw, 20, 24);
 
     return Instruction{
         instruction: inst as u64,
-        length: 2,
-        mask: 0x6000,
-        mmatch: 0xe003,
-        extension: String::from("rv64_c"),
-        mnemonic: String::from("c_ld"),
-        operands: vec![(2,4), (7,9), (5,6), (10,12), ],
+        length: 4,
+        mask: 0x48001013,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv32_zbs"),
+        mnemonic: String::from("bclri_rv32"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Shamt, ],
     };
 }
-pub fn c_ldsp(rd_n0: u32,c_uimm9sphi: u32,c_uimm9splo: u32) -> Instruction
+pub fn beq(bimm12hi: u32,rs1: u32,rs2: u32,bimm12lo: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x6002;
+    let mask: u32 = 0x63;
     inst |= mask;
-    inst |= build_operand(rd_n0, 7, 11);
-    inst |= build_operand(c_uimm9sphi, 12, 12);
-    inst |= build_operand(c_uimm9splo, 2, 6);
+    inst |= build_operand(bimm12hi, 25, 31);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(bimm12lo, 7, 11);
 
     return Instruction{
         instruction: inst as u64,
-        length: 2,
-        mask: 0x6002,
-        mmatch: 0xe003,
-        extension: String::from("rv64_c"),
-        mnemonic: String::from("c_ldsp"),
-        operands: vec![(7,11), (12,12), (2,6), ],
+        length: 4,
+        mask: 0x63,
+        mmatch: 0x707f,
+        extension: String::from("rv_i"),
+        mnemonic: String::from("beq"),
+        operands: vec![(25,31), (15,19), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::SignedImm, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
     };
 }
-pub fn c_li(rd_n0: u32,c_imm6lo: u32,c_imm6hi: u32) -> Instruction
+pub fn bext(rd: u32,rs1: u32,rs2: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x4001;
+    let mask: u32 = 0x48005033;
     inst |= mask;
-    inst |= build_operand(rd_n0, 7, 11);
-    inst |= build_operand(c_imm6lo, 2, 6);
-    inst |= build_operand(c_imm6hi, 12, 12);
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
 
     return Instruction{
         instruction: inst as u64,
-        length: 2,
-        mask: 0x4001,
-        mmatch: 0xe003,
-        extension: String::from("rv_c"),
-        mnemonic: String::from("c_li"),
-        operands: vec![(7,11), (2,6), (12,12), ],
+        length: 4,
+        mask: 0x48005033,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_zbs"),
+        mnemonic: String::from("bext"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
     };
 }
-pub fn c_lui(rd_n2: u32,c_nzimm18hi: u32,c_nzimm18lo: u32) -> Instruction
+pub fn bexti(rd: u32,rs1: u32,shamtd: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x6001;
+    let mask: u32 = 0x48005013;
     inst |= mask;
-    inst |= build_operand(rd_n2, 7, 11);
-    inst |= build_operand(c_nzimm18hi, 12, 12);
-    inst |= build_operand(c_nzimm18lo, 2, 6);
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(shamtd, 20, 25);
 
     return Instruction{
         instruction: inst as u64,
-        length: 2,
-        mask: 0x6001,
-        mmatch: 0xe003,
-        extension: String::from("rv_c"),
-        mnemonic: String::from("c_lui"),
-        operands: vec![(7,11), (12,12), (2,6), ],
+        length: 4,
+        mask: 0x48005013,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv64_zbs"),
+        mnemonic: String::from("bexti"),
+        operands: vec![(7,11), (15,19), (20,25), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Shamt, ],
     };
 }
-pub fn c_lw(rd_p: u32,rs1_p: u32,c_uimm7lo: u32,c_uimm7hi: u32) -> Instruction
+pub fn bexti_rv32(rd: u32,rs1: u32,shamtw: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x4000;
+    let mask: u32 = 0x48005013;
     inst |= mask;
-    inst |= build_operand(rd_p, 2, 4);
-    inst |= build_operand(rs1_p, 7, 9);
-    inst |= build_operand(c_uimm7lo, 5, 6);
-    inst |= build_operand(c_uimm7hi, 10, 12);
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(shamtw, 20, 24);
 
     return Instruction{
         instruction: inst as u64,
-        length: 2,
-        mask: 0x4000,
-        mmatch: 0xe003,
-        extension: String::from("rv_c"),
-        mnemonic: String::from("c_lw"),
-        operands: vec![(2,4), (7,9), (5,6), (10,12), ],
+        length: 4,
+        mask: 0x48005013,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv32_zbs"),
+        mnemonic: String::from("bexti_rv32"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Shamt, ],
     };
 }
-pub fn c_lwsp(rd_n0: u32,c_uimm8sphi: u32,c_uimm8splo: u32) -> Instruction
+pub fn bge(bimm12hi: u32,rs1: u32,rs2: u32,bimm12lo: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x4002;
+    let mask: u32 = 0x5063;
     inst |= mask;
-    inst |= build_operand(rd_n0, 7, 11);
-    inst |= build_operand(c_uimm8sphi, 12, 12);
-    inst |= build_operand(c_uimm8splo, 2, 6);
+    inst |= build_operand(bimm12hi, 25, 31);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(bimm12lo, 7, 11);
 
     return Instruction{
         instruction: inst as u64,
-        length: 2,
-        mask: 0x4002,
-        mmatch: 0xe003,
-        extension: String::from("rv_c"),
-        mnemonic: String::from("c_lwsp"),
-        operands: vec![(7,11), (12,12), (2,6), ],
+        length: 4,
+        mask: 0x5063,
+        mmatch: 0x707f,
+        extension: String::from("rv_i"),
+        mnemonic: String::from("bge"),
+        operands: vec![(25,31), (15,19), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::SignedImm, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
     };
 }
-pub fn c_mv(rd_n0: u32,c_rs2_n0: u32) -> Instruction
+pub fn bgeu(bimm12hi: u32,rs1: u32,rs2: u32,bimm12lo: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x8002;
+    let mask: u32 = 0x7063;
     inst |= mask;
-    inst |= build_operand(rd_n0, 7, 11);
-    inst |= build_operand(c_rs2_n0, 2, 6);
+    inst |= build_operand(bimm12hi, 25, 31);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(bimm12lo, 7, 11);
 
     return Instruction{
         instruction: inst as u64,
-        length: 2,
-        mask: 0x8002,
-        mmatch: 0xf003,
-        extension: String::from("rv_c"),
-        mnemonic: String::from("c_mv"),
-        operands: vec![(7,11), (2,6), ],
+        length: 4,
+        mask: 0x7063,
+        mmatch: 0x707f,
+        extension: String::from("rv_i"),
+        mnemonic: String::from("bgeu"),
+        operands: vec![(25,31), (15,19), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::SignedImm, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
     };
 }
-pub fn c_nop(c_nzimm6hi: u32,c_nzimm6lo: u32) -> Instruction
+pub fn binv(rd: u32,rs1: u32,rs2: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x1;
+    let mask: u32 = 0x68001033;
     inst |= mask;
-    inst |= build_operand(c_nzimm6hi, 12, 12);
-    inst |= build_operand(c_nzimm6lo, 2, 6);
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
 
     return Instruction{
         instruction: inst as u64,
-        length: 2,
-        mask: 0x1,
-        mmatch: 0xef83,
-        extension: String::from("rv_c"),
-        mnemonic: String::from("c_nop"),
-        operands: vec![(12,12), (2,6), ],
+        length: 4,
+        mask: 0x68001033,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_zbs"),
+        mnemonic: String::from("binv"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
     };
 }
-pub fn c_or(rd_rs1_p: u32,rs2_p: u32) -> Instruction
+pub fn binvi(rd: u32,rs1: u32,shamtd: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x8c41;
+    let mask: u32 = 0x68001013;
     inst |= mask;
-    inst |= build_operand(rd_rs1_p, 7, 9);
-    inst |= build_operand(rs2_p, 2, 4);
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(shamtd, 20, 25);
 
     return Instruction{
         instruction: inst as u64,
-        length: 2,
-        mask: 0x8c41,
-        mmatch: 0xfc63,
-        extension: String::from("rv_c"),
-        mnemonic: String::from("c_or"),
-        operands: vec![(7,9), (2,4), ],
+        length: 4,
+        mask: 0x68001013,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv64_zbs"),
+        mnemonic: String::from("binvi"),
+        operands: vec![(7,11), (15,19), (20,25), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Shamt, ],
     };
 }
-pub fn c_sd(rs1_p: u32,rs2_p: u32,c_uimm8hi: u32,c_uimm8lo: u32) -> Instruction
+pub fn binvi_rv32(rd: u32,rs1: u32,shamtw: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0xe000;
+    let mask: u32 = 0x68001013;
     inst |= mask;
-    inst |= build_operand(rs1_p, 7, 9);
-    inst |= build_operand(rs2_p, 2, 4);
-    inst |= build_operand(c_uimm8hi, 10, 12);
-    inst |= build_operand(c_uimm8lo, 5, 6);
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(shamtw, 20, 24);
 
     return Instruction{
         instruction: inst as u64,
-        length: 2,
-        mask: 0xe000,
-        mmatch: 0xe003,
-        extension: String::from("rv64_c"),
-        mnemonic: String::from("c_sd"),
-        operands: vec![(7,9), (2,4), (10,12), (5,6), ],
+        length: 4,
+        mask: 0x68001013,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv32_zbs"),
+        mnemonic: String::from("binvi_rv32"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Shamt, ],
     };
 }
-pub fn c_sdsp(c_rs2: u32,c_uimm9sp_s: u32) -> Instruction
+pub fn blt(bimm12hi: u32,rs1: u32,rs2: u32,bimm12lo: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0xe002;
+    let mask: u32 = 0x4063;
     inst |= mask;
-    inst |= build_operand(c_rs2, 2, 6);
-    inst |= build_operand(c_uimm9sp_s, 7, 12);
+    inst |= build_operand(bimm12hi, 25, 31);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(bimm12lo, 7, 11);
 
     return Instruction{
         instruction: inst as u64,
-        length: 2,
-        mask: 0xe002,
-        mmatch: 0xe003,
-        extension: String::from("rv64_c"),
-        mnemonic: String::from("c_sdsp"),
-        operands: vec![(2,6), (7,12), ],
+        length: 4,
+        mask: 0x4063,
+        mmatch: 0x707f,
+        extension: String::from("rv_i"),
+        mnemonic: String::from("blt"),
+        operands: vec![(25,31), (15,19), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::SignedImm, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
     };
 }
-pub fn c_slli(rd_rs1_n0: u32,c_nzuimm6hi: u32,c_nzuimm6lo: u32) -> Instruction
+pub fn bltu(bimm12hi: u32,rs1: u32,rs2: u32,bimm12lo: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x2;
+    let mask: u32 = 0x6063;
     inst |= mask;
-    inst |= build_operand(rd_rs1_n0, 7, 11);
-    inst |= build_operand(c_nzuimm6hi, 12, 12);
-    inst |= build_operand(c_nzuimm6lo, 2, 6);
+    inst |= build_operand(bimm12hi, 25, 31);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(bimm12lo, 7, 11);
 
     return Instruction{
         instruction: inst as u64,
-        length: 2,
-        mask: 0x2,
-        mmatch: 0xe003,
-        extension: String::from("rv64_c"),
-        mnemonic: String::from("c_slli"),
-        operands: vec![(7,11), (12,12), (2,6), ],
+        length: 4,
+        mask: 0x6063,
+        mmatch: 0x707f,
+        extension: String::from("rv_i"),
+        mnemonic: String::from("bltu"),
+        operands: vec![(25,31), (15,19), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::SignedImm, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
     };
 }
-pub fn c_slli_rv32(rd_rs1_n0: u32,c_nzuimm6lo: u32) -> Instruction
+pub fn bne(bimm12hi: u32,rs1: u32,rs2: u32,bimm12lo: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x2;
+    let mask: u32 = 0x1063;
     inst |= mask;
-    inst |= build_operand(rd_rs1_n0, 7, 11);
-    inst |= build_operand(c_nzuimm6lo, 2, 6);
+    inst |= build_operand(bimm12hi, 25, 31);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(bimm12lo, 7, 11);
 
     return Instruction{
         instruction: inst as u64,
-        length: 2,
-        mask: 0x2,
-        mmatch: 0xf003,
-        extension: String::from("rv32_c"),
-        mnemonic: String::from("c_slli_rv32"),
-        operands: vec![(7,11), (2,6), ],
+        length: 4,
+        mask: 0x1063,
+        mmatch: 0x707f,
+        extension: String::from("rv_i"),
+        mnemonic: String::from("bne"),
+        operands: vec![(25,31), (15,19), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::SignedImm, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
     };
 }
-pub fn c_srai(rd_rs1_p: u32,c_nzuimm6lo: u32,c_nzuimm6hi: u32) -> Instruction
+pub fn bset(rd: u32,rs1: u32,rs2: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x8401;
+    let mask: u32 = 0x28001033;
     inst |= mask;
-    inst |= build_operand(rd_rs1_p, 7, 9);
-    inst |= build_operand(c_nzuimm6lo, 2, 6);
-    inst |= build_operand(c_nzuimm6hi, 12, 12);
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
 
     return Instruction{
         instruction: inst as u64,
-        length: 2,
-        mask: 0x8401,
-        mmatch: 0xec03,
-        extension: String::from("rv64_c"),
-        mnemonic: String::from("c_srai"),
-        operands: vec![(7,9), (2,6), (12,12), ],
+        length: 4,
+        mask: 0x28001033,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_zbs"),
+        mnemonic: String::from("bset"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
     };
 }
-pub fn c_srai_rv32(rd_rs1_p: u32,c_nzuimm5: u32) -> Instruction
+pub fn bseti(rd: u32,rs1: u32,shamtd: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x8401;
+    let mask: u32 = 0x28001013;
     inst |= mask;
-    inst |= build_operand(rd_rs1_p, 7, 9);
-    inst |= build_operand(c_nzuimm5, 2, 6);
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(shamtd, 20, 25);
 
     return Instruction{
         instruction: inst as u64,
-        length: 2,
-        mask: 0x8401,
-        mmatch: 0xfc03,
-        extension: String::from("rv32_c"),
-        mnemonic: String::from("c_srai_rv32"),
-        operands: vec![(7,9), (2,6), ],
+        length: 4,
+        mask: 0x28001013,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv64_zbs"),
+        mnemonic: String::from("bseti"),
+        operands: vec![(7,11), (15,19), (20,25), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Shamt, ],
     };
 }
-pub fn c_srli(rd_rs1_p: u32,c_nzuimm6lo: u32,c_nzuimm6hi: u32) -> Instruction
+pub fn bseti_rv32(rd: u32,rs1: u32,shamtw: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x8001;
+    let mask: u32 = 0x28001013;
     inst |= mask;
-    inst |= build_operand(rd_rs1_p, 7, 9);
-    inst |= build_operand(c_nzuimm6lo, 2, 6);
-    inst |= build_operand(c_nzuimm6hi, 12, 12);
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(shamtw, 20, 24);
 
     return Instruction{
         instruction: inst as u64,
-        length: 2,
-        mask: 0x8001,
-        mmatch: 0xec03,
-        extension: String::from("rv64_c"),
-        mnemonic: String::from("c_srli"),
-        operands: vec![(7,9), (2,6), (12,12), ],
+        length: 4,
+        mask: 0x28001013,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv32_zbs"),
+        mnemonic: String::from("bseti_rv32"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Shamt, ],
     };
 }
-pub fn c_srli_rv32(rd_rs1_p: u32,c_nzuimm5: u32) -> Instruction
+pub fn c_add(rd_rs1_n0: u32,c_rs2_n0: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x8001;
+    let mask: u32 = 0x9002;
     inst |= mask;
-    inst |= build_operand(rd_rs1_p, 7, 9);
-    inst |= build_operand(c_nzuimm5, 2, 6);
+    inst |= build_operand(rd_rs1_n0, 7, 11);
+    inst |= build_operand(c_rs2_n0, 2, 6);
 
     return Instruction{
         instruction: inst as u64,
         length: 2,
-        mask: 0x8001,
-        mmatch: 0xfc03,
-        extension: String::from("rv32_c"),
-        mnemonic: String::from("c_srli_rv32"),
-        operands: vec![(7,9), (2,6), ],
+        mask: 0x9002,
+        mmatch: 0xf003,
+        extension: String::from("rv_c"),
+        mnemonic: String::from("c_add"),
+        operands: vec![(7,11), (2,6), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
     };
 }
-pub fn c_sub(rd_rs1_p: u32,rs2_p: u32) -> Instruction
+pub fn c_addi(rd_rs1_n0: u32,c_nzimm6lo: u32,c_nzimm6hi: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x8c01;
+    let mask: u32 = 0x1;
     inst |= mask;
-    inst |= build_operand(rd_rs1_p, 7, 9);
-    inst |= build_operand(rs2_p, 2, 4);
+    inst |= build_operand(rd_rs1_n0, 7, 11);
+    inst |= build_operand(c_nzimm6lo, 2, 6);
+    inst |= build_operand(c_nzimm6hi, 12, 12);
 
     return Instruction{
         instruction: inst as u64,
         length: 2,
-        mask: 0x8c01,
-        mmatch: 0xfc63,
+        mask: 0x1,
+        mmatch: 0xe003,
         extension: String::from("rv_c"),
-        mnemonic: String::from("c_sub"),
-        operands: vec![(7,9), (2,4), ],
+        mnemonic: String::from("c_addi"),
+        operands: vec![(7,11), (2,6), (12,12), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SignedImm, OperandKind::SignedImm, ],
     };
 }
-pub fn c_subw(rd_rs1_p: u32,rs2_p: u32) -> Instruction
+pub fn c_addi16sp(c_nzimm10hi: u32,c_nzimm10lo: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x9c01;
+    let mask: u32 = 0x6101;
     inst |= mask;
-    inst |= build_operand(rd_rs1_p, 7, 9);
-    inst |= build_operand(rs2_p, 2, 4);
+    inst |= build_operand(c_nzimm10hi, 12, 12);
+    inst |= build_operand(c_nzimm10lo, 2, 6);
 
     return Instruction{
         instruction: inst as u64,
         length: 2,
-        mask: 0x9c01,
-        mmatch: 0xfc63,
-        extension: String::from("rv64_c"),
-        mnemonic: String::from("c_subw"),
-        operands: vec![(7,9), (2,4), ],
+        mask: 0x6101,
+        mmatch: 0xef83,
+        extension: String::from("rv_c"),
+        mnemonic: String::from("c_addi16sp"),
+        operands: vec![(12,12), (2,6), ],
+        operand_kinds: vec![OperandKind::SignedImm, OperandKind::SignedImm, ],
     };
 }
-pub fn c_sw(rs1_p: u32,rs2_p: u32,c_uimm7lo: u32,c_uimm7hi: u32) -> Instruction
+pub fn c_addi4spn(rd_p: u32,c_nzuimm10: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0xc000;
+    let mask: u32 = 0x0;
     inst |= mask;
-    inst |= build_operand(rs1_p, 7, 9);
-    inst |= build_operand(rs2_p, 2, 4);
-    inst |= build_operand(c_uimm7lo, 5, 6);
-    inst |= build_operand(c_uimm7hi, 10, 12);
+    inst |= build_operand(rd_p, 2, 4);
+    inst |= build_operand(c_nzuimm10, 5, 12);
 
     return Instruction{
         instruction: inst as u64,
         length: 2,
-        mask: 0xc000,
+        mask: 0x0,
         mmatch: 0xe003,
         extension: String::from("rv_c"),
-        mnemonic: String::from("c_sw"),
-        operands: vec![(7,9), (2,4), (5,6), (10,12), ],
+        mnemonic: String::from("c_addi4spn"),
+        operands: vec![(2,4), (5,12), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::UnsignedImm, ],
     };
 }
-pub fn c_swsp(c_rs2: u32,c_uimm8sp_s: u32) -> Instruction
+pub fn c_addiw(rd_rs1_n0: u32,c_imm6lo: u32,c_imm6hi: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0xc002;
+    let mask: u32 = 0x2001;
     inst |= mask;
-    inst |= build_operand(c_rs2, 2, 6);
-    inst |= build_operand(c_uimm8sp_s, 7, 12);
+    inst |= build_operand(rd_rs1_n0, 7, 11);
+    inst |= build_operand(c_imm6lo, 2, 6);
+    inst |= build_operand(c_imm6hi, 12, 12);
 
     return Instruction{
         instruction: inst as u64,
         length: 2,
-        mask: 0xc002,
+        mask: 0x2001,
         mmatch: 0xe003,
-        extension: String::from("rv_c"),
-        mnemonic: String::from("c_swsp"),
-        operands: vec![(2,6), (7,12), ],
+        extension: String::from("rv64_c"),
+        mnemonic: String::from("c_addiw"),
+        operands: vec![(7,11), (2,6), (12,12), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SignedImm, OperandKind::SignedImm, ],
     };
 }
-pub fn c_xor(rd_rs1_p: u32,rs2_p: u32) -> Instruction
+pub fn c_addw(rd_rs1_p: u32,rs2_p: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x8c21;
+    let mask: u32 = 0x9c21;
     inst |= mask;
     inst |= build_operand(rd_rs1_p, 7, 9);
     inst |= build_operand(rs2_p, 2, 4);
@@ -974,821 +1132,853 @@ pub fn c_xor(rd_rs1_p: u32,rs2_p: u32) -> Instruction
     return Instruction{
         instruction: inst as u64,
         length: 2,
-        mask: 0x8c21,
+        mask: 0x9c21,
         mmatch: 0xfc63,
-        extension: String::from("rv_c"),
-        mnemonic: String::from("c_xor"),
+        extension: String::from("rv64_c"),
+        mnemonic: String::from("c_addw"),
         operands: vec![(7,9), (2,4), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
     };
 }
-pub fn div(rd: u32,rs1: u32,rs2: u32) -> Instruction
+pub fn c_and(rd_rs1_p: u32,rs2_p: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x2004033;
+    let mask: u32 = 0x8c61;
     inst |= mask;
-    inst |= build_operand(rd, 7, 11);
-    inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(rd_rs1_p, 7, 9);
+    inst |= build_operand(rs2_p, 2, 4);
 
     return Instruction{
         instruction: inst as u64,
-        length: 4,
-        mask: 0x2004033,
-        mmatch: 0xfe00707f,
-        extension: String::from("rv_m"),
-        mnemonic: String::from("div"),
-        operands: vec![(7,11), (15,19), (20,24), ],
+        length: 2,
+        mask: 0x8c61,
+        mmatch: 0xfc63,
+        extension: String::from("rv_c"),
+        mnemonic: String::from("c_and"),
+        operands: vec![(7,9), (2,4), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
     };
 }
-pub fn divu(rd: u32,rs1: u32,rs2: u32) -> Instruction
+pub fn c_andi(rd_rs1_p: u32,c_imm6hi: u32,c_imm6lo: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x2005033;
+    let mask: u32 = 0x8801;
     inst |= mask;
-    inst |= build_operand(rd, 7, 11);
-    inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(rd_rs1_p, 7, 9);
+    inst |= build_operand(c_imm6hi, 12, 12);
+    inst |= build_operand(c_imm6lo, 2, 6);
 
     return Instruction{
         instruction: inst as u64,
-        length: 4,
-        mask: 0x2005033,
-        mmatch: 0xfe00707f,
-        extension: String::from("rv_m"),
-        mnemonic: String::from("divu"),
-        operands: vec![(7,11), (15,19), (20,24), ],
+        length: 2,
+        mask: 0x8801,
+        mmatch: 0xec03,
+        extension: String::from("rv_c"),
+        mnemonic: String::from("c_andi"),
+        operands: vec![(7,9), (12,12), (2,6), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SignedImm, OperandKind::SignedImm, ],
     };
 }
-pub fn divuw(rd: u32,rs1: u32,rs2: u32) -> Instruction
+pub fn c_beqz(rs1_p: u32,c_bimm9lo: u32,c_bimm9hi: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x200503b;
+    let mask: u32 = 0xc001;
     inst |= mask;
-    inst |= build_operand(rd, 7, 11);
-    inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(rs1_p, 7, 9);
+    inst |= build_operand(c_bimm9lo, 2, 6);
+    inst |= build_operand(c_bimm9hi, 10, 12);
 
     return Instruction{
         instruction: inst as u64,
-        length: 4,
-        mask: 0x200503b,
-        mmatch: 0xfe00707f,
-        extension: String::from("rv64_m"),
-        mnemonic: String::from("divuw"),
-        operands: vec![(7,11), (15,19), (20,24), ],
+        length: 2,
+        mask: 0xc001,
+        mmatch: 0xe003,
+        extension: String::from("rv_c"),
+        mnemonic: String::from("c_beqz"),
+        operands: vec![(7,9), (2,6), (10,12), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::SignedImm, ],
     };
 }
-pub fn divw(rd: u32,rs1: u32,rs2: u32) -> Instruction
+pub fn c_bnez(rs1_p: u32,c_bimm9lo: u32,c_bimm9hi: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x200403b;
+    let mask: u32 = 0xe001;
     inst |= mask;
-    inst |= build_operand(rd, 7, 11);
-    inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(rs1_p, 7, 9);
+    inst |= build_operand(c_bimm9lo, 2, 6);
+    inst |= build_operand(c_bimm9hi, 10, 12);
 
     return Instruction{
         instruction: inst as u64,
-        length: 4,
-        mask: 0x200403b,
-        mmatch: 0xfe00707f,
-        extension: String::from("rv64_m"),
-        mnemonic: String::from("divw"),
-        operands: vec![(7,11), (15,19), (20,24), ],
+        length: 2,
+        mask: 0xe001,
+        mmatch: 0xe003,
+        extension: String::from("rv_c"),
+        mnemonic: String::from("c_bnez"),
+        operands: vec![(7,9), (2,6), (10,12), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::SignedImm, ],
     };
 }
-pub fn ebreak() -> Instruction
+pub fn c_ebreak() -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x100073;
+    let mask: u32 = 0x9002;
     inst |= mask;
 
     return Instruction{
         instruction: inst as u64,
-        length: 4,
-        mask: 0x100073,
-        mmatch: 0xffffffff,
-        extension: String::from("rv_i"),
-        mnemonic: String::from("ebreak"),
+        length: 2,
+        mask: 0x9002,
+        mmatch: 0xffff,
+        extension: String::from("rv_c"),
+        mnemonic: String::from("c_ebreak"),
         operands: vec![],
+        operand_kinds: vec![],
     };
 }
-pub fn ecall() -> Instruction
+pub fn c_j(c_imm12: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x73;
+    let mask: u32 = 0xa001;
     inst |= mask;
+    inst |= build_operand(c_imm12, 2, 12);
 
     return Instruction{
         instruction: inst as u64,
-        length: 4,
-        mask: 0x73,
-        mmatch: 0xffffffff,
-        extension: String::from("rv_i"),
-        mnemonic: String::from("ecall"),
-        operands: vec![],
+        length: 2,
+        mask: 0xa001,
+        mmatch: 0xe003,
+        extension: String::from("rv_c"),
+        mnemonic: String::from("c_j"),
+        operands: vec![(2,12), ],
+        operand_kinds: vec![OperandKind::SignedImm, ],
     };
 }
-pub fn fence(fm: u32,pred: u32,succ: u32,rs1: u32,rd: u32) -> Instruction
+pub fn c_jal(c_imm12: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0xf;
+    let mask: u32 = 0x2001;
     inst |= mask;
-    inst |= build_operand(fm, 28, 31);
-    inst |= build_operand(pred, 24, 27);
-    inst |= build_operand(succ, 20, 23);
-    inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(c_imm12, 2, 12);
 
     return Instruction{
         instruction: inst as u64,
-        length: 4,
-        mask: 0xf,
-        mmatch: 0x707f,
-        extension: String::from("rv_i"),
-        mnemonic: String::from("fence"),
-        operands: vec![(28,31), (24,27), (20,23), (15,19), (7,11), ],
+        length: 2,
+        mask: 0x2001,
+        mmatch: 0xe003,
+        extension: String::from("rv32_c"),
+        mnemonic: String::from("c_jal"),
+        operands: vec![(2,12), ],
+        operand_kinds: vec![OperandKind::SignedImm, ],
     };
 }
-pub fn fence_tso(rs1: u32,rd: u32) -> Instruction
+pub fn c_jalr(c_rs1_n0: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x8330000f;
+    let mask: u32 = 0x9002;
     inst |= mask;
-    inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(c_rs1_n0, 7, 11);
 
     return Instruction{
         instruction: inst as u64,
-        length: 4,
-        mask: 0x8330000f,
-        mmatch: 0xfff0707f,
-        extension: String::from("rv_i"),
-        mnemonic: String::from("fence_tso"),
-        operands: vec![(15,19), (7,11), ],
+        length: 2,
+        mask: 0x9002,
+        mmatch: 0xf07f,
+        extension: String::from("rv_c"),
+        mnemonic: String::from("c_jalr"),
+        operands: vec![(7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, ],
     };
 }
-pub fn jal(rd: u32,jimm20: u32) -> Instruction
+pub fn c_jr(rs1_n0: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x6f;
+    let mask: u32 = 0x8002;
     inst |= mask;
-    inst |= build_operand(rd, 7, 11);
-    inst |= build_operand(jimm20, 12, 31);
+    inst |= build_operand(rs1_n0, 7, 11);
 
     return Instruction{
         instruction: inst as u64,
-        length: 4,
-        mask: 0x6f,
-        mmatch: 0x7f,
-        extension: String::from("rv_i"),
-        mnemonic: String::from("jal"),
-        operands: vec![(7,11), (12,31), ],
+        length: 2,
+        mask: 0x8002,
+        mmatch: 0xf07f,
+        extension: String::from("rv_c"),
+        mnemonic: String::from("c_jr"),
+        operands: vec![(7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, ],
     };
 }
-pub fn jalr(rd: u32,rs1: u32,imm12: u32) -> Instruction
+pub fn c_ld(rd_p: u32,rs1_p: u32,c_uimm8lo: u32,c_uimm8hi: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x67;
+    let mask: u32 = 0x6000;
     inst |= mask;
-    inst |= build_operand(rd, 7, 11);
-    inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(imm12, 20, 31);
+    inst |= build_operand(rd_p, 2, 4);
+    inst |= build_operand(rs1_p, 7, 9);
+    inst |= build_operand(c_uimm8lo, 5, 6);
+    inst |= build_operand(c_uimm8hi, 10, 12);
 
     return Instruction{
         instruction: inst as u64,
-        length: 4,
-        mask: 0x67,
-        mmatch: 0x707f,
-        extension: String::from("rv_i"),
-        mnemonic: String::from("jalr"),
-        operands: vec![(7,11), (15,19), (20,31), ],
+        length: 2,
+        mask: 0x6000,
+        mmatch: 0xe003,
+        extension: String::from("rv64_c"),
+        mnemonic: String::from("c_ld"),
+        operands: vec![(2,4), (7,9), (5,6), (10,12), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::UnsignedImm, OperandKind::UnsignedImm, ],
     };
 }
-pub fn lb(rd: u32,rs1: u32,imm12: u32) -> Instruction
+pub fn c_ldsp(rd_n0: u32,c_uimm9sphi: u32,c_uimm9splo: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x3;
+    let mask: u32 = 0x6002;
     inst |= mask;
-    inst |= build_operand(rd, 7, 11);
-    inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(imm12, 20, 31);
+    inst |= build_operand(rd_n0, 7, 11);
+    inst |= build_operand(c_uimm9sphi, 12, 12);
+    inst |= build_operand(c_uimm9splo, 2, 6);
 
     return Instruction{
         instruction: inst as u64,
-        length: 4,
-        mask: 0x3,
-        mmatch: 0x707f,
-        extension: String::from("rv_i"),
-        mnemonic: String::from("lb"),
-        operands: vec![(7,11), (15,19), (20,31), ],
+        length: 2,
+        mask: 0x6002,
+        mmatch: 0xe003,
+        extension: String::from("rv64_c"),
+        mnemonic: String::from("c_ldsp"),
+        operands: vec![(7,11), (12,12), (2,6), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::UnsignedImm, OperandKind::UnsignedImm, ],
     };
 }
-pub fn lbu(rd: u32,rs1: u32,imm12: u32) -> Instruction
+pub fn c_li(rd_n0: u32,c_imm6lo: u32,c_imm6hi: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x4003;
+    let mask: u32 = 0x4001;
     inst |= mask;
-    inst |= build_operand(rd, 7, 11);
-    inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(imm12, 20, 31);
+    inst |= build_operand(rd_n0, 7, 11);
+    inst |= build_operand(c_imm6lo, 2, 6);
+    inst |= build_operand(c_imm6hi, 12, 12);
 
     return Instruction{
         instruction: inst as u64,
-        length: 4,
-        mask: 0x4003,
-        mmatch: 0x707f,
-        extension: String::from("rv_i"),
-        mnemonic: String::from("lbu"),
-        operands: vec![(7,11), (15,19), (20,31), ],
+        length: 2,
+        mask: 0x4001,
+        mmatch: 0xe003,
+        extension: String::from("rv_c"),
+        mnemonic: String::from("c_li"),
+        operands: vec![(7,11), (2,6), (12,12), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SignedImm, OperandKind::SignedImm, ],
     };
 }
-pub fn ld(rd: u32,rs1: u32,imm12: u32) -> Instruction
+pub fn c_lui(rd_n2: u32,c_nzimm18hi: u32,c_nzimm18lo: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x3003;
+    let mask: u32 = 0x6001;
     inst |= mask;
-    inst |= build_operand(rd, 7, 11);
-    inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(imm12, 20, 31);
+    inst |= build_operand(rd_n2, 7, 11);
+    inst |= build_operand(c_nzimm18hi, 12, 12);
+    inst |= build_operand(c_nzimm18lo, 2, 6);
 
     return Instruction{
         instruction: inst as u64,
-        length: 4,
-        mask: 0x3003,
-        mmatch: 0x707f,
-        extension: String::from("rv64_i"),
-        mnemonic: String::from("ld"),
-        operands: vec![(7,11), (15,19), (20,31), ],
+        length: 2,
+        mask: 0x6001,
+        mmatch: 0xe003,
+        extension: String::from("rv_c"),
+        mnemonic: String::from("c_lui"),
+        operands: vec![(7,11), (12,12), (2,6), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SignedImm, OperandKind::SignedImm, ],
     };
 }
-pub fn lh(rd: u32,rs1: u32,imm12: u32) -> Instruction
+pub fn c_lw(rd_p: u32,rs1_p: u32,c_uimm7lo: u32,c_uimm7hi: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x1003;
+    let mask: u32 = 0x4000;
     inst |= mask;
-    inst |= build_operand(rd, 7, 11);
-    inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(imm12, 20, 31);
+    inst |= build_operand(rd_p, 2, 4);
+    inst |= build_operand(rs1_p, 7, 9);
+    inst |= build_operand(c_uimm7lo, 5, 6);
+    inst |= build_operand(c_uimm7hi, 10, 12);
 
     return Instruction{
         instruction: inst as u64,
-        length: 4,
-        mask: 0x1003,
-        mmatch: 0x707f,
-        extension: String::from("rv_i"),
-        mnemonic: String::from("lh"),
-        operands: vec![(7,11), (15,19), (20,31), ],
-    };
-}
-pub fn lhu(rd: u32,rs1: u32,imm12: u32) -> Instruction
+        length: 2,
+        mask: 0x4000,
+        mmatch: 0xe003,
+        extension: String::from("rv_c"),
+        mnemonic: String::from("c_lw"),
+        operands: vec![(2,4), (7,9), (5,6), (10,12), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::UnsignedImm, OperandKind::UnsignedImm, ],
+    };
+}
+pub fn c_lwsp(rd_n0: u32,c_uimm8sphi: u32,c_uimm8splo: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x5003;
+    let mask: u32 = 0x4002;
     inst |= mask;
-    inst |= build_operand(rd, 7, 11);
-    inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(imm12, 20, 31);
+    inst |= build_operand(rd_n0, 7, 11);
+    inst |= build_operand(c_uimm8sphi, 12, 12);
+    inst |= build_operand(c_uimm8splo, 2, 6);
 
     return Instruction{
         instruction: inst as u64,
-        length: 4,
-        mask: 0x5003,
-        mmatch: 0x707f,
-        extension: String::from("rv_i"),
-        mnemonic: String::from("lhu"),
-        operands: vec![(7,11), (15,19), (20,31), ],
+        length: 2,
+        mask: 0x4002,
+        mmatch: 0xe003,
+        extension: String::from("rv_c"),
+        mnemonic: String::from("c_lwsp"),
+        operands: vec![(7,11), (12,12), (2,6), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::UnsignedImm, OperandKind::UnsignedImm, ],
     };
 }
-pub fn lui(rd: u32,imm20: u32) -> Instruction
+pub fn c_mv(rd_n0: u32,c_rs2_n0: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x37;
+    let mask: u32 = 0x8002;
     inst |= mask;
-    inst |= build_operand(rd, 7, 11);
-    inst |= build_operand(imm20, 12, 31);
+    inst |= build_operand(rd_n0, 7, 11);
+    inst |= build_operand(c_rs2_n0, 2, 6);
 
     return Instruction{
         instruction: inst as u64,
-        length: 4,
-        mask: 0x37,
-        mmatch: 0x7f,
-        extension: String::from("rv_i"),
-        mnemonic: String::from("lui"),
-        operands: vec![(7,11), (12,31), ],
+        length: 2,
+        mask: 0x8002,
+        mmatch: 0xf003,
+        extension: String::from("rv_c"),
+        mnemonic: String::from("c_mv"),
+        operands: vec![(7,11), (2,6), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
     };
 }
-pub fn lw(rd: u32,rs1: u32,imm12: u32) -> Instruction
+pub fn c_nop(c_nzimm6hi: u32,c_nzimm6lo: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x2003;
+    let mask: u32 = 0x1;
     inst |= mask;
-    inst |= build_operand(rd, 7, 11);
-    inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(imm12, 20, 31);
+    inst |= build_operand(c_nzimm6hi, 12, 12);
+    inst |= build_operand(c_nzimm6lo, 2, 6);
 
     return Instruction{
         instruction: inst as u64,
-        length: 4,
-        mask: 0x2003,
-        mmatch: 0x707f,
-        extension: String::from("rv_i"),
-        mnemonic: String::from("lw"),
-        operands: vec![(7,11), (15,19), (20,31), ],
+        length: 2,
+        mask: 0x1,
+        mmatch: 0xef83,
+        extension: String::from("rv_c"),
+        mnemonic: String::from("c_nop"),
+        operands: vec![(12,12), (2,6), ],
+        operand_kinds: vec![OperandKind::SignedImm, OperandKind::SignedImm, ],
     };
 }
-pub fn lwu(rd: u32,rs1: u32,imm12: u32) -> Instruction
+pub fn c_or(rd_rs1_p: u32,rs2_p: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x6003;
+    let mask: u32 = 0x8c41;
     inst |= mask;
-    inst |= build_operand(rd, 7, 11);
-    inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(imm12, 20, 31);
+    inst |= build_operand(rd_rs1_p, 7, 9);
+    inst |= build_operand(rs2_p, 2, 4);
 
     return Instruction{
         instruction: inst as u64,
-        length: 4,
-        mask: 0x6003,
-        mmatch: 0x707f,
-        extension: String::from("rv64_i"),
-        mnemonic: String::from("lwu"),
-        operands: vec![(7,11), (15,19), (20,31), ],
+        length: 2,
+        mask: 0x8c41,
+        mmatch: 0xfc63,
+        extension: String::from("rv_c"),
+        mnemonic: String::from("c_or"),
+        operands: vec![(7,9), (2,4), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
     };
 }
-pub fn mul(rd: u32,rs1: u32,rs2: u32) -> Instruction
+pub fn c_sd(rs1_p: u32,rs2_p: u32,c_uimm8hi: u32,c_uimm8lo: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x2000033;
+    let mask: u32 = 0xe000;
     inst |= mask;
-    inst |= build_operand(rd, 7, 11);
-    inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(rs1_p, 7, 9);
+    inst |= build_operand(rs2_p, 2, 4);
+    inst |= build_operand(c_uimm8hi, 10, 12);
+    inst |= build_operand(c_uimm8lo, 5, 6);
 
     return Instruction{
         instruction: inst as u64,
-        length: 4,
-        mask: 0x2000033,
-        mmatch: 0xfe00707f,
-        extension: String::from("rv_m"),
-        mnemonic: String::from("mul"),
-        operands: vec![(7,11), (15,19), (20,24), ],
+        length: 2,
+        mask: 0xe000,
+        mmatch: 0xe003,
+        extension: String::from("rv64_c"),
+        mnemonic: String::from("c_sd"),
+        operands: vec![(7,9), (2,4), (10,12), (5,6), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::UnsignedImm, OperandKind::UnsignedImm, ],
     };
 }
-pub fn mulh(rd: u32,rs1: u32,rs2: u32) -> Instruction
+pub fn c_sdsp(c_rs2: u32,c_uimm9sp_s: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x2001033;
+    let mask: u32 = 0xe002;
     inst |= mask;
-    inst |= build_operand(rd, 7, 11);
-    inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(c_rs2, 2, 6);
+    inst |= build_operand(c_uimm9sp_s, 7, 12);
 
     return Instruction{
         instruction: inst as u64,
-        length: 4,
-        mask: 0x2001033,
-        mmatch: 0xfe00707f,
-        extension: String::from("rv_m"),
-        mnemonic: String::from("mulh"),
-        operands: vec![(7,11), (15,19), (20,24), ],
+        length: 2,
+        mask: 0xe002,
+        mmatch: 0xe003,
+        extension: String::from("rv64_c"),
+        mnemonic: String::from("c_sdsp"),
+        operands: vec![(2,6), (7,12), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::UnsignedImm, ],
     };
 }
-pub fn mulhsu(rd: u32,rs1: u32,rs2: u32) -> Instruction
+pub fn c_slli(rd_rs1_n0: u32,c_nzuimm6hi: u32,c_nzuimm6lo: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x2002033;
+    let mask: u32 = 0x2;
     inst |= mask;
-    inst |= build_operand(rd, 7, 11);
-    inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(rd_rs1_n0, 7, 11);
+    inst |= build_operand(c_nzuimm6hi, 12, 12);
+    inst |= build_operand(c_nzuimm6lo, 2, 6);
 
     return Instruction{
         instruction: inst as u64,
-        length: 4,
-        mask: 0x2002033,
-        mmatch: 0xfe00707f,
-        extension: String::from("rv_m"),
-        mnemonic: String::from("mulhsu"),
-        operands: vec![(7,11), (15,19), (20,24), ],
+        length: 2,
+        mask: 0x2,
+        mmatch: 0xe003,
+        extension: String::from("rv64_c"),
+        mnemonic: String::from("c_slli"),
+        operands: vec![(7,11), (12,12), (2,6), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::Shamt, OperandKind::Shamt, ],
     };
 }
-pub fn mulhu(rd: u32,rs1: u32,rs2: u32) -> Instruction
+pub fn c_slli_rv32(rd_rs1_n0: u32,c_nzuimm6lo: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x2003033;
+    let mask: u32 = 0x2;
     inst |= mask;
-    inst |= build_operand(rd, 7, 11);
-    inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(rd_rs1_n0, 7, 11);
+    inst |= build_operand(c_nzuimm6lo, 2, 6);
 
     return Instruction{
         instruction: inst as u64,
-        length: 4,
-        mask: 0x2003033,
-        mmatch: 0xfe00707f,
-        extension: String::from("rv_m"),
-        mnemonic: String::from("mulhu"),
-        operands: vec![(7,11), (15,19), (20,24), ],
+        length: 2,
+        mask: 0x2,
+        mmatch: 0xf003,
+        extension: String::from("rv32_c"),
+        mnemonic: String::from("c_slli_rv32"),
+        operands: vec![(7,11), (2,6), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::Shamt, ],
     };
 }
-pub fn mulw(rd: u32,rs1: u32,rs2: u32) -> Instruction
+pub fn c_srai(rd_rs1_p: u32,c_nzuimm6lo: u32,c_nzuimm6hi: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x200003b;
+    let mask: u32 = 0x8401;
     inst |= mask;
-    inst |= build_operand(rd, 7, 11);
-    inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(rd_rs1_p, 7, 9);
+    inst |= build_operand(c_nzuimm6lo, 2, 6);
+    inst |= build_operand(c_nzuimm6hi, 12, 12);
 
     return Instruction{
         instruction: inst as u64,
-        length: 4,
-        mask: 0x200003b,
-        mmatch: 0xfe00707f,
-        extension: String::from("rv64_m"),
-        mnemonic: String::from("mulw"),
-        operands: vec![(7,11), (15,19), (20,24), ],
+        length: 2,
+        mask: 0x8401,
+        mmatch: 0xec03,
+        extension: String::from("rv64_c"),
+        mnemonic: String::from("c_srai"),
+        operands: vec![(7,9), (2,6), (12,12), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::Shamt, OperandKind::Shamt, ],
     };
 }
-pub fn or(rd: u32,rs1: u32,rs2: u32) -> Instruction
+pub fn c_srai_rv32(rd_rs1_p: u32,c_nzuimm5: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x6033;
+    let mask: u32 = 0x8401;
     inst |= mask;
-    inst |= build_operand(rd, 7, 11);
-    inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(rd_rs1_p, 7, 9);
+    inst |= build_operand(c_nzuimm5, 2, 6);
 
     return Instruction{
         instruction: inst as u64,
-        length: 4,
-        mask: 0x6033,
-        mmatch: 0xfe00707f,
-        extension: String::from("rv_i"),
-        mnemonic: String::from("or"),
-        operands: vec![(7,11), (15,19), (20,24), ],
+        length: 2,
+        mask: 0x8401,
+        mmatch: 0xfc03,
+        extension: String::from("rv32_c"),
+        mnemonic: String::from("c_srai_rv32"),
+        operands: vec![(7,9), (2,6), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::Shamt, ],
     };
 }
-pub fn ori(rd: u32,rs1: u32,imm12: u32) -> Instruction
+pub fn c_srli(rd_rs1_p: u32,c_nzuimm6lo: u32,c_nzuimm6hi: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x6013;
+    let mask: u32 = 0x8001;
     inst |= mask;
-    inst |= build_operand(rd, 7, 11);
-    inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(imm12, 20, 31);
+    inst |= build_operand(rd_rs1_p, 7, 9);
+    inst |= build_operand(c_nzuimm6lo, 2, 6);
+    inst |= build_operand(c_nzuimm6hi, 12, 12);
 
     return Instruction{
         instruction: inst as u64,
-        length: 4,
-        mask: 0x6013,
-        mmatch: 0x707f,
-        extension: String::from("rv_i"),
-        mnemonic: String::from("ori"),
-        operands: vec![(7,11), (15,19), (20,31), ],
+        length: 2,
+        mask: 0x8001,
+        mmatch: 0xec03,
+        extension: String::from("rv64_c"),
+        mnemonic: String::from("c_srli"),
+        operands: vec![(7,9), (2,6), (12,12), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::Shamt, OperandKind::Shamt, ],
     };
 }
-pub fn pause() -> Instruction
+pub fn c_srli_rv32(rd_rs1_p: u32,c_nzuimm5: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x100000f;
+    let mask: u32 = 0x8001;
     inst |= mask;
+    inst |= build_operand(rd_rs1_p, 7, 9);
+    inst |= build_operand(c_nzuimm5, 2, 6);
 
     return Instruction{
         instruction: inst as u64,
-        length: 4,
-        mask: 0x100000f,
-        mmatch: 0xffffffff,
-        extension: String::from("rv_i"),
-        mnemonic: String::from("pause"),
-        operands: vec![],
-    };
+        length: 2,
+        mask: 0x8001,
+        mmatch: 0xfc03,
+        extension: String::from("rv32_c"),
+        mnemonic: String::from("c_srli_rv32"),
+        operands: vec![(7,9), (2,6), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::Shamt, ],
+    };
 }
-pub fn rem(rd: u32,rs1: u32,rs2: u32) -> Instruction
+pub fn c_sub(rd_rs1_p: u32,rs2_p: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x2006033;
+    let mask: u32 = 0x8c01;
     inst |= mask;
-    inst |= build_operand(rd, 7, 11);
-    inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(rd_rs1_p, 7, 9);
+    inst |= build_operand(rs2_p, 2, 4);
 
     return Instruction{
         instruction: inst as u64,
-        length: 4,
-        mask: 0x2006033,
-        mmatch: 0xfe00707f,
-        extension: String::from("rv_m"),
-        mnemonic: String::from("rem"),
-        operands: vec![(7,11), (15,19), (20,24), ],
+        length: 2,
+        mask: 0x8c01,
+        mmatch: 0xfc63,
+        extension: String::from("rv_c"),
+        mnemonic: String::from("c_sub"),
+        operands: vec![(7,9), (2,4), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
     };
 }
-pub fn remu(rd: u32,rs1: u32,rs2: u32) -> Instruction
+pub fn c_subw(rd_rs1_p: u32,rs2_p: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x2007033;
+    let mask: u32 = 0x9c01;
     inst |= mask;
-    inst |= build_operand(rd, 7, 11);
-    inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(rd_rs1_p, 7, 9);
+    inst |= build_operand(rs2_p, 2, 4);
 
     return Instruction{
         instruction: inst as u64,
-        length: 4,
-        mask: 0x2007033,
-        mmatch: 0xfe00707f,
-        extension: String::from("rv_m"),
-        mnemonic: String::from("remu"),
-        operands: vec![(7,11), (15,19), (20,24), ],
+        length: 2,
+        mask: 0x9c01,
+        mmatch: 0xfc63,
+        extension: String::from("rv64_c"),
+        mnemonic: String::from("c_subw"),
+        operands: vec![(7,9), (2,4), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
     };
 }
-pub fn remuw(rd: u32,rs1: u32,rs2: u32) -> Instruction
+pub fn c_sw(rs1_p: u32,rs2_p: u32,c_uimm7lo: u32,c_uimm7hi: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x200703b;
+    let mask: u32 = 0xc000;
     inst |= mask;
-    inst |= build_operand(rd, 7, 11);
-    inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(rs1_p, 7, 9);
+    inst |= build_operand(rs2_p, 2, 4);
+    inst |= build_operand(c_uimm7lo, 5, 6);
+    inst |= build_operand(c_uimm7hi, 10, 12);
 
     return Instruction{
         instruction: inst as u64,
-        length: 4,
-        mask: 0x200703b,
-        mmatch: 0xfe00707f,
-        extension: String::from("rv64_m"),
-        mnemonic: String::from("remuw"),
-        operands: vec![(7,11), (15,19), (20,24), ],
+        length: 2,
+        mask: 0xc000,
+        mmatch: 0xe003,
+        extension: String::from("rv_c"),
+        mnemonic: String::from("c_sw"),
+        operands: vec![(7,9), (2,4), (5,6), (10,12), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::UnsignedImm, OperandKind::UnsignedImm, ],
     };
 }
-pub fn remw(rd: u32,rs1: u32,rs2: u32) -> Instruction
+pub fn c_swsp(c_rs2: u32,c_uimm8sp_s: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x200603b;
+    let mask: u32 = 0xc002;
     inst |= mask;
-    inst |= build_operand(rd, 7, 11);
-    inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(c_rs2, 2, 6);
+    inst |= build_operand(c_uimm8sp_s, 7, 12);
 
     return Instruction{
         instruction: inst as u64,
-        length: 4,
-        mask: 0x200603b,
-        mmatch: 0xfe00707f,
-        extension: String::from("rv64_m"),
-        mnemonic: String::from("remw"),
-        operands: vec![(7,11), (15,19), (20,24), ],
+        length: 2,
+        mask: 0xc002,
+        mmatch: 0xe003,
+        extension: String::from("rv_c"),
+        mnemonic: String::from("c_swsp"),
+        operands: vec![(2,6), (7,12), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::UnsignedImm, ],
     };
 }
-pub fn sb(imm12hi: u32,rs1: u32,rs2: u32,imm12lo: u32) -> Instruction
+pub fn c_xor(rd_rs1_p: u32,rs2_p: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x23;
+    let mask: u32 = 0x8c21;
     inst |= mask;
-    inst |= build_operand(imm12hi, 25, 31);
-    inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(rs2, 20, 24);
-    inst |= build_operand(imm12lo, 7, 11);
+    inst |= build_operand(rd_rs1_p, 7, 9);
+    inst |= build_operand(rs2_p, 2, 4);
 
     return Instruction{
         instruction: inst as u64,
-        length: 4,
-        mask: 0x23,
-        mmatch: 0x707f,
-        extension: String::from("rv_i"),
-        mnemonic: String::from("sb"),
-        operands: vec![(25,31), (15,19), (20,24), (7,11), ],
+        length: 2,
+        mask: 0x8c21,
+        mmatch: 0xfc63,
+        extension: String::from("rv_c"),
+        mnemonic: String::from("c_xor"),
+        operands: vec![(7,9), (2,4), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
     };
 }
-pub fn sbreak() -> Instruction
+pub fn clz(rd: u32,rs1: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x100073;
+    let mask: u32 = 0x60001013;
     inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
 
     return Instruction{
         instruction: inst as u64,
         length: 4,
-        mask: 0x100073,
-        mmatch: 0xffffffff,
-        extension: String::from("rv_i"),
-        mnemonic: String::from("sbreak"),
-        operands: vec![],
+        mask: 0x60001013,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv_zbb"),
+        mnemonic: String::from("clz"),
+        operands: vec![(7,11), (15,19), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
     };
 }
-pub fn scall() -> Instruction
+pub fn clzw(rd: u32,rs1: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x73;
+    let mask: u32 = 0x6000101b;
     inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
 
     return Instruction{
         instruction: inst as u64,
         length: 4,
-        mask: 0x73,
-        mmatch: 0xffffffff,
-        extension: String::from("rv_i"),
-        mnemonic: String::from("scall"),
-        operands: vec![],
+        mask: 0x6000101b,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv64_zbb"),
+        mnemonic: String::from("clzw"),
+        operands: vec![(7,11), (15,19), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
     };
 }
-pub fn sd(imm12hi: u32,rs1: u32,rs2: u32,imm12lo: u32) -> Instruction
+pub fn cpop(rd: u32,rs1: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x3023;
+    let mask: u32 = 0x60201013;
     inst |= mask;
-    inst |= build_operand(imm12hi, 25, 31);
+    inst |= build_operand(rd, 7, 11);
     inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(rs2, 20, 24);
-    inst |= build_operand(imm12lo, 7, 11);
 
     return Instruction{
         instruction: inst as u64,
         length: 4,
-        mask: 0x3023,
-        mmatch: 0x707f,
-        extension: String::from("rv64_i"),
-        mnemonic: String::from("sd"),
-        operands: vec![(25,31), (15,19), (20,24), (7,11), ],
+        mask: 0x60201013,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv_zbb"),
+        mnemonic: String::from("cpop"),
+        operands: vec![(7,11), (15,19), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
     };
 }
-pub fn sh(imm12hi: u32,rs1: u32,rs2: u32,imm12lo: u32) -> Instruction
+pub fn cpopw(rd: u32,rs1: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x1023;
+    let mask: u32 = 0x6020101b;
     inst |= mask;
-    inst |= build_operand(imm12hi, 25, 31);
+    inst |= build_operand(rd, 7, 11);
     inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(rs2, 20, 24);
-    inst |= build_operand(imm12lo, 7, 11);
 
     return Instruction{
         instruction: inst as u64,
         length: 4,
-        mask: 0x1023,
-        mmatch: 0x707f,
-        extension: String::from("rv_i"),
-        mnemonic: String::from("sh"),
-        operands: vec![(25,31), (15,19), (20,24), (7,11), ],
+        mask: 0x6020101b,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv64_zbb"),
+        mnemonic: String::from("cpopw"),
+        operands: vec![(7,11), (15,19), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
     };
 }
-pub fn sll(rd: u32,rs1: u32,rs2: u32) -> Instruction
+pub fn csrrc(rd: u32,rs1: u32,csr: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x1033;
+    let mask: u32 = 0x3073;
     inst |= mask;
     inst |= build_operand(rd, 7, 11);
     inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(csr, 20, 31);
 
     return Instruction{
         instruction: inst as u64,
         length: 4,
-        mask: 0x1033,
-        mmatch: 0xfe00707f,
-        extension: String::from("rv_i"),
-        mnemonic: String::from("sll"),
-        operands: vec![(7,11), (15,19), (20,24), ],
+        mask: 0x3073,
+        mmatch: 0x707f,
+        extension: String::from("rv_zicsr"),
+        mnemonic: String::from("csrrc"),
+        operands: vec![(7,11), (15,19), (20,31), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Csr, ],
     };
 }
-pub fn slli(rd: u32,rs1: u32,shamtd: u32) -> Instruction
+pub fn csrrci(rd: u32,csr: u32,zimm: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x1013;
+    let mask: u32 = 0x7073;
     inst |= mask;
     inst |= build_operand(rd, 7, 11);
-    inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(shamtd, 20, 25);
+    inst |= build_operand(csr, 20, 31);
+    inst |= build_operand(zimm, 15, 19);
 
     return Instruction{
         instruction: inst as u64,
         length: 4,
-        mask: 0x1013,
-        mmatch: 0xfc00707f,
-        extension: String::from("rv64_i"),
-        mnemonic: String::from("slli"),
-        operands: vec![(7,11), (15,19), (20,25), ],
+        mask: 0x7073,
+        mmatch: 0x707f,
+        extension: String::from("rv_zicsr"),
+        mnemonic: String::from("csrrci"),
+        operands: vec![(7,11), (20,31), (15,19), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::Csr, OperandKind::UnsignedImm, ],
     };
 }
-pub fn slli_rv32(rd: u32,rs1: u32,shamtw: u32) -> Instruction
+pub fn csrrs(rd: u32,rs1: u32,csr: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x1013;
+    let mask: u32 = 0x2073;
     inst |= mask;
     inst |= build_operand(rd, 7, 11);
     inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(shamtw, 20, 24);
+    inst |= build_operand(csr, 20, 31);
 
     return Instruction{
         instruction: inst as u64,
         length: 4,
-        mask: 0x1013,
-        mmatch: 0xfe00707f,
-        extension: String::from("rv32_i"),
-        mnemonic: String::from("slli_rv32"),
-        operands: vec![(7,11), (15,19), (20,24), ],
+        mask: 0x2073,
+        mmatch: 0x707f,
+        extension: String::from("rv_zicsr"),
+        mnemonic: String::from("csrrs"),
+        operands: vec![(7,11), (15,19), (20,31), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Csr, ],
     };
 }
-pub fn slliw(rd: u32,rs1: u32,shamtw: u32) -> Instruction
+pub fn csrrsi(rd: u32,csr: u32,zimm: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x101b;
+    let mask: u32 = 0x6073;
     inst |= mask;
     inst |= build_operand(rd, 7, 11);
-    inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(shamtw, 20, 24);
+    inst |= build_operand(csr, 20, 31);
+    inst |= build_operand(zimm, 15, 19);
 
     return Instruction{
         instruction: inst as u64,
         length: 4,
-        mask: 0x101b,
-        mmatch: 0xfe00707f,
-        extension: String::from("rv64_i"),
-        mnemonic: String::from("slliw"),
-        operands: vec![(7,11), (15,19), (20,24), ],
+        mask: 0x6073,
+        mmatch: 0x707f,
+        extension: String::from("rv_zicsr"),
+        mnemonic: String::from("csrrsi"),
+        operands: vec![(7,11), (20,31), (15,19), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::Csr, OperandKind::UnsignedImm, ],
     };
 }
-pub fn sllw(rd: u32,rs1: u32,rs2: u32) -> Instruction
+pub fn csrrw(rd: u32,rs1: u32,csr: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x103b;
+    let mask: u32 = 0x1073;
     inst |= mask;
     inst |= build_operand(rd, 7, 11);
     inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(csr, 20, 31);
 
     return Instruction{
         instruction: inst as u64,
         length: 4,
-        mask: 0x103b,
-        mmatch: 0xfe00707f,
-        extension: String::from("rv64_i"),
-        mnemonic: String::from("sllw"),
-        operands: vec![(7,11), (15,19), (20,24), ],
+        mask: 0x1073,
+        mmatch: 0x707f,
+        extension: String::from("rv_zicsr"),
+        mnemonic: String::from("csrrw"),
+        operands: vec![(7,11), (15,19), (20,31), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Csr, ],
     };
 }
-pub fn slt(rd: u32,rs1: u32,rs2: u32) -> Instruction
+pub fn csrrwi(rd: u32,csr: u32,zimm: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x2033;
+    let mask: u32 = 0x5073;
     inst |= mask;
     inst |= build_operand(rd, 7, 11);
-    inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(csr, 20, 31);
+    inst |= build_operand(zimm, 15, 19);
 
     return Instruction{
         instruction: inst as u64,
         length: 4,
-        mask: 0x2033,
-        mmatch: 0xfe00707f,
-        extension: String::from("rv_i"),
-        mnemonic: String::from("slt"),
-        operands: vec![(7,11), (15,19), (20,24), ],
+        mask: 0x5073,
+        mmatch: 0x707f,
+        extension: String::from("rv_zicsr"),
+        mnemonic: String::from("csrrwi"),
+        operands: vec![(7,11), (20,31), (15,19), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::Csr, OperandKind::UnsignedImm, ],
     };
 }
-pub fn slti(rd: u32,rs1: u32,imm12: u32) -> Instruction
+pub fn ctz(rd: u32,rs1: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x2013;
+    let mask: u32 = 0x60101013;
     inst |= mask;
     inst |= build_operand(rd, 7, 11);
     inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(imm12, 20, 31);
 
     return Instruction{
         instruction: inst as u64,
         length: 4,
-        mask: 0x2013,
-        mmatch: 0x707f,
-        extension: String::from("rv_i"),
-        mnemonic: String::from("slti"),
-        operands: vec![(7,11), (15,19), (20,31), ],
+        mask: 0x60101013,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv_zbb"),
+        mnemonic: String::from("ctz"),
+        operands: vec![(7,11), (15,19), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
     };
 }
-pub fn sltiu(rd: u32,rs1: u32,imm12: u32) -> Instruction
+pub fn ctzw(rd: u32,rs1: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x3013;
+    let mask: u32 = 0x6010101b;
     inst |= mask;
     inst |= build_operand(rd, 7, 11);
     inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(imm12, 20, 31);
 
     return Instruction{
         instruction: inst as u64,
         length: 4,
-        mask: 0x3013,
-        mmatch: 0x707f,
-        extension: String::from("rv_i"),
-        mnemonic: String::from("sltiu"),
-        operands: vec![(7,11), (15,19), (20,31), ],
+        mask: 0x6010101b,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv64_zbb"),
+        mnemonic: String::from("ctzw"),
+        operands: vec![(7,11), (15,19), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
     };
 }
-pub fn sltu(rd: u32,rs1: u32,rs2: u32) -> Instruction
+pub fn div(rd: u32,rs1: u32,rs2: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x3033;
+    let mask: u32 = 0x2004033;
     inst |= mask;
     inst |= build_operand(rd, 7, 11);
     inst |= build_operand(rs1, 15, 19);
@@ -1797,17 +1987,18 @@ pub fn sltu(rd: u32,rs1: u32,rs2: u32) -> Instruction
     return Instruction{
         instruction: inst as u64,
         length: 4,
-        mask: 0x3033,
+        mask: 0x2004033,
         mmatch: 0xfe00707f,
-        extension: String::from("rv_i"),
-        mnemonic: String::from("sltu"),
+        extension: String::from("rv_m"),
+        mnemonic: String::from("div"),
         operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
     };
 }
-pub fn sra(rd: u32,rs1: u32,rs2: u32) -> Instruction
+pub fn divu(rd: u32,rs1: u32,rs2: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x40005033;
+    let mask: u32 = 0x2005033;
     inst |= mask;
     inst |= build_operand(rd, 7, 11);
     inst |= build_operand(rs1, 15, 19);
@@ -1816,1269 +2007,17330 @@ pub fn sra(rd: u32,rs1: u32,rs2: u32) -> Instruction
     return Instruction{
         instruction: inst as u64,
         length: 4,
-        mask: 0x40005033,
+        mask: 0x2005033,
         mmatch: 0xfe00707f,
-        extension: String::from("rv_i"),
-        mnemonic: String::from("sra"),
-        operands: vec![(7,11), (15,19), (20,24), ],
+        extension: String::from("rv_m"),
+        mnemonic: String::from("divu"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
     };
 }
-pub fn srai(rd: u32,rs1: u32,shamtd: u32) -> Instruction
+pub fn divuw(rd: u32,rs1: u32,rs2: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x40005013;
+    let mask: u32 = 0x200503b;
     inst |= mask;
     inst |= build_operand(rd, 7, 11);
     inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(shamtd, 20, 25);
+    inst |= build_operand(rs2, 20, 24);
 
     return Instruction{
         instruction: inst as u64,
         length: 4,
-        mask: 0x40005013,
-        mmatch: 0xfc00707f,
-        extension: String::from("rv64_i"),
-        mnemonic: String::from("srai"),
-        operands: vec![(7,11), (15,19), (20,25), ],
+        mask: 0x200503b,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv64_m"),
+        mnemonic: String::from("divuw"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
     };
 }
-pub fn srai_rv32(rd: u32,rs1: u32,shamtw: u32) -> Instruction
+pub fn divw(rd: u32,rs1: u32,rs2: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x40005013;
+    let mask: u32 = 0x200403b;
     inst |= mask;
     inst |= build_operand(rd, 7, 11);
     inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(shamtw, 20, 24);
+    inst |= build_operand(rs2, 20, 24);
 
     return Instruction{
         instruction: inst as u64,
         length: 4,
-        mask: 0x40005013,
+        mask: 0x200403b,
         mmatch: 0xfe00707f,
-        extension: String::from("rv32_i"),
-        mnemonic: String::from("srai_rv32"),
+        extension: String::from("rv64_m"),
+        mnemonic: String::from("divw"),
         operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
     };
 }
-pub fn sraiw(rd: u32,rs1: u32,shamtw: u32) -> Instruction
+pub fn ebreak() -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x4000501b;
+    let mask: u32 = 0x100073;
+    inst |= mask;
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x100073,
+        mmatch: 0xffffffff,
+        extension: String::from("rv_i"),
+        mnemonic: String::from("ebreak"),
+        operands: vec![],
+        operand_kinds: vec![],
+    };
+}
+pub fn ecall() -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x73;
+    inst |= mask;
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x73,
+        mmatch: 0xffffffff,
+        extension: String::from("rv_i"),
+        mnemonic: String::from("ecall"),
+        operands: vec![],
+        operand_kinds: vec![],
+    };
+}
+pub fn fadd_d(rd: u32,rs1: u32,rs2: u32,rm: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x2000053;
     inst |= mask;
     inst |= build_operand(rd, 7, 11);
     inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(shamtw, 20, 24);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(rm, 12, 14);
 
     return Instruction{
         instruction: inst as u64,
         length: 4,
-        mask: 0x4000501b,
-        mmatch: 0xfe00707f,
-        extension: String::from("rv64_i"),
-        mnemonic: String::from("sraiw"),
-        operands: vec![(7,11), (15,19), (20,24), ],
+        mask: 0x2000053,
+        mmatch: 0xfe00007f,
+        extension: String::from("rv_d"),
+        mnemonic: String::from("fadd_d"),
+        operands: vec![(7,11), (15,19), (20,24), (12,14), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
     };
 }
-pub fn sraw(rd: u32,rs1: u32,rs2: u32) -> Instruction
+pub fn fadd_s(rd: u32,rs1: u32,rs2: u32,rm: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x4000503b;
+    let mask: u32 = 0x53;
     inst |= mask;
     inst |= build_operand(rd, 7, 11);
     inst |= build_operand(rs1, 15, 19);
     inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(rm, 12, 14);
 
     return Instruction{
         instruction: inst as u64,
         length: 4,
-        mask: 0x4000503b,
-        mmatch: 0xfe00707f,
-        extension: String::from("rv64_i"),
-        mnemonic: String::from("sraw"),
-        operands: vec![(7,11), (15,19), (20,24), ],
+        mask: 0x53,
+        mmatch: 0xfe00007f,
+        extension: String::from("rv_f"),
+        mnemonic: String::from("fadd_s"),
+        operands: vec![(7,11), (15,19), (20,24), (12,14), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
     };
 }
-pub fn srl(rd: u32,rs1: u32,rs2: u32) -> Instruction
+pub fn fclass_d(rd: u32,rs1: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x5033;
+    let mask: u32 = 0xe2001053;
     inst |= mask;
     inst |= build_operand(rd, 7, 11);
     inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(rs2, 20, 24);
 
     return Instruction{
         instruction: inst as u64,
         length: 4,
-        mask: 0x5033,
-        mmatch: 0xfe00707f,
-        extension: String::from("rv_i"),
-        mnemonic: String::from("srl"),
-        operands: vec![(7,11), (15,19), (20,24), ],
+        mask: 0xe2001053,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv_d"),
+        mnemonic: String::from("fclass_d"),
+        operands: vec![(7,11), (15,19), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
     };
 }
-pub fn srli(rd: u32,rs1: u32,shamtd: u32) -> Instruction
+pub fn fclass_s(rd: u32,rs1: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x5013;
+    let mask: u32 = 0xe0001053;
     inst |= mask;
     inst |= build_operand(rd, 7, 11);
     inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(shamtd, 20, 25);
 
     return Instruction{
         instruction: inst as u64,
         length: 4,
-        mask: 0x5013,
-        mmatch: 0xfc00707f,
-        extension: String::from("rv64_i"),
-        mnemonic: String::from("srli"),
-        operands: vec![(7,11), (15,19), (20,25), ],
+        mask: 0xe0001053,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv_f"),
+        mnemonic: String::from("fclass_s"),
+        operands: vec![(7,11), (15,19), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
     };
 }
-pub fn srli_rv32(rd: u32,rs1: u32,shamtw: u32) -> Instruction
+pub fn fcvt_d_l(rd: u32,rs1: u32,rm: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x5013;
+    let mask: u32 = 0xd2200053;
     inst |= mask;
     inst |= build_operand(rd, 7, 11);
     inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(shamtw, 20, 24);
+    inst |= build_operand(rm, 12, 14);
 
     return Instruction{
         instruction: inst as u64,
         length: 4,
-        mask: 0x5013,
-        mmatch: 0xfe00707f,
-        extension: String::from("rv32_i"),
-        mnemonic: String::from("srli_rv32"),
-        operands: vec![(7,11), (15,19), (20,24), ],
+        mask: 0xd2200053,
+        mmatch: 0xfff0007f,
+        extension: String::from("rv64_d"),
+        mnemonic: String::from("fcvt_d_l"),
+        operands: vec![(7,11), (15,19), (12,14), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
     };
 }
-pub fn srliw(rd: u32,rs1: u32,shamtw: u32) -> Instruction
+pub fn fcvt_d_lu(rd: u32,rs1: u32,rm: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x501b;
+    let mask: u32 = 0xd2300053;
     inst |= mask;
     inst |= build_operand(rd, 7, 11);
     inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(shamtw, 20, 24);
+    inst |= build_operand(rm, 12, 14);
 
     return Instruction{
         instruction: inst as u64,
         length: 4,
-        mask: 0x501b,
-        mmatch: 0xfe00707f,
-        extension: String::from("rv64_i"),
-        mnemonic: String::from("srliw"),
-        operands: vec![(7,11), (15,19), (20,24), ],
+        mask: 0xd2300053,
+        mmatch: 0xfff0007f,
+        extension: String::from("rv64_d"),
+        mnemonic: String::from("fcvt_d_lu"),
+        operands: vec![(7,11), (15,19), (12,14), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
     };
 }
-pub fn srlw(rd: u32,rs1: u32,rs2: u32) -> Instruction
+pub fn fcvt_d_s(rd: u32,rs1: u32,rm: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x503b;
+    let mask: u32 = 0x42000053;
     inst |= mask;
     inst |= build_operand(rd, 7, 11);
     inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(rm, 12, 14);
 
     return Instruction{
         instruction: inst as u64,
         length: 4,
-        mask: 0x503b,
-        mmatch: 0xfe00707f,
-        extension: String::from("rv64_i"),
-        mnemonic: String::from("srlw"),
-        operands: vec![(7,11), (15,19), (20,24), ],
+        mask: 0x42000053,
+        mmatch: 0xfff0007f,
+        extension: String::from("rv_d"),
+        mnemonic: String::from("fcvt_d_s"),
+        operands: vec![(7,11), (15,19), (12,14), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
     };
 }
-pub fn sub(rd: u32,rs1: u32,rs2: u32) -> Instruction
+pub fn fcvt_d_w(rd: u32,rs1: u32,rm: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x40000033;
+    let mask: u32 = 0xd2000053;
     inst |= mask;
     inst |= build_operand(rd, 7, 11);
     inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(rm, 12, 14);
 
     return Instruction{
         instruction: inst as u64,
         length: 4,
-        mask: 0x40000033,
-        mmatch: 0xfe00707f,
-        extension: String::from("rv_i"),
-        mnemonic: String::from("sub"),
-        operands: vec![(7,11), (15,19), (20,24), ],
+        mask: 0xd2000053,
+        mmatch: 0xfff0007f,
+        extension: String::from("rv_d"),
+        mnemonic: String::from("fcvt_d_w"),
+        operands: vec![(7,11), (15,19), (12,14), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
     };
 }
-pub fn subw(rd: u32,rs1: u32,rs2: u32) -> Instruction
+pub fn fcvt_d_wu(rd: u32,rs1: u32,rm: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x4000003b;
+    let mask: u32 = 0xd2100053;
     inst |= mask;
     inst |= build_operand(rd, 7, 11);
     inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(rm, 12, 14);
 
     return Instruction{
         instruction: inst as u64,
         length: 4,
-        mask: 0x4000003b,
-        mmatch: 0xfe00707f,
-        extension: String::from("rv64_i"),
-        mnemonic: String::from("subw"),
-        operands: vec![(7,11), (15,19), (20,24), ],
+        mask: 0xd2100053,
+        mmatch: 0xfff0007f,
+        extension: String::from("rv_d"),
+        mnemonic: String::from("fcvt_d_wu"),
+        operands: vec![(7,11), (15,19), (12,14), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
     };
 }
-pub fn sw(imm12hi: u32,rs1: u32,rs2: u32,imm12lo: u32) -> Instruction
+pub fn fcvt_l_d(rd: u32,rs1: u32,rm: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x2023;
+    let mask: u32 = 0xc2200053;
     inst |= mask;
-    inst |= build_operand(imm12hi, 25, 31);
+    inst |= build_operand(rd, 7, 11);
     inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(rs2, 20, 24);
-    inst |= build_operand(imm12lo, 7, 11);
+    inst |= build_operand(rm, 12, 14);
 
     return Instruction{
         instruction: inst as u64,
         length: 4,
-        mask: 0x2023,
-        mmatch: 0x707f,
-        extension: String::from("rv_i"),
-        mnemonic: String::from("sw"),
-        operands: vec![(25,31), (15,19), (20,24), (7,11), ],
+        mask: 0xc2200053,
+        mmatch: 0xfff0007f,
+        extension: String::from("rv64_d"),
+        mnemonic: String::from("fcvt_l_d"),
+        operands: vec![(7,11), (15,19), (12,14), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
     };
 }
-pub fn xor(rd: u32,rs1: u32,rs2: u32) -> Instruction
+pub fn fcvt_l_s(rd: u32,rs1: u32,rm: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x4033;
+    let mask: u32 = 0xc0200053;
     inst |= mask;
     inst |= build_operand(rd, 7, 11);
     inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(rm, 12, 14);
 
     return Instruction{
         instruction: inst as u64,
         length: 4,
-        mask: 0x4033,
-        mmatch: 0xfe00707f,
-        extension: String::from("rv_i"),
-        mnemonic: String::from("xor"),
-        operands: vec![(7,11), (15,19), (20,24), ],
+        mask: 0xc0200053,
+        mmatch: 0xfff0007f,
+        extension: String::from("rv64_f"),
+        mnemonic: String::from("fcvt_l_s"),
+        operands: vec![(7,11), (15,19), (12,14), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
     };
 }
-pub fn xori(rd: u32,rs1: u32,imm12: u32) -> Instruction
+pub fn fcvt_lu_d(rd: u32,rs1: u32,rm: u32) -> Instruction
 {
     let mut inst : u32 = 0;
-    let mask: u32 = 0x4013;
+    let mask: u32 = 0xc2300053;
     inst |= mask;
     inst |= build_operand(rd, 7, 11);
     inst |= build_operand(rs1, 15, 19);
-    inst |= build_operand(imm12, 20, 31);
+    inst |= build_operand(rm, 12, 14);
 
     return Instruction{
         instruction: inst as u64,
         length: 4,
-        mask: 0x4013,
-        mmatch: 0x707f,
-        extension: String::from("rv_i"),
-        mnemonic: String::from("xori"),
-        operands: vec![(7,11), (15,19), (20,31), ],
+        mask: 0xc2300053,
+        mmatch: 0xfff0007f,
+        extension: String::from("rv64_d"),
+        mnemonic: String::from("fcvt_lu_d"),
+        operands: vec![(7,11), (15,19), (12,14), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
     };
 }
+pub fn fcvt_lu_s(rd: u32,rs1: u32,rm: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xc0300053;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rm, 12, 14);
 
-use lazy_static::lazy_static;
-lazy_static! {
-// Global Vec to hold instructions
-pub static ref INSTRUCTIONS: Vec<Instruction> = {
-    vec![
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xc0300053,
+        mmatch: 0xfff0007f,
+        extension: String::from("rv64_f"),
+        mnemonic: String::from("fcvt_lu_s"),
+        operands: vec![(7,11), (15,19), (12,14), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+    };
+}
+pub fn fcvt_s_d(rd: u32,rs1: u32,rm: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x40100053;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rm, 12, 14);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x40100053,
+        mmatch: 0xfff0007f,
+        extension: String::from("rv_d"),
+        mnemonic: String::from("fcvt_s_d"),
+        operands: vec![(7,11), (15,19), (12,14), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+    };
+}
+pub fn fcvt_s_l(rd: u32,rs1: u32,rm: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xd0200053;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rm, 12, 14);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xd0200053,
+        mmatch: 0xfff0007f,
+        extension: String::from("rv64_f"),
+        mnemonic: String::from("fcvt_s_l"),
+        operands: vec![(7,11), (15,19), (12,14), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+    };
+}
+pub fn fcvt_s_lu(rd: u32,rs1: u32,rm: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xd0300053;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rm, 12, 14);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xd0300053,
+        mmatch: 0xfff0007f,
+        extension: String::from("rv64_f"),
+        mnemonic: String::from("fcvt_s_lu"),
+        operands: vec![(7,11), (15,19), (12,14), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+    };
+}
+pub fn fcvt_s_w(rd: u32,rs1: u32,rm: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xd0000053;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rm, 12, 14);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xd0000053,
+        mmatch: 0xfff0007f,
+        extension: String::from("rv_f"),
+        mnemonic: String::from("fcvt_s_w"),
+        operands: vec![(7,11), (15,19), (12,14), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+    };
+}
+pub fn fcvt_s_wu(rd: u32,rs1: u32,rm: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xd0100053;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rm, 12, 14);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xd0100053,
+        mmatch: 0xfff0007f,
+        extension: String::from("rv_f"),
+        mnemonic: String::from("fcvt_s_wu"),
+        operands: vec![(7,11), (15,19), (12,14), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+    };
+}
+pub fn fcvt_w_d(rd: u32,rs1: u32,rm: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xc2000053;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rm, 12, 14);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xc2000053,
+        mmatch: 0xfff0007f,
+        extension: String::from("rv_d"),
+        mnemonic: String::from("fcvt_w_d"),
+        operands: vec![(7,11), (15,19), (12,14), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+    };
+}
+pub fn fcvt_w_s(rd: u32,rs1: u32,rm: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xc0000053;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rm, 12, 14);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xc0000053,
+        mmatch: 0xfff0007f,
+        extension: String::from("rv_f"),
+        mnemonic: String::from("fcvt_w_s"),
+        operands: vec![(7,11), (15,19), (12,14), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+    };
+}
+pub fn fcvt_wu_d(rd: u32,rs1: u32,rm: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xc2100053;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rm, 12, 14);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xc2100053,
+        mmatch: 0xfff0007f,
+        extension: String::from("rv_d"),
+        mnemonic: String::from("fcvt_wu_d"),
+        operands: vec![(7,11), (15,19), (12,14), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+    };
+}
+pub fn fcvt_wu_s(rd: u32,rs1: u32,rm: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xc0100053;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rm, 12, 14);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xc0100053,
+        mmatch: 0xfff0007f,
+        extension: String::from("rv_f"),
+        mnemonic: String::from("fcvt_wu_s"),
+        operands: vec![(7,11), (15,19), (12,14), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+    };
+}
+pub fn fdiv_d(rd: u32,rs1: u32,rs2: u32,rm: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x1a000053;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(rm, 12, 14);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x1a000053,
+        mmatch: 0xfe00007f,
+        extension: String::from("rv_d"),
+        mnemonic: String::from("fdiv_d"),
+        operands: vec![(7,11), (15,19), (20,24), (12,14), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+    };
+}
+pub fn fdiv_s(rd: u32,rs1: u32,rs2: u32,rm: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x18000053;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(rm, 12, 14);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x18000053,
+        mmatch: 0xfe00007f,
+        extension: String::from("rv_f"),
+        mnemonic: String::from("fdiv_s"),
+        operands: vec![(7,11), (15,19), (20,24), (12,14), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+    };
+}
+pub fn fence(fm: u32,pred: u32,succ: u32,rs1: u32,rd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xf;
+    inst |= mask;
+    inst |= build_operand(fm, 28, 31);
+    inst |= build_operand(pred, 24, 27);
+    inst |= build_operand(succ, 20, 23);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xf,
+        mmatch: 0x707f,
+        extension: String::from("rv_i"),
+        mnemonic: String::from("fence"),
+        operands: vec![(28,31), (24,27), (20,23), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn fence_i(imm12: u32,rs1: u32,rd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x100f;
+    inst |= mask;
+    inst |= build_operand(imm12, 20, 31);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x100f,
+        mmatch: 0x707f,
+        extension: String::from("rv_zifencei"),
+        mnemonic: String::from("fence_i"),
+        operands: vec![(20,31), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SignedImm, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn fence_tso(rs1: u32,rd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x8330000f;
+    inst |= mask;
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x8330000f,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv_i"),
+        mnemonic: String::from("fence_tso"),
+        operands: vec![(15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn feq_d(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xa2002053;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xa2002053,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_d"),
+        mnemonic: String::from("feq_d"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn feq_s(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xa0002053;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xa0002053,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_f"),
+        mnemonic: String::from("feq_s"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn fld(rd: u32,rs1: u32,imm12: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x3007;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(imm12, 20, 31);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x3007,
+        mmatch: 0x707f,
+        extension: String::from("rv_d"),
+        mnemonic: String::from("fld"),
+        operands: vec![(7,11), (15,19), (20,31), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+    };
+}
+pub fn fle_d(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xa2000053;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xa2000053,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_d"),
+        mnemonic: String::from("fle_d"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn fle_s(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xa0000053;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xa0000053,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_f"),
+        mnemonic: String::from("fle_s"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn flt_d(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xa2001053;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xa2001053,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_d"),
+        mnemonic: String::from("flt_d"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn flt_s(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xa0001053;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xa0001053,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_f"),
+        mnemonic: String::from("flt_s"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn flw(rd: u32,rs1: u32,imm12: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x2007;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(imm12, 20, 31);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x2007,
+        mmatch: 0x707f,
+        extension: String::from("rv_f"),
+        mnemonic: String::from("flw"),
+        operands: vec![(7,11), (15,19), (20,31), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+    };
+}
+pub fn fmadd_d(rd: u32,rs1: u32,rs2: u32,rs3: u32,rm: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x2000043;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(rs3, 27, 31);
+    inst |= build_operand(rm, 12, 14);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x2000043,
+        mmatch: 0x600007f,
+        extension: String::from("rv_d"),
+        mnemonic: String::from("fmadd_d"),
+        operands: vec![(7,11), (15,19), (20,24), (27,31), (12,14), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+    };
+}
+pub fn fmadd_s(rd: u32,rs1: u32,rs2: u32,rs3: u32,rm: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x43;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(rs3, 27, 31);
+    inst |= build_operand(rm, 12, 14);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x43,
+        mmatch: 0x600007f,
+        extension: String::from("rv_f"),
+        mnemonic: String::from("fmadd_s"),
+        operands: vec![(7,11), (15,19), (20,24), (27,31), (12,14), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+    };
+}
+pub fn fmax_d(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x2a001053;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x2a001053,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_d"),
+        mnemonic: String::from("fmax_d"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn fmax_s(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x28001053;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x28001053,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_f"),
+        mnemonic: String::from("fmax_s"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn fmin_d(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x2a000053;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x2a000053,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_d"),
+        mnemonic: String::from("fmin_d"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn fmin_s(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x28000053;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x28000053,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_f"),
+        mnemonic: String::from("fmin_s"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn fmsub_d(rd: u32,rs1: u32,rs2: u32,rs3: u32,rm: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x2000047;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(rs3, 27, 31);
+    inst |= build_operand(rm, 12, 14);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x2000047,
+        mmatch: 0x600007f,
+        extension: String::from("rv_d"),
+        mnemonic: String::from("fmsub_d"),
+        operands: vec![(7,11), (15,19), (20,24), (27,31), (12,14), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+    };
+}
+pub fn fmsub_s(rd: u32,rs1: u32,rs2: u32,rs3: u32,rm: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x47;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(rs3, 27, 31);
+    inst |= build_operand(rm, 12, 14);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x47,
+        mmatch: 0x600007f,
+        extension: String::from("rv_f"),
+        mnemonic: String::from("fmsub_s"),
+        operands: vec![(7,11), (15,19), (20,24), (27,31), (12,14), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+    };
+}
+pub fn fmul_d(rd: u32,rs1: u32,rs2: u32,rm: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x12000053;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(rm, 12, 14);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x12000053,
+        mmatch: 0xfe00007f,
+        extension: String::from("rv_d"),
+        mnemonic: String::from("fmul_d"),
+        operands: vec![(7,11), (15,19), (20,24), (12,14), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+    };
+}
+pub fn fmul_s(rd: u32,rs1: u32,rs2: u32,rm: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x10000053;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(rm, 12, 14);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x10000053,
+        mmatch: 0xfe00007f,
+        extension: String::from("rv_f"),
+        mnemonic: String::from("fmul_s"),
+        operands: vec![(7,11), (15,19), (20,24), (12,14), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+    };
+}
+pub fn fmv_d_x(rd: u32,rs1: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xf2000053;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xf2000053,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv64_d"),
+        mnemonic: String::from("fmv_d_x"),
+        operands: vec![(7,11), (15,19), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn fmv_w_x(rd: u32,rs1: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xf0000053;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xf0000053,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv_f"),
+        mnemonic: String::from("fmv_w_x"),
+        operands: vec![(7,11), (15,19), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn fmv_x_d(rd: u32,rs1: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xe2000053;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xe2000053,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv64_d"),
+        mnemonic: String::from("fmv_x_d"),
+        operands: vec![(7,11), (15,19), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn fmv_x_w(rd: u32,rs1: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xe0000053;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xe0000053,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv_f"),
+        mnemonic: String::from("fmv_x_w"),
+        operands: vec![(7,11), (15,19), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn fnmadd_d(rd: u32,rs1: u32,rs2: u32,rs3: u32,rm: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x200004f;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(rs3, 27, 31);
+    inst |= build_operand(rm, 12, 14);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x200004f,
+        mmatch: 0x600007f,
+        extension: String::from("rv_d"),
+        mnemonic: String::from("fnmadd_d"),
+        operands: vec![(7,11), (15,19), (20,24), (27,31), (12,14), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+    };
+}
+pub fn fnmadd_s(rd: u32,rs1: u32,rs2: u32,rs3: u32,rm: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x4f;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(rs3, 27, 31);
+    inst |= build_operand(rm, 12, 14);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x4f,
+        mmatch: 0x600007f,
+        extension: String::from("rv_f"),
+        mnemonic: String::from("fnmadd_s"),
+        operands: vec![(7,11), (15,19), (20,24), (27,31), (12,14), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+    };
+}
+pub fn fnmsub_d(rd: u32,rs1: u32,rs2: u32,rs3: u32,rm: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x200004b;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(rs3, 27, 31);
+    inst |= build_operand(rm, 12, 14);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x200004b,
+        mmatch: 0x600007f,
+        extension: String::from("rv_d"),
+        mnemonic: String::from("fnmsub_d"),
+        operands: vec![(7,11), (15,19), (20,24), (27,31), (12,14), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+    };
+}
+pub fn fnmsub_s(rd: u32,rs1: u32,rs2: u32,rs3: u32,rm: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x4b;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(rs3, 27, 31);
+    inst |= build_operand(rm, 12, 14);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x4b,
+        mmatch: 0x600007f,
+        extension: String::from("rv_f"),
+        mnemonic: String::from("fnmsub_s"),
+        operands: vec![(7,11), (15,19), (20,24), (27,31), (12,14), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+    };
+}
+pub fn fsd(imm12hi: u32,rs1: u32,rs2: u32,imm12lo: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x3027;
+    inst |= mask;
+    inst |= build_operand(imm12hi, 25, 31);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(imm12lo, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x3027,
+        mmatch: 0x707f,
+        extension: String::from("rv_d"),
+        mnemonic: String::from("fsd"),
+        operands: vec![(25,31), (15,19), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::SignedImm, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+    };
+}
+pub fn fsgnj_d(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x22000053;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x22000053,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_d"),
+        mnemonic: String::from("fsgnj_d"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn fsgnj_s(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x20000053;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x20000053,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_f"),
+        mnemonic: String::from("fsgnj_s"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn fsgnjn_d(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x22001053;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x22001053,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_d"),
+        mnemonic: String::from("fsgnjn_d"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn fsgnjn_s(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x20001053;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x20001053,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_f"),
+        mnemonic: String::from("fsgnjn_s"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn fsgnjx_d(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x22002053;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x22002053,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_d"),
+        mnemonic: String::from("fsgnjx_d"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn fsgnjx_s(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x20002053;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x20002053,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_f"),
+        mnemonic: String::from("fsgnjx_s"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn fsqrt_d(rd: u32,rs1: u32,rm: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x5a000053;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rm, 12, 14);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x5a000053,
+        mmatch: 0xfff0007f,
+        extension: String::from("rv_d"),
+        mnemonic: String::from("fsqrt_d"),
+        operands: vec![(7,11), (15,19), (12,14), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+    };
+}
+pub fn fsqrt_s(rd: u32,rs1: u32,rm: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x58000053;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rm, 12, 14);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x58000053,
+        mmatch: 0xfff0007f,
+        extension: String::from("rv_f"),
+        mnemonic: String::from("fsqrt_s"),
+        operands: vec![(7,11), (15,19), (12,14), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+    };
+}
+pub fn fsub_d(rd: u32,rs1: u32,rs2: u32,rm: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xa000053;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(rm, 12, 14);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xa000053,
+        mmatch: 0xfe00007f,
+        extension: String::from("rv_d"),
+        mnemonic: String::from("fsub_d"),
+        operands: vec![(7,11), (15,19), (20,24), (12,14), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+    };
+}
+pub fn fsub_s(rd: u32,rs1: u32,rs2: u32,rm: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x8000053;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(rm, 12, 14);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x8000053,
+        mmatch: 0xfe00007f,
+        extension: String::from("rv_f"),
+        mnemonic: String::from("fsub_s"),
+        operands: vec![(7,11), (15,19), (20,24), (12,14), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+    };
+}
+pub fn fsw(imm12hi: u32,rs1: u32,rs2: u32,imm12lo: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x2027;
+    inst |= mask;
+    inst |= build_operand(imm12hi, 25, 31);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(imm12lo, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x2027,
+        mmatch: 0x707f,
+        extension: String::from("rv_f"),
+        mnemonic: String::from("fsw"),
+        operands: vec![(25,31), (15,19), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::SignedImm, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+    };
+}
+pub fn jal(rd: u32,jimm20: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x6f;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(jimm20, 12, 31);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x6f,
+        mmatch: 0x7f,
+        extension: String::from("rv_i"),
+        mnemonic: String::from("jal"),
+        operands: vec![(7,11), (12,31), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SignedImm, ],
+    };
+}
+pub fn jalr(rd: u32,rs1: u32,imm12: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x67;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(imm12, 20, 31);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x67,
+        mmatch: 0x707f,
+        extension: String::from("rv_i"),
+        mnemonic: String::from("jalr"),
+        operands: vec![(7,11), (15,19), (20,31), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+    };
+}
+pub fn lb(rd: u32,rs1: u32,imm12: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x3;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(imm12, 20, 31);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x3,
+        mmatch: 0x707f,
+        extension: String::from("rv_i"),
+        mnemonic: String::from("lb"),
+        operands: vec![(7,11), (15,19), (20,31), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+    };
+}
+pub fn lbu(rd: u32,rs1: u32,imm12: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x4003;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(imm12, 20, 31);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x4003,
+        mmatch: 0x707f,
+        extension: String::from("rv_i"),
+        mnemonic: String::from("lbu"),
+        operands: vec![(7,11), (15,19), (20,31), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+    };
+}
+pub fn ld(rd: u32,rs1: u32,imm12: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x3003;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(imm12, 20, 31);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x3003,
+        mmatch: 0x707f,
+        extension: String::from("rv64_i"),
+        mnemonic: String::from("ld"),
+        operands: vec![(7,11), (15,19), (20,31), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+    };
+}
+pub fn lh(rd: u32,rs1: u32,imm12: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x1003;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(imm12, 20, 31);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x1003,
+        mmatch: 0x707f,
+        extension: String::from("rv_i"),
+        mnemonic: String::from("lh"),
+        operands: vec![(7,11), (15,19), (20,31), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+    };
+}
+pub fn lhu(rd: u32,rs1: u32,imm12: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x5003;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(imm12, 20, 31);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x5003,
+        mmatch: 0x707f,
+        extension: String::from("rv_i"),
+        mnemonic: String::from("lhu"),
+        operands: vec![(7,11), (15,19), (20,31), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+    };
+}
+pub fn lr_d(rd: u32,rs1: u32,aqrl: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x1000302f;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(aqrl, 25, 26);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x1000302f,
+        mmatch: 0xf9f0707f,
+        extension: String::from("rv64_a"),
+        mnemonic: String::from("lr_d"),
+        operands: vec![(7,11), (15,19), (25,26), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Other, ],
+    };
+}
+pub fn lr_w(rd: u32,rs1: u32,aqrl: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x1000202f;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(aqrl, 25, 26);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x1000202f,
+        mmatch: 0xf9f0707f,
+        extension: String::from("rv_a"),
+        mnemonic: String::from("lr_w"),
+        operands: vec![(7,11), (15,19), (25,26), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Other, ],
+    };
+}
+pub fn lui(rd: u32,imm20: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x37;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(imm20, 12, 31);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x37,
+        mmatch: 0x7f,
+        extension: String::from("rv_i"),
+        mnemonic: String::from("lui"),
+        operands: vec![(7,11), (12,31), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::UnsignedImm, ],
+    };
+}
+pub fn lw(rd: u32,rs1: u32,imm12: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x2003;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(imm12, 20, 31);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x2003,
+        mmatch: 0x707f,
+        extension: String::from("rv_i"),
+        mnemonic: String::from("lw"),
+        operands: vec![(7,11), (15,19), (20,31), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+    };
+}
+pub fn lwu(rd: u32,rs1: u32,imm12: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x6003;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(imm12, 20, 31);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x6003,
+        mmatch: 0x707f,
+        extension: String::from("rv64_i"),
+        mnemonic: String::from("lwu"),
+        operands: vec![(7,11), (15,19), (20,31), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+    };
+}
+pub fn max(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xa006033;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xa006033,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_zbb"),
+        mnemonic: String::from("max"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn maxu(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xa007033;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xa007033,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_zbb"),
+        mnemonic: String::from("maxu"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn min(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xa004033;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xa004033,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_zbb"),
+        mnemonic: String::from("min"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn minu(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xa005033;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xa005033,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_zbb"),
+        mnemonic: String::from("minu"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn mret() -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x30200073;
+    inst |= mask;
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x30200073,
+        mmatch: 0xffffffff,
+        extension: String::from("rv_system"),
+        mnemonic: String::from("mret"),
+        operands: vec![],
+        operand_kinds: vec![],
+    };
+}
+pub fn mul(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x2000033;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x2000033,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_m"),
+        mnemonic: String::from("mul"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn mulh(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x2001033;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x2001033,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_m"),
+        mnemonic: String::from("mulh"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn mulhsu(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x2002033;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x2002033,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_m"),
+        mnemonic: String::from("mulhsu"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn mulhu(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x2003033;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x2003033,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_m"),
+        mnemonic: String::from("mulhu"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn mulw(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x200003b;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x200003b,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv64_m"),
+        mnemonic: String::from("mulw"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn or(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x6033;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x6033,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_i"),
+        mnemonic: String::from("or"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn orc_b(rd: u32,rs1: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x28705013;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x28705013,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv_zbb"),
+        mnemonic: String::from("orc_b"),
+        operands: vec![(7,11), (15,19), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn ori(rd: u32,rs1: u32,imm12: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x6013;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(imm12, 20, 31);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x6013,
+        mmatch: 0x707f,
+        extension: String::from("rv_i"),
+        mnemonic: String::from("ori"),
+        operands: vec![(7,11), (15,19), (20,31), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+    };
+}
+pub fn orn(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x40006033;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x40006033,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_zbb"),
+        mnemonic: String::from("orn"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn pause() -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x100000f;
+    inst |= mask;
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x100000f,
+        mmatch: 0xffffffff,
+        extension: String::from("rv_i"),
+        mnemonic: String::from("pause"),
+        operands: vec![],
+        operand_kinds: vec![],
+    };
+}
+pub fn rem(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x2006033;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x2006033,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_m"),
+        mnemonic: String::from("rem"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn remu(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x2007033;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x2007033,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_m"),
+        mnemonic: String::from("remu"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn remuw(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x200703b;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x200703b,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv64_m"),
+        mnemonic: String::from("remuw"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn remw(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x200603b;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x200603b,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv64_m"),
+        mnemonic: String::from("remw"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn rev8(rd: u32,rs1: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x6b805013;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x6b805013,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv64_zbb"),
+        mnemonic: String::from("rev8"),
+        operands: vec![(7,11), (15,19), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn rev8_rv32(rd: u32,rs1: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x69805013;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x69805013,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv32_zbb"),
+        mnemonic: String::from("rev8_rv32"),
+        operands: vec![(7,11), (15,19), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn rol(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x60001033;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x60001033,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_zbb"),
+        mnemonic: String::from("rol"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn rolw(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x6000103b;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x6000103b,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv64_zbb"),
+        mnemonic: String::from("rolw"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn ror(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x60005033;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x60005033,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_zbb"),
+        mnemonic: String::from("ror"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn rori(rd: u32,rs1: u32,shamtd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x60005013;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(shamtd, 20, 25);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x60005013,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv64_zbb"),
+        mnemonic: String::from("rori"),
+        operands: vec![(7,11), (15,19), (20,25), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Shamt, ],
+    };
+}
+pub fn rori_rv32(rd: u32,rs1: u32,shamtw: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x60005013;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(shamtw, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x60005013,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv32_zbb"),
+        mnemonic: String::from("rori_rv32"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Shamt, ],
+    };
+}
+pub fn roriw(rd: u32,rs1: u32,shamtw: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x6000501b;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(shamtw, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x6000501b,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv64_zbb"),
+        mnemonic: String::from("roriw"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Shamt, ],
+    };
+}
+pub fn rorw(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x6000503b;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x6000503b,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv64_zbb"),
+        mnemonic: String::from("rorw"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn sb(imm12hi: u32,rs1: u32,rs2: u32,imm12lo: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x23;
+    inst |= mask;
+    inst |= build_operand(imm12hi, 25, 31);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(imm12lo, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x23,
+        mmatch: 0x707f,
+        extension: String::from("rv_i"),
+        mnemonic: String::from("sb"),
+        operands: vec![(25,31), (15,19), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::SignedImm, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+    };
+}
+pub fn sbreak() -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x100073;
+    inst |= mask;
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x100073,
+        mmatch: 0xffffffff,
+        extension: String::from("rv_i"),
+        mnemonic: String::from("sbreak"),
+        operands: vec![],
+        operand_kinds: vec![],
+    };
+}
+pub fn sc_d(rd: u32,rs1: u32,rs2: u32,aqrl: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x1800302f;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(aqrl, 25, 26);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x1800302f,
+        mmatch: 0xf800707f,
+        extension: String::from("rv64_a"),
+        mnemonic: String::from("sc_d"),
+        operands: vec![(7,11), (15,19), (20,24), (25,26), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::Other, ],
+    };
+}
+pub fn sc_w(rd: u32,rs1: u32,rs2: u32,aqrl: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x1800202f;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(aqrl, 25, 26);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x1800202f,
+        mmatch: 0xf800707f,
+        extension: String::from("rv_a"),
+        mnemonic: String::from("sc_w"),
+        operands: vec![(7,11), (15,19), (20,24), (25,26), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::Other, ],
+    };
+}
+pub fn scall() -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x73;
+    inst |= mask;
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x73,
+        mmatch: 0xffffffff,
+        extension: String::from("rv_i"),
+        mnemonic: String::from("scall"),
+        operands: vec![],
+        operand_kinds: vec![],
+    };
+}
+pub fn sd(imm12hi: u32,rs1: u32,rs2: u32,imm12lo: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x3023;
+    inst |= mask;
+    inst |= build_operand(imm12hi, 25, 31);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(imm12lo, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x3023,
+        mmatch: 0x707f,
+        extension: String::from("rv64_i"),
+        mnemonic: String::from("sd"),
+        operands: vec![(25,31), (15,19), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::SignedImm, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+    };
+}
+pub fn sext_b(rd: u32,rs1: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x60401013;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x60401013,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv_zbb"),
+        mnemonic: String::from("sext_b"),
+        operands: vec![(7,11), (15,19), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn sext_h(rd: u32,rs1: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x60501013;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x60501013,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv_zbb"),
+        mnemonic: String::from("sext_h"),
+        operands: vec![(7,11), (15,19), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn sfence_vma(rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x12000073;
+    inst |= mask;
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x12000073,
+        mmatch: 0xfe007fff,
+        extension: String::from("rv_system"),
+        mnemonic: String::from("sfence_vma"),
+        operands: vec![(15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn sh(imm12hi: u32,rs1: u32,rs2: u32,imm12lo: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x1023;
+    inst |= mask;
+    inst |= build_operand(imm12hi, 25, 31);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(imm12lo, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x1023,
+        mmatch: 0x707f,
+        extension: String::from("rv_i"),
+        mnemonic: String::from("sh"),
+        operands: vec![(25,31), (15,19), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::SignedImm, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+    };
+}
+pub fn sh1add(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x20002033;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x20002033,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_zba"),
+        mnemonic: String::from("sh1add"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn sh1add_uw(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x2000203b;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x2000203b,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv64_zba"),
+        mnemonic: String::from("sh1add_uw"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn sh2add(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x20004033;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x20004033,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_zba"),
+        mnemonic: String::from("sh2add"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn sh2add_uw(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x2000403b;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x2000403b,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv64_zba"),
+        mnemonic: String::from("sh2add_uw"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn sh3add(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x20006033;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x20006033,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_zba"),
+        mnemonic: String::from("sh3add"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn sh3add_uw(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x2000603b;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x2000603b,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv64_zba"),
+        mnemonic: String::from("sh3add_uw"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn sll(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x1033;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x1033,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_i"),
+        mnemonic: String::from("sll"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn slli(rd: u32,rs1: u32,shamtd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x1013;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(shamtd, 20, 25);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x1013,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv64_i"),
+        mnemonic: String::from("slli"),
+        operands: vec![(7,11), (15,19), (20,25), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Shamt, ],
+    };
+}
+pub fn slli_rv32(rd: u32,rs1: u32,shamtw: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x1013;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(shamtw, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x1013,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv32_i"),
+        mnemonic: String::from("slli_rv32"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Shamt, ],
+    };
+}
+pub fn slli_uw(rd: u32,rs1: u32,shamtd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x800101b;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(shamtd, 20, 25);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x800101b,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv64_zba"),
+        mnemonic: String::from("slli_uw"),
+        operands: vec![(7,11), (15,19), (20,25), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Shamt, ],
+    };
+}
+pub fn slliw(rd: u32,rs1: u32,shamtw: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x101b;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(shamtw, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x101b,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv64_i"),
+        mnemonic: String::from("slliw"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Shamt, ],
+    };
+}
+pub fn sllw(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x103b;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x103b,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv64_i"),
+        mnemonic: String::from("sllw"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn slt(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x2033;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x2033,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_i"),
+        mnemonic: String::from("slt"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn slti(rd: u32,rs1: u32,imm12: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x2013;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(imm12, 20, 31);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x2013,
+        mmatch: 0x707f,
+        extension: String::from("rv_i"),
+        mnemonic: String::from("slti"),
+        operands: vec![(7,11), (15,19), (20,31), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+    };
+}
+pub fn sltiu(rd: u32,rs1: u32,imm12: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x3013;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(imm12, 20, 31);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x3013,
+        mmatch: 0x707f,
+        extension: String::from("rv_i"),
+        mnemonic: String::from("sltiu"),
+        operands: vec![(7,11), (15,19), (20,31), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+    };
+}
+pub fn sltu(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x3033;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x3033,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_i"),
+        mnemonic: String::from("sltu"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn sra(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x40005033;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x40005033,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_i"),
+        mnemonic: String::from("sra"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn srai(rd: u32,rs1: u32,shamtd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x40005013;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(shamtd, 20, 25);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x40005013,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv64_i"),
+        mnemonic: String::from("srai"),
+        operands: vec![(7,11), (15,19), (20,25), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Shamt, ],
+    };
+}
+pub fn srai_rv32(rd: u32,rs1: u32,shamtw: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x40005013;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(shamtw, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x40005013,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv32_i"),
+        mnemonic: String::from("srai_rv32"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Shamt, ],
+    };
+}
+pub fn sraiw(rd: u32,rs1: u32,shamtw: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x4000501b;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(shamtw, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x4000501b,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv64_i"),
+        mnemonic: String::from("sraiw"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Shamt, ],
+    };
+}
+pub fn sraw(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x4000503b;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x4000503b,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv64_i"),
+        mnemonic: String::from("sraw"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn sret() -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x10200073;
+    inst |= mask;
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x10200073,
+        mmatch: 0xffffffff,
+        extension: String::from("rv_system"),
+        mnemonic: String::from("sret"),
+        operands: vec![],
+        operand_kinds: vec![],
+    };
+}
+pub fn srl(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x5033;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x5033,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_i"),
+        mnemonic: String::from("srl"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn srli(rd: u32,rs1: u32,shamtd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x5013;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(shamtd, 20, 25);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x5013,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv64_i"),
+        mnemonic: String::from("srli"),
+        operands: vec![(7,11), (15,19), (20,25), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Shamt, ],
+    };
+}
+pub fn srli_rv32(rd: u32,rs1: u32,shamtw: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x5013;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(shamtw, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x5013,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv32_i"),
+        mnemonic: String::from("srli_rv32"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Shamt, ],
+    };
+}
+pub fn srliw(rd: u32,rs1: u32,shamtw: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x501b;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(shamtw, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x501b,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv64_i"),
+        mnemonic: String::from("srliw"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Shamt, ],
+    };
+}
+pub fn srlw(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x503b;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x503b,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv64_i"),
+        mnemonic: String::from("srlw"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn sub(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x40000033;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x40000033,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_i"),
+        mnemonic: String::from("sub"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn subw(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x4000003b;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x4000003b,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv64_i"),
+        mnemonic: String::from("subw"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn sw(imm12hi: u32,rs1: u32,rs2: u32,imm12lo: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x2023;
+    inst |= mask;
+    inst |= build_operand(imm12hi, 25, 31);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(imm12lo, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x2023,
+        mmatch: 0x707f,
+        extension: String::from("rv_i"),
+        mnemonic: String::from("sw"),
+        operands: vec![(25,31), (15,19), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::SignedImm, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+    };
+}
+pub fn vaadd_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x24002057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x24002057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vaadd_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vaadd_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x24006057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x24006057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vaadd_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vaaddu_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x20002057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x20002057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vaaddu_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vaaddu_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x20006057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x20006057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vaaddu_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vadc_vim(vs2: u32,simm5: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x40003057;
+    inst |= mask;
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(simm5, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x40003057,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vadc_vim"),
+        operands: vec![(20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
+    };
+}
+pub fn vadc_vvm(vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x40000057;
+    inst |= mask;
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x40000057,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vadc_vvm"),
+        operands: vec![(20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vadc_vxm(vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x40004057;
+    inst |= mask;
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x40004057,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vadc_vxm"),
+        operands: vec![(20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vadd_vi(vm: u32,vs2: u32,simm5: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x3057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(simm5, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x3057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vadd_vi"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
+    };
+}
+pub fn vadd_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x57;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x57,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vadd_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vadd_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x4057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x4057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vadd_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vand_vi(vm: u32,vs2: u32,simm5: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x24003057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(simm5, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x24003057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vand_vi"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
+    };
+}
+pub fn vand_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x24000057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x24000057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vand_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vand_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x24004057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x24004057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vand_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vasub_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x2c002057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x2c002057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vasub_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vasub_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x2c006057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x2c006057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vasub_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vasubu_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x28002057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x28002057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vasubu_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vasubu_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x28006057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x28006057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vasubu_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vcompress_vm(vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x5e002057;
+    inst |= mask;
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x5e002057,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vcompress_vm"),
+        operands: vec![(20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vcpop_m(vm: u32,vs2: u32,rd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x40082057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x40082057,
+        mmatch: 0xfc0ff07f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vcpop_m"),
+        operands: vec![(25,25), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vdiv_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x84002057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x84002057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vdiv_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vdiv_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x84006057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x84006057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vdiv_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vdivu_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x80002057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x80002057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vdivu_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vdivu_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x80006057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x80006057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vdivu_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfadd_vf(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x5057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x5057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfadd_vf"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfadd_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x1057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x1057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfadd_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfclass_v(vm: u32,vs2: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x4c081057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x4c081057,
+        mmatch: 0xfc0ff07f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfclass_v"),
+        operands: vec![(25,25), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfcvt_f_x_v(vm: u32,vs2: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x48019057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x48019057,
+        mmatch: 0xfc0ff07f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfcvt_f_x_v"),
+        operands: vec![(25,25), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfcvt_f_xu_v(vm: u32,vs2: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x48011057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x48011057,
+        mmatch: 0xfc0ff07f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfcvt_f_xu_v"),
+        operands: vec![(25,25), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfcvt_rtz_x_f_v(vm: u32,vs2: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x48039057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x48039057,
+        mmatch: 0xfc0ff07f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfcvt_rtz_x_f_v"),
+        operands: vec![(25,25), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfcvt_rtz_xu_f_v(vm: u32,vs2: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x48031057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x48031057,
+        mmatch: 0xfc0ff07f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfcvt_rtz_xu_f_v"),
+        operands: vec![(25,25), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfcvt_x_f_v(vm: u32,vs2: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x48009057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x48009057,
+        mmatch: 0xfc0ff07f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfcvt_x_f_v"),
+        operands: vec![(25,25), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfcvt_xu_f_v(vm: u32,vs2: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x48001057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x48001057,
+        mmatch: 0xfc0ff07f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfcvt_xu_f_v"),
+        operands: vec![(25,25), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfdiv_vf(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x80005057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x80005057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfdiv_vf"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfdiv_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x80001057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x80001057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfdiv_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfirst_m(vm: u32,vs2: u32,rd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x4008a057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x4008a057,
+        mmatch: 0xfc0ff07f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfirst_m"),
+        operands: vec![(25,25), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfmacc_vf(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xb0005057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xb0005057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfmacc_vf"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfmacc_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xb0001057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xb0001057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfmacc_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfmadd_vf(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xa0005057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xa0005057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfmadd_vf"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfmadd_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xa0001057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xa0001057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfmadd_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfmax_vf(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x18005057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x18005057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfmax_vf"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfmax_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x18001057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x18001057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfmax_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfmerge_vfm(vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x5c005057;
+    inst |= mask;
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x5c005057,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfmerge_vfm"),
+        operands: vec![(20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfmin_vf(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x10005057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x10005057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfmin_vf"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfmin_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x10001057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x10001057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfmin_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfmsac_vf(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xb8005057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xb8005057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfmsac_vf"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfmsac_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xb8001057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xb8001057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfmsac_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfmsub_vf(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xa8005057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xa8005057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfmsub_vf"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfmsub_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xa8001057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xa8001057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfmsub_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfmul_vf(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x90005057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x90005057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfmul_vf"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfmul_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x90001057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x90001057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfmul_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfmv_f_s(vs2: u32,rd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x42001057;
+    inst |= mask;
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x42001057,
+        mmatch: 0xfe0ff07f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfmv_f_s"),
+        operands: vec![(20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfmv_s_f(rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x42005057;
+    inst |= mask;
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x42005057,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfmv_s_f"),
+        operands: vec![(15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfmv_v_f(rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x5e005057;
+    inst |= mask;
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x5e005057,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfmv_v_f"),
+        operands: vec![(15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfncvt_f_f_w(vm: u32,vs2: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x480a1057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x480a1057,
+        mmatch: 0xfc0ff07f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfncvt_f_f_w"),
+        operands: vec![(25,25), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfncvt_f_x_w(vm: u32,vs2: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x48099057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x48099057,
+        mmatch: 0xfc0ff07f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfncvt_f_x_w"),
+        operands: vec![(25,25), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfncvt_f_xu_w(vm: u32,vs2: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x48091057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x48091057,
+        mmatch: 0xfc0ff07f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfncvt_f_xu_w"),
+        operands: vec![(25,25), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfncvt_rod_f_f_w(vm: u32,vs2: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x480a9057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x480a9057,
+        mmatch: 0xfc0ff07f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfncvt_rod_f_f_w"),
+        operands: vec![(25,25), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfncvt_rtz_x_f_w(vm: u32,vs2: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x480b9057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x480b9057,
+        mmatch: 0xfc0ff07f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfncvt_rtz_x_f_w"),
+        operands: vec![(25,25), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfncvt_rtz_xu_f_w(vm: u32,vs2: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x480b1057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x480b1057,
+        mmatch: 0xfc0ff07f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfncvt_rtz_xu_f_w"),
+        operands: vec![(25,25), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfncvt_x_f_w(vm: u32,vs2: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x48089057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x48089057,
+        mmatch: 0xfc0ff07f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfncvt_x_f_w"),
+        operands: vec![(25,25), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfncvt_xu_f_w(vm: u32,vs2: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x48081057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x48081057,
+        mmatch: 0xfc0ff07f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfncvt_xu_f_w"),
+        operands: vec![(25,25), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfnmacc_vf(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xb4005057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xb4005057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfnmacc_vf"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfnmacc_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xb4001057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xb4001057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfnmacc_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfnmadd_vf(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xa4005057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xa4005057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfnmadd_vf"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfnmadd_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xa4001057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xa4001057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfnmadd_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfnmsac_vf(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xbc005057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xbc005057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfnmsac_vf"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfnmsac_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xbc001057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xbc001057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfnmsac_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfnmsub_vf(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xac005057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xac005057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfnmsub_vf"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfnmsub_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xac001057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xac001057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfnmsub_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfrdiv_vf(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x84005057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x84005057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfrdiv_vf"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfrec7_v(vm: u32,vs2: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x4c029057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x4c029057,
+        mmatch: 0xfc0ff07f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfrec7_v"),
+        operands: vec![(25,25), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfredmax_vs(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x1c001057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x1c001057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfredmax_vs"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfredmin_vs(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x14001057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x14001057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfredmin_vs"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfredosum_vs(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xc001057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xc001057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfredosum_vs"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfredusum_vs(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x4001057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x4001057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfredusum_vs"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfrsqrt7_v(vm: u32,vs2: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x4c021057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x4c021057,
+        mmatch: 0xfc0ff07f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfrsqrt7_v"),
+        operands: vec![(25,25), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfrsub_vf(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x9c005057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x9c005057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfrsub_vf"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfsgnj_vf(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x20005057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x20005057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfsgnj_vf"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfsgnj_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x20001057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x20001057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfsgnj_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfsgnjn_vf(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x24005057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x24005057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfsgnjn_vf"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfsgnjn_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x24001057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x24001057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfsgnjn_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfsgnjx_vf(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x28005057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x28005057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfsgnjx_vf"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfsgnjx_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x28001057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x28001057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfsgnjx_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfslide1down_vf(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x3c005057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x3c005057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfslide1down_vf"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfslide1up_vf(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x38005057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x38005057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfslide1up_vf"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfsqrt_v(vm: u32,vs2: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x4c001057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x4c001057,
+        mmatch: 0xfc0ff07f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfsqrt_v"),
+        operands: vec![(25,25), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfsub_vf(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x8005057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x8005057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfsub_vf"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfsub_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x8001057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x8001057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfsub_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfwadd_vf(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xc0005057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xc0005057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfwadd_vf"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfwadd_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xc0001057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xc0001057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfwadd_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfwadd_wf(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xd0005057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xd0005057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfwadd_wf"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfwadd_wv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xd0001057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xd0001057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfwadd_wv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfwcvt_f_f_v(vm: u32,vs2: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x48061057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x48061057,
+        mmatch: 0xfc0ff07f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfwcvt_f_f_v"),
+        operands: vec![(25,25), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfwcvt_f_x_v(vm: u32,vs2: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x48059057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x48059057,
+        mmatch: 0xfc0ff07f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfwcvt_f_x_v"),
+        operands: vec![(25,25), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfwcvt_f_xu_v(vm: u32,vs2: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x48051057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x48051057,
+        mmatch: 0xfc0ff07f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfwcvt_f_xu_v"),
+        operands: vec![(25,25), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfwcvt_rtz_x_f_v(vm: u32,vs2: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x48079057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x48079057,
+        mmatch: 0xfc0ff07f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfwcvt_rtz_x_f_v"),
+        operands: vec![(25,25), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfwcvt_rtz_xu_f_v(vm: u32,vs2: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x48071057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x48071057,
+        mmatch: 0xfc0ff07f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfwcvt_rtz_xu_f_v"),
+        operands: vec![(25,25), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfwcvt_x_f_v(vm: u32,vs2: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x48049057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x48049057,
+        mmatch: 0xfc0ff07f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfwcvt_x_f_v"),
+        operands: vec![(25,25), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfwcvt_xu_f_v(vm: u32,vs2: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x48041057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x48041057,
+        mmatch: 0xfc0ff07f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfwcvt_xu_f_v"),
+        operands: vec![(25,25), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfwmacc_vf(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xf0005057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xf0005057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfwmacc_vf"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfwmacc_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xf0001057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xf0001057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfwmacc_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfwmsac_vf(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xf8005057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xf8005057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfwmsac_vf"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfwmsac_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xf8001057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xf8001057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfwmsac_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfwmul_vf(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xe0005057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xe0005057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfwmul_vf"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfwmul_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xe0001057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xe0001057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfwmul_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfwnmacc_vf(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xf4005057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xf4005057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfwnmacc_vf"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfwnmacc_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xf4001057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xf4001057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfwnmacc_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfwnmsac_vf(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xfc005057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xfc005057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfwnmsac_vf"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfwnmsac_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xfc001057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xfc001057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfwnmsac_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfwredosum_vs(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xcc001057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xcc001057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfwredosum_vs"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfwredusum_vs(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xc4001057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xc4001057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfwredusum_vs"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfwsub_vf(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xc8005057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xc8005057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfwsub_vf"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfwsub_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xc8001057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xc8001057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfwsub_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfwsub_wf(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xd8005057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xd8005057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfwsub_wf"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vfwsub_wv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xd8001057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xd8001057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vfwsub_wv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vid_v(vm: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x5008a057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x5008a057,
+        mmatch: 0xfdfff07f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vid_v"),
+        operands: vec![(25,25), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::DestReg, ],
+    };
+}
+pub fn viota_m(vm: u32,vs2: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x50082057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x50082057,
+        mmatch: 0xfc0ff07f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("viota_m"),
+        operands: vec![(25,25), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vl1re16_v(rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x2805007;
+    inst |= mask;
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x2805007,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vl1re16_v"),
+        operands: vec![(15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vl1re32_v(rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x2806007;
+    inst |= mask;
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x2806007,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vl1re32_v"),
+        operands: vec![(15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vl1re64_v(rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x2807007;
+    inst |= mask;
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x2807007,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vl1re64_v"),
+        operands: vec![(15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vl1re8_v(rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x2800007;
+    inst |= mask;
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x2800007,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vl1re8_v"),
+        operands: vec![(15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vl2re16_v(rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x22805007;
+    inst |= mask;
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x22805007,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vl2re16_v"),
+        operands: vec![(15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vl2re32_v(rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x22806007;
+    inst |= mask;
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x22806007,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vl2re32_v"),
+        operands: vec![(15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vl2re64_v(rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x22807007;
+    inst |= mask;
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x22807007,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vl2re64_v"),
+        operands: vec![(15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vl2re8_v(rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x22800007;
+    inst |= mask;
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x22800007,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vl2re8_v"),
+        operands: vec![(15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vl4re16_v(rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x62805007;
+    inst |= mask;
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x62805007,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vl4re16_v"),
+        operands: vec![(15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vl4re32_v(rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x62806007;
+    inst |= mask;
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x62806007,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vl4re32_v"),
+        operands: vec![(15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vl4re64_v(rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x62807007;
+    inst |= mask;
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x62807007,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vl4re64_v"),
+        operands: vec![(15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vl4re8_v(rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x62800007;
+    inst |= mask;
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x62800007,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vl4re8_v"),
+        operands: vec![(15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vl8re16_v(rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xe2805007;
+    inst |= mask;
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xe2805007,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vl8re16_v"),
+        operands: vec![(15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vl8re32_v(rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xe2806007;
+    inst |= mask;
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xe2806007,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vl8re32_v"),
+        operands: vec![(15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vl8re64_v(rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xe2807007;
+    inst |= mask;
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xe2807007,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vl8re64_v"),
+        operands: vec![(15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vl8re8_v(rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xe2800007;
+    inst |= mask;
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xe2800007,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vl8re8_v"),
+        operands: vec![(15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vle16_v(nf: u32,vm: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x5007;
+    inst |= mask;
+    inst |= build_operand(nf, 29, 31);
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x5007,
+        mmatch: 0x1df0707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vle16_v"),
+        operands: vec![(29,31), (25,25), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vle16ff_v(nf: u32,vm: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x1005007;
+    inst |= mask;
+    inst |= build_operand(nf, 29, 31);
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x1005007,
+        mmatch: 0x1df0707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vle16ff_v"),
+        operands: vec![(29,31), (25,25), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vle32_v(nf: u32,vm: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x6007;
+    inst |= mask;
+    inst |= build_operand(nf, 29, 31);
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x6007,
+        mmatch: 0x1df0707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vle32_v"),
+        operands: vec![(29,31), (25,25), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vle32ff_v(nf: u32,vm: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x1006007;
+    inst |= mask;
+    inst |= build_operand(nf, 29, 31);
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x1006007,
+        mmatch: 0x1df0707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vle32ff_v"),
+        operands: vec![(29,31), (25,25), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vle64_v(nf: u32,vm: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x7007;
+    inst |= mask;
+    inst |= build_operand(nf, 29, 31);
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x7007,
+        mmatch: 0x1df0707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vle64_v"),
+        operands: vec![(29,31), (25,25), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vle64ff_v(nf: u32,vm: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x1007007;
+    inst |= mask;
+    inst |= build_operand(nf, 29, 31);
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x1007007,
+        mmatch: 0x1df0707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vle64ff_v"),
+        operands: vec![(29,31), (25,25), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vle8_v(nf: u32,vm: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x7;
+    inst |= mask;
+    inst |= build_operand(nf, 29, 31);
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x7,
+        mmatch: 0x1df0707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vle8_v"),
+        operands: vec![(29,31), (25,25), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vle8ff_v(nf: u32,vm: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x1000007;
+    inst |= mask;
+    inst |= build_operand(nf, 29, 31);
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x1000007,
+        mmatch: 0x1df0707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vle8ff_v"),
+        operands: vec![(29,31), (25,25), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vlm_v(rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x2b00007;
+    inst |= mask;
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x2b00007,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vlm_v"),
+        operands: vec![(15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vloxei16_v(nf: u32,vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xc005007;
+    inst |= mask;
+    inst |= build_operand(nf, 29, 31);
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xc005007,
+        mmatch: 0x1c00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vloxei16_v"),
+        operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vloxei32_v(nf: u32,vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xc006007;
+    inst |= mask;
+    inst |= build_operand(nf, 29, 31);
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xc006007,
+        mmatch: 0x1c00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vloxei32_v"),
+        operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vloxei64_v(nf: u32,vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xc007007;
+    inst |= mask;
+    inst |= build_operand(nf, 29, 31);
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xc007007,
+        mmatch: 0x1c00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vloxei64_v"),
+        operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vloxei8_v(nf: u32,vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xc000007;
+    inst |= mask;
+    inst |= build_operand(nf, 29, 31);
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xc000007,
+        mmatch: 0x1c00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vloxei8_v"),
+        operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vlse16_v(nf: u32,vm: u32,rs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x8005007;
+    inst |= mask;
+    inst |= build_operand(nf, 29, 31);
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x8005007,
+        mmatch: 0x1c00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vlse16_v"),
+        operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vlse32_v(nf: u32,vm: u32,rs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x8006007;
+    inst |= mask;
+    inst |= build_operand(nf, 29, 31);
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x8006007,
+        mmatch: 0x1c00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vlse32_v"),
+        operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vlse64_v(nf: u32,vm: u32,rs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x8007007;
+    inst |= mask;
+    inst |= build_operand(nf, 29, 31);
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x8007007,
+        mmatch: 0x1c00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vlse64_v"),
+        operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vlse8_v(nf: u32,vm: u32,rs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x8000007;
+    inst |= mask;
+    inst |= build_operand(nf, 29, 31);
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x8000007,
+        mmatch: 0x1c00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vlse8_v"),
+        operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vluxei16_v(nf: u32,vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x4005007;
+    inst |= mask;
+    inst |= build_operand(nf, 29, 31);
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x4005007,
+        mmatch: 0x1c00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vluxei16_v"),
+        operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vluxei32_v(nf: u32,vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x4006007;
+    inst |= mask;
+    inst |= build_operand(nf, 29, 31);
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x4006007,
+        mmatch: 0x1c00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vluxei32_v"),
+        operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vluxei64_v(nf: u32,vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x4007007;
+    inst |= mask;
+    inst |= build_operand(nf, 29, 31);
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x4007007,
+        mmatch: 0x1c00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vluxei64_v"),
+        operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vluxei8_v(nf: u32,vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x4000007;
+    inst |= mask;
+    inst |= build_operand(nf, 29, 31);
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x4000007,
+        mmatch: 0x1c00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vluxei8_v"),
+        operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmacc_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xb4002057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xb4002057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmacc_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmacc_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xb4006057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xb4006057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmacc_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmadc_vi(vs2: u32,simm5: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x46003057;
+    inst |= mask;
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(simm5, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x46003057,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmadc_vi"),
+        operands: vec![(20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
+    };
+}
+pub fn vmadc_vim(vs2: u32,simm5: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x44003057;
+    inst |= mask;
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(simm5, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x44003057,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmadc_vim"),
+        operands: vec![(20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
+    };
+}
+pub fn vmadc_vv(vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x46000057;
+    inst |= mask;
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x46000057,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmadc_vv"),
+        operands: vec![(20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmadc_vvm(vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x44000057;
+    inst |= mask;
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x44000057,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmadc_vvm"),
+        operands: vec![(20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmadc_vx(vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x46004057;
+    inst |= mask;
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x46004057,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmadc_vx"),
+        operands: vec![(20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmadc_vxm(vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x44004057;
+    inst |= mask;
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x44004057,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmadc_vxm"),
+        operands: vec![(20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmadd_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xa4002057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xa4002057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmadd_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmadd_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xa4006057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xa4006057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmadd_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmand_mm(vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x66002057;
+    inst |= mask;
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x66002057,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmand_mm"),
+        operands: vec![(20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmandn_mm(vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x62002057;
+    inst |= mask;
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x62002057,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmandn_mm"),
+        operands: vec![(20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmax_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x1c000057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x1c000057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmax_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmax_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x1c004057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x1c004057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmax_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmaxu_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x18000057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x18000057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmaxu_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmaxu_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x18004057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x18004057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmaxu_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmerge_vim(vs2: u32,simm5: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x5c003057;
+    inst |= mask;
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(simm5, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x5c003057,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmerge_vim"),
+        operands: vec![(20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
+    };
+}
+pub fn vmerge_vvm(vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x5c000057;
+    inst |= mask;
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x5c000057,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmerge_vvm"),
+        operands: vec![(20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmerge_vxm(vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x5c004057;
+    inst |= mask;
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x5c004057,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmerge_vxm"),
+        operands: vec![(20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmfeq_vf(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x60005057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x60005057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmfeq_vf"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmfeq_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x60001057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x60001057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmfeq_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmfge_vf(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x7c005057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x7c005057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmfge_vf"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmfgt_vf(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x74005057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x74005057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmfgt_vf"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmfle_vf(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x64005057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x64005057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmfle_vf"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmfle_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x64001057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x64001057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmfle_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmflt_vf(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x6c005057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x6c005057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmflt_vf"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmflt_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x6c001057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x6c001057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmflt_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmfne_vf(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x70005057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x70005057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmfne_vf"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmfne_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x70001057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x70001057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmfne_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmin_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x14000057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x14000057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmin_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmin_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x14004057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x14004057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmin_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vminu_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x10000057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x10000057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vminu_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vminu_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x10004057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x10004057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vminu_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmnand_mm(vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x76002057;
+    inst |= mask;
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x76002057,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmnand_mm"),
+        operands: vec![(20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmnor_mm(vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x7a002057;
+    inst |= mask;
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x7a002057,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmnor_mm"),
+        operands: vec![(20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmor_mm(vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x6a002057;
+    inst |= mask;
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x6a002057,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmor_mm"),
+        operands: vec![(20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmorn_mm(vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x72002057;
+    inst |= mask;
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x72002057,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmorn_mm"),
+        operands: vec![(20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmsbc_vv(vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x4e000057;
+    inst |= mask;
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x4e000057,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmsbc_vv"),
+        operands: vec![(20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmsbc_vvm(vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x4c000057;
+    inst |= mask;
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x4c000057,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmsbc_vvm"),
+        operands: vec![(20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmsbc_vx(vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x4e004057;
+    inst |= mask;
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x4e004057,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmsbc_vx"),
+        operands: vec![(20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmsbc_vxm(vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x4c004057;
+    inst |= mask;
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x4c004057,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmsbc_vxm"),
+        operands: vec![(20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmsbf_m(vm: u32,vs2: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x5000a057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x5000a057,
+        mmatch: 0xfc0ff07f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmsbf_m"),
+        operands: vec![(25,25), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmseq_vi(vm: u32,vs2: u32,simm5: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x60003057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(simm5, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x60003057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmseq_vi"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
+    };
+}
+pub fn vmseq_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x60000057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x60000057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmseq_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmseq_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x60004057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x60004057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmseq_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmsgt_vi(vm: u32,vs2: u32,simm5: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x7c003057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(simm5, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x7c003057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmsgt_vi"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
+    };
+}
+pub fn vmsgt_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x7c004057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x7c004057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmsgt_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmsgtu_vi(vm: u32,vs2: u32,simm5: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x78003057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(simm5, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x78003057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmsgtu_vi"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
+    };
+}
+pub fn vmsgtu_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x78004057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x78004057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmsgtu_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmsif_m(vm: u32,vs2: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x5001a057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x5001a057,
+        mmatch: 0xfc0ff07f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmsif_m"),
+        operands: vec![(25,25), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmsle_vi(vm: u32,vs2: u32,simm5: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x74003057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(simm5, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x74003057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmsle_vi"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
+    };
+}
+pub fn vmsle_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x74000057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x74000057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmsle_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmsle_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x74004057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x74004057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmsle_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmsleu_vi(vm: u32,vs2: u32,simm5: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x70003057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(simm5, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x70003057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmsleu_vi"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
+    };
+}
+pub fn vmsleu_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x70000057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x70000057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmsleu_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmsleu_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x70004057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x70004057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmsleu_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmslt_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x6c000057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x6c000057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmslt_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmslt_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x6c004057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x6c004057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmslt_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmsltu_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x68000057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x68000057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmsltu_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmsltu_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x68004057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x68004057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmsltu_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmsne_vi(vm: u32,vs2: u32,simm5: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x64003057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(simm5, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x64003057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmsne_vi"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
+    };
+}
+pub fn vmsne_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x64000057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x64000057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmsne_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmsne_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x64004057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x64004057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmsne_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmsof_m(vm: u32,vs2: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x50012057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x50012057,
+        mmatch: 0xfc0ff07f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmsof_m"),
+        operands: vec![(25,25), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmul_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x94002057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x94002057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmul_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmul_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x94006057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x94006057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmul_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmulh_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x9c002057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x9c002057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmulh_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmulh_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x9c006057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x9c006057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmulh_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmulhsu_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x98002057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x98002057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmulhsu_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmulhsu_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x98006057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x98006057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmulhsu_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmulhu_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x90002057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x90002057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmulhu_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmulhu_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x90006057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x90006057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmulhu_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmv1r_v(vs2: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x9e003057;
+    inst |= mask;
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x9e003057,
+        mmatch: 0xfe0ff07f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmv1r_v"),
+        operands: vec![(20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmv2r_v(vs2: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x9e00b057;
+    inst |= mask;
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x9e00b057,
+        mmatch: 0xfe0ff07f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmv2r_v"),
+        operands: vec![(20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmv4r_v(vs2: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x9e01b057;
+    inst |= mask;
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x9e01b057,
+        mmatch: 0xfe0ff07f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmv4r_v"),
+        operands: vec![(20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmv8r_v(vs2: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x9e03b057;
+    inst |= mask;
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x9e03b057,
+        mmatch: 0xfe0ff07f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmv8r_v"),
+        operands: vec![(20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmv_s_x(rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x42006057;
+    inst |= mask;
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x42006057,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmv_s_x"),
+        operands: vec![(15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmv_v_i(simm5: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x5e003057;
+    inst |= mask;
+    inst |= build_operand(simm5, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x5e003057,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmv_v_i"),
+        operands: vec![(15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SignedImm, OperandKind::DestReg, ],
+    };
+}
+pub fn vmv_v_v(vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x5e000057;
+    inst |= mask;
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x5e000057,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmv_v_v"),
+        operands: vec![(15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmv_v_x(rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x5e004057;
+    inst |= mask;
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x5e004057,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmv_v_x"),
+        operands: vec![(15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmv_x_s(vs2: u32,rd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x42002057;
+    inst |= mask;
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x42002057,
+        mmatch: 0xfe0ff07f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmv_x_s"),
+        operands: vec![(20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmxnor_mm(vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x7e002057;
+    inst |= mask;
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x7e002057,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmxnor_mm"),
+        operands: vec![(20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vmxor_mm(vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x6e002057;
+    inst |= mask;
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x6e002057,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vmxor_mm"),
+        operands: vec![(20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vnclip_wi(vm: u32,vs2: u32,simm5: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xbc003057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(simm5, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xbc003057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vnclip_wi"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
+    };
+}
+pub fn vnclip_wv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xbc000057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xbc000057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vnclip_wv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vnclip_wx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xbc004057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xbc004057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vnclip_wx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vnclipu_wi(vm: u32,vs2: u32,simm5: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xb8003057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(simm5, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xb8003057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vnclipu_wi"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
+    };
+}
+pub fn vnclipu_wv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xb8000057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xb8000057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vnclipu_wv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vnclipu_wx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xb8004057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xb8004057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vnclipu_wx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vnmsac_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xbc002057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xbc002057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vnmsac_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vnmsac_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xbc006057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xbc006057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vnmsac_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vnmsub_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xac002057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xac002057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vnmsub_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vnmsub_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xac006057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xac006057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vnmsub_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vnsra_wi(vm: u32,vs2: u32,simm5: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xb4003057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(simm5, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xb4003057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vnsra_wi"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
+    };
+}
+pub fn vnsra_wv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xb4000057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xb4000057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vnsra_wv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vnsra_wx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xb4004057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xb4004057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vnsra_wx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vnsrl_wi(vm: u32,vs2: u32,simm5: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xb0003057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(simm5, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xb0003057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vnsrl_wi"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
+    };
+}
+pub fn vnsrl_wv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xb0000057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xb0000057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vnsrl_wv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vnsrl_wx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xb0004057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xb0004057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vnsrl_wx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vor_vi(vm: u32,vs2: u32,simm5: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x28003057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(simm5, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x28003057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vor_vi"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
+    };
+}
+pub fn vor_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x28000057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x28000057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vor_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vor_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x28004057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x28004057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vor_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vredand_vs(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x4002057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x4002057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vredand_vs"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vredmax_vs(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x1c002057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x1c002057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vredmax_vs"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vredmaxu_vs(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x18002057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x18002057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vredmaxu_vs"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vredmin_vs(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x14002057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x14002057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vredmin_vs"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vredminu_vs(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x10002057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x10002057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vredminu_vs"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vredor_vs(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x8002057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x8002057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vredor_vs"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vredsum_vs(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x2057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x2057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vredsum_vs"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vredxor_vs(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xc002057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xc002057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vredxor_vs"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vrem_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x8c002057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x8c002057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vrem_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vrem_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x8c006057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x8c006057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vrem_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vremu_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x88002057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x88002057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vremu_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vremu_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x88006057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x88006057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vremu_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vrgather_vi(vm: u32,vs2: u32,simm5: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x30003057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(simm5, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x30003057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vrgather_vi"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
+    };
+}
+pub fn vrgather_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x30000057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x30000057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vrgather_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vrgather_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x30004057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x30004057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vrgather_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vrgatherei16_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x38000057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x38000057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vrgatherei16_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vrsub_vi(vm: u32,vs2: u32,simm5: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xc003057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(simm5, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xc003057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vrsub_vi"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
+    };
+}
+pub fn vrsub_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xc004057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xc004057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vrsub_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vs1r_v(rs1: u32,vs3: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x2800027;
+    inst |= mask;
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vs3, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x2800027,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vs1r_v"),
+        operands: vec![(15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn vs2r_v(rs1: u32,vs3: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x22800027;
+    inst |= mask;
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vs3, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x22800027,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vs2r_v"),
+        operands: vec![(15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn vs4r_v(rs1: u32,vs3: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x62800027;
+    inst |= mask;
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vs3, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x62800027,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vs4r_v"),
+        operands: vec![(15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn vs8r_v(rs1: u32,vs3: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xe2800027;
+    inst |= mask;
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vs3, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xe2800027,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vs8r_v"),
+        operands: vec![(15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn vsadd_vi(vm: u32,vs2: u32,simm5: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x84003057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(simm5, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x84003057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vsadd_vi"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
+    };
+}
+pub fn vsadd_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x84000057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x84000057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vsadd_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vsadd_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x84004057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x84004057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vsadd_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vsaddu_vi(vm: u32,vs2: u32,simm5: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x80003057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(simm5, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x80003057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vsaddu_vi"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
+    };
+}
+pub fn vsaddu_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x80000057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x80000057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vsaddu_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vsaddu_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x80004057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x80004057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vsaddu_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vsbc_vvm(vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x48000057;
+    inst |= mask;
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x48000057,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vsbc_vvm"),
+        operands: vec![(20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vsbc_vxm(vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x48004057;
+    inst |= mask;
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x48004057,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vsbc_vxm"),
+        operands: vec![(20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vse16_v(nf: u32,vm: u32,rs1: u32,vs3: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x5027;
+    inst |= mask;
+    inst |= build_operand(nf, 29, 31);
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vs3, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x5027,
+        mmatch: 0x1df0707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vse16_v"),
+        operands: vec![(29,31), (25,25), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn vse32_v(nf: u32,vm: u32,rs1: u32,vs3: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x6027;
+    inst |= mask;
+    inst |= build_operand(nf, 29, 31);
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vs3, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x6027,
+        mmatch: 0x1df0707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vse32_v"),
+        operands: vec![(29,31), (25,25), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn vse64_v(nf: u32,vm: u32,rs1: u32,vs3: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x7027;
+    inst |= mask;
+    inst |= build_operand(nf, 29, 31);
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vs3, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x7027,
+        mmatch: 0x1df0707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vse64_v"),
+        operands: vec![(29,31), (25,25), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn vse8_v(nf: u32,vm: u32,rs1: u32,vs3: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x27;
+    inst |= mask;
+    inst |= build_operand(nf, 29, 31);
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vs3, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x27,
+        mmatch: 0x1df0707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vse8_v"),
+        operands: vec![(29,31), (25,25), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn vsetivli(zimm10: u32,zimm: u32,rd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xc0007057;
+    inst |= mask;
+    inst |= build_operand(zimm10, 20, 29);
+    inst |= build_operand(zimm, 15, 19);
+    inst |= build_operand(rd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xc0007057,
+        mmatch: 0xc000707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vsetivli"),
+        operands: vec![(20,29), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::UnsignedImm, OperandKind::UnsignedImm, OperandKind::DestReg, ],
+    };
+}
+pub fn vsetvl(rs2: u32,rs1: u32,rd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x80007057;
+    inst |= mask;
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x80007057,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vsetvl"),
+        operands: vec![(20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vsetvli(zimm11: u32,rs1: u32,rd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x7057;
+    inst |= mask;
+    inst |= build_operand(zimm11, 20, 30);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x7057,
+        mmatch: 0x8000707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vsetvli"),
+        operands: vec![(20,30), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::UnsignedImm, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vsext_vf2(vm: u32,vs2: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x4803a057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x4803a057,
+        mmatch: 0xfc0ff07f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vsext_vf2"),
+        operands: vec![(25,25), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vsext_vf4(vm: u32,vs2: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x4802a057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x4802a057,
+        mmatch: 0xfc0ff07f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vsext_vf4"),
+        operands: vec![(25,25), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vsext_vf8(vm: u32,vs2: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x4801a057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x4801a057,
+        mmatch: 0xfc0ff07f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vsext_vf8"),
+        operands: vec![(25,25), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vslide1down_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x3c006057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x3c006057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vslide1down_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vslide1up_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x38006057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x38006057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vslide1up_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vslidedown_vi(vm: u32,vs2: u32,simm5: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x3c003057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(simm5, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x3c003057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vslidedown_vi"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
+    };
+}
+pub fn vslidedown_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x3c004057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x3c004057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vslidedown_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vslideup_vi(vm: u32,vs2: u32,simm5: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x38003057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(simm5, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x38003057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vslideup_vi"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
+    };
+}
+pub fn vslideup_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x38004057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x38004057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vslideup_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vsll_vi(vm: u32,vs2: u32,simm5: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x94003057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(simm5, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x94003057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vsll_vi"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
+    };
+}
+pub fn vsll_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x94000057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x94000057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vsll_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vsll_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x94004057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x94004057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vsll_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vsm_v(rs1: u32,vs3: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x2b00027;
+    inst |= mask;
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vs3, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x2b00027,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vsm_v"),
+        operands: vec![(15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn vsmul_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x9c000057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x9c000057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vsmul_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vsmul_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x9c004057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x9c004057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vsmul_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vsoxei16_v(nf: u32,vm: u32,vs2: u32,rs1: u32,vs3: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xc005027;
+    inst |= mask;
+    inst |= build_operand(nf, 29, 31);
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vs3, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xc005027,
+        mmatch: 0x1c00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vsoxei16_v"),
+        operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn vsoxei32_v(nf: u32,vm: u32,vs2: u32,rs1: u32,vs3: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xc006027;
+    inst |= mask;
+    inst |= build_operand(nf, 29, 31);
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vs3, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xc006027,
+        mmatch: 0x1c00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vsoxei32_v"),
+        operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn vsoxei64_v(nf: u32,vm: u32,vs2: u32,rs1: u32,vs3: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xc007027;
+    inst |= mask;
+    inst |= build_operand(nf, 29, 31);
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vs3, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xc007027,
+        mmatch: 0x1c00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vsoxei64_v"),
+        operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn vsoxei8_v(nf: u32,vm: u32,vs2: u32,rs1: u32,vs3: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xc000027;
+    inst |= mask;
+    inst |= build_operand(nf, 29, 31);
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vs3, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xc000027,
+        mmatch: 0x1c00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vsoxei8_v"),
+        operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn vsra_vi(vm: u32,vs2: u32,simm5: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xa4003057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(simm5, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xa4003057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vsra_vi"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
+    };
+}
+pub fn vsra_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xa4000057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xa4000057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vsra_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vsra_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xa4004057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xa4004057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vsra_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vsrl_vi(vm: u32,vs2: u32,simm5: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xa0003057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(simm5, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xa0003057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vsrl_vi"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
+    };
+}
+pub fn vsrl_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xa0000057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xa0000057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vsrl_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vsrl_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xa0004057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xa0004057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vsrl_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vsse16_v(nf: u32,vm: u32,rs2: u32,rs1: u32,vs3: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x8005027;
+    inst |= mask;
+    inst |= build_operand(nf, 29, 31);
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vs3, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x8005027,
+        mmatch: 0x1c00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vsse16_v"),
+        operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn vsse32_v(nf: u32,vm: u32,rs2: u32,rs1: u32,vs3: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x8006027;
+    inst |= mask;
+    inst |= build_operand(nf, 29, 31);
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vs3, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x8006027,
+        mmatch: 0x1c00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vsse32_v"),
+        operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn vsse64_v(nf: u32,vm: u32,rs2: u32,rs1: u32,vs3: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x8007027;
+    inst |= mask;
+    inst |= build_operand(nf, 29, 31);
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vs3, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x8007027,
+        mmatch: 0x1c00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vsse64_v"),
+        operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn vsse8_v(nf: u32,vm: u32,rs2: u32,rs1: u32,vs3: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x8000027;
+    inst |= mask;
+    inst |= build_operand(nf, 29, 31);
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(rs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vs3, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x8000027,
+        mmatch: 0x1c00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vsse8_v"),
+        operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn vssra_vi(vm: u32,vs2: u32,simm5: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xac003057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(simm5, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xac003057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vssra_vi"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
+    };
+}
+pub fn vssra_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xac000057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xac000057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vssra_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vssra_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xac004057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xac004057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vssra_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vssrl_vi(vm: u32,vs2: u32,simm5: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xa8003057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(simm5, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xa8003057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vssrl_vi"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
+    };
+}
+pub fn vssrl_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xa8000057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xa8000057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vssrl_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vssrl_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xa8004057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xa8004057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vssrl_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vssub_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x8c000057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x8c000057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vssub_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vssub_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x8c004057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x8c004057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vssub_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vssubu_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x88000057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x88000057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vssubu_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vssubu_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x88004057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x88004057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vssubu_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vsub_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x8000057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x8000057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vsub_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vsub_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x8004057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x8004057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vsub_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vsuxei16_v(nf: u32,vm: u32,vs2: u32,rs1: u32,vs3: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x4005027;
+    inst |= mask;
+    inst |= build_operand(nf, 29, 31);
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vs3, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x4005027,
+        mmatch: 0x1c00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vsuxei16_v"),
+        operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn vsuxei32_v(nf: u32,vm: u32,vs2: u32,rs1: u32,vs3: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x4006027;
+    inst |= mask;
+    inst |= build_operand(nf, 29, 31);
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vs3, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x4006027,
+        mmatch: 0x1c00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vsuxei32_v"),
+        operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn vsuxei64_v(nf: u32,vm: u32,vs2: u32,rs1: u32,vs3: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x4007027;
+    inst |= mask;
+    inst |= build_operand(nf, 29, 31);
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vs3, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x4007027,
+        mmatch: 0x1c00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vsuxei64_v"),
+        operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn vsuxei8_v(nf: u32,vm: u32,vs2: u32,rs1: u32,vs3: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x4000027;
+    inst |= mask;
+    inst |= build_operand(nf, 29, 31);
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vs3, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x4000027,
+        mmatch: 0x1c00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vsuxei8_v"),
+        operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn vwadd_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xc4002057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xc4002057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vwadd_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vwadd_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xc4006057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xc4006057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vwadd_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vwadd_wv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xd4002057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xd4002057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vwadd_wv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vwadd_wx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xd4006057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xd4006057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vwadd_wx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vwaddu_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xc0002057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xc0002057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vwaddu_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vwaddu_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xc0006057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xc0006057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vwaddu_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vwaddu_wv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xd0002057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xd0002057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vwaddu_wv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vwaddu_wx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xd0006057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xd0006057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vwaddu_wx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vwmacc_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xf4002057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xf4002057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vwmacc_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vwmacc_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xf4006057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xf4006057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vwmacc_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vwmaccsu_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xfc002057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xfc002057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vwmaccsu_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vwmaccsu_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xfc006057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xfc006057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vwmaccsu_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vwmaccu_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xf0002057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xf0002057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vwmaccu_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vwmaccu_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xf0006057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xf0006057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vwmaccu_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vwmaccus_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xf8006057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xf8006057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vwmaccus_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vwmul_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xec002057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xec002057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vwmul_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vwmul_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xec006057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xec006057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vwmul_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vwmulsu_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xe8002057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xe8002057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vwmulsu_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vwmulsu_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xe8006057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xe8006057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vwmulsu_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vwmulu_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xe0002057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xe0002057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vwmulu_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vwmulu_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xe0006057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xe0006057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vwmulu_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vwredsum_vs(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xc4000057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xc4000057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vwredsum_vs"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vwredsumu_vs(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xc0000057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xc0000057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vwredsumu_vs"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vwsub_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xcc002057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xcc002057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vwsub_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vwsub_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xcc006057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xcc006057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vwsub_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vwsub_wv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xdc002057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xdc002057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vwsub_wv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vwsub_wx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xdc006057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xdc006057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vwsub_wx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vwsubu_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xc8002057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xc8002057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vwsubu_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vwsubu_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xc8006057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xc8006057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vwsubu_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vwsubu_wv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xd8002057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xd8002057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vwsubu_wv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vwsubu_wx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0xd8006057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0xd8006057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vwsubu_wx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vxor_vi(vm: u32,vs2: u32,simm5: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x2c003057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(simm5, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x2c003057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vxor_vi"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
+    };
+}
+pub fn vxor_vv(vm: u32,vs2: u32,vs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x2c000057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x2c000057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vxor_vv"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vxor_vx(vm: u32,vs2: u32,rs1: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x2c004057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x2c004057,
+        mmatch: 0xfc00707f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vxor_vx"),
+        operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vzext_vf2(vm: u32,vs2: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x48032057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x48032057,
+        mmatch: 0xfc0ff07f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vzext_vf2"),
+        operands: vec![(25,25), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vzext_vf4(vm: u32,vs2: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x48022057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x48022057,
+        mmatch: 0xfc0ff07f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vzext_vf4"),
+        operands: vec![(25,25), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn vzext_vf8(vm: u32,vs2: u32,vd: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x48012057;
+    inst |= mask;
+    inst |= build_operand(vm, 25, 25);
+    inst |= build_operand(vs2, 20, 24);
+    inst |= build_operand(vd, 7, 11);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x48012057,
+        mmatch: 0xfc0ff07f,
+        extension: String::from("rv_v"),
+        mnemonic: String::from("vzext_vf8"),
+        operands: vec![(25,25), (20,24), (7,11), ],
+        operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+    };
+}
+pub fn wfi() -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x10500073;
+    inst |= mask;
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x10500073,
+        mmatch: 0xffffffff,
+        extension: String::from("rv_system"),
+        mnemonic: String::from("wfi"),
+        operands: vec![],
+        operand_kinds: vec![],
+    };
+}
+pub fn xnor(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x40004033;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x40004033,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_zbb"),
+        mnemonic: String::from("xnor"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn xor(rd: u32,rs1: u32,rs2: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x4033;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(rs2, 20, 24);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x4033,
+        mmatch: 0xfe00707f,
+        extension: String::from("rv_i"),
+        mnemonic: String::from("xor"),
+        operands: vec![(7,11), (15,19), (20,24), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn xori(rd: u32,rs1: u32,imm12: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x4013;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+    inst |= build_operand(imm12, 20, 31);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x4013,
+        mmatch: 0x707f,
+        extension: String::from("rv_i"),
+        mnemonic: String::from("xori"),
+        operands: vec![(7,11), (15,19), (20,31), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+    };
+}
+pub fn zext_h(rd: u32,rs1: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x800403b;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x800403b,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv64_zbb"),
+        mnemonic: String::from("zext_h"),
+        operands: vec![(7,11), (15,19), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
+    };
+}
+pub fn zext_h_rv32(rd: u32,rs1: u32) -> Instruction
+{
+    let mut inst : u32 = 0;
+    let mask: u32 = 0x8004033;
+    inst |= mask;
+    inst |= build_operand(rd, 7, 11);
+    inst |= build_operand(rs1, 15, 19);
+
+    return Instruction{
+        instruction: inst as u64,
+        length: 4,
+        mask: 0x8004033,
+        mmatch: 0xfff0707f,
+        extension: String::from("rv32_zbb"),
+        mnemonic: String::from("zext_h_rv32"),
+        operands: vec![(7,11), (15,19), ],
+        operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
+    };
+}
+
+use lazy_static::lazy_static;
+lazy_static! {
+// Instructions of the rv64_i extension
+pub static ref RV64_I: Vec<Instruction> = {
+    vec![
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x1b,
+            mmatch: 0x707f,
+            extension: String::from("rv64_i"),
+            mnemonic: String::from("ADDIW"),
+            operands: vec![(7,11), (15,19), (20,31), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x3b,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv64_i"),
+            mnemonic: String::from("ADDW"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x3003,
+            mmatch: 0x707f,
+            extension: String::from("rv64_i"),
+            mnemonic: String::from("LD"),
+            operands: vec![(7,11), (15,19), (20,31), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x6003,
+            mmatch: 0x707f,
+            extension: String::from("rv64_i"),
+            mnemonic: String::from("LWU"),
+            operands: vec![(7,11), (15,19), (20,31), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x3023,
+            mmatch: 0x707f,
+            extension: String::from("rv64_i"),
+            mnemonic: String::from("SD"),
+            operands: vec![(25,31), (15,19), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::SignedImm, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x1013,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv64_i"),
+            mnemonic: String::from("SLLI"),
+            operands: vec![(7,11), (15,19), (20,25), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Shamt, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x101b,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv64_i"),
+            mnemonic: String::from("SLLIW"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Shamt, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x103b,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv64_i"),
+            mnemonic: String::from("SLLW"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x40005013,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv64_i"),
+            mnemonic: String::from("SRAI"),
+            operands: vec![(7,11), (15,19), (20,25), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Shamt, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x4000501b,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv64_i"),
+            mnemonic: String::from("SRAIW"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Shamt, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x4000503b,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv64_i"),
+            mnemonic: String::from("SRAW"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x5013,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv64_i"),
+            mnemonic: String::from("SRLI"),
+            operands: vec![(7,11), (15,19), (20,25), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Shamt, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x501b,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv64_i"),
+            mnemonic: String::from("SRLIW"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Shamt, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x503b,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv64_i"),
+            mnemonic: String::from("SRLW"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x4000003b,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv64_i"),
+            mnemonic: String::from("SUBW"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        ]
+    };
+// Instructions of the rv_i extension
+pub static ref RV_I: Vec<Instruction> = {
+    vec![
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x33,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_i"),
+            mnemonic: String::from("ADD"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x13,
+            mmatch: 0x707f,
+            extension: String::from("rv_i"),
+            mnemonic: String::from("ADDI"),
+            operands: vec![(7,11), (15,19), (20,31), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x7033,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_i"),
+            mnemonic: String::from("AND"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x7013,
+            mmatch: 0x707f,
+            extension: String::from("rv_i"),
+            mnemonic: String::from("ANDI"),
+            operands: vec![(7,11), (15,19), (20,31), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x17,
+            mmatch: 0x7f,
+            extension: String::from("rv_i"),
+            mnemonic: String::from("AUIPC"),
+            operands: vec![(7,11), (12,31), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::UnsignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x63,
+            mmatch: 0x707f,
+            extension: String::from("rv_i"),
+            mnemonic: String::from("BEQ"),
+            operands: vec![(25,31), (15,19), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::SignedImm, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x5063,
+            mmatch: 0x707f,
+            extension: String::from("rv_i"),
+            mnemonic: String::from("BGE"),
+            operands: vec![(25,31), (15,19), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::SignedImm, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x7063,
+            mmatch: 0x707f,
+            extension: String::from("rv_i"),
+            mnemonic: String::from("BGEU"),
+            operands: vec![(25,31), (15,19), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::SignedImm, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x4063,
+            mmatch: 0x707f,
+            extension: String::from("rv_i"),
+            mnemonic: String::from("BLT"),
+            operands: vec![(25,31), (15,19), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::SignedImm, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x6063,
+            mmatch: 0x707f,
+            extension: String::from("rv_i"),
+            mnemonic: String::from("BLTU"),
+            operands: vec![(25,31), (15,19), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::SignedImm, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x1063,
+            mmatch: 0x707f,
+            extension: String::from("rv_i"),
+            mnemonic: String::from("BNE"),
+            operands: vec![(25,31), (15,19), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::SignedImm, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x100073,
+            mmatch: 0xffffffff,
+            extension: String::from("rv_i"),
+            mnemonic: String::from("EBREAK"),
+            operands: vec![],
+            operand_kinds: vec![],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x73,
+            mmatch: 0xffffffff,
+            extension: String::from("rv_i"),
+            mnemonic: String::from("ECALL"),
+            operands: vec![],
+            operand_kinds: vec![],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xf,
+            mmatch: 0x707f,
+            extension: String::from("rv_i"),
+            mnemonic: String::from("FENCE"),
+            operands: vec![(28,31), (24,27), (20,23), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x8330000f,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv_i"),
+            mnemonic: String::from("FENCE_TSO"),
+            operands: vec![(15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x6f,
+            mmatch: 0x7f,
+            extension: String::from("rv_i"),
+            mnemonic: String::from("JAL"),
+            operands: vec![(7,11), (12,31), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x67,
+            mmatch: 0x707f,
+            extension: String::from("rv_i"),
+            mnemonic: String::from("JALR"),
+            operands: vec![(7,11), (15,19), (20,31), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x3,
+            mmatch: 0x707f,
+            extension: String::from("rv_i"),
+            mnemonic: String::from("LB"),
+            operands: vec![(7,11), (15,19), (20,31), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x4003,
+            mmatch: 0x707f,
+            extension: String::from("rv_i"),
+            mnemonic: String::from("LBU"),
+            operands: vec![(7,11), (15,19), (20,31), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x1003,
+            mmatch: 0x707f,
+            extension: String::from("rv_i"),
+            mnemonic: String::from("LH"),
+            operands: vec![(7,11), (15,19), (20,31), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x5003,
+            mmatch: 0x707f,
+            extension: String::from("rv_i"),
+            mnemonic: String::from("LHU"),
+            operands: vec![(7,11), (15,19), (20,31), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x37,
+            mmatch: 0x7f,
+            extension: String::from("rv_i"),
+            mnemonic: String::from("LUI"),
+            operands: vec![(7,11), (12,31), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::UnsignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x2003,
+            mmatch: 0x707f,
+            extension: String::from("rv_i"),
+            mnemonic: String::from("LW"),
+            operands: vec![(7,11), (15,19), (20,31), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x6033,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_i"),
+            mnemonic: String::from("OR"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x6013,
+            mmatch: 0x707f,
+            extension: String::from("rv_i"),
+            mnemonic: String::from("ORI"),
+            operands: vec![(7,11), (15,19), (20,31), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x100000f,
+            mmatch: 0xffffffff,
+            extension: String::from("rv_i"),
+            mnemonic: String::from("PAUSE"),
+            operands: vec![],
+            operand_kinds: vec![],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x23,
+            mmatch: 0x707f,
+            extension: String::from("rv_i"),
+            mnemonic: String::from("SB"),
+            operands: vec![(25,31), (15,19), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::SignedImm, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x100073,
+            mmatch: 0xffffffff,
+            extension: String::from("rv_i"),
+            mnemonic: String::from("SBREAK"),
+            operands: vec![],
+            operand_kinds: vec![],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x73,
+            mmatch: 0xffffffff,
+            extension: String::from("rv_i"),
+            mnemonic: String::from("SCALL"),
+            operands: vec![],
+            operand_kinds: vec![],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x1023,
+            mmatch: 0x707f,
+            extension: String::from("rv_i"),
+            mnemonic: String::from("SH"),
+            operands: vec![(25,31), (15,19), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::SignedImm, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x1033,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_i"),
+            mnemonic: String::from("SLL"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x2033,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_i"),
+            mnemonic: String::from("SLT"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x2013,
+            mmatch: 0x707f,
+            extension: String::from("rv_i"),
+            mnemonic: String::from("SLTI"),
+            operands: vec![(7,11), (15,19), (20,31), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x3013,
+            mmatch: 0x707f,
+            extension: String::from("rv_i"),
+            mnemonic: String::from("SLTIU"),
+            operands: vec![(7,11), (15,19), (20,31), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x3033,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_i"),
+            mnemonic: String::from("SLTU"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x40005033,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_i"),
+            mnemonic: String::from("SRA"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x5033,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_i"),
+            mnemonic: String::from("SRL"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x40000033,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_i"),
+            mnemonic: String::from("SUB"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x2023,
+            mmatch: 0x707f,
+            extension: String::from("rv_i"),
+            mnemonic: String::from("SW"),
+            operands: vec![(25,31), (15,19), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::SignedImm, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x4033,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_i"),
+            mnemonic: String::from("XOR"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x4013,
+            mmatch: 0x707f,
+            extension: String::from("rv_i"),
+            mnemonic: String::from("XORI"),
+            operands: vec![(7,11), (15,19), (20,31), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+        },
+        ]
+    };
+// Instructions of the rv32_i extension
+pub static ref RV32_I: Vec<Instruction> = {
+    vec![
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x1013,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv32_i"),
+            mnemonic: String::from("SLLI_RV32"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Shamt, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x40005013,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv32_i"),
+            mnemonic: String::from("SRAI_RV32"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Shamt, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x5013,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv32_i"),
+            mnemonic: String::from("SRLI_RV32"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Shamt, ],
+        },
+        ]
+    };
+// Instructions of the rv64_m extension
+pub static ref RV64_M: Vec<Instruction> = {
+    vec![
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x200503b,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv64_m"),
+            mnemonic: String::from("DIVUW"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x200403b,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv64_m"),
+            mnemonic: String::from("DIVW"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x200003b,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv64_m"),
+            mnemonic: String::from("MULW"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x200703b,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv64_m"),
+            mnemonic: String::from("REMUW"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x200603b,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv64_m"),
+            mnemonic: String::from("REMW"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        ]
+    };
+// Instructions of the rv_m extension
+pub static ref RV_M: Vec<Instruction> = {
+    vec![
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x2004033,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_m"),
+            mnemonic: String::from("DIV"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x2005033,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_m"),
+            mnemonic: String::from("DIVU"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x2000033,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_m"),
+            mnemonic: String::from("MUL"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x2001033,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_m"),
+            mnemonic: String::from("MULH"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x2002033,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_m"),
+            mnemonic: String::from("MULHSU"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x2003033,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_m"),
+            mnemonic: String::from("MULHU"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x2006033,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_m"),
+            mnemonic: String::from("REM"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x2007033,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_m"),
+            mnemonic: String::from("REMU"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        ]
+    };
+// Instructions of the rv64_a extension
+pub static ref RV64_A: Vec<Instruction> = {
+    vec![
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x302f,
+            mmatch: 0xf800707f,
+            extension: String::from("rv64_a"),
+            mnemonic: String::from("AMOADD_D"),
+            operands: vec![(7,11), (15,19), (20,24), (25,26), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::Other, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x6000302f,
+            mmatch: 0xf800707f,
+            extension: String::from("rv64_a"),
+            mnemonic: String::from("AMOAND_D"),
+            operands: vec![(7,11), (15,19), (20,24), (25,26), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::Other, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xa000302f,
+            mmatch: 0xf800707f,
+            extension: String::from("rv64_a"),
+            mnemonic: String::from("AMOMAX_D"),
+            operands: vec![(7,11), (15,19), (20,24), (25,26), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::Other, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xe000302f,
+            mmatch: 0xf800707f,
+            extension: String::from("rv64_a"),
+            mnemonic: String::from("AMOMAXU_D"),
+            operands: vec![(7,11), (15,19), (20,24), (25,26), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::Other, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x8000302f,
+            mmatch: 0xf800707f,
+            extension: String::from("rv64_a"),
+            mnemonic: String::from("AMOMIN_D"),
+            operands: vec![(7,11), (15,19), (20,24), (25,26), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::Other, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xc000302f,
+            mmatch: 0xf800707f,
+            extension: String::from("rv64_a"),
+            mnemonic: String::from("AMOMINU_D"),
+            operands: vec![(7,11), (15,19), (20,24), (25,26), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::Other, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x4000302f,
+            mmatch: 0xf800707f,
+            extension: String::from("rv64_a"),
+            mnemonic: String::from("AMOOR_D"),
+            operands: vec![(7,11), (15,19), (20,24), (25,26), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::Other, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x800302f,
+            mmatch: 0xf800707f,
+            extension: String::from("rv64_a"),
+            mnemonic: String::from("AMOSWAP_D"),
+            operands: vec![(7,11), (15,19), (20,24), (25,26), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::Other, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x2000302f,
+            mmatch: 0xf800707f,
+            extension: String::from("rv64_a"),
+            mnemonic: String::from("AMOXOR_D"),
+            operands: vec![(7,11), (15,19), (20,24), (25,26), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::Other, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x1000302f,
+            mmatch: 0xf9f0707f,
+            extension: String::from("rv64_a"),
+            mnemonic: String::from("LR_D"),
+            operands: vec![(7,11), (15,19), (25,26), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Other, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x1800302f,
+            mmatch: 0xf800707f,
+            extension: String::from("rv64_a"),
+            mnemonic: String::from("SC_D"),
+            operands: vec![(7,11), (15,19), (20,24), (25,26), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::Other, ],
+        },
+        ]
+    };
+// Instructions of the rv_a extension
+pub static ref RV_A: Vec<Instruction> = {
+    vec![
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x202f,
+            mmatch: 0xf800707f,
+            extension: String::from("rv_a"),
+            mnemonic: String::from("AMOADD_W"),
+            operands: vec![(7,11), (15,19), (20,24), (25,26), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::Other, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x6000202f,
+            mmatch: 0xf800707f,
+            extension: String::from("rv_a"),
+            mnemonic: String::from("AMOAND_W"),
+            operands: vec![(7,11), (15,19), (20,24), (25,26), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::Other, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xa000202f,
+            mmatch: 0xf800707f,
+            extension: String::from("rv_a"),
+            mnemonic: String::from("AMOMAX_W"),
+            operands: vec![(7,11), (15,19), (20,24), (25,26), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::Other, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xe000202f,
+            mmatch: 0xf800707f,
+            extension: String::from("rv_a"),
+            mnemonic: String::from("AMOMAXU_W"),
+            operands: vec![(7,11), (15,19), (20,24), (25,26), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::Other, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x8000202f,
+            mmatch: 0xf800707f,
+            extension: String::from("rv_a"),
+            mnemonic: String::from("AMOMIN_W"),
+            operands: vec![(7,11), (15,19), (20,24), (25,26), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::Other, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xc000202f,
+            mmatch: 0xf800707f,
+            extension: String::from("rv_a"),
+            mnemonic: String::from("AMOMINU_W"),
+            operands: vec![(7,11), (15,19), (20,24), (25,26), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::Other, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x4000202f,
+            mmatch: 0xf800707f,
+            extension: String::from("rv_a"),
+            mnemonic: String::from("AMOOR_W"),
+            operands: vec![(7,11), (15,19), (20,24), (25,26), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::Other, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x800202f,
+            mmatch: 0xf800707f,
+            extension: String::from("rv_a"),
+            mnemonic: String::from("AMOSWAP_W"),
+            operands: vec![(7,11), (15,19), (20,24), (25,26), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::Other, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x2000202f,
+            mmatch: 0xf800707f,
+            extension: String::from("rv_a"),
+            mnemonic: String::from("AMOXOR_W"),
+            operands: vec![(7,11), (15,19), (20,24), (25,26), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::Other, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x1000202f,
+            mmatch: 0xf9f0707f,
+            extension: String::from("rv_a"),
+            mnemonic: String::from("LR_W"),
+            operands: vec![(7,11), (15,19), (25,26), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Other, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x1800202f,
+            mmatch: 0xf800707f,
+            extension: String::from("rv_a"),
+            mnemonic: String::from("SC_W"),
+            operands: vec![(7,11), (15,19), (20,24), (25,26), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::Other, ],
+        },
+        ]
+    };
+// Instructions of the rv64_f extension
+pub static ref RV64_F: Vec<Instruction> = {
+    vec![
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xc0200053,
+            mmatch: 0xfff0007f,
+            extension: String::from("rv64_f"),
+            mnemonic: String::from("FCVT_L_S"),
+            operands: vec![(7,11), (15,19), (12,14), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xc0300053,
+            mmatch: 0xfff0007f,
+            extension: String::from("rv64_f"),
+            mnemonic: String::from("FCVT_LU_S"),
+            operands: vec![(7,11), (15,19), (12,14), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xd0200053,
+            mmatch: 0xfff0007f,
+            extension: String::from("rv64_f"),
+            mnemonic: String::from("FCVT_S_L"),
+            operands: vec![(7,11), (15,19), (12,14), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xd0300053,
+            mmatch: 0xfff0007f,
+            extension: String::from("rv64_f"),
+            mnemonic: String::from("FCVT_S_LU"),
+            operands: vec![(7,11), (15,19), (12,14), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+        },
+        ]
+    };
+// Instructions of the rv_f extension
+pub static ref RV_F: Vec<Instruction> = {
+    vec![
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x53,
+            mmatch: 0xfe00007f,
+            extension: String::from("rv_f"),
+            mnemonic: String::from("FADD_S"),
+            operands: vec![(7,11), (15,19), (20,24), (12,14), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xe0001053,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv_f"),
+            mnemonic: String::from("FCLASS_S"),
+            operands: vec![(7,11), (15,19), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xd0000053,
+            mmatch: 0xfff0007f,
+            extension: String::from("rv_f"),
+            mnemonic: String::from("FCVT_S_W"),
+            operands: vec![(7,11), (15,19), (12,14), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xd0100053,
+            mmatch: 0xfff0007f,
+            extension: String::from("rv_f"),
+            mnemonic: String::from("FCVT_S_WU"),
+            operands: vec![(7,11), (15,19), (12,14), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xc0000053,
+            mmatch: 0xfff0007f,
+            extension: String::from("rv_f"),
+            mnemonic: String::from("FCVT_W_S"),
+            operands: vec![(7,11), (15,19), (12,14), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xc0100053,
+            mmatch: 0xfff0007f,
+            extension: String::from("rv_f"),
+            mnemonic: String::from("FCVT_WU_S"),
+            operands: vec![(7,11), (15,19), (12,14), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x18000053,
+            mmatch: 0xfe00007f,
+            extension: String::from("rv_f"),
+            mnemonic: String::from("FDIV_S"),
+            operands: vec![(7,11), (15,19), (20,24), (12,14), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xa0002053,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_f"),
+            mnemonic: String::from("FEQ_S"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xa0000053,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_f"),
+            mnemonic: String::from("FLE_S"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xa0001053,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_f"),
+            mnemonic: String::from("FLT_S"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x2007,
+            mmatch: 0x707f,
+            extension: String::from("rv_f"),
+            mnemonic: String::from("FLW"),
+            operands: vec![(7,11), (15,19), (20,31), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x43,
+            mmatch: 0x600007f,
+            extension: String::from("rv_f"),
+            mnemonic: String::from("FMADD_S"),
+            operands: vec![(7,11), (15,19), (20,24), (27,31), (12,14), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x28001053,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_f"),
+            mnemonic: String::from("FMAX_S"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x28000053,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_f"),
+            mnemonic: String::from("FMIN_S"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x47,
+            mmatch: 0x600007f,
+            extension: String::from("rv_f"),
+            mnemonic: String::from("FMSUB_S"),
+            operands: vec![(7,11), (15,19), (20,24), (27,31), (12,14), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x10000053,
+            mmatch: 0xfe00007f,
+            extension: String::from("rv_f"),
+            mnemonic: String::from("FMUL_S"),
+            operands: vec![(7,11), (15,19), (20,24), (12,14), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xf0000053,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv_f"),
+            mnemonic: String::from("FMV_W_X"),
+            operands: vec![(7,11), (15,19), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xe0000053,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv_f"),
+            mnemonic: String::from("FMV_X_W"),
+            operands: vec![(7,11), (15,19), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x4f,
+            mmatch: 0x600007f,
+            extension: String::from("rv_f"),
+            mnemonic: String::from("FNMADD_S"),
+            operands: vec![(7,11), (15,19), (20,24), (27,31), (12,14), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x4b,
+            mmatch: 0x600007f,
+            extension: String::from("rv_f"),
+            mnemonic: String::from("FNMSUB_S"),
+            operands: vec![(7,11), (15,19), (20,24), (27,31), (12,14), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x20000053,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_f"),
+            mnemonic: String::from("FSGNJ_S"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x20001053,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_f"),
+            mnemonic: String::from("FSGNJN_S"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x20002053,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_f"),
+            mnemonic: String::from("FSGNJX_S"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x58000053,
+            mmatch: 0xfff0007f,
+            extension: String::from("rv_f"),
+            mnemonic: String::from("FSQRT_S"),
+            operands: vec![(7,11), (15,19), (12,14), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x8000053,
+            mmatch: 0xfe00007f,
+            extension: String::from("rv_f"),
+            mnemonic: String::from("FSUB_S"),
+            operands: vec![(7,11), (15,19), (20,24), (12,14), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x2027,
+            mmatch: 0x707f,
+            extension: String::from("rv_f"),
+            mnemonic: String::from("FSW"),
+            operands: vec![(25,31), (15,19), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::SignedImm, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+        },
+        ]
+    };
+// Instructions of the rv64_d extension
+pub static ref RV64_D: Vec<Instruction> = {
+    vec![
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xd2200053,
+            mmatch: 0xfff0007f,
+            extension: String::from("rv64_d"),
+            mnemonic: String::from("FCVT_D_L"),
+            operands: vec![(7,11), (15,19), (12,14), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xd2300053,
+            mmatch: 0xfff0007f,
+            extension: String::from("rv64_d"),
+            mnemonic: String::from("FCVT_D_LU"),
+            operands: vec![(7,11), (15,19), (12,14), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xc2200053,
+            mmatch: 0xfff0007f,
+            extension: String::from("rv64_d"),
+            mnemonic: String::from("FCVT_L_D"),
+            operands: vec![(7,11), (15,19), (12,14), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xc2300053,
+            mmatch: 0xfff0007f,
+            extension: String::from("rv64_d"),
+            mnemonic: String::from("FCVT_LU_D"),
+            operands: vec![(7,11), (15,19), (12,14), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xf2000053,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv64_d"),
+            mnemonic: String::from("FMV_D_X"),
+            operands: vec![(7,11), (15,19), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xe2000053,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv64_d"),
+            mnemonic: String::from("FMV_X_D"),
+            operands: vec![(7,11), (15,19), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
+        },
+        ]
+    };
+// Instructions of the rv_d extension
+pub static ref RV_D: Vec<Instruction> = {
+    vec![
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x2000053,
+            mmatch: 0xfe00007f,
+            extension: String::from("rv_d"),
+            mnemonic: String::from("FADD_D"),
+            operands: vec![(7,11), (15,19), (20,24), (12,14), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xe2001053,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv_d"),
+            mnemonic: String::from("FCLASS_D"),
+            operands: vec![(7,11), (15,19), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x42000053,
+            mmatch: 0xfff0007f,
+            extension: String::from("rv_d"),
+            mnemonic: String::from("FCVT_D_S"),
+            operands: vec![(7,11), (15,19), (12,14), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xd2000053,
+            mmatch: 0xfff0007f,
+            extension: String::from("rv_d"),
+            mnemonic: String::from("FCVT_D_W"),
+            operands: vec![(7,11), (15,19), (12,14), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xd2100053,
+            mmatch: 0xfff0007f,
+            extension: String::from("rv_d"),
+            mnemonic: String::from("FCVT_D_WU"),
+            operands: vec![(7,11), (15,19), (12,14), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x40100053,
+            mmatch: 0xfff0007f,
+            extension: String::from("rv_d"),
+            mnemonic: String::from("FCVT_S_D"),
+            operands: vec![(7,11), (15,19), (12,14), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xc2000053,
+            mmatch: 0xfff0007f,
+            extension: String::from("rv_d"),
+            mnemonic: String::from("FCVT_W_D"),
+            operands: vec![(7,11), (15,19), (12,14), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xc2100053,
+            mmatch: 0xfff0007f,
+            extension: String::from("rv_d"),
+            mnemonic: String::from("FCVT_WU_D"),
+            operands: vec![(7,11), (15,19), (12,14), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x1a000053,
+            mmatch: 0xfe00007f,
+            extension: String::from("rv_d"),
+            mnemonic: String::from("FDIV_D"),
+            operands: vec![(7,11), (15,19), (20,24), (12,14), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xa2002053,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_d"),
+            mnemonic: String::from("FEQ_D"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x3007,
+            mmatch: 0x707f,
+            extension: String::from("rv_d"),
+            mnemonic: String::from("FLD"),
+            operands: vec![(7,11), (15,19), (20,31), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xa2000053,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_d"),
+            mnemonic: String::from("FLE_D"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xa2001053,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_d"),
+            mnemonic: String::from("FLT_D"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x2000043,
+            mmatch: 0x600007f,
+            extension: String::from("rv_d"),
+            mnemonic: String::from("FMADD_D"),
+            operands: vec![(7,11), (15,19), (20,24), (27,31), (12,14), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x2a001053,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_d"),
+            mnemonic: String::from("FMAX_D"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x2a000053,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_d"),
+            mnemonic: String::from("FMIN_D"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x2000047,
+            mmatch: 0x600007f,
+            extension: String::from("rv_d"),
+            mnemonic: String::from("FMSUB_D"),
+            operands: vec![(7,11), (15,19), (20,24), (27,31), (12,14), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x12000053,
+            mmatch: 0xfe00007f,
+            extension: String::from("rv_d"),
+            mnemonic: String::from("FMUL_D"),
+            operands: vec![(7,11), (15,19), (20,24), (12,14), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x200004f,
+            mmatch: 0x600007f,
+            extension: String::from("rv_d"),
+            mnemonic: String::from("FNMADD_D"),
+            operands: vec![(7,11), (15,19), (20,24), (27,31), (12,14), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x200004b,
+            mmatch: 0x600007f,
+            extension: String::from("rv_d"),
+            mnemonic: String::from("FNMSUB_D"),
+            operands: vec![(7,11), (15,19), (20,24), (27,31), (12,14), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x3027,
+            mmatch: 0x707f,
+            extension: String::from("rv_d"),
+            mnemonic: String::from("FSD"),
+            operands: vec![(25,31), (15,19), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::SignedImm, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x22000053,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_d"),
+            mnemonic: String::from("FSGNJ_D"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x22001053,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_d"),
+            mnemonic: String::from("FSGNJN_D"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x22002053,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_d"),
+            mnemonic: String::from("FSGNJX_D"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x5a000053,
+            mmatch: 0xfff0007f,
+            extension: String::from("rv_d"),
+            mnemonic: String::from("FSQRT_D"),
+            operands: vec![(7,11), (15,19), (12,14), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xa000053,
+            mmatch: 0xfe00007f,
+            extension: String::from("rv_d"),
+            mnemonic: String::from("FSUB_D"),
+            operands: vec![(7,11), (15,19), (20,24), (12,14), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::RoundingMode, ],
+        },
+        ]
+    };
+// Instructions of the rv64_c extension
+pub static ref RV64_C: Vec<Instruction> = {
+    vec![
+        Instruction {
+            instruction: 0 as u64,
+            length: 2,
+            mask: 0x2001,
+            mmatch: 0xe003,
+            extension: String::from("rv64_c"),
+            mnemonic: String::from("C_ADDIW"),
+            operands: vec![(7,11), (2,6), (12,12), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SignedImm, OperandKind::SignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 2,
+            mask: 0x9c21,
+            mmatch: 0xfc63,
+            extension: String::from("rv64_c"),
+            mnemonic: String::from("C_ADDW"),
+            operands: vec![(7,9), (2,4), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 2,
+            mask: 0x6000,
+            mmatch: 0xe003,
+            extension: String::from("rv64_c"),
+            mnemonic: String::from("C_LD"),
+            operands: vec![(2,4), (7,9), (5,6), (10,12), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::UnsignedImm, OperandKind::UnsignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 2,
+            mask: 0x6002,
+            mmatch: 0xe003,
+            extension: String::from("rv64_c"),
+            mnemonic: String::from("C_LDSP"),
+            operands: vec![(7,11), (12,12), (2,6), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::UnsignedImm, OperandKind::UnsignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 2,
+            mask: 0xe000,
+            mmatch: 0xe003,
+            extension: String::from("rv64_c"),
+            mnemonic: String::from("C_SD"),
+            operands: vec![(7,9), (2,4), (10,12), (5,6), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::UnsignedImm, OperandKind::UnsignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 2,
+            mask: 0xe002,
+            mmatch: 0xe003,
+            extension: String::from("rv64_c"),
+            mnemonic: String::from("C_SDSP"),
+            operands: vec![(2,6), (7,12), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::UnsignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 2,
+            mask: 0x2,
+            mmatch: 0xe003,
+            extension: String::from("rv64_c"),
+            mnemonic: String::from("C_SLLI"),
+            operands: vec![(7,11), (12,12), (2,6), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::Shamt, OperandKind::Shamt, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 2,
+            mask: 0x8401,
+            mmatch: 0xec03,
+            extension: String::from("rv64_c"),
+            mnemonic: String::from("C_SRAI"),
+            operands: vec![(7,9), (2,6), (12,12), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::Shamt, OperandKind::Shamt, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 2,
+            mask: 0x8001,
+            mmatch: 0xec03,
+            extension: String::from("rv64_c"),
+            mnemonic: String::from("C_SRLI"),
+            operands: vec![(7,9), (2,6), (12,12), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::Shamt, OperandKind::Shamt, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 2,
+            mask: 0x9c01,
+            mmatch: 0xfc63,
+            extension: String::from("rv64_c"),
+            mnemonic: String::from("C_SUBW"),
+            operands: vec![(7,9), (2,4), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
+        },
+        ]
+    };
+// Instructions of the rv_c extension
+pub static ref RV_C: Vec<Instruction> = {
+    vec![
+        Instruction {
+            instruction: 0 as u64,
+            length: 2,
+            mask: 0x9002,
+            mmatch: 0xf003,
+            extension: String::from("rv_c"),
+            mnemonic: String::from("C_ADD"),
+            operands: vec![(7,11), (2,6), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 2,
+            mask: 0x1,
+            mmatch: 0xe003,
+            extension: String::from("rv_c"),
+            mnemonic: String::from("C_ADDI"),
+            operands: vec![(7,11), (2,6), (12,12), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SignedImm, OperandKind::SignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 2,
+            mask: 0x6101,
+            mmatch: 0xef83,
+            extension: String::from("rv_c"),
+            mnemonic: String::from("C_ADDI16SP"),
+            operands: vec![(12,12), (2,6), ],
+            operand_kinds: vec![OperandKind::SignedImm, OperandKind::SignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 2,
+            mask: 0x0,
+            mmatch: 0xe003,
+            extension: String::from("rv_c"),
+            mnemonic: String::from("C_ADDI4SPN"),
+            operands: vec![(2,4), (5,12), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::UnsignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 2,
+            mask: 0x8c61,
+            mmatch: 0xfc63,
+            extension: String::from("rv_c"),
+            mnemonic: String::from("C_AND"),
+            operands: vec![(7,9), (2,4), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 2,
+            mask: 0x8801,
+            mmatch: 0xec03,
+            extension: String::from("rv_c"),
+            mnemonic: String::from("C_ANDI"),
+            operands: vec![(7,9), (12,12), (2,6), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SignedImm, OperandKind::SignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 2,
+            mask: 0xc001,
+            mmatch: 0xe003,
+            extension: String::from("rv_c"),
+            mnemonic: String::from("C_BEQZ"),
+            operands: vec![(7,9), (2,6), (10,12), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::SignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 2,
+            mask: 0xe001,
+            mmatch: 0xe003,
+            extension: String::from("rv_c"),
+            mnemonic: String::from("C_BNEZ"),
+            operands: vec![(7,9), (2,6), (10,12), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::SignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 2,
+            mask: 0x9002,
+            mmatch: 0xffff,
+            extension: String::from("rv_c"),
+            mnemonic: String::from("C_EBREAK"),
+            operands: vec![],
+            operand_kinds: vec![],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 2,
+            mask: 0xa001,
+            mmatch: 0xe003,
+            extension: String::from("rv_c"),
+            mnemonic: String::from("C_J"),
+            operands: vec![(2,12), ],
+            operand_kinds: vec![OperandKind::SignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 2,
+            mask: 0x9002,
+            mmatch: 0xf07f,
+            extension: String::from("rv_c"),
+            mnemonic: String::from("C_JALR"),
+            operands: vec![(7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 2,
+            mask: 0x8002,
+            mmatch: 0xf07f,
+            extension: String::from("rv_c"),
+            mnemonic: String::from("C_JR"),
+            operands: vec![(7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 2,
+            mask: 0x4001,
+            mmatch: 0xe003,
+            extension: String::from("rv_c"),
+            mnemonic: String::from("C_LI"),
+            operands: vec![(7,11), (2,6), (12,12), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SignedImm, OperandKind::SignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 2,
+            mask: 0x6001,
+            mmatch: 0xe003,
+            extension: String::from("rv_c"),
+            mnemonic: String::from("C_LUI"),
+            operands: vec![(7,11), (12,12), (2,6), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SignedImm, OperandKind::SignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 2,
+            mask: 0x4000,
+            mmatch: 0xe003,
+            extension: String::from("rv_c"),
+            mnemonic: String::from("C_LW"),
+            operands: vec![(2,4), (7,9), (5,6), (10,12), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::UnsignedImm, OperandKind::UnsignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 2,
+            mask: 0x4002,
+            mmatch: 0xe003,
+            extension: String::from("rv_c"),
+            mnemonic: String::from("C_LWSP"),
+            operands: vec![(7,11), (12,12), (2,6), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::UnsignedImm, OperandKind::UnsignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 2,
+            mask: 0x8002,
+            mmatch: 0xf003,
+            extension: String::from("rv_c"),
+            mnemonic: String::from("C_MV"),
+            operands: vec![(7,11), (2,6), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 2,
+            mask: 0x1,
+            mmatch: 0xef83,
+            extension: String::from("rv_c"),
+            mnemonic: String::from("C_NOP"),
+            operands: vec![(12,12), (2,6), ],
+            operand_kinds: vec![OperandKind::SignedImm, OperandKind::SignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 2,
+            mask: 0x8c41,
+            mmatch: 0xfc63,
+            extension: String::from("rv_c"),
+            mnemonic: String::from("C_OR"),
+            operands: vec![(7,9), (2,4), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 2,
+            mask: 0x8c01,
+            mmatch: 0xfc63,
+            extension: String::from("rv_c"),
+            mnemonic: String::from("C_SUB"),
+            operands: vec![(7,9), (2,4), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 2,
+            mask: 0xc000,
+            mmatch: 0xe003,
+            extension: String::from("rv_c"),
+            mnemonic: String::from("C_SW"),
+            operands: vec![(7,9), (2,4), (5,6), (10,12), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::UnsignedImm, OperandKind::UnsignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 2,
+            mask: 0xc002,
+            mmatch: 0xe003,
+            extension: String::from("rv_c"),
+            mnemonic: String::from("C_SWSP"),
+            operands: vec![(2,6), (7,12), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::UnsignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 2,
+            mask: 0x8c21,
+            mmatch: 0xfc63,
+            extension: String::from("rv_c"),
+            mnemonic: String::from("C_XOR"),
+            operands: vec![(7,9), (2,4), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
+        },
+        ]
+    };
+// Instructions of the rv32_c extension
+pub static ref RV32_C: Vec<Instruction> = {
+    vec![
+        Instruction {
+            instruction: 0 as u64,
+            length: 2,
+            mask: 0x2001,
+            mmatch: 0xe003,
+            extension: String::from("rv32_c"),
+            mnemonic: String::from("C_JAL"),
+            operands: vec![(2,12), ],
+            operand_kinds: vec![OperandKind::SignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 2,
+            mask: 0x2,
+            mmatch: 0xf003,
+            extension: String::from("rv32_c"),
+            mnemonic: String::from("C_SLLI_RV32"),
+            operands: vec![(7,11), (2,6), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::Shamt, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 2,
+            mask: 0x8401,
+            mmatch: 0xfc03,
+            extension: String::from("rv32_c"),
+            mnemonic: String::from("C_SRAI_RV32"),
+            operands: vec![(7,9), (2,6), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::Shamt, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 2,
+            mask: 0x8001,
+            mmatch: 0xfc03,
+            extension: String::from("rv32_c"),
+            mnemonic: String::from("C_SRLI_RV32"),
+            operands: vec![(7,9), (2,6), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::Shamt, ],
+        },
+        ]
+    };
+// Instructions of the rv_zicsr extension
+pub static ref RV_ZICSR: Vec<Instruction> = {
+    vec![
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x3073,
+            mmatch: 0x707f,
+            extension: String::from("rv_zicsr"),
+            mnemonic: String::from("CSRRC"),
+            operands: vec![(7,11), (15,19), (20,31), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Csr, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x7073,
+            mmatch: 0x707f,
+            extension: String::from("rv_zicsr"),
+            mnemonic: String::from("CSRRCI"),
+            operands: vec![(7,11), (20,31), (15,19), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::Csr, OperandKind::UnsignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x2073,
+            mmatch: 0x707f,
+            extension: String::from("rv_zicsr"),
+            mnemonic: String::from("CSRRS"),
+            operands: vec![(7,11), (15,19), (20,31), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Csr, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x6073,
+            mmatch: 0x707f,
+            extension: String::from("rv_zicsr"),
+            mnemonic: String::from("CSRRSI"),
+            operands: vec![(7,11), (20,31), (15,19), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::Csr, OperandKind::UnsignedImm, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x1073,
+            mmatch: 0x707f,
+            extension: String::from("rv_zicsr"),
+            mnemonic: String::from("CSRRW"),
+            operands: vec![(7,11), (15,19), (20,31), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Csr, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x5073,
+            mmatch: 0x707f,
+            extension: String::from("rv_zicsr"),
+            mnemonic: String::from("CSRRWI"),
+            operands: vec![(7,11), (20,31), (15,19), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::Csr, OperandKind::UnsignedImm, ],
+        },
+        ]
+    };
+// Instructions of the rv_zifencei extension
+pub static ref RV_ZIFENCEI: Vec<Instruction> = {
+    vec![
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x100f,
+            mmatch: 0x707f,
+            extension: String::from("rv_zifencei"),
+            mnemonic: String::from("FENCE_I"),
+            operands: vec![(20,31), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SignedImm, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        ]
+    };
+// Instructions of the rv64_zba extension
+pub static ref RV64_ZBA: Vec<Instruction> = {
+    vec![
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x800003b,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv64_zba"),
+            mnemonic: String::from("ADD_UW"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x2000203b,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv64_zba"),
+            mnemonic: String::from("SH1ADD_UW"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x2000403b,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv64_zba"),
+            mnemonic: String::from("SH2ADD_UW"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x2000603b,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv64_zba"),
+            mnemonic: String::from("SH3ADD_UW"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x800101b,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv64_zba"),
+            mnemonic: String::from("SLLI_UW"),
+            operands: vec![(7,11), (15,19), (20,25), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Shamt, ],
+        },
+        ]
+    };
+// Instructions of the rv_zba extension
+pub static ref RV_ZBA: Vec<Instruction> = {
+    vec![
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x20002033,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_zba"),
+            mnemonic: String::from("SH1ADD"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x20004033,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_zba"),
+            mnemonic: String::from("SH2ADD"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x20006033,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_zba"),
+            mnemonic: String::from("SH3ADD"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        ]
+    };
+// Instructions of the rv64_zbb extension
+pub static ref RV64_ZBB: Vec<Instruction> = {
+    vec![
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x6000101b,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv64_zbb"),
+            mnemonic: String::from("CLZW"),
+            operands: vec![(7,11), (15,19), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x6020101b,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv64_zbb"),
+            mnemonic: String::from("CPOPW"),
+            operands: vec![(7,11), (15,19), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x6010101b,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv64_zbb"),
+            mnemonic: String::from("CTZW"),
+            operands: vec![(7,11), (15,19), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x6b805013,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv64_zbb"),
+            mnemonic: String::from("REV8"),
+            operands: vec![(7,11), (15,19), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x6000103b,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv64_zbb"),
+            mnemonic: String::from("ROLW"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x60005013,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv64_zbb"),
+            mnemonic: String::from("RORI"),
+            operands: vec![(7,11), (15,19), (20,25), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Shamt, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x6000501b,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv64_zbb"),
+            mnemonic: String::from("RORIW"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Shamt, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x6000503b,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv64_zbb"),
+            mnemonic: String::from("RORW"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x800403b,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv64_zbb"),
+            mnemonic: String::from("ZEXT_H"),
+            operands: vec![(7,11), (15,19), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
+        },
+        ]
+    };
+// Instructions of the rv_zbb extension
+pub static ref RV_ZBB: Vec<Instruction> = {
+    vec![
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x40007033,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_zbb"),
+            mnemonic: String::from("ANDN"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x60001013,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv_zbb"),
+            mnemonic: String::from("CLZ"),
+            operands: vec![(7,11), (15,19), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x60201013,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv_zbb"),
+            mnemonic: String::from("CPOP"),
+            operands: vec![(7,11), (15,19), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x60101013,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv_zbb"),
+            mnemonic: String::from("CTZ"),
+            operands: vec![(7,11), (15,19), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xa006033,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_zbb"),
+            mnemonic: String::from("MAX"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xa007033,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_zbb"),
+            mnemonic: String::from("MAXU"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xa004033,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_zbb"),
+            mnemonic: String::from("MIN"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xa005033,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_zbb"),
+            mnemonic: String::from("MINU"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x28705013,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv_zbb"),
+            mnemonic: String::from("ORC_B"),
+            operands: vec![(7,11), (15,19), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x40006033,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_zbb"),
+            mnemonic: String::from("ORN"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x60001033,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_zbb"),
+            mnemonic: String::from("ROL"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x60005033,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_zbb"),
+            mnemonic: String::from("ROR"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x60401013,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv_zbb"),
+            mnemonic: String::from("SEXT_B"),
+            operands: vec![(7,11), (15,19), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x60501013,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv_zbb"),
+            mnemonic: String::from("SEXT_H"),
+            operands: vec![(7,11), (15,19), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x40004033,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_zbb"),
+            mnemonic: String::from("XNOR"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        ]
+    };
+// Instructions of the rv32_zbb extension
+pub static ref RV32_ZBB: Vec<Instruction> = {
+    vec![
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x69805013,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv32_zbb"),
+            mnemonic: String::from("REV8_RV32"),
+            operands: vec![(7,11), (15,19), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x60005013,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv32_zbb"),
+            mnemonic: String::from("RORI_RV32"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Shamt, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x8004033,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv32_zbb"),
+            mnemonic: String::from("ZEXT_H_RV32"),
+            operands: vec![(7,11), (15,19), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, ],
+        },
+        ]
+    };
+// Instructions of the rv64_zbs extension
+pub static ref RV64_ZBS: Vec<Instruction> = {
+    vec![
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x48001013,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv64_zbs"),
+            mnemonic: String::from("BCLRI"),
+            operands: vec![(7,11), (15,19), (20,25), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Shamt, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x48005013,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv64_zbs"),
+            mnemonic: String::from("BEXTI"),
+            operands: vec![(7,11), (15,19), (20,25), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Shamt, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x68001013,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv64_zbs"),
+            mnemonic: String::from("BINVI"),
+            operands: vec![(7,11), (15,19), (20,25), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Shamt, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x28001013,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv64_zbs"),
+            mnemonic: String::from("BSETI"),
+            operands: vec![(7,11), (15,19), (20,25), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Shamt, ],
+        },
+        ]
+    };
+// Instructions of the rv_zbs extension
+pub static ref RV_ZBS: Vec<Instruction> = {
+    vec![
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x48001033,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_zbs"),
+            mnemonic: String::from("BCLR"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x48005033,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_zbs"),
+            mnemonic: String::from("BEXT"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x68001033,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_zbs"),
+            mnemonic: String::from("BINV"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x28001033,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_zbs"),
+            mnemonic: String::from("BSET"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        ]
+    };
+// Instructions of the rv32_zbs extension
+pub static ref RV32_ZBS: Vec<Instruction> = {
+    vec![
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x48001013,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv32_zbs"),
+            mnemonic: String::from("BCLRI_RV32"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Shamt, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x48005013,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv32_zbs"),
+            mnemonic: String::from("BEXTI_RV32"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Shamt, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x68001013,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv32_zbs"),
+            mnemonic: String::from("BINVI_RV32"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Shamt, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x28001013,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv32_zbs"),
+            mnemonic: String::from("BSETI_RV32"),
+            operands: vec![(7,11), (15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::DestReg, OperandKind::SrcReg, OperandKind::Shamt, ],
+        },
+        ]
+    };
+// Instructions of the rv_v extension
+pub static ref RV_V: Vec<Instruction> = {
+    vec![
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x24002057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VAADD_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x24006057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VAADD_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x20002057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VAADDU_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x20006057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VAADDU_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x40003057,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VADC_VIM"),
+            operands: vec![(20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x40000057,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VADC_VVM"),
+            operands: vec![(20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x40004057,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VADC_VXM"),
+            operands: vec![(20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x3057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VADD_VI"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x57,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VADD_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x4057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VADD_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x24003057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VAND_VI"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x24000057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VAND_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x24004057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VAND_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x2c002057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VASUB_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x2c006057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VASUB_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x28002057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VASUBU_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x28006057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VASUBU_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x5e002057,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VCOMPRESS_VM"),
+            operands: vec![(20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x40082057,
+            mmatch: 0xfc0ff07f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VCPOP_M"),
+            operands: vec![(25,25), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x84002057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VDIV_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x84006057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VDIV_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x80002057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VDIVU_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x80006057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VDIVU_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x5057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFADD_VF"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x1057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFADD_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x4c081057,
+            mmatch: 0xfc0ff07f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFCLASS_V"),
+            operands: vec![(25,25), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x48019057,
+            mmatch: 0xfc0ff07f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFCVT_F_X_V"),
+            operands: vec![(25,25), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x48011057,
+            mmatch: 0xfc0ff07f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFCVT_F_XU_V"),
+            operands: vec![(25,25), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x48039057,
+            mmatch: 0xfc0ff07f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFCVT_RTZ_X_F_V"),
+            operands: vec![(25,25), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x48031057,
+            mmatch: 0xfc0ff07f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFCVT_RTZ_XU_F_V"),
+            operands: vec![(25,25), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x48009057,
+            mmatch: 0xfc0ff07f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFCVT_X_F_V"),
+            operands: vec![(25,25), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x48001057,
+            mmatch: 0xfc0ff07f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFCVT_XU_F_V"),
+            operands: vec![(25,25), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x80005057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFDIV_VF"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x80001057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFDIV_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x4008a057,
+            mmatch: 0xfc0ff07f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFIRST_M"),
+            operands: vec![(25,25), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xb0005057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFMACC_VF"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xb0001057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFMACC_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xa0005057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFMADD_VF"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xa0001057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFMADD_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x18005057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFMAX_VF"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x18001057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFMAX_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x5c005057,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFMERGE_VFM"),
+            operands: vec![(20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x10005057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFMIN_VF"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x10001057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFMIN_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xb8005057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFMSAC_VF"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xb8001057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFMSAC_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xa8005057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFMSUB_VF"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xa8001057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFMSUB_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x90005057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFMUL_VF"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x90001057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFMUL_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x42001057,
+            mmatch: 0xfe0ff07f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFMV_F_S"),
+            operands: vec![(20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x42005057,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFMV_S_F"),
+            operands: vec![(15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x5e005057,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFMV_V_F"),
+            operands: vec![(15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x480a1057,
+            mmatch: 0xfc0ff07f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFNCVT_F_F_W"),
+            operands: vec![(25,25), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x48099057,
+            mmatch: 0xfc0ff07f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFNCVT_F_X_W"),
+            operands: vec![(25,25), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x48091057,
+            mmatch: 0xfc0ff07f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFNCVT_F_XU_W"),
+            operands: vec![(25,25), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x480a9057,
+            mmatch: 0xfc0ff07f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFNCVT_ROD_F_F_W"),
+            operands: vec![(25,25), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x480b9057,
+            mmatch: 0xfc0ff07f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFNCVT_RTZ_X_F_W"),
+            operands: vec![(25,25), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x480b1057,
+            mmatch: 0xfc0ff07f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFNCVT_RTZ_XU_F_W"),
+            operands: vec![(25,25), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x48089057,
+            mmatch: 0xfc0ff07f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFNCVT_X_F_W"),
+            operands: vec![(25,25), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x48081057,
+            mmatch: 0xfc0ff07f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFNCVT_XU_F_W"),
+            operands: vec![(25,25), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xb4005057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFNMACC_VF"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xb4001057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFNMACC_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xa4005057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFNMADD_VF"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xa4001057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFNMADD_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xbc005057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFNMSAC_VF"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xbc001057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFNMSAC_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xac005057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFNMSUB_VF"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xac001057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFNMSUB_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x84005057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFRDIV_VF"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x4c029057,
+            mmatch: 0xfc0ff07f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFREC7_V"),
+            operands: vec![(25,25), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x1c001057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFREDMAX_VS"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x14001057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFREDMIN_VS"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xc001057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFREDOSUM_VS"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x4001057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFREDUSUM_VS"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x4c021057,
+            mmatch: 0xfc0ff07f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFRSQRT7_V"),
+            operands: vec![(25,25), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x9c005057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFRSUB_VF"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x20005057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFSGNJ_VF"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x20001057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFSGNJ_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x24005057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFSGNJN_VF"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x24001057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFSGNJN_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x28005057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFSGNJX_VF"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x28001057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFSGNJX_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x3c005057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFSLIDE1DOWN_VF"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x38005057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFSLIDE1UP_VF"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x4c001057,
+            mmatch: 0xfc0ff07f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFSQRT_V"),
+            operands: vec![(25,25), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x8005057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFSUB_VF"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x8001057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFSUB_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xc0005057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFWADD_VF"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xc0001057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFWADD_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xd0005057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFWADD_WF"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xd0001057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFWADD_WV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x48061057,
+            mmatch: 0xfc0ff07f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFWCVT_F_F_V"),
+            operands: vec![(25,25), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x48059057,
+            mmatch: 0xfc0ff07f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFWCVT_F_X_V"),
+            operands: vec![(25,25), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x48051057,
+            mmatch: 0xfc0ff07f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFWCVT_F_XU_V"),
+            operands: vec![(25,25), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x48079057,
+            mmatch: 0xfc0ff07f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFWCVT_RTZ_X_F_V"),
+            operands: vec![(25,25), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x48071057,
+            mmatch: 0xfc0ff07f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFWCVT_RTZ_XU_F_V"),
+            operands: vec![(25,25), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x48049057,
+            mmatch: 0xfc0ff07f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFWCVT_X_F_V"),
+            operands: vec![(25,25), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x48041057,
+            mmatch: 0xfc0ff07f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFWCVT_XU_F_V"),
+            operands: vec![(25,25), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xf0005057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFWMACC_VF"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xf0001057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFWMACC_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xf8005057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFWMSAC_VF"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xf8001057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFWMSAC_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xe0005057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFWMUL_VF"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xe0001057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFWMUL_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xf4005057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFWNMACC_VF"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xf4001057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFWNMACC_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xfc005057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFWNMSAC_VF"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xfc001057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFWNMSAC_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xcc001057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFWREDOSUM_VS"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xc4001057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFWREDUSUM_VS"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xc8005057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFWSUB_VF"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xc8001057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFWSUB_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xd8005057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFWSUB_WF"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xd8001057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VFWSUB_WV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x5008a057,
+            mmatch: 0xfdfff07f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VID_V"),
+            operands: vec![(25,25), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x50082057,
+            mmatch: 0xfc0ff07f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VIOTA_M"),
+            operands: vec![(25,25), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x2805007,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VL1RE16_V"),
+            operands: vec![(15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x2806007,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VL1RE32_V"),
+            operands: vec![(15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x2807007,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VL1RE64_V"),
+            operands: vec![(15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x2800007,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VL1RE8_V"),
+            operands: vec![(15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x22805007,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VL2RE16_V"),
+            operands: vec![(15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x22806007,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VL2RE32_V"),
+            operands: vec![(15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x22807007,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VL2RE64_V"),
+            operands: vec![(15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x22800007,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VL2RE8_V"),
+            operands: vec![(15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x62805007,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VL4RE16_V"),
+            operands: vec![(15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x62806007,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VL4RE32_V"),
+            operands: vec![(15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x62807007,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VL4RE64_V"),
+            operands: vec![(15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x62800007,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VL4RE8_V"),
+            operands: vec![(15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xe2805007,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VL8RE16_V"),
+            operands: vec![(15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xe2806007,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VL8RE32_V"),
+            operands: vec![(15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xe2807007,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VL8RE64_V"),
+            operands: vec![(15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xe2800007,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VL8RE8_V"),
+            operands: vec![(15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x5007,
+            mmatch: 0x1df0707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VLE16_V"),
+            operands: vec![(29,31), (25,25), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x1005007,
+            mmatch: 0x1df0707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VLE16FF_V"),
+            operands: vec![(29,31), (25,25), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x6007,
+            mmatch: 0x1df0707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VLE32_V"),
+            operands: vec![(29,31), (25,25), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x1006007,
+            mmatch: 0x1df0707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VLE32FF_V"),
+            operands: vec![(29,31), (25,25), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x7007,
+            mmatch: 0x1df0707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VLE64_V"),
+            operands: vec![(29,31), (25,25), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x1007007,
+            mmatch: 0x1df0707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VLE64FF_V"),
+            operands: vec![(29,31), (25,25), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x7,
+            mmatch: 0x1df0707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VLE8_V"),
+            operands: vec![(29,31), (25,25), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x1000007,
+            mmatch: 0x1df0707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VLE8FF_V"),
+            operands: vec![(29,31), (25,25), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x2b00007,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VLM_V"),
+            operands: vec![(15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xc005007,
+            mmatch: 0x1c00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VLOXEI16_V"),
+            operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xc006007,
+            mmatch: 0x1c00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VLOXEI32_V"),
+            operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xc007007,
+            mmatch: 0x1c00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VLOXEI64_V"),
+            operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xc000007,
+            mmatch: 0x1c00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VLOXEI8_V"),
+            operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x8005007,
+            mmatch: 0x1c00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VLSE16_V"),
+            operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x8006007,
+            mmatch: 0x1c00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VLSE32_V"),
+            operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x8007007,
+            mmatch: 0x1c00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VLSE64_V"),
+            operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x8000007,
+            mmatch: 0x1c00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VLSE8_V"),
+            operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x4005007,
+            mmatch: 0x1c00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VLUXEI16_V"),
+            operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x4006007,
+            mmatch: 0x1c00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VLUXEI32_V"),
+            operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x4007007,
+            mmatch: 0x1c00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VLUXEI64_V"),
+            operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x4000007,
+            mmatch: 0x1c00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VLUXEI8_V"),
+            operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xb4002057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMACC_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xb4006057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMACC_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x46003057,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMADC_VI"),
+            operands: vec![(20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x44003057,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMADC_VIM"),
+            operands: vec![(20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x46000057,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMADC_VV"),
+            operands: vec![(20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x44000057,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMADC_VVM"),
+            operands: vec![(20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x46004057,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMADC_VX"),
+            operands: vec![(20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x44004057,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMADC_VXM"),
+            operands: vec![(20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xa4002057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMADD_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xa4006057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMADD_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x66002057,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMAND_MM"),
+            operands: vec![(20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x62002057,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMANDN_MM"),
+            operands: vec![(20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x1c000057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMAX_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x1c004057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMAX_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x18000057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMAXU_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x18004057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMAXU_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x5c003057,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMERGE_VIM"),
+            operands: vec![(20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x5c000057,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMERGE_VVM"),
+            operands: vec![(20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x5c004057,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMERGE_VXM"),
+            operands: vec![(20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x60005057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMFEQ_VF"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x60001057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMFEQ_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x7c005057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMFGE_VF"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x74005057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMFGT_VF"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x64005057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMFLE_VF"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x64001057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMFLE_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x6c005057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMFLT_VF"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x6c001057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMFLT_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x70005057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMFNE_VF"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x70001057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMFNE_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x14000057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMIN_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x14004057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMIN_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x10000057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMINU_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x10004057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMINU_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x76002057,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMNAND_MM"),
+            operands: vec![(20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x7a002057,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMNOR_MM"),
+            operands: vec![(20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x6a002057,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMOR_MM"),
+            operands: vec![(20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x72002057,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMORN_MM"),
+            operands: vec![(20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x4e000057,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMSBC_VV"),
+            operands: vec![(20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x4c000057,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMSBC_VVM"),
+            operands: vec![(20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x4e004057,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMSBC_VX"),
+            operands: vec![(20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x4c004057,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMSBC_VXM"),
+            operands: vec![(20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x5000a057,
+            mmatch: 0xfc0ff07f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMSBF_M"),
+            operands: vec![(25,25), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x60003057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMSEQ_VI"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x60000057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMSEQ_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x60004057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMSEQ_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x7c003057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMSGT_VI"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x7c004057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMSGT_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x78003057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMSGTU_VI"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x78004057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMSGTU_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x5001a057,
+            mmatch: 0xfc0ff07f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMSIF_M"),
+            operands: vec![(25,25), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x74003057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMSLE_VI"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x74000057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMSLE_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x74004057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMSLE_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x70003057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMSLEU_VI"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x70000057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMSLEU_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x70004057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMSLEU_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x6c000057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMSLT_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x6c004057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMSLT_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x68000057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMSLTU_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x68004057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMSLTU_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x64003057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMSNE_VI"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x64000057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMSNE_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x64004057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMSNE_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x50012057,
+            mmatch: 0xfc0ff07f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMSOF_M"),
+            operands: vec![(25,25), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x94002057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMUL_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x94006057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMUL_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x9c002057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMULH_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x9c006057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMULH_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x98002057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMULHSU_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x98006057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMULHSU_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x90002057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMULHU_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x90006057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMULHU_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x9e003057,
+            mmatch: 0xfe0ff07f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMV1R_V"),
+            operands: vec![(20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x9e00b057,
+            mmatch: 0xfe0ff07f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMV2R_V"),
+            operands: vec![(20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x9e01b057,
+            mmatch: 0xfe0ff07f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMV4R_V"),
+            operands: vec![(20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x9e03b057,
+            mmatch: 0xfe0ff07f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMV8R_V"),
+            operands: vec![(20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x42006057,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMV_S_X"),
+            operands: vec![(15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x5e003057,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMV_V_I"),
+            operands: vec![(15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SignedImm, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x5e000057,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMV_V_V"),
+            operands: vec![(15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x5e004057,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMV_V_X"),
+            operands: vec![(15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x42002057,
+            mmatch: 0xfe0ff07f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMV_X_S"),
+            operands: vec![(20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x7e002057,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMXNOR_MM"),
+            operands: vec![(20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x6e002057,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VMXOR_MM"),
+            operands: vec![(20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xbc003057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VNCLIP_WI"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xbc000057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VNCLIP_WV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xbc004057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VNCLIP_WX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xb8003057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VNCLIPU_WI"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xb8000057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VNCLIPU_WV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xb8004057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VNCLIPU_WX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xbc002057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VNMSAC_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xbc006057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VNMSAC_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xac002057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VNMSUB_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xac006057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VNMSUB_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xb4003057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VNSRA_WI"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xb4000057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VNSRA_WV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xb4004057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VNSRA_WX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xb0003057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VNSRL_WI"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xb0000057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VNSRL_WV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xb0004057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VNSRL_WX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x28003057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VOR_VI"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x28000057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VOR_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x28004057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VOR_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x4002057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VREDAND_VS"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x1c002057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VREDMAX_VS"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x18002057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VREDMAXU_VS"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x33,
-            mmatch: 0xfe00707f,
-            extension: String::from("rv_i"),
-            mnemonic: String::from("ADD"),
-            operands: vec![(7,11), (15,19), (20,24), ],
+            mask: 0x14002057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VREDMIN_VS"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x13,
-            mmatch: 0x707f,
-            extension: String::from("rv_i"),
-            mnemonic: String::from("ADDI"),
-            operands: vec![(7,11), (15,19), (20,31), ],
+            mask: 0x10002057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VREDMINU_VS"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x1b,
-            mmatch: 0x707f,
-            extension: String::from("rv64_i"),
-            mnemonic: String::from("ADDIW"),
-            operands: vec![(7,11), (15,19), (20,31), ],
+            mask: 0x8002057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VREDOR_VS"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x3b,
-            mmatch: 0xfe00707f,
-            extension: String::from("rv64_i"),
-            mnemonic: String::from("ADDW"),
-            operands: vec![(7,11), (15,19), (20,24), ],
+            mask: 0x2057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VREDSUM_VS"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x7033,
-            mmatch: 0xfe00707f,
-            extension: String::from("rv_i"),
-            mnemonic: String::from("AND"),
-            operands: vec![(7,11), (15,19), (20,24), ],
+            mask: 0xc002057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VREDXOR_VS"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x7013,
-            mmatch: 0x707f,
-            extension: String::from("rv_i"),
-            mnemonic: String::from("ANDI"),
-            operands: vec![(7,11), (15,19), (20,31), ],
+            mask: 0x8c002057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VREM_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x17,
-            mmatch: 0x7f,
-            extension: String::from("rv_i"),
-            mnemonic: String::from("AUIPC"),
-            operands: vec![(7,11), (12,31), ],
+            mask: 0x8c006057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VREM_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x63,
-            mmatch: 0x707f,
-            extension: String::from("rv_i"),
-            mnemonic: String::from("BEQ"),
-            operands: vec![(25,31), (15,19), (20,24), (7,11), ],
+            mask: 0x88002057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VREMU_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x5063,
-            mmatch: 0x707f,
-            extension: String::from("rv_i"),
-            mnemonic: String::from("BGE"),
-            operands: vec![(25,31), (15,19), (20,24), (7,11), ],
+            mask: 0x88006057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VREMU_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x7063,
-            mmatch: 0x707f,
-            extension: String::from("rv_i"),
-            mnemonic: String::from("BGEU"),
-            operands: vec![(25,31), (15,19), (20,24), (7,11), ],
+            mask: 0x30003057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VRGATHER_VI"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x4063,
-            mmatch: 0x707f,
-            extension: String::from("rv_i"),
-            mnemonic: String::from("BLT"),
-            operands: vec![(25,31), (15,19), (20,24), (7,11), ],
+            mask: 0x30000057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VRGATHER_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x6063,
-            mmatch: 0x707f,
-            extension: String::from("rv_i"),
-            mnemonic: String::from("BLTU"),
-            operands: vec![(25,31), (15,19), (20,24), (7,11), ],
+            mask: 0x30004057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VRGATHER_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x1063,
-            mmatch: 0x707f,
-            extension: String::from("rv_i"),
-            mnemonic: String::from("BNE"),
-            operands: vec![(25,31), (15,19), (20,24), (7,11), ],
+            mask: 0x38000057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VRGATHEREI16_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
-            length: 2,
-            mask: 0x9002,
-            mmatch: 0xf003,
-            extension: String::from("rv_c"),
-            mnemonic: String::from("C_ADD"),
-            operands: vec![(7,11), (2,6), ],
+            length: 4,
+            mask: 0xc003057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VRSUB_VI"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
-            length: 2,
-            mask: 0x1,
-            mmatch: 0xe003,
-            extension: String::from("rv_c"),
-            mnemonic: String::from("C_ADDI"),
-            operands: vec![(7,11), (2,6), (12,12), ],
+            length: 4,
+            mask: 0xc004057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VRSUB_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
-            length: 2,
-            mask: 0x6101,
-            mmatch: 0xef83,
-            extension: String::from("rv_c"),
-            mnemonic: String::from("C_ADDI16SP"),
-            operands: vec![(12,12), (2,6), ],
+            length: 4,
+            mask: 0x2800027,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VS1R_V"),
+            operands: vec![(15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, ],
         },
         Instruction {
             instruction: 0 as u64,
-            length: 2,
-            mask: 0x0,
-            mmatch: 0xe003,
-            extension: String::from("rv_c"),
-            mnemonic: String::from("C_ADDI4SPN"),
-            operands: vec![(2,4), (5,12), ],
+            length: 4,
+            mask: 0x22800027,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VS2R_V"),
+            operands: vec![(15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, ],
         },
         Instruction {
             instruction: 0 as u64,
-            length: 2,
-            mask: 0x2001,
-            mmatch: 0xe003,
-            extension: String::from("rv64_c"),
-            mnemonic: String::from("C_ADDIW"),
-            operands: vec![(7,11), (2,6), (12,12), ],
+            length: 4,
+            mask: 0x62800027,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VS4R_V"),
+            operands: vec![(15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, ],
         },
         Instruction {
             instruction: 0 as u64,
-            length: 2,
-            mask: 0x9c21,
-            mmatch: 0xfc63,
-            extension: String::from("rv64_c"),
-            mnemonic: String::from("C_ADDW"),
-            operands: vec![(7,9), (2,4), ],
+            length: 4,
+            mask: 0xe2800027,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VS8R_V"),
+            operands: vec![(15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, ],
         },
         Instruction {
             instruction: 0 as u64,
-            length: 2,
-            mask: 0x8c61,
-            mmatch: 0xfc63,
-            extension: String::from("rv_c"),
-            mnemonic: String::from("C_AND"),
-            operands: vec![(7,9), (2,4), ],
+            length: 4,
+            mask: 0x84003057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSADD_VI"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
-            length: 2,
-            mask: 0x8801,
-            mmatch: 0xec03,
-            extension: String::from("rv_c"),
-            mnemonic: String::from("C_ANDI"),
-            operands: vec![(7,9), (12,12), (2,6), ],
+            length: 4,
+            mask: 0x84000057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSADD_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
-            length: 2,
-            mask: 0xc001,
-            mmatch: 0xe003,
-            extension: String::from("rv_c"),
-            mnemonic: String::from("C_BEQZ"),
-            operands: vec![(7,9), (2,6), (10,12), ],
+            length: 4,
+            mask: 0x84004057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSADD_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
-            length: 2,
-            mask: 0xe001,
-            mmatch: 0xe003,
-            extension: String::from("rv_c"),
-            mnemonic: String::from("C_BNEZ"),
-            operands: vec![(7,9), (2,6), (10,12), ],
+            length: 4,
+            mask: 0x80003057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSADDU_VI"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
-            length: 2,
-            mask: 0x9002,
-            mmatch: 0xffff,
-            extension: String::from("rv_c"),
-            mnemonic: String::from("C_EBREAK"),
-            operands: vec![],
+            length: 4,
+            mask: 0x80000057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSADDU_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
-            length: 2,
-            mask: 0xa001,
-            mmatch: 0xe003,
-            extension: String::from("rv_c"),
-            mnemonic: String::from("C_J"),
-            operands: vec![(2,12), ],
+            length: 4,
+            mask: 0x80004057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSADDU_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
-            length: 2,
-            mask: 0x2001,
-            mmatch: 0xe003,
-            extension: String::from("rv32_c"),
-            mnemonic: String::from("C_JAL"),
-            operands: vec![(2,12), ],
+            length: 4,
+            mask: 0x48000057,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSBC_VVM"),
+            operands: vec![(20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
-            length: 2,
-            mask: 0x9002,
-            mmatch: 0xf07f,
-            extension: String::from("rv_c"),
-            mnemonic: String::from("C_JALR"),
-            operands: vec![(7,11), ],
+            length: 4,
+            mask: 0x48004057,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSBC_VXM"),
+            operands: vec![(20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
-            length: 2,
-            mask: 0x8002,
-            mmatch: 0xf07f,
-            extension: String::from("rv_c"),
-            mnemonic: String::from("C_JR"),
-            operands: vec![(7,11), ],
+            length: 4,
+            mask: 0x5027,
+            mmatch: 0x1df0707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSE16_V"),
+            operands: vec![(29,31), (25,25), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, ],
         },
         Instruction {
             instruction: 0 as u64,
-            length: 2,
-            mask: 0x6000,
-            mmatch: 0xe003,
-            extension: String::from("rv64_c"),
-            mnemonic: String::from("C_LD"),
-            operands: vec![(2,4), (7,9), (5,6), (10,12), ],
+            length: 4,
+            mask: 0x6027,
+            mmatch: 0x1df0707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSE32_V"),
+            operands: vec![(29,31), (25,25), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, ],
         },
         Instruction {
             instruction: 0 as u64,
-            length: 2,
-            mask: 0x6002,
-            mmatch: 0xe003,
-            extension: String::from("rv64_c"),
-            mnemonic: String::from("C_LDSP"),
-            operands: vec![(7,11), (12,12), (2,6), ],
+            length: 4,
+            mask: 0x7027,
+            mmatch: 0x1df0707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSE64_V"),
+            operands: vec![(29,31), (25,25), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x27,
+            mmatch: 0x1df0707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSE8_V"),
+            operands: vec![(29,31), (25,25), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0xc0007057,
+            mmatch: 0xc000707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSETIVLI"),
+            operands: vec![(20,29), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::UnsignedImm, OperandKind::UnsignedImm, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x80007057,
+            mmatch: 0xfe00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSETVL"),
+            operands: vec![(20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x7057,
+            mmatch: 0x8000707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSETVLI"),
+            operands: vec![(20,30), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::UnsignedImm, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x4803a057,
+            mmatch: 0xfc0ff07f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSEXT_VF2"),
+            operands: vec![(25,25), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x4802a057,
+            mmatch: 0xfc0ff07f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSEXT_VF4"),
+            operands: vec![(25,25), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x4801a057,
+            mmatch: 0xfc0ff07f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSEXT_VF8"),
+            operands: vec![(25,25), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x3c006057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSLIDE1DOWN_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x38006057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSLIDE1UP_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x3c003057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSLIDEDOWN_VI"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
-            length: 2,
-            mask: 0x4001,
-            mmatch: 0xe003,
-            extension: String::from("rv_c"),
-            mnemonic: String::from("C_LI"),
-            operands: vec![(7,11), (2,6), (12,12), ],
+            length: 4,
+            mask: 0x3c004057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSLIDEDOWN_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
-            length: 2,
-            mask: 0x6001,
-            mmatch: 0xe003,
-            extension: String::from("rv_c"),
-            mnemonic: String::from("C_LUI"),
-            operands: vec![(7,11), (12,12), (2,6), ],
+            length: 4,
+            mask: 0x38003057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSLIDEUP_VI"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
-            length: 2,
-            mask: 0x4000,
-            mmatch: 0xe003,
-            extension: String::from("rv_c"),
-            mnemonic: String::from("C_LW"),
-            operands: vec![(2,4), (7,9), (5,6), (10,12), ],
+            length: 4,
+            mask: 0x38004057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSLIDEUP_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
-            length: 2,
-            mask: 0x4002,
-            mmatch: 0xe003,
-            extension: String::from("rv_c"),
-            mnemonic: String::from("C_LWSP"),
-            operands: vec![(7,11), (12,12), (2,6), ],
+            length: 4,
+            mask: 0x94003057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSLL_VI"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
-            length: 2,
-            mask: 0x8002,
-            mmatch: 0xf003,
-            extension: String::from("rv_c"),
-            mnemonic: String::from("C_MV"),
-            operands: vec![(7,11), (2,6), ],
+            length: 4,
+            mask: 0x94000057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSLL_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
-            length: 2,
-            mask: 0x1,
-            mmatch: 0xef83,
-            extension: String::from("rv_c"),
-            mnemonic: String::from("C_NOP"),
-            operands: vec![(12,12), (2,6), ],
+            length: 4,
+            mask: 0x94004057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSLL_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
-            length: 2,
-            mask: 0x8c41,
-            mmatch: 0xfc63,
-            extension: String::from("rv_c"),
-            mnemonic: String::from("C_OR"),
-            operands: vec![(7,9), (2,4), ],
+            length: 4,
+            mask: 0x2b00027,
+            mmatch: 0xfff0707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSM_V"),
+            operands: vec![(15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, ],
         },
         Instruction {
             instruction: 0 as u64,
-            length: 2,
-            mask: 0xe000,
-            mmatch: 0xe003,
-            extension: String::from("rv64_c"),
-            mnemonic: String::from("C_SD"),
-            operands: vec![(7,9), (2,4), (10,12), (5,6), ],
+            length: 4,
+            mask: 0x9c000057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSMUL_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
-            length: 2,
-            mask: 0xe002,
-            mmatch: 0xe003,
-            extension: String::from("rv64_c"),
-            mnemonic: String::from("C_SDSP"),
-            operands: vec![(2,6), (7,12), ],
+            length: 4,
+            mask: 0x9c004057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSMUL_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
-            length: 2,
-            mask: 0x2,
-            mmatch: 0xe003,
-            extension: String::from("rv64_c"),
-            mnemonic: String::from("C_SLLI"),
-            operands: vec![(7,11), (12,12), (2,6), ],
+            length: 4,
+            mask: 0xc005027,
+            mmatch: 0x1c00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSOXEI16_V"),
+            operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
         },
         Instruction {
             instruction: 0 as u64,
-            length: 2,
-            mask: 0x2,
-            mmatch: 0xf003,
-            extension: String::from("rv32_c"),
-            mnemonic: String::from("C_SLLI_RV32"),
-            operands: vec![(7,11), (2,6), ],
+            length: 4,
+            mask: 0xc006027,
+            mmatch: 0x1c00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSOXEI32_V"),
+            operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
         },
         Instruction {
             instruction: 0 as u64,
-            length: 2,
-            mask: 0x8401,
-            mmatch: 0xec03,
-            extension: String::from("rv64_c"),
-            mnemonic: String::from("C_SRAI"),
-            operands: vec![(7,9), (2,6), (12,12), ],
+            length: 4,
+            mask: 0xc007027,
+            mmatch: 0x1c00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSOXEI64_V"),
+            operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
         },
         Instruction {
             instruction: 0 as u64,
-            length: 2,
-            mask: 0x8401,
-            mmatch: 0xfc03,
-            extension: String::from("rv32_c"),
-            mnemonic: String::from("C_SRAI_RV32"),
-            operands: vec![(7,9), (2,6), ],
+            length: 4,
+            mask: 0xc000027,
+            mmatch: 0x1c00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSOXEI8_V"),
+            operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
         },
         Instruction {
             instruction: 0 as u64,
-            length: 2,
-            mask: 0x8001,
-            mmatch: 0xec03,
-            extension: String::from("rv64_c"),
-            mnemonic: String::from("C_SRLI"),
-            operands: vec![(7,9), (2,6), (12,12), ],
+            length: 4,
+            mask: 0xa4003057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSRA_VI"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
-            length: 2,
-            mask: 0x8001,
-            mmatch: 0xfc03,
-            extension: String::from("rv32_c"),
-            mnemonic: String::from("C_SRLI_RV32"),
-            operands: vec![(7,9), (2,6), ],
+            length: 4,
+            mask: 0xa4000057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSRA_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
-            length: 2,
-            mask: 0x8c01,
-            mmatch: 0xfc63,
-            extension: String::from("rv_c"),
-            mnemonic: String::from("C_SUB"),
-            operands: vec![(7,9), (2,4), ],
+            length: 4,
+            mask: 0xa4004057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSRA_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
-            length: 2,
-            mask: 0x9c01,
-            mmatch: 0xfc63,
-            extension: String::from("rv64_c"),
-            mnemonic: String::from("C_SUBW"),
-            operands: vec![(7,9), (2,4), ],
+            length: 4,
+            mask: 0xa0003057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSRL_VI"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
-            length: 2,
-            mask: 0xc000,
-            mmatch: 0xe003,
-            extension: String::from("rv_c"),
-            mnemonic: String::from("C_SW"),
-            operands: vec![(7,9), (2,4), (5,6), (10,12), ],
+            length: 4,
+            mask: 0xa0000057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSRL_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
-            length: 2,
-            mask: 0xc002,
-            mmatch: 0xe003,
-            extension: String::from("rv_c"),
-            mnemonic: String::from("C_SWSP"),
-            operands: vec![(2,6), (7,12), ],
+            length: 4,
+            mask: 0xa0004057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSRL_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
-            length: 2,
-            mask: 0x8c21,
-            mmatch: 0xfc63,
-            extension: String::from("rv_c"),
-            mnemonic: String::from("C_XOR"),
-            operands: vec![(7,9), (2,4), ],
+            length: 4,
+            mask: 0x8005027,
+            mmatch: 0x1c00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSSE16_V"),
+            operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x2004033,
-            mmatch: 0xfe00707f,
-            extension: String::from("rv_m"),
-            mnemonic: String::from("DIV"),
-            operands: vec![(7,11), (15,19), (20,24), ],
+            mask: 0x8006027,
+            mmatch: 0x1c00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSSE32_V"),
+            operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x2005033,
-            mmatch: 0xfe00707f,
-            extension: String::from("rv_m"),
-            mnemonic: String::from("DIVU"),
-            operands: vec![(7,11), (15,19), (20,24), ],
+            mask: 0x8007027,
+            mmatch: 0x1c00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSSE64_V"),
+            operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x200503b,
-            mmatch: 0xfe00707f,
-            extension: String::from("rv64_m"),
-            mnemonic: String::from("DIVUW"),
-            operands: vec![(7,11), (15,19), (20,24), ],
+            mask: 0x8000027,
+            mmatch: 0x1c00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSSE8_V"),
+            operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x200403b,
-            mmatch: 0xfe00707f,
-            extension: String::from("rv64_m"),
-            mnemonic: String::from("DIVW"),
-            operands: vec![(7,11), (15,19), (20,24), ],
+            mask: 0xac003057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSSRA_VI"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x100073,
-            mmatch: 0xffffffff,
-            extension: String::from("rv_i"),
-            mnemonic: String::from("EBREAK"),
-            operands: vec![],
+            mask: 0xac000057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSSRA_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x73,
-            mmatch: 0xffffffff,
-            extension: String::from("rv_i"),
-            mnemonic: String::from("ECALL"),
-            operands: vec![],
+            mask: 0xac004057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSSRA_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0xf,
-            mmatch: 0x707f,
-            extension: String::from("rv_i"),
-            mnemonic: String::from("FENCE"),
-            operands: vec![(28,31), (24,27), (20,23), (15,19), (7,11), ],
+            mask: 0xa8003057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSSRL_VI"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x8330000f,
-            mmatch: 0xfff0707f,
-            extension: String::from("rv_i"),
-            mnemonic: String::from("FENCE_TSO"),
-            operands: vec![(15,19), (7,11), ],
+            mask: 0xa8000057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSSRL_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x6f,
-            mmatch: 0x7f,
-            extension: String::from("rv_i"),
-            mnemonic: String::from("JAL"),
-            operands: vec![(7,11), (12,31), ],
+            mask: 0xa8004057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSSRL_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x67,
-            mmatch: 0x707f,
-            extension: String::from("rv_i"),
-            mnemonic: String::from("JALR"),
-            operands: vec![(7,11), (15,19), (20,31), ],
+            mask: 0x8c000057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSSUB_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x3,
-            mmatch: 0x707f,
-            extension: String::from("rv_i"),
-            mnemonic: String::from("LB"),
-            operands: vec![(7,11), (15,19), (20,31), ],
+            mask: 0x8c004057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSSUB_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x4003,
-            mmatch: 0x707f,
-            extension: String::from("rv_i"),
-            mnemonic: String::from("LBU"),
-            operands: vec![(7,11), (15,19), (20,31), ],
+            mask: 0x88000057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSSUBU_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x3003,
-            mmatch: 0x707f,
-            extension: String::from("rv64_i"),
-            mnemonic: String::from("LD"),
-            operands: vec![(7,11), (15,19), (20,31), ],
+            mask: 0x88004057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSSUBU_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x1003,
-            mmatch: 0x707f,
-            extension: String::from("rv_i"),
-            mnemonic: String::from("LH"),
-            operands: vec![(7,11), (15,19), (20,31), ],
+            mask: 0x8000057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSUB_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x5003,
-            mmatch: 0x707f,
-            extension: String::from("rv_i"),
-            mnemonic: String::from("LHU"),
-            operands: vec![(7,11), (15,19), (20,31), ],
+            mask: 0x8004057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSUB_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x37,
-            mmatch: 0x7f,
-            extension: String::from("rv_i"),
-            mnemonic: String::from("LUI"),
-            operands: vec![(7,11), (12,31), ],
+            mask: 0x4005027,
+            mmatch: 0x1c00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSUXEI16_V"),
+            operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x2003,
-            mmatch: 0x707f,
-            extension: String::from("rv_i"),
-            mnemonic: String::from("LW"),
-            operands: vec![(7,11), (15,19), (20,31), ],
+            mask: 0x4006027,
+            mmatch: 0x1c00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSUXEI32_V"),
+            operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x6003,
-            mmatch: 0x707f,
-            extension: String::from("rv64_i"),
-            mnemonic: String::from("LWU"),
-            operands: vec![(7,11), (15,19), (20,31), ],
+            mask: 0x4007027,
+            mmatch: 0x1c00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSUXEI64_V"),
+            operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x2000033,
-            mmatch: 0xfe00707f,
-            extension: String::from("rv_m"),
-            mnemonic: String::from("MUL"),
-            operands: vec![(7,11), (15,19), (20,24), ],
+            mask: 0x4000027,
+            mmatch: 0x1c00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VSUXEI8_V"),
+            operands: vec![(29,31), (25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::SrcReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x2001033,
-            mmatch: 0xfe00707f,
-            extension: String::from("rv_m"),
-            mnemonic: String::from("MULH"),
-            operands: vec![(7,11), (15,19), (20,24), ],
+            mask: 0xc4002057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VWADD_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x2002033,
-            mmatch: 0xfe00707f,
-            extension: String::from("rv_m"),
-            mnemonic: String::from("MULHSU"),
-            operands: vec![(7,11), (15,19), (20,24), ],
+            mask: 0xc4006057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VWADD_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x2003033,
-            mmatch: 0xfe00707f,
-            extension: String::from("rv_m"),
-            mnemonic: String::from("MULHU"),
-            operands: vec![(7,11), (15,19), (20,24), ],
+            mask: 0xd4002057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VWADD_WV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x200003b,
-            mmatch: 0xfe00707f,
-            extension: String::from("rv64_m"),
-            mnemonic: String::from("MULW"),
-            operands: vec![(7,11), (15,19), (20,24), ],
+            mask: 0xd4006057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VWADD_WX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x6033,
-            mmatch: 0xfe00707f,
-            extension: String::from("rv_i"),
-            mnemonic: String::from("OR"),
-            operands: vec![(7,11), (15,19), (20,24), ],
+            mask: 0xc0002057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VWADDU_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x6013,
-            mmatch: 0x707f,
-            extension: String::from("rv_i"),
-            mnemonic: String::from("ORI"),
-            operands: vec![(7,11), (15,19), (20,31), ],
+            mask: 0xc0006057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VWADDU_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x100000f,
-            mmatch: 0xffffffff,
-            extension: String::from("rv_i"),
-            mnemonic: String::from("PAUSE"),
-            operands: vec![],
+            mask: 0xd0002057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VWADDU_WV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x2006033,
-            mmatch: 0xfe00707f,
-            extension: String::from("rv_m"),
-            mnemonic: String::from("REM"),
-            operands: vec![(7,11), (15,19), (20,24), ],
+            mask: 0xd0006057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VWADDU_WX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x2007033,
-            mmatch: 0xfe00707f,
-            extension: String::from("rv_m"),
-            mnemonic: String::from("REMU"),
-            operands: vec![(7,11), (15,19), (20,24), ],
+            mask: 0xf4002057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VWMACC_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x200703b,
-            mmatch: 0xfe00707f,
-            extension: String::from("rv64_m"),
-            mnemonic: String::from("REMUW"),
-            operands: vec![(7,11), (15,19), (20,24), ],
+            mask: 0xf4006057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VWMACC_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x200603b,
-            mmatch: 0xfe00707f,
-            extension: String::from("rv64_m"),
-            mnemonic: String::from("REMW"),
-            operands: vec![(7,11), (15,19), (20,24), ],
+            mask: 0xfc002057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VWMACCSU_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x23,
-            mmatch: 0x707f,
-            extension: String::from("rv_i"),
-            mnemonic: String::from("SB"),
-            operands: vec![(25,31), (15,19), (20,24), (7,11), ],
+            mask: 0xfc006057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VWMACCSU_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x100073,
-            mmatch: 0xffffffff,
-            extension: String::from("rv_i"),
-            mnemonic: String::from("SBREAK"),
-            operands: vec![],
+            mask: 0xf0002057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VWMACCU_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x73,
-            mmatch: 0xffffffff,
-            extension: String::from("rv_i"),
-            mnemonic: String::from("SCALL"),
-            operands: vec![],
+            mask: 0xf0006057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VWMACCU_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x3023,
-            mmatch: 0x707f,
-            extension: String::from("rv64_i"),
-            mnemonic: String::from("SD"),
-            operands: vec![(25,31), (15,19), (20,24), (7,11), ],
+            mask: 0xf8006057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VWMACCUS_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x1023,
-            mmatch: 0x707f,
-            extension: String::from("rv_i"),
-            mnemonic: String::from("SH"),
-            operands: vec![(25,31), (15,19), (20,24), (7,11), ],
+            mask: 0xec002057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VWMUL_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x1033,
-            mmatch: 0xfe00707f,
-            extension: String::from("rv_i"),
-            mnemonic: String::from("SLL"),
-            operands: vec![(7,11), (15,19), (20,24), ],
+            mask: 0xec006057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VWMUL_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x1013,
+            mask: 0xe8002057,
             mmatch: 0xfc00707f,
-            extension: String::from("rv64_i"),
-            mnemonic: String::from("SLLI"),
-            operands: vec![(7,11), (15,19), (20,25), ],
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VWMULSU_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x1013,
-            mmatch: 0xfe00707f,
-            extension: String::from("rv32_i"),
-            mnemonic: String::from("SLLI_RV32"),
-            operands: vec![(7,11), (15,19), (20,24), ],
+            mask: 0xe8006057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VWMULSU_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x101b,
-            mmatch: 0xfe00707f,
-            extension: String::from("rv64_i"),
-            mnemonic: String::from("SLLIW"),
-            operands: vec![(7,11), (15,19), (20,24), ],
+            mask: 0xe0002057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VWMULU_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x103b,
-            mmatch: 0xfe00707f,
-            extension: String::from("rv64_i"),
-            mnemonic: String::from("SLLW"),
-            operands: vec![(7,11), (15,19), (20,24), ],
+            mask: 0xe0006057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VWMULU_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x2033,
-            mmatch: 0xfe00707f,
-            extension: String::from("rv_i"),
-            mnemonic: String::from("SLT"),
-            operands: vec![(7,11), (15,19), (20,24), ],
+            mask: 0xc4000057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VWREDSUM_VS"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x2013,
-            mmatch: 0x707f,
-            extension: String::from("rv_i"),
-            mnemonic: String::from("SLTI"),
-            operands: vec![(7,11), (15,19), (20,31), ],
+            mask: 0xc0000057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VWREDSUMU_VS"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x3013,
-            mmatch: 0x707f,
-            extension: String::from("rv_i"),
-            mnemonic: String::from("SLTIU"),
-            operands: vec![(7,11), (15,19), (20,31), ],
+            mask: 0xcc002057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VWSUB_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x3033,
-            mmatch: 0xfe00707f,
-            extension: String::from("rv_i"),
-            mnemonic: String::from("SLTU"),
-            operands: vec![(7,11), (15,19), (20,24), ],
+            mask: 0xcc006057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VWSUB_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x40005033,
-            mmatch: 0xfe00707f,
-            extension: String::from("rv_i"),
-            mnemonic: String::from("SRA"),
-            operands: vec![(7,11), (15,19), (20,24), ],
+            mask: 0xdc002057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VWSUB_WV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x40005013,
+            mask: 0xdc006057,
             mmatch: 0xfc00707f,
-            extension: String::from("rv64_i"),
-            mnemonic: String::from("SRAI"),
-            operands: vec![(7,11), (15,19), (20,25), ],
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VWSUB_WX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x40005013,
-            mmatch: 0xfe00707f,
-            extension: String::from("rv32_i"),
-            mnemonic: String::from("SRAI_RV32"),
-            operands: vec![(7,11), (15,19), (20,24), ],
+            mask: 0xc8002057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VWSUBU_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x4000501b,
-            mmatch: 0xfe00707f,
-            extension: String::from("rv64_i"),
-            mnemonic: String::from("SRAIW"),
-            operands: vec![(7,11), (15,19), (20,24), ],
+            mask: 0xc8006057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VWSUBU_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x4000503b,
-            mmatch: 0xfe00707f,
-            extension: String::from("rv64_i"),
-            mnemonic: String::from("SRAW"),
-            operands: vec![(7,11), (15,19), (20,24), ],
+            mask: 0xd8002057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VWSUBU_WV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x5033,
-            mmatch: 0xfe00707f,
-            extension: String::from("rv_i"),
-            mnemonic: String::from("SRL"),
-            operands: vec![(7,11), (15,19), (20,24), ],
+            mask: 0xd8006057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VWSUBU_WX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x5013,
+            mask: 0x2c003057,
             mmatch: 0xfc00707f,
-            extension: String::from("rv64_i"),
-            mnemonic: String::from("SRLI"),
-            operands: vec![(7,11), (15,19), (20,25), ],
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VXOR_VI"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SignedImm, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x5013,
-            mmatch: 0xfe00707f,
-            extension: String::from("rv32_i"),
-            mnemonic: String::from("SRLI_RV32"),
-            operands: vec![(7,11), (15,19), (20,24), ],
+            mask: 0x2c000057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VXOR_VV"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x501b,
-            mmatch: 0xfe00707f,
-            extension: String::from("rv64_i"),
-            mnemonic: String::from("SRLIW"),
-            operands: vec![(7,11), (15,19), (20,24), ],
+            mask: 0x2c004057,
+            mmatch: 0xfc00707f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VXOR_VX"),
+            operands: vec![(25,25), (20,24), (15,19), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x503b,
-            mmatch: 0xfe00707f,
-            extension: String::from("rv64_i"),
-            mnemonic: String::from("SRLW"),
-            operands: vec![(7,11), (15,19), (20,24), ],
+            mask: 0x48032057,
+            mmatch: 0xfc0ff07f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VZEXT_VF2"),
+            operands: vec![(25,25), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x40000033,
-            mmatch: 0xfe00707f,
-            extension: String::from("rv_i"),
-            mnemonic: String::from("SUB"),
-            operands: vec![(7,11), (15,19), (20,24), ],
+            mask: 0x48022057,
+            mmatch: 0xfc0ff07f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VZEXT_VF4"),
+            operands: vec![(25,25), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x4000003b,
-            mmatch: 0xfe00707f,
-            extension: String::from("rv64_i"),
-            mnemonic: String::from("SUBW"),
-            operands: vec![(7,11), (15,19), (20,24), ],
+            mask: 0x48012057,
+            mmatch: 0xfc0ff07f,
+            extension: String::from("rv_v"),
+            mnemonic: String::from("VZEXT_VF8"),
+            operands: vec![(25,25), (20,24), (7,11), ],
+            operand_kinds: vec![OperandKind::Other, OperandKind::SrcReg, OperandKind::DestReg, ],
+        },
+        ]
+    };
+// Instructions of the rv_system extension
+pub static ref RV_SYSTEM: Vec<Instruction> = {
+    vec![
+        Instruction {
+            instruction: 0 as u64,
+            length: 4,
+            mask: 0x30200073,
+            mmatch: 0xffffffff,
+            extension: String::from("rv_system"),
+            mnemonic: String::from("MRET"),
+            operands: vec![],
+            operand_kinds: vec![],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x2023,
-            mmatch: 0x707f,
-            extension: String::from("rv_i"),
-            mnemonic: String::from("SW"),
-            operands: vec![(25,31), (15,19), (20,24), (7,11), ],
+            mask: 0x12000073,
+            mmatch: 0xfe007fff,
+            extension: String::from("rv_system"),
+            mnemonic: String::from("SFENCE_VMA"),
+            operands: vec![(15,19), (20,24), ],
+            operand_kinds: vec![OperandKind::SrcReg, OperandKind::SrcReg, ],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x4033,
-            mmatch: 0xfe00707f,
-            extension: String::from("rv_i"),
-            mnemonic: String::from("XOR"),
-            operands: vec![(7,11), (15,19), (20,24), ],
+            mask: 0x10200073,
+            mmatch: 0xffffffff,
+            extension: String::from("rv_system"),
+            mnemonic: String::from("SRET"),
+            operands: vec![],
+            operand_kinds: vec![],
         },
         Instruction {
             instruction: 0 as u64,
             length: 4,
-            mask: 0x4013,
-            mmatch: 0x707f,
-            extension: String::from("rv_i"),
-            mnemonic: String::from("XORI"),
-            operands: vec![(7,11), (15,19), (20,31), ],
+            mask: 0x10500073,
+            mmatch: 0xffffffff,
+            extension: String::from("rv_system"),
+            mnemonic: String::from("WFI"),
+            operands: vec![],
+            operand_kinds: vec![],
         },
         ]
     };
+// Global Vec to hold instructions
+pub static ref INSTRUCTIONS: Vec<Instruction> = {
+    EXTENSIONS
+        .iter()
+        .flat_map(|ext| extension_instructions(ext).unwrap().iter().cloned())
+        .collect()
+    };
 }
 
-    
+/// The extensions with an instruction table, in decoding order: the first matching
+/// instruction wins, so RV64 encodings come before the RV32 ones they overlap
+pub const EXTENSIONS: &[&str] = &[
+    "rv64_i",
+    "rv_i",
+    "rv32_i",
+    "rv64_m",
+    "rv_m",
+    "rv64_a",
+    "rv_a",
+    "rv64_f",
+    "rv_f",
+    "rv64_d",
+    "rv_d",
+    "rv64_c",
+    "rv_c",
+    "rv32_c",
+    "rv_zicsr",
+    "rv_zifencei",
+    "rv64_zba",
+    "rv_zba",
+    "rv64_zbb",
+    "rv_zbb",
+    "rv32_zbb",
+    "rv64_zbs",
+    "rv_zbs",
+    "rv32_zbs",
+    "rv_v",
+    "rv_system",
+];
+
+/// The instruction table of an extension, named after its riscv-opcodes file (e.g. `rv64_f`)
+pub fn extension_instructions(extension: &str) -> Option<&'static [Instruction]> {
+    match extension {
+        "rv64_i" => Some(&RV64_I),
+        "rv_i" => Some(&RV_I),
+        "rv32_i" => Some(&RV32_I),
+        "rv64_m" => Some(&RV64_M),
+        "rv_m" => Some(&RV_M),
+        "rv64_a" => Some(&RV64_A),
+        "rv_a" => Some(&RV_A),
+        "rv64_f" => Some(&RV64_F),
+        "rv_f" => Some(&RV_F),
+        "rv64_d" => Some(&RV64_D),
+        "rv_d" => Some(&RV_D),
+        "rv64_c" => Some(&RV64_C),
+        "rv_c" => Some(&RV_C),
+        "rv32_c" => Some(&RV32_C),
+        "rv_zicsr" => Some(&RV_ZICSR),
+        "rv_zifencei" => Some(&RV_ZIFENCEI),
+        "rv64_zba" => Some(&RV64_ZBA),
+        "rv_zba" => Some(&RV_ZBA),
+        "rv64_zbb" => Some(&RV64_ZBB),
+        "rv_zbb" => Some(&RV_ZBB),
+        "rv32_zbb" => Some(&RV32_ZBB),
+        "rv64_zbs" => Some(&RV64_ZBS),
+        "rv_zbs" => Some(&RV_ZBS),
+        "rv32_zbs" => Some(&RV32_ZBS),
+        "rv_v" => Some(&RV_V),
+        "rv_system" => Some(&RV_SYSTEM),
+        _ => None,
+    }
+}
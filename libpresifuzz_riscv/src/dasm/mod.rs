@@ -6,6 +6,7 @@ pub mod spike_dasm;
 pub mod objdump_dasm;
 pub mod helper;
 pub mod gen;
+pub mod native_dasm;
 
 use std::ops::{Deref, DerefMut};
 
@@ -0,0 +1,540 @@
+// SPDX-FileCopyrightText: 2024 Intel Corporation
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! A disassembler decoding with the instruction tables of the [`CpuProfile`], without
+//! external process.
+//!
+//! The output follows the syntax of `spike-dasm`, as normalized for objdump by
+//! `ObjdumpDasm::translate_format`: `x` register names except `ra`, `sp`, `gp` and `tp`,
+//! decimal immediates, `pc + offset` targets, and the pseudo-instructions `nop`,
+//! `c.nop`, `li`, `snez`, `j`, `jr` and `ret`. Instructions outside the profile, and the
+//! compressed floating-point loads and stores, are `unknown`.
+
+use libafl::Error;
+
+use super::helper::{process_branch, reg_to_str_spike};
+use super::{Dasm, DasmInstruction, InstructionType, RiscvInstruction, RiscvInstructions};
+use crate::instruction::Instruction;
+use crate::profile::CpuProfile;
+
+/// ABI names of the floating-point registers
+pub(crate) const FPR_NAMES: [&str; 32] = [
+    "ft0", "ft1", "ft2", "ft3", "ft4", "ft5", "ft6", "ft7", "fs0", "fs1", "fa0", "fa1", "fa2", "fa3", "fa4", "fa5", "fa6",
+    "fa7", "fs2", "fs3", "fs4", "fs5", "fs6", "fs7", "fs8", "fs9", "fs10", "fs11", "ft8", "ft9", "ft10", "ft11",
+];
+
+/// Names of the CSRs printed by name, the others are printed in hexadecimal
+pub(crate) const CSR_NAMES: &[(u32, &str)] = &[
+    (0x001, "fflags"),
+    (0x002, "frm"),
+    (0x003, "fcsr"),
+    (0x004, "uie"),
+    (0x008, "vstart"),
+    (0x009, "vxsat"),
+    (0x00a, "vxrm"),
+    (0x00f, "vcsr"),
+    (0x100, "sstatus"),
+    (0x104, "sie"),
+    (0x105, "stvec"),
+    (0x106, "scounteren"),
+    (0x140, "sscratch"),
+    (0x141, "sepc"),
+    (0x142, "scause"),
+    (0x143, "stval"),
+    (0x144, "sip"),
+    (0x180, "satp"),
+    (0x300, "mstatus"),
+    (0x301, "misa"),
+    (0x302, "medeleg"),
+    (0x303, "mideleg"),
+    (0x304, "mie"),
+    (0x305, "mtvec"),
+    (0x306, "mcounteren"),
+    (0x340, "mscratch"),
+    (0x341, "mepc"),
+    (0x342, "mcause"),
+    (0x343, "mtval"),
+    (0x344, "mip"),
+    (0x3a0, "pmpcfg0"),
+    (0x3a1, "pmpcfg1"),
+    (0x3a2, "pmpcfg2"),
+    (0x3a3, "pmpcfg3"),
+    (0x7a0, "tselect"),
+    (0x7a1, "tdata1"),
+    (0x7a2, "tdata2"),
+    (0x7a3, "tdata3"),
+    (0x7b0, "dcsr"),
+    (0x7b1, "dpc"),
+    (0x7b2, "dscratch0"),
+    (0x7b3, "dscratch1"),
+    (0xb00, "mcycle"),
+    (0xb02, "minstret"),
+    (0xc00, "cycle"),
+    (0xc01, "time"),
+    (0xc02, "instret"),
+    (0xc20, "vl"),
+    (0xc21, "vtype"),
+    (0xc22, "vlenb"),
+    (0xf11, "mvendorid"),
+    (0xf12, "marchid"),
+    (0xf13, "mimpid"),
+    (0xf14, "mhartid"),
+];
+
+/// Disassembles with the instruction tables of a [`CpuProfile`], see the module
+/// documentation
+#[derive(Debug, Default)]
+pub struct NativeDasm {
+    profile: CpuProfile,
+}
+
+impl NativeDasm {
+    /// Creates a new [`NativeDasm`] decoding every known instruction
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only decode the instructions of `profile`
+    #[must_use]
+    pub fn with_profile(profile: CpuProfile) -> Self {
+        Self { profile }
+    }
+
+    /// The mnemonic and arguments of `word`, `None` when it is not in the profile
+    #[must_use]
+    pub fn disassemble(&self, word: u32) -> Option<(String, Option<String>)> {
+        let meta = self.decode(word)?;
+        let mut name = meta.mnemonic.to_lowercase();
+        for suffix in ["_rv32", "_rv64"] {
+            if let Some(stripped) = name.strip_suffix(suffix) {
+                name = stripped.to_string();
+            }
+        }
+        let name = name.replace('_', ".");
+
+        let (mnemonic, args) = if meta.length == 2 {
+            Self::compressed(&name, word)
+        } else {
+            Self::standard(meta, &name, word)
+        };
+        Some((mnemonic.unwrap_or(name), args))
+    }
+
+    /// The most specific instruction of the profile matching `word`, e.g. `c.ebreak`
+    /// rather than `c.add`
+    fn decode(&self, word: u32) -> Option<&'static Instruction> {
+        let word = if word & 3 == 3 { word } else { word & 0xffff };
+        self.profile
+            .instructions()
+            .filter(|meta| word & meta.mmatch == meta.mask && (meta.length == 4) == (word & 3 == 3))
+            .max_by_key(|meta| meta.mmatch.count_ones())
+    }
+
+    /// Mnemonic replaced by a pseudo-instruction, if any, and the arguments of a 32-bit
+    /// instruction
+    fn standard(meta: &Instruction, name: &str, w: u32) -> (Option<String>, Option<String>) {
+        let (rd, rs1, rs2, rs3) = ((w >> 7) & 31, (w >> 15) & 31, (w >> 20) & 31, (w >> 27) & 31);
+        let imm_i = (w as i32) >> 20;
+        let imm_s = (((w >> 25) << 5) | ((w >> 7) & 31)) as i32;
+        let imm_s = (imm_s << 20) >> 20;
+        let x = |reg: u32| reg_to_str_spike(reg as u8);
+        let f = |reg: u32| FPR_NAMES[reg as usize].to_string();
+        let has_field = |lsb: u32| meta.operands.iter().any(|(start, _)| *start == lsb);
+        let vector = meta.extension.ends_with("_v");
+
+        let args = match w & 0x7f {
+            // lui, auipc
+            0x37 | 0x17 => format!("{}, 0x{:x}", x(rd), w >> 12),
+            0x6f => {
+                let offset = Self::sext(
+                    ((w >> 31) << 20) | (((w >> 12) & 0xff) << 12) | (((w >> 20) & 1) << 11) | (((w >> 21) & 0x3ff) << 1),
+                    21,
+                );
+                return match rd {
+                    0 => (Some("j".to_string()), Some(Self::target(offset))),
+                    1 => (None, Some(Self::target(offset))),
+                    _ => (None, Some(format!("{}, {}", x(rd), Self::target(offset)))),
+                };
+            }
+            0x67 => {
+                return match (rd, rs1, imm_i) {
+                    (0, 1, 0) => (Some("ret".to_string()), None),
+                    (0, _, 0) => (Some("jr".to_string()), Some(x(rs1))),
+                    (0, _, _) => (Some("jr".to_string()), Some(format!("{imm_i}({})", x(rs1)))),
+                    _ => (None, Some(format!("{}, {}, {imm_i}", x(rd), x(rs1)))),
+                };
+            }
+            0x63 => {
+                let offset = Self::sext(
+                    ((w >> 31) << 12) | (((w >> 7) & 1) << 11) | (((w >> 25) & 0x3f) << 5) | (((w >> 8) & 0xf) << 1),
+                    13,
+                );
+                format!("{}, {}, {}", x(rs1), x(rs2), Self::target(offset))
+            }
+            0x03 => format!("{}, {imm_i}({})", x(rd), x(rs1)),
+            0x23 => format!("{}, {imm_s}({})", x(rs2), x(rs1)),
+            0x07 | 0x27 if vector => Self::vector(meta, name, w),
+            0x07 => format!("{}, {imm_i}({})", f(rd), x(rs1)),
+            0x27 => format!("{}, {imm_s}({})", f(rs2), x(rs1)),
+            0x13 | 0x1b => {
+                if name == "addi" && rd == 0 && rs1 == 0 && imm_i == 0 {
+                    return (Some("nop".to_string()), None);
+                }
+                if name == "addi" && rs1 == 0 {
+                    return (Some("li".to_string()), Some(format!("{}, {imm_i}", x(rd))));
+                }
+                if !has_field(20) {
+                    format!("{}, {}", x(rd), x(rs1))
+                } else if meta.operands.iter().any(|(start, end)| *start == 20 && *end < 31) {
+                    // shift amounts
+                    format!("{}, {}, {}", x(rd), x(rs1), (w >> 20) & 0x3f)
+                } else {
+                    format!("{}, {}, {imm_i}", x(rd), x(rs1))
+                }
+            }
+            0x33 | 0x3b => {
+                if name == "sltu" && rs1 == 0 {
+                    return (Some("snez".to_string()), Some(format!("{}, {}", x(rd), x(rs2))));
+                }
+                if has_field(20) {
+                    format!("{}, {}, {}", x(rd), x(rs1), x(rs2))
+                } else {
+                    format!("{}, {}", x(rd), x(rs1))
+                }
+            }
+            0x2f if name.starts_with("lr.") => format!("{}, ({})", x(rd), x(rs1)),
+            0x2f => format!("{}, {}, ({})", x(rd), x(rs2), x(rs1)),
+            0x73 => match (w >> 12) & 7 {
+                0 if name == "sfence.vma" => format!("{}, {}", x(rs1), x(rs2)),
+                0 => return (None, None),
+                funct3 => {
+                    let csr = w >> 20;
+                    let csr = CSR_NAMES
+                        .iter()
+                        .find(|(number, _)| *number == csr)
+                        .map_or_else(|| format!("0x{csr:x}"), |(_, csr)| (*csr).to_string());
+                    if funct3 >= 4 {
+                        format!("{}, {csr}, {rs1}", x(rd))
+                    } else {
+                        format!("{}, {csr}, {}", x(rd), x(rs1))
+                    }
+                }
+            },
+            0x0f if name == "fence" => {
+                let set = |bits: u32| {
+                    let set: String = "iorw"
+                        .chars()
+                        .enumerate()
+                        .filter(|(idx, _)| bits & (8 >> idx) != 0)
+                        .map(|(_, c)| c)
+                        .collect();
+                    if set.is_empty() {
+                        "0".to_string()
+                    } else {
+                        set
+                    }
+                };
+                format!("{}, {}", set((w >> 24) & 0xf), set((w >> 20) & 0xf))
+            }
+            0x0f => return (None, None),
+            0x43 | 0x47 | 0x4b | 0x4f => format!("{}, {}, {}, {}", f(rd), f(rs1), f(rs2), f(rs3)),
+            0x53 => {
+                let parts: Vec<&str> = name.split('.').collect();
+                let integer = |part: Option<&&str>| part.is_some_and(|part| matches!(*part, "w" | "wu" | "l" | "lu" | "x"));
+                let int_rd = matches!(parts[0], "feq" | "flt" | "fle" | "fclass")
+                    || (matches!(parts[0], "fcvt" | "fmv") && integer(parts.get(1)));
+                let int_rs1 = matches!(parts[0], "fcvt" | "fmv") && integer(parts.get(2));
+                let dest = if int_rd { x(rd) } else { f(rd) };
+                let src = if int_rs1 { x(rs1) } else { f(rs1) };
+                if has_field(20) {
+                    format!("{dest}, {src}, {}", f(rs2))
+                } else {
+                    format!("{dest}, {src}")
+                }
+            }
+            0x57 => Self::vector(meta, name, w),
+            _ => return (None, None),
+        };
+        (None, Some(args))
+    }
+
+    /// The arguments of a vector instruction: the destination, the vector sources, then
+    /// the scalar or immediate one, and the mask
+    fn vector(meta: &Instruction, name: &str, w: u32) -> String {
+        let (rd, rs1, rs2) = ((w >> 7) & 31, (w >> 15) & 31, (w >> 20) & 31);
+        let x = |reg: u32| reg_to_str_spike(reg as u8);
+        let has_field = |lsb: u32| meta.operands.iter().any(|(start, _)| *start == lsb);
+
+        let mut args = vec![];
+        if name.starts_with("vset") {
+            args.push(x(rd));
+            if name == "vsetivli" {
+                args.push(rs1.to_string());
+            } else {
+                args.push(x(rs1));
+            }
+            if name == "vsetvl" {
+                args.push(x(rs2));
+            } else {
+                args.push(format!("0x{:x}", (w >> 20) & 0x3ff));
+            }
+            return args.join(", ");
+        }
+
+        let destination = if name.starts_with("vmv.x") || name.starts_with("vcpop") || name.starts_with("vfirst") {
+            x(rd)
+        } else if name.starts_with("vfmv.f") {
+            FPR_NAMES[rd as usize].to_string()
+        } else {
+            format!("v{rd}")
+        };
+        if has_field(7) {
+            args.push(destination);
+        }
+
+        if matches!(w & 0x7f, 0x07 | 0x27) {
+            args.push(format!("({})", x(rs1)));
+            if has_field(20) {
+                // strides are scalar, indices are vectors
+                args.push(if name.contains("se") { x(rs2) } else { format!("v{rs2}") });
+            }
+        } else {
+            if has_field(20) {
+                args.push(format!("v{rs2}"));
+            }
+            if has_field(15) {
+                let source = if name.ends_with(".vx") || name.ends_with(".wx") || name.ends_with(".vxm") || name.ends_with(".x") {
+                    x(rs1)
+                } else if name.ends_with(".vf") || name.ends_with(".wf") || name.ends_with(".vfm") || name.ends_with(".f") {
+                    FPR_NAMES[rs1 as usize].to_string()
+                } else if name.ends_with(".vi") || name.ends_with(".wi") || name.ends_with(".vim") || name.ends_with(".i") {
+                    Self::sext(rs1, 5).to_string()
+                } else {
+                    format!("v{rs1}")
+                };
+                args.push(source);
+            }
+        }
+
+        if has_field(25) && (w >> 25) & 1 == 0 {
+            args.push("v0.t".to_string());
+        }
+        args.join(", ")
+    }
+
+    /// Mnemonic replaced by a pseudo-instruction, if any, and the arguments of a
+    /// compressed instruction
+    fn compressed(name: &str, w: u32) -> (Option<String>, Option<String>) {
+        let x = |reg: u32| reg_to_str_spike(reg as u8);
+        let rd = (w >> 7) & 31;
+        let rs2 = (w >> 2) & 31;
+        let rd_prime = ((w >> 2) & 7) + 8;
+        let rs1_prime = ((w >> 7) & 7) + 8;
+        let imm6 = Self::sext((((w >> 12) & 1) << 5) | ((w >> 2) & 31), 6);
+        let shamt = (((w >> 12) & 1) << 5) | ((w >> 2) & 31);
+
+        let args = match name {
+            "c.nop" | "c.addi" if rd == 0 && imm6 == 0 => return (Some("c.nop".to_string()), None),
+            "c.nop" => return (Some("c.addi".to_string()), Some(format!("x0, {imm6}"))),
+            "c.addi" | "c.addiw" | "c.li" => format!("{}, {imm6}", x(rd)),
+            "c.andi" => format!("{}, {imm6}", x(rs1_prime)),
+            "c.lui" => format!("{}, 0x{:x}", x(rd), (imm6 as u32) & 0xfffff),
+            "c.addi16sp" => {
+                let imm = (((w >> 12) & 1) << 9)
+                    | (((w >> 6) & 1) << 4)
+                    | (((w >> 5) & 1) << 6)
+                    | (((w >> 3) & 3) << 7)
+                    | (((w >> 2) & 1) << 5);
+                format!("sp, {}", Self::sext(imm, 10))
+            }
+            "c.addi4spn" => {
+                let imm = (((w >> 6) & 1) << 2) | (((w >> 5) & 1) << 3) | (((w >> 11) & 3) << 4) | (((w >> 7) & 0xf) << 6);
+                format!("{}, sp, {imm}", x(rd_prime))
+            }
+            "c.slli" => format!("{}, {shamt}", x(rd)),
+            "c.srli" | "c.srai" => format!("{}, {shamt}", x(rs1_prime)),
+            "c.sub" | "c.xor" | "c.or" | "c.and" | "c.subw" | "c.addw" => {
+                format!("{}, {}", x(rs1_prime), x(rd_prime))
+            }
+            "c.lw" | "c.sw" => {
+                let imm = (((w >> 10) & 7) << 3) | (((w >> 6) & 1) << 2) | (((w >> 5) & 1) << 6);
+                format!("{}, {imm}({})", x(rd_prime), x(rs1_prime))
+            }
+            "c.ld" | "c.sd" => {
+                let imm = (((w >> 10) & 7) << 3) | (((w >> 5) & 3) << 6);
+                format!("{}, {imm}({})", x(rd_prime), x(rs1_prime))
+            }
+            "c.lwsp" => {
+                let imm = (((w >> 12) & 1) << 5) | (((w >> 4) & 7) << 2) | (((w >> 2) & 3) << 6);
+                format!("{}, {imm}(sp)", x(rd))
+            }
+            "c.ldsp" => {
+                let imm = (((w >> 12) & 1) << 5) | (((w >> 5) & 3) << 3) | (((w >> 2) & 7) << 6);
+                format!("{}, {imm}(sp)", x(rd))
+            }
+            "c.swsp" => {
+                let imm = (((w >> 9) & 0xf) << 2) | (((w >> 7) & 3) << 6);
+                format!("{}, {imm}(sp)", x(rs2))
+            }
+            "c.sdsp" => {
+                let imm = (((w >> 10) & 7) << 3) | (((w >> 7) & 7) << 6);
+                format!("{}, {imm}(sp)", x(rs2))
+            }
+            "c.j" | "c.jal" => {
+                let offset = (((w >> 12) & 1) << 11)
+                    | (((w >> 11) & 1) << 4)
+                    | (((w >> 9) & 3) << 8)
+                    | (((w >> 8) & 1) << 10)
+                    | (((w >> 7) & 1) << 6)
+                    | (((w >> 6) & 1) << 7)
+                    | (((w >> 3) & 7) << 1)
+                    | (((w >> 2) & 1) << 5);
+                Self::target(Self::sext(offset, 12))
+            }
+            "c.beqz" | "c.bnez" => {
+                let offset = (((w >> 12) & 1) << 8)
+                    | (((w >> 10) & 3) << 3)
+                    | (((w >> 5) & 3) << 6)
+                    | (((w >> 3) & 3) << 1)
+                    | (((w >> 2) & 1) << 5);
+                format!("{}, {}", x(rs1_prime), Self::target(Self::sext(offset, 9)))
+            }
+            "c.jr" | "c.jalr" => x(rd),
+            "c.mv" | "c.add" => format!("{}, {}", x(rd), x(rs2)),
+            _ => return (None, None),
+        };
+        (None, Some(args))
+    }
+
+    /// A branch target in Spike's syntax, e.g. `pc + 8`
+    fn target(offset: i32) -> String {
+        if offset < 0 {
+            format!("pc - {}", offset.unsigned_abs())
+        } else {
+            format!("pc + {offset}")
+        }
+    }
+
+    /// `value` sign-extended from `bits` bits
+    fn sext(value: u32, bits: u32) -> i32 {
+        ((value << (32 - bits)) as i32) >> (32 - bits)
+    }
+}
+
+impl Dasm for NativeDasm {
+    fn process_single(&mut self, ins: &RiscvInstruction, address: u64) -> Result<DasmInstruction, Error> {
+        let (size, bytes) = match ins {
+            RiscvInstruction::U16(_) => (2, ins.be_value() & 0xffff),
+            RiscvInstruction::U32(_) => (4, ins.be_value()),
+        };
+
+        let Some((mnemonic, args)) = self.disassemble(bytes) else {
+            return Ok(DasmInstruction {
+                mnemonic: "unknown".to_string(),
+                args: None,
+                bytes: u64::from(bytes),
+                size,
+                ins_type: InstructionType::Normal,
+            });
+        };
+
+        let ins_type = process_branch(&mnemonic, args.clone(), address);
+        Ok(DasmInstruction {
+            mnemonic,
+            args,
+            bytes: u64::from(bytes),
+            size,
+            ins_type,
+        })
+    }
+
+    fn process_slice(&mut self, ins: &RiscvInstructions, address: u64) -> Result<Vec<(u64, DasmInstruction)>, Error> {
+        let mut ret = Vec::<(u64, DasmInstruction)>::with_capacity(ins.count());
+        let mut cur_address = address;
+
+        for i in ins.iter() {
+            let instruction = self.process_single(i, cur_address)?;
+            cur_address += u64::from(instruction.size);
+            ret.push((cur_address - u64::from(instruction.size), instruction));
+        }
+
+        Ok(ret)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    use super::NativeDasm;
+    use crate::dasm::gen::{gen_branch_instruction, gen_cbeqz_instruction, gen_cj_instruction, gen_jal_instruction};
+    use crate::dasm::objdump_dasm::ObjdumpDasm;
+    use crate::dasm::{Dasm, DasmInstruction, InstructionType, RiscvInstruction};
+    use crate::profile::CpuProfile;
+
+    fn dasm(disas: &mut NativeDasm, word: u32) -> DasmInstruction {
+        disas.process_single(&RiscvInstruction::from_be32(word), 0x1000).unwrap()
+    }
+
+    #[test]
+    fn native_dasm() {
+        let mut disas = NativeDasm::with_profile(CpuProfile::from_isa_string("rv64gc").unwrap());
+
+        // the outputs of spike-dasm in the SpikeDasm tests
+        for (word, mnemonic, args) in [
+            (0x49, "c.addi", "x0, 18"),
+            (0xaa, "c.slli", "ra, 10"),
+            (0xbb, "addw", "ra, x0, x0"),
+            (0x0049_3a73, "csrrc", "x20, uie, x18"),
+            (0x0000_8067, "ret", ""),
+            (0x3000_2573, "csrrs", "x10, mstatus, x0"),
+            (0xfe11_3c23, "sd", "ra, -8(sp)"),
+            (0x1234_52b7, "lui", "x5, 0x12345"),
+            (0x9002, "c.ebreak", ""),
+            (0x9282, "c.jalr", "x5"),
+            (0x0007_b007, "fld", "ft0, 0(x15)"),
+            (0xc000_7553, "fcvt.w.s", "x10, ft0"),
+        ] {
+            let instruction = dasm(&mut disas, word);
+            assert_eq!(instruction.mnemonic, mnemonic, "{word:x}");
+            assert_eq!(instruction.args.unwrap_or_default(), args, "{word:x}");
+        }
+
+        // the pseudo-instructions of translate_format
+        for (word, objdump, objdump_args) in [
+            (0x0001, "c.addi", "x0,0"),
+            (0x00a0_0293, "addi", "x5,x0,10"),
+            (0x0060_32b3, "sltu", "x5,x0,x6"),
+            (0x0002_8067, "jalr", "x0,0(x5)"),
+            (0x0100_006f, "jal", "x0,1010 <payload+0x10>"),
+            (0x2000, "c.fld", "fs0,0(s0)"),
+        ] {
+            let (mnemonic, _) = ObjdumpDasm::translate_format(objdump, Some(objdump_args));
+            assert_eq!(Some(dasm(&mut disas, word).mnemonic), mnemonic, "{word:x}");
+        }
+
+        // branch targets, as encoded by dasm::gen
+        for offset in [-64, -2, 8, 200] {
+            let (word, _) = gen_branch_instruction(5, 6, offset, 1, false).unwrap();
+            assert_eq!(dasm(&mut disas, word).ins_type, InstructionType::CondBranchRelativeCMP((5, 6, offset)));
+            let (word, _) = gen_jal_instruction(1, offset, false).unwrap();
+            assert_eq!(dasm(&mut disas, word).ins_type, InstructionType::BranchRelativeStore((1, offset)));
+            let (word, _) = gen_jal_instruction(0, offset, false).unwrap();
+            assert_eq!(dasm(&mut disas, word).ins_type, InstructionType::BranchRelativeStore((0, offset)));
+            let (word, _) = gen_cj_instruction(offset as i16, false).unwrap();
+            assert_eq!(dasm(&mut disas, word as u32).ins_type, InstructionType::BranchRelativeStore((0, offset)));
+            let (word, _) = gen_cbeqz_instruction(1, offset as i16, false).unwrap();
+            assert_eq!(dasm(&mut disas, word as u32).ins_type, InstructionType::CondBranchRelative((9, offset)));
+        }
+
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut disas = NativeDasm::new();
+        for _ in 0..4096 {
+            let word = rng.gen::<u32>();
+            let instruction = dasm(&mut disas, word);
+            assert_eq!(instruction.size, if word & 3 == 3 { 4 } else { 2 });
+        }
+    }
+}
@@ -35,31 +35,13 @@ impl ObjdumpDasm {
     }
 
     /* Spike uses a small subset of pseudo instructions. This function translates common patterns into the representation used by Spike. */
-    fn translate_format(mnemonic: &str, arg: Option<&str>) -> (Option<String>, Option<String>) {
+    pub(crate) fn translate_format(mnemonic: &str, arg: Option<&str>) -> (Option<String>, Option<String>) {
 
         match (mnemonic, arg) {
 
-            /* unsupported RISCV features (RV32F & RV64F - single-precision floating point instructions) */
-            ("flw", _) | ("fsw", _) | ("fmadd.s", _) | ("fmsub.s", _) 
-                | ("fnmsub.s", _) | ("fnmadd.s", _) | ("fadd.s", _) 
-                | ("fsub.s", _) | ("fmul.s", _) | ("fdiv.s" | "fsqrt.s", _) 
-                | ("fsgnj.s", _) | ("fsgnjn.s", _) | ("fsgnjx.s", _) 
-                | ("fmin.s", _) | ("fmax.s", _) | ("fcvt.w.s", _) 
-                | ("fcvt.wu.s", _) | ("fmv.x.w", _) | ("feq.s", _) | ("flt.s", _) 
-                | ("fle.s", _) | ("fclass.s", _) | ("fcvt.s.w", _) | ("fcvt.s.wu", _) 
-                | ("fmv.w.x", _) | ("fcvt.l.s", _) | ("fcvt.lu.s", _) | ("fcvt.s.l", _) 
-                | ("c.fld", _) | ("c.fldsp", _) | ("c.fsd", _) | ("c.fsdsp", _)
-                | ("fcvt.d.lu", _) => (Some("unknown".to_string()), None),
-
-            /* unsupported RISCV features (RV32D & RV64D - double-precision floating point instructions) */
-            ("fld", _) | ("fsd", _) | ("fmadd.d", _) | ("fmsub.d", _) | ("fnmsub.d", _) 
-                | ("fnmadd.d", _) | ("fadd.d", _) | ("fsub.d", _) | ("fmul.d", _) 
-                | ("fdiv.d", _) | ("fsqrt.d", _) | ("fsgnj.d", _) | ("fsgnjn.d", _) 
-                | ("fsgnjx.d", _) | ("fmin.d", _) | ("fmax.d", _) | ("fcvt.s.d", _) 
-                | ("fcvt.d.s", _) | ("feq.d", _) | ("flt.d", _) | ("fle.d", _) | ("fclass.d", _) 
-                | ("fcvt.w.d", _) | ("fcvt.wu.d", _) | ("fcvt.d.w", _) | ("fcvt.d.wu", _) 
-                | ("fcvt.l.d", _) | ("fcvt.lu.d", _) | ("fmv.x.d", _) | ("fcvt.d.l", _) 
-                | ("fmv.d.x", _) => (Some("unknown".to_string()), None),
+            /* unsupported RISCV features (compressed floating point instructions) */
+            ("c.flw", _) | ("c.flwsp", _) | ("c.fsw", _) | ("c.fswsp", _)
+                | ("c.fld", _) | ("c.fldsp", _) | ("c.fsd", _) | ("c.fsdsp", _) => (Some("unknown".to_string()), None),
 
             // todo add info on instruction size
             (".2byte", _) => (Some("unknown".to_string()), None),
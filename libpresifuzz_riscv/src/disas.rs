@@ -12,7 +12,7 @@ use crate::dasm::gen::gen_branch_instruction;
 use crate::dasm::DasmInstruction;
 use crate::dasm::objdump_dasm::ObjdumpDasm;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum CofiType {
     
     /* InstructionType::CondBranchRelative */
@@ -39,6 +39,23 @@ pub enum CofiType {
 
 }
 
+impl CofiType {
+    /// Whether this is a compressed instruction
+    pub fn is_compressed(&self) -> bool {
+        matches!(self, CofiType::CBEQZ | CofiType::CBNEZ | CofiType::CJ)
+    }
+
+    /// The largest distance in bytes between the instruction and its target
+    pub fn reach(&self) -> u64 {
+        match self {
+            CofiType::CBEQZ | CofiType::CBNEZ => 256,
+            CofiType::CJ => 2048,
+            CofiType::BEQ | CofiType::BNE | CofiType::BLT | CofiType::BGE | CofiType::BLTU | CofiType::BGEU => 4096,
+            CofiType::J | CofiType::JAL => 1 << 20,
+        }
+    }
+}
+
 impl FromStr for CofiType {
     type Err = ();
 
@@ -206,9 +223,13 @@ impl DisasDataTable {
     }
     
     pub fn from_riscv_ins(input: &RiscvInstructions, base_address: u64) ->  Result<Self, Error>{
+        Self::from_riscv_ins_with_dasm(input, base_address, Box::new(SpikeDasm::new()))
+    }
+
+    /* Disassemble with `disas` instead of spike-dasm, e.g. a NativeDasm. */
+    pub fn from_riscv_ins_with_dasm(input: &RiscvInstructions, base_address: u64, mut disas: Box<dyn Dasm>) ->  Result<Self, Error>{
         let mut result = IndexMap::<DisasId, DasmInstruction>::new();
         let cofi_table = IndexMap::<DisasId, CofiInstruction>::new();
-        let mut disas: Box<dyn Dasm> = Box::new(SpikeDasm::new());
 
         let instructions = disas.process_slice(input, base_address)?;
 
@@ -295,12 +316,12 @@ impl DisasDataTable {
 
             match instruction.ins_type {
                 InstructionType::CondBranchRelativeCMP(x) => {
-                    let cofi_type =  CofiType::from_str(&instruction.mnemonic).unwrap();
+                    let cofi_type = Self::cofi_type(&instruction.mnemonic)?;
 
                     /* TODO: check whether this will fail for target_address < address */
                     let target_address = ((*address as i64) + x.2 as i64) as u64;
 
-                    let id = cofi_table.get_id_by_address(*address).unwrap();
+                    let id = cofi_table.get_id_by_address(*address)?;
 
                     let target_id = cofi_table.get_id_by_address(target_address);
                     if target_id.is_ok() {
@@ -312,11 +333,11 @@ impl DisasDataTable {
                 }
 
                 InstructionType::CondBranchRelative(x) => {
-                    let cofi_type =  CofiType::from_str(&instruction.mnemonic).unwrap();
+                    let cofi_type = Self::cofi_type(&instruction.mnemonic)?;
 
                     let target_address = ((*address as i64) + x.1 as i64) as u64;
 
-                    let id = cofi_table.get_id_by_address(*address).unwrap();
+                    let id = cofi_table.get_id_by_address(*address)?;
 
                     let target_id = cofi_table.get_id_by_address(target_address);
                     if target_id.is_ok() {
@@ -329,10 +350,10 @@ impl DisasDataTable {
 
                 InstructionType::BranchRelativeStore(x) => {
         
-                    let cofi_type =  CofiType::from_str(&instruction.mnemonic).unwrap();
+                    let cofi_type = Self::cofi_type(&instruction.mnemonic)?;
                     let target_address = ((*address as i64) + x.1 as i64) as u64;
 
-                    let id = cofi_table.get_id_by_address(*address).unwrap();
+                    let id = cofi_table.get_id_by_address(*address)?;
                     let target_id = cofi_table.get_id_by_address(target_address);
                     if target_id.is_ok() {
                         Self::add_cofi_object(&mut cofi_table.cofi_table, id, CofiTarget::TargetID(target_id.unwrap()), x.0, 0, cofi_type)?;
@@ -359,6 +380,10 @@ impl DisasDataTable {
         Ok(())
     }
 
+    fn cofi_type(mnemonic: &str) -> Result<CofiType, Error> {
+        CofiType::from_str(mnemonic).map_err(|_| Error::Unknown(format!("unsupported CoFI instruction ({})", mnemonic), ErrorBacktrace::new()))
+    }
+
     /* Check if the current lookup map is dirty and update it (there's probably a much more efficient way to do, but for now we keep it more simple and less efficient). */
     fn update_map(&mut self) {
         // fix this
@@ -1,8 +1,10 @@
 // SPDX-FileCopyrightText: 2024 Intel Corporation
 //
 // SPDX-License-Identifier: Apache-2.0
+use std::collections::BTreeMap;
 use std::io::Write;
 
+use elf::abi::{SHF_ALLOC, SHF_WRITE, SHT_NOBITS};
 use elf::section::SectionHeader;
 use elf::symbol::Symbol;
 use elf::{ElfBytes, endian::AnyEndian};
@@ -11,17 +13,65 @@ use libafl_bolts::ErrorBacktrace;
 
 use crate::dasm::RiscvInstructions;
 
-#[derive(Debug)]
-pub struct ELF{
-    payload_address: u64,
-    payload_offset: u64,
-    payload_size: u64,
+/// What a fuzzable region of an ELF template holds, after the prefix of its symbol
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RegionKind {
+    /// Instructions, `payload` or `payload_code*`, padded with `c.ebreak`
+    Code,
+    /// Data buffers, `payload_data*`, padded with zeroes
+    Data,
+    /// Page tables, `payload_pt*`, 4 KiB aligned and padded with zeroes (invalid entries)
+    PageTable,
+}
+
+impl RegionKind {
+    /// The kind of the region starting at the symbol `name`, `None` if it is no region
+    #[must_use]
+    pub fn from_symbol(name: &str) -> Option<Self> {
+        let suffix = name.strip_prefix("payload")?;
+        let kind = |prefix: &str| suffix == prefix || suffix.starts_with(&format!("{prefix}_"));
+        if suffix.is_empty() || kind("_code") {
+            Some(Self::Code)
+        } else if kind("_data") {
+            Some(Self::Data)
+        } else if kind("_pt") {
+            Some(Self::PageTable)
+        } else {
+            None
+        }
+    }
 
-    file_data_start: Vec<u8>,
-    file_data_payload: RiscvInstructions,
+    /// The two bytes repeated after the contents of a region
+    fn padding(self) -> [u8; 2] {
+        match self {
+            Self::Code => 0x9002u16.to_le_bytes(),
+            Self::Data | Self::PageTable => [0, 0],
+        }
+    }
+}
+
+/// A fuzzable region of an ELF template
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PayloadRegion {
+    /// The symbol marking the start of the region, e.g. `payload_data`
+    pub name: String,
+    pub kind: RegionKind,
+    pub address: u64,
+    /// Offset of the region in the file
+    pub offset: u64,
+    /// Size of the symbol, or distance to the next symbol or to the end of the section
+    pub size: u64,
+}
 
-    ebreak_padding: Vec<u8>,
-    file_data_end: Vec<u8>,
+#[derive(Debug, Clone)]
+pub struct ELF{
+    file_data: Vec<u8>,
+    regions: Vec<PayloadRegion>,
+    /// The contents of the updated regions, padded up to the size of the region. The other
+    /// regions keep the bytes of the template.
+    contents: Vec<Option<Vec<u8>>>,
+    /// The code region patched by [`ELF::update`]
+    payload: usize,
 }
 
 // Abstraction layer to load, generate and modify ELF files
@@ -29,7 +79,8 @@ impl ELF {
 
     pub fn find_symbol(template_file_path: &str, symbol: &str) -> Result<(u64, u64, u64), Error> {
         let path = std::path::PathBuf::from(template_file_path.to_string());
-        let data = std::fs::read(path).expect("Could not read file.");
+        let data = std::fs::read(&path)
+            .map_err(|e| Error::Unknown(format!("Could not read {:?}: {}", path, e), ErrorBacktrace::new()))?;
         Self::find_symbol_bytes(&data, symbol)
     }
 
@@ -37,16 +88,24 @@ impl ELF {
     ///
     /// # Arguments
     ///
-    /// * `template_file_path` - A string slice that holds the path to the template ELF file
+    /// * `data` - The content of the ELF file
+    /// * `symbol` - The name of the symbol marking the start of the region, inside `.text`
     ///
     pub fn find_symbol_bytes(data: &Vec<u8>, symbol: &str) -> Result<(u64, u64, u64), Error> {
         let slice = data.as_slice();
-        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(slice)
+            .map_err(|e| Error::Unknown(format!("Could not parse ELF file: {}", e), ErrorBacktrace::new()))?;
 
         let text_shdr: SectionHeader = file
             .section_header_by_name(".text")
-            .expect("section table should be parseable")
-            .expect("file should have a .note.ABI-tag section");
+            .map_err(|e| Error::Unknown(format!("Could not parse section table: {}", e), ErrorBacktrace::new()))?
+            .ok_or(Error::Unknown("Could not find .text section".to_string(), ErrorBacktrace::new()))?;
+
+        // symbols are matched on their section, not only on their address
+        let text_index = file
+            .section_headers()
+            .and_then(|shdrs| shdrs.iter().position(|shdr| shdr == text_shdr))
+            .ok_or(Error::Unknown("Could not find .text section".to_string(), ErrorBacktrace::new()))?;
 
         let text_section_vaddr = text_shdr.sh_addr;
         let text_section_foffset = text_shdr.sh_offset;
@@ -57,19 +116,15 @@ impl ELF {
         //println!("text_shdr: {:?}", text_shdr);
 
         let (parsing_table, string_table) = file.symbol_table()
-            .expect("Could not parse symtab!")
-            .expect("Could not find symtab!");
-
-        let symbol_table: Vec<Symbol> = parsing_table.iter().collect();
+            .map_err(|e| Error::Unknown(format!("Could not parse symtab: {}", e), ErrorBacktrace::new()))?
+            .ok_or(Error::Unknown("Could not find symtab".to_string(), ErrorBacktrace::new()))?;
 
-        let payload_symbol = 
-        symbol_table.iter()                     
-            .filter(|s| string_table.get(s.st_name as usize)
-            .expect("Could not map symbol_id to string_table entry.") == symbol)
-            .next();
+        let payload_symbol = parsing_table
+            .iter()
+            .find(|s| string_table.get(s.st_name as usize).is_ok_and(|name| name == symbol));
 
         match payload_symbol {
-            Some(x) => {
+            Some(x) if x.st_shndx as usize == text_index && x.st_value >= text_section_vaddr && x.st_value <= text_section_end => {
                 //println!("=> {:?}", x);
                 Ok((
                     x.st_value, 
@@ -77,27 +132,67 @@ impl ELF {
                     (text_section_end-x.st_value)
                 ))
             },
+            Some(_) => {
+                Err(Error::Unknown(format!("Symbol <{}> is not in the .text section", symbol), ErrorBacktrace::new()))
+            },
             None => {
                 Err(Error::Unknown(format!("Could not find <{}> symbol", symbol).to_string(), ErrorBacktrace::new()))
             }
         }
     }
 
+    pub fn writable_regions(template_file_path: &str) -> Result<Vec<(String, u64, u64)>, Error> {
+        let path = std::path::PathBuf::from(template_file_path.to_string());
+        let data = std::fs::read(&path)
+            .map_err(|e| Error::Unknown(format!("Could not read {:?}: {}", path, e), ErrorBacktrace::new()))?;
+        Self::writable_regions_bytes(&data)
+    }
+
+    /// Returns the name, address and size of the non-empty writable sections loaded in memory
+    /// (e.g. `.data`, `.bss`), the memory a test case can safely access
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The content of the ELF file
+    ///
+    pub fn writable_regions_bytes(data: &[u8]) -> Result<Vec<(String, u64, u64)>, Error> {
+        let file = ElfBytes::<AnyEndian>::minimal_parse(data)
+            .map_err(|e| Error::Unknown(format!("Could not parse ELF file: {}", e), ErrorBacktrace::new()))?;
+
+        let (shdrs, strtab) = file.section_headers_with_strtab()
+            .map_err(|e| Error::Unknown(format!("Could not parse section table: {}", e), ErrorBacktrace::new()))?;
+        let (Some(shdrs), Some(strtab)) = (shdrs, strtab) else {
+            return Err(Error::Unknown("Could not find section table".to_string(), ErrorBacktrace::new()));
+        };
+
+        let flags = u64::from(SHF_ALLOC | SHF_WRITE);
+        Ok(shdrs
+            .iter()
+            .filter(|shdr| shdr.sh_flags & flags == flags && shdr.sh_size > 0)
+            .map(|shdr| {
+                let name = strtab.get(shdr.sh_name as usize).unwrap_or_default();
+                (name.to_string(), shdr.sh_addr, shdr.sh_size)
+            })
+            .collect())
+    }
+
     pub fn first_symbol(template_file_path: &str, section: &str) -> Result<String, Error> {
         let path = std::path::PathBuf::from(template_file_path.to_string());
-        let file_data = std::fs::read(path).expect("Could not read file.");
+        let file_data = std::fs::read(&path)
+            .map_err(|e| Error::Unknown(format!("Could not read {:?}: {}", path, e), ErrorBacktrace::new()))?;
         let slice = file_data.as_slice();
-        let file = ElfBytes::<AnyEndian>::minimal_parse(slice).expect("Open test1");
+        let file = ElfBytes::<AnyEndian>::minimal_parse(slice)
+            .map_err(|e| Error::Unknown(format!("Could not parse ELF file: {}", e), ErrorBacktrace::new()))?;
 
 
         let (parsing_table, string_table) = file.symbol_table()
-            .expect("Could not parse symtab!")
-            .expect("Could not find symtab!");
+            .map_err(|e| Error::Unknown(format!("Could not parse symtab: {}", e), ErrorBacktrace::new()))?
+            .ok_or(Error::Unknown("Could not find symtab".to_string(), ErrorBacktrace::new()))?;
 
         let text_shdr: SectionHeader = file
             .section_header_by_name(section)
-            .expect("section table should be parseable")
-            .expect("file should have a .note.ABI-tag section");
+            .map_err(|e| Error::Unknown(format!("Could not parse section table: {}", e), ErrorBacktrace::new()))?
+            .ok_or(Error::Unknown(format!("Could not find {} section", section), ErrorBacktrace::new()))?;
 
         /* 
         let text_section_vaddr = text_shdr.sh_addr;
@@ -126,7 +221,7 @@ impl ELF {
 
             let mut a: Vec<(u64, String)> = symbol_table.iter()                     
                 .filter(|s| (s.st_shndx == section_id as u16))
-                .map(|x| (x.st_value, string_table.get(x.st_name as usize).unwrap().to_string()))
+                .map(|x| (x.st_value, string_table.get(x.st_name as usize).unwrap_or_default().to_string()))
                 .filter(|(_,y)| y.len() != 0)
                 .collect();
 
@@ -139,40 +234,122 @@ impl ELF {
         }
     }
 
-    fn from_template_bytes(data: &Vec<u8>, _data: Option<&Vec<u8>>) ->  Result<Self, Error>{
-        let (payload_address, payload_file_offset, payload_size) = Self::find_symbol_bytes(data, "payload")?;
+    pub fn payload_regions(template_file_path: &str) -> Result<Vec<PayloadRegion>, Error> {
+        let path = std::path::PathBuf::from(template_file_path.to_string());
+        let data = std::fs::read(&path)
+            .map_err(|e| Error::Unknown(format!("Could not read {:?}: {}", path, e), ErrorBacktrace::new()))?;
+        Self::payload_regions_bytes(&data)
+    }
 
-        let slice = data.as_slice();
+    /// Returns the fuzzable regions of the template by address, see [`RegionKind`] for their
+    /// symbols. The regions must hold file contents, stay in their section and not overlap.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The content of the ELF file
+    ///
+    pub fn payload_regions_bytes(data: &[u8]) -> Result<Vec<PayloadRegion>, Error> {
+        let file = ElfBytes::<AnyEndian>::minimal_parse(data)
+            .map_err(|e| Error::Unknown(format!("Could not parse ELF file: {}", e), ErrorBacktrace::new()))?;
 
-        let slice_a = &slice[0..payload_file_offset as usize];
-        let slice_b = &slice[payload_file_offset as usize .. (payload_file_offset+payload_size) as usize];
-        let slice_c = &slice[(payload_file_offset+payload_size) as usize .. slice.len()];
+        let shdrs = file.section_headers()
+            .ok_or(Error::Unknown("Could not find section table".to_string(), ErrorBacktrace::new()))?;
+
+        let (parsing_table, string_table) = file.symbol_table()
+            .map_err(|e| Error::Unknown(format!("Could not parse symtab: {}", e), ErrorBacktrace::new()))?
+            .ok_or(Error::Unknown("Could not find symtab".to_string(), ErrorBacktrace::new()))?;
+
+        let symbols: Vec<(String, Symbol)> = parsing_table
+            .iter()
+            .filter_map(|s| {
+                let name = string_table.get(s.st_name as usize).ok()?;
+                (!name.is_empty()).then(|| (name.to_string(), s))
+            })
+            .collect();
+
+        let mut regions = vec![];
+        for (name, symbol) in &symbols {
+            let Some(kind) = RegionKind::from_symbol(name) else {
+                continue;
+            };
+            let shdr = shdrs
+                .get(symbol.st_shndx as usize)
+                .map_err(|_| Error::illegal_argument(format!("Region <{}> is not in a section", name)))?;
+            if shdr.sh_type == SHT_NOBITS {
+                return Err(Error::illegal_argument(format!("Region <{}> is in a section without file contents", name)));
+            }
+
+            let section_end = shdr.sh_addr + shdr.sh_size;
+            if symbol.st_value < shdr.sh_addr || symbol.st_value >= section_end {
+                return Err(Error::illegal_argument(format!("Region <{}> is not in a section", name)));
+            }
+            let end = if symbol.st_size > 0 {
+                symbol.st_value + symbol.st_size
+            } else {
+                symbols
+                    .iter()
+                    .filter(|(_, s)| s.st_shndx == symbol.st_shndx && s.st_value > symbol.st_value)
+                    .map(|(_, s)| s.st_value)
+                    .fold(section_end, u64::min)
+            };
+            if end > section_end {
+                return Err(Error::illegal_argument(format!(
+                    "Region <{}> of {} bytes overflows its section",
+                    name,
+                    end - symbol.st_value
+                )));
+            }
+            if kind == RegionKind::PageTable && symbol.st_value % 0x1000 != 0 {
+                return Err(Error::illegal_argument(format!("Page table region <{}> is not 4 KiB aligned", name)));
+            }
+
+            let offset = symbol.st_value - shdr.sh_addr + shdr.sh_offset;
+            if offset + (end - symbol.st_value) > data.len() as u64 {
+                return Err(Error::illegal_argument(format!("Region <{}> is out of the file", name)));
+            }
+            regions.push(PayloadRegion {
+                name: name.clone(),
+                kind,
+                address: symbol.st_value,
+                offset,
+                size: end - symbol.st_value,
+            });
+        }
 
-        let padding16: Vec<u16> = vec![0x9002; payload_size as usize];
-        let padding8 = unsafe {
-            padding16.align_to::<u8>().1.to_owned()
-        }; 
+        regions.sort_by_key(|region| region.address);
+        if let Some(pair) = regions.windows(2).find(|pair| pair[0].address + pair[0].size > pair[1].address) {
+            return Err(Error::illegal_argument(format!(
+                "Regions <{}> and <{}> overlap",
+                pair[0].name, pair[1].name
+            )));
+        }
+        Ok(regions)
+    }
 
-        assert_eq!(payload_size as usize, slice_b.len());
+    fn from_template_bytes(data: &[u8], initial_payload: Option<&Vec<u8>>) ->  Result<Self, Error>{
+        let regions = Self::payload_regions_bytes(data)?;
+        let payload = regions
+            .iter()
+            .position(|region| region.kind == RegionKind::Code)
+            .ok_or(Error::illegal_argument("Could not find a <payload> or <payload_code> symbol"))?;
 
-        let file_data_payload = RiscvInstructions::new();
+        // the code payload is always written, padded with `c.ebreak`
+        let mut contents = vec![None; regions.len()];
+        contents[payload] = Some(initial_payload.cloned().unwrap_or_default());
 
         Ok(Self{
-            payload_address: payload_address,
-            payload_offset: payload_file_offset,
-            payload_size: payload_size,
-        
-            file_data_start: slice_a.into(),
-            file_data_payload: file_data_payload,
-            ebreak_padding: padding8,
-            file_data_end: slice_c.into(),
+            file_data: data.to_vec(),
+            contents,
+            regions,
+            payload,
         })
     }
 
 
-    fn from_template(template_file: &str, _data: Option<&Vec<u8>>) ->  Result<Self, Error>{
-        let file_data = std::fs::read(template_file).expect("Could not read file.");
-        Self::from_template_bytes(&file_data, _data)
+    fn from_template(template_file: &str, initial_payload: Option<&Vec<u8>>) ->  Result<Self, Error>{
+        let file_data = std::fs::read(template_file)
+            .map_err(|e| Error::Unknown(format!("Could not read {}: {}", template_file, e), ErrorBacktrace::new()))?;
+        Self::from_template_bytes(&file_data, initial_payload)
     }
 
     pub fn new(template_file: &str) ->  Result<Self, Error> {
@@ -180,73 +357,280 @@ impl ELF {
     }
 
     pub fn from_bytes(bytes: &[u8]) ->  Result<Self, Error> {
-        Self::from_template_bytes(&bytes.to_vec(), None)
+        Self::from_template_bytes(bytes, None)
     }
 
     pub fn with_slice(template_file: &str, data: &Vec<u8>) -> Result<Self, Error>{
         Self::from_template(template_file, Some(&data))
     }
 
+    /// Replace the first code region, the size is checked by [`ELF::get_elf_data`]
     pub fn update_ins(&mut self, input: RiscvInstructions) {
-        self.file_data_payload = input;
+        self.contents[self.payload] = Some(input.serialize());
     }
 
+    /// Replace the first code region, the size is checked by [`ELF::get_elf_data`]
     pub fn update(&mut self, input: &RiscvInstructions) {
-        self.file_data_payload = input.clone();
+        self.contents[self.payload] = Some(input.serialize());
     }
 
-    pub fn get_payload_address(&self) -> u64 {
-        self.payload_address
+    /// Replace the contents of the region `name`, the rest of the region is padded
+    pub fn update_region(&mut self, name: &str, bytes: &[u8]) -> Result<(), Error> {
+        let idx = self.region_index(name, bytes.len())?;
+        self.contents[idx] = Some(bytes.to_vec());
+        Ok(())
     }
 
-    pub fn get_payload_size(&self) -> u64 {
-        self.payload_size
+    /// Replace the contents of several regions, by name. Nothing is replaced if one of
+    /// them is unknown or too large.
+    pub fn update_regions(&mut self, contents: &BTreeMap<String, Vec<u8>>) -> Result<(), Error> {
+        let indices = contents
+            .iter()
+            .map(|(name, bytes)| self.region_index(name, bytes.len()))
+            .collect::<Result<Vec<usize>, Error>>()?;
+        for (idx, bytes) in indices.into_iter().zip(contents.values()) {
+            self.contents[idx] = Some(bytes.clone());
+        }
+        Ok(())
     }
 
-    pub fn get_payload_offset(&self) -> u64 {
-        self.payload_offset
+    /// The index of the region `name`, if `len` bytes fit in it
+    fn region_index(&self, name: &str, len: usize) -> Result<usize, Error> {
+        let idx = self
+            .regions
+            .iter()
+            .position(|region| region.name == name)
+            .ok_or(Error::illegal_argument(format!("Could not find region <{}>", name)))?;
+        Self::check_size(&self.regions[idx], len)?;
+        Ok(idx)
     }
 
-    fn get_payload_buffer(&mut self) -> Vec<u8> {
-
-        let mut output = self.file_data_payload.serialize();
-        let buf_len = output.len();
-
-        /* ebreak padding */
-        let padding_len = self.payload_size as usize - buf_len;
-        let padding_slice = &self.ebreak_padding[0..padding_len];
+    fn check_size(region: &PayloadRegion, len: usize) -> Result<(), Error> {
+        if len as u64 > region.size {
+            return Err(Error::illegal_argument(format!(
+                "{} bytes do not fit in the {} bytes region <{}>",
+                len, region.size, region.name
+            )));
+        }
+        Ok(())
+    }
 
-        output.extend_from_slice(padding_slice);
+    /// The fuzzable regions of the template, by address
+    pub fn regions(&self) -> &[PayloadRegion] {
+        &self.regions
+    }
 
-        //file.write_all(padding_slice).expect("Unable to write to testcase.elf... spike failed to create new testcase!");
+    pub fn get_payload_address(&self) -> u64 {
+        self.regions[self.payload].address
+    }
 
-        return output;
+    pub fn get_payload_size(&self) -> u64 {
+        self.regions[self.payload].size
     }
 
-    pub fn get_elf_data(&mut self) -> Result<Vec<u8>, Error> {
-        let mut output = Vec::<u8>::new();
+    pub fn get_payload_offset(&self) -> u64 {
+        self.regions[self.payload].offset
+    }
 
-        output.extend_from_slice(self.file_data_start.as_slice());
-        output.extend_from_slice(&mut self.get_payload_buffer());
-        output.extend_from_slice(self.file_data_end.as_slice());
+    /// The template with the contents of the updated regions, padded up to the size of the
+    /// region. The regions that were not updated are left as in the template.
+    pub fn get_elf_data(&self) -> Result<Vec<u8>, Error> {
+        let mut output = self.file_data.clone();
+
+        for (region, content) in self.regions.iter().zip(&self.contents) {
+            let Some(content) = content else {
+                continue;
+            };
+            Self::check_size(region, content.len())?;
+
+            /* the bounds are checked by payload_regions_bytes */
+            let start = region.offset as usize;
+            let buffer = &mut output[start..start + region.size as usize];
+            buffer[..content.len()].copy_from_slice(content);
+
+            let padding = region.kind.padding();
+            for (idx, byte) in buffer.iter_mut().enumerate().skip(content.len()) {
+                *byte = padding[idx % 2];
+            }
+        }
 
         Ok(output)
     }
     
-    pub fn write_elf(&mut self, output_file: &str) -> Result<(), Error> {
+    pub fn write_elf(&self, output_file: &str) -> Result<(), Error> {
+        let io_err = |e: std::io::Error| Error::Unknown(format!("Could not write {}: {}", output_file, e), ErrorBacktrace::new());
+
+        let output_buffer = self.get_elf_data()?;
+
         let mut file = std::fs::OpenOptions::new()
             .create(true)
             .write(true)
             .truncate(true)
-            .open(output_file).expect("Unable to write testcase.elf, maybe the file already exists?");
-        
-        let output_buffer = self.get_elf_data()?;
-
-        file.write_all(&output_buffer).expect("Unable to write to elf file ...");
-        file.sync_all().expect("Unable to write to testcase.elf... spike failed to create new testcase!");
+            .open(output_file)
+            .map_err(io_err)?;
+        file.write_all(&output_buffer).map_err(io_err)?;
+        file.sync_all().map_err(io_err)?;
 
         Ok(())
     }
 
 }
 
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use elf::abi::{EM_RISCV, SHF_ALLOC, SHF_EXECINSTR, SHF_WRITE, SHT_NOBITS, SHT_PROGBITS, SHT_STRTAB, SHT_SYMTAB};
+
+    use super::{RegionKind, ELF};
+    use crate::dasm::RiscvInstructions;
+
+    /// `.text`, `.data`, `.pt` and `.bss` sections, and the symbols `(name, section, offset, size)`
+    fn template(symbols: &[(&str, u16, u64, u64)]) -> Vec<u8> {
+        // name, type, flags, address, size
+        let sections = [
+            (".text", SHT_PROGBITS, SHF_ALLOC | SHF_EXECINSTR, 0x8000_0000u64, 0x40u64),
+            (".data", SHT_PROGBITS, SHF_ALLOC | SHF_WRITE, 0x8000_1000, 0x20),
+            (".pt", SHT_PROGBITS, SHF_ALLOC | SHF_WRITE, 0x8000_2000, 0x40),
+            (".bss", SHT_NOBITS, SHF_ALLOC | SHF_WRITE, 0x8000_3000, 0x20),
+        ];
+
+        let mut strtab = vec![0u8];
+        let mut symtab = vec![0u8; 24];
+        for (name, section, offset, size) in symbols {
+            symtab.extend((strtab.len() as u32).to_le_bytes());
+            symtab.extend([0x10, 0]);
+            symtab.extend(section.to_le_bytes());
+            symtab.extend(sections[*section as usize - 1].3.wrapping_add(*offset).to_le_bytes());
+            symtab.extend(size.to_le_bytes());
+            strtab.extend(name.as_bytes());
+            strtab.push(0);
+        }
+        let mut shstrtab = vec![0u8];
+        let mut names = vec![];
+        for name in sections.iter().map(|s| s.0).chain([".symtab", ".strtab", ".shstrtab"]) {
+            names.push(shstrtab.len() as u32);
+            shstrtab.extend(name.as_bytes());
+            shstrtab.push(0);
+        }
+
+        // contents after the header, then the section headers
+        let mut body = vec![];
+        let mut headers = vec![0u8; 64];
+        let mut header = |name: u32, kind: u32, flags: u32, addr: u64, offset: usize, size: usize, link: u32, entsize: u64| {
+            headers.extend(name.to_le_bytes());
+            headers.extend(kind.to_le_bytes());
+            headers.extend(u64::from(flags).to_le_bytes());
+            headers.extend(addr.to_le_bytes());
+            headers.extend((64 + offset as u64).to_le_bytes());
+            headers.extend((size as u64).to_le_bytes());
+            headers.extend(link.to_le_bytes());
+            headers.extend(u32::from(link != 0).to_le_bytes());
+            headers.extend(8u64.to_le_bytes());
+            headers.extend(entsize.to_le_bytes());
+        };
+        for (idx, (_, kind, flags, addr, size)) in sections.iter().enumerate() {
+            header(names[idx], *kind, *flags, *addr, body.len(), *size as usize, 0, 0);
+            if *kind != SHT_NOBITS {
+                body.extend(vec![0xaa; *size as usize]);
+            }
+        }
+        header(names[4], SHT_SYMTAB, 0, 0, body.len(), symtab.len(), 6, 24);
+        body.extend(symtab);
+        header(names[5], SHT_STRTAB, 0, 0, body.len(), strtab.len(), 0, 0);
+        body.extend(strtab);
+        header(names[6], SHT_STRTAB, 0, 0, body.len(), shstrtab.len(), 0, 0);
+        body.extend(shstrtab);
+        body.resize(body.len().next_multiple_of(8), 0);
+
+        let mut elf = vec![0x7f, b'E', b'L', b'F', 2, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        elf.extend(2u16.to_le_bytes());
+        elf.extend(EM_RISCV.to_le_bytes());
+        elf.extend(1u32.to_le_bytes());
+        elf.extend(0x8000_0000u64.to_le_bytes());
+        elf.extend(0u64.to_le_bytes());
+        elf.extend((64 + body.len() as u64).to_le_bytes());
+        elf.extend(0u32.to_le_bytes());
+        elf.extend([64u16, 56, 0, 64, 8, 7].iter().flat_map(|v| v.to_le_bytes()));
+        elf.extend(body);
+        elf.extend(headers);
+        elf
+    }
+
+    #[test]
+    fn payload_regions() {
+        let data = template(&[
+            ("_start", 1, 0, 0),
+            ("payload_code", 1, 0x10, 0),
+            ("payload_data", 2, 0, 0x10),
+            ("buffer", 2, 0x10, 0),
+            ("payload_pt", 3, 0, 0),
+        ]);
+        let mut elf = ELF::from_bytes(&data).unwrap();
+        let regions: Vec<(&str, RegionKind, u64, u64)> =
+            elf.regions().iter().map(|r| (r.name.as_str(), r.kind, r.address, r.size)).collect();
+        assert_eq!(
+            regions,
+            [
+                ("payload_code", RegionKind::Code, 0x8000_0010, 0x30),
+                ("payload_data", RegionKind::Data, 0x8000_1000, 0x10),
+                ("payload_pt", RegionKind::PageTable, 0x8000_2000, 0x40),
+            ]
+        );
+
+        // each region is patched and padded on its own
+        let code = RiscvInstructions::from_le(vec![0x13, 0, 0, 0]);
+        elf.update(&code);
+        elf.update_regions(&BTreeMap::from([("payload_data".to_string(), vec![1, 2, 3])])).unwrap();
+        let output = elf.get_elf_data().unwrap();
+        assert_eq!(output.len(), data.len());
+        let code = elf.regions()[0].offset as usize;
+        assert_eq!(&output[code - 2..code + 8], &[0xaa, 0xaa, 0x13, 0, 0, 0, 0x02, 0x90, 0x02, 0x90]);
+        let data_offset = elf.regions()[1].offset as usize;
+        assert_eq!(&output[data_offset..data_offset + 0x11], &[[1, 2, 3].as_slice(), &[0; 13], &[0xaa]].concat());
+        // the regions that were not updated keep the bytes of the template
+        let pt = elf.regions()[2].offset as usize;
+        assert!(output[pt..pt + 0x40].iter().all(|b| *b == 0xaa));
+
+        // sizes are checked, nothing is patched on error
+        assert!(elf.update_region("payload_data", &[0; 0x11]).is_err());
+        assert!(elf.update_region("payload_stack", &[0]).is_err());
+        let contents = BTreeMap::from([("payload_code".to_string(), vec![0; 4]), ("payload_data".to_string(), vec![0; 0x20])]);
+        assert!(elf.update_regions(&contents).is_err());
+        assert_eq!(elf.get_elf_data().unwrap(), output);
+        elf.update(&RiscvInstructions::from_le([0x13, 0, 0, 0].repeat(13)));
+        assert!(elf.get_elf_data().is_err());
+
+        // a single payload up to the end of .text, as in the templates of the fuzzers
+        let elf = ELF::from_bytes(&template(&[("payload", 1, 0x20, 0)])).unwrap();
+        assert_eq!((elf.get_payload_address(), elf.get_payload_size()), (0x8000_0020, 0x20));
+
+        for symbols in [
+            vec![("payload_data", 2, 0, 0)],
+            vec![("payload", 1, 0, 0), ("payload_pt", 2, 8, 0)],
+            vec![("payload", 1, 0, 0), ("payload_data", 4, 0, 0)],
+            vec![("payload", 1, 0, 0x80)],
+            vec![("payload", 1, 0, 0x20), ("payload_code_1", 1, 0x10, 0)],
+        ] {
+            assert!(ELF::from_bytes(&template(&symbols)).is_err(), "{symbols:?}");
+        }
+        assert!(ELF::from_bytes(&[0x7f, b'E', b'L', b'F']).is_err());
+    }
+
+    #[test]
+    fn find_payload_symbol() {
+        let data = template(&[("_start", 1, 0, 0), ("payload", 1, 0x10, 0), ("buffer", 2, 0, 0x10)]);
+        let (address, offset, size) = ELF::find_symbol_bytes(&data, "payload").unwrap();
+        assert_eq!((address, size), (0x8000_0010, 0x30));
+        assert!(data[offset as usize..(offset + size) as usize].iter().all(|b| *b == 0xaa));
+        let (_, start, start_size) = ELF::find_symbol_bytes(&data, "_start").unwrap();
+        assert_eq!((offset, start_size), (start + 0x10, 0x40));
+
+        assert!(ELF::find_symbol_bytes(&data, "buffer").is_err());
+        assert!(ELF::find_symbol_bytes(&data, "missing").is_err());
+
+        // a symbol of another section is rejected, even at an address of .text
+        let data = template(&[("payload", 2, 0u64.wrapping_sub(0xff0), 0)]);
+        assert!(ELF::find_symbol_bytes(&data, "payload").is_err());
+    }
+}
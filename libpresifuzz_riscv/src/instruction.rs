@@ -15,5 +15,159 @@ pub struct Instruction {
     pub mnemonic: String,
     pub extension: String,
     pub operands: Vec<(u32, u32)>,
+    /// What each field of `operands` encodes
+    #[serde(default)]
+    pub operand_kinds: Vec<OperandKind>,
 }
 
+/// What an operand field of an [`Instruction`] encodes
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum OperandKind {
+    /// A register written by the instruction (`rd`, `vd`)
+    DestReg,
+    /// A register read by the instruction (`rs1`, `rs2`, `rs3`, `vs1`, ...)
+    SrcReg,
+    /// A signed immediate, or a piece of a split one (e.g. branch offsets)
+    SignedImm,
+    /// An unsigned immediate, or a piece of a split one (e.g. `lui` upper bits)
+    UnsignedImm,
+    /// A shift amount
+    Shamt,
+    /// A CSR number
+    Csr,
+    /// A floating-point rounding mode
+    RoundingMode,
+    /// Any other field: memory ordering, fence sets, vector mask, segment count
+    Other,
+}
+
+impl OperandKind {
+    /// Whether the field holds a register number
+    #[must_use]
+    pub fn is_register(&self) -> bool {
+        matches!(self, Self::DestReg | Self::SrcReg)
+    }
+}
+
+impl Instruction {
+    /// The kind of operand `idx`, [`OperandKind::Other`] when unknown
+    #[must_use]
+    pub fn operand_kind(&self, idx: usize) -> OperandKind {
+        self.operand_kinds.get(idx).copied().unwrap_or(OperandKind::Other)
+    }
+
+    /// The value of operand `idx`, as encoded in the instruction
+    #[must_use]
+    pub fn operand_value(&self, idx: usize) -> u32 {
+        let (lsb, msb) = self.operands[idx];
+        let nb_bits = msb - lsb + 1;
+        ((self.instruction >> lsb) as u32) & (u32::MAX >> (32 - nb_bits))
+    }
+
+    /// Replace the value of operand `idx`, the value is truncated to the field width
+    pub fn set_operand_value(&mut self, idx: usize, value: u32) {
+        let (lsb, msb) = self.operands[idx];
+        let nb_bits = msb - lsb + 1;
+        let field = (u64::from(u32::MAX >> (32 - nb_bits))) << lsb;
+        self.instruction = (self.instruction & !field) | ((u64::from(value) << lsb) & field);
+    }
+
+    /// The major opcode (bits 0-6) of the instruction
+    #[must_use]
+    pub fn major_opcode(&self) -> u32 {
+        self.mask & 0x7f
+    }
+
+    /// Whether this is an integer load (`lb` ... `ld`)
+    #[must_use]
+    pub fn is_load(&self) -> bool {
+        self.length == 4 && self.major_opcode() == 0x03
+    }
+
+    /// Whether this is an integer store (`sb` ... `sd`)
+    #[must_use]
+    pub fn is_store(&self) -> bool {
+        self.length == 4 && self.major_opcode() == 0x23
+    }
+
+    /// Whether this is an integer computation on registers and immediates (OP, OP-IMM and
+    /// their 32-bit variants)
+    #[must_use]
+    pub fn is_integer_alu(&self) -> bool {
+        self.length == 4 && matches!(self.major_opcode(), 0x13 | 0x1b | 0x33 | 0x3b)
+    }
+
+    /// The number of bytes accessed by an integer load or store
+    #[must_use]
+    pub fn access_size(&self) -> Option<u32> {
+        (self.is_load() || self.is_store()).then(|| 1 << ((self.mask >> 12) & 0x3))
+    }
+
+    /// The offset added to the base register by an integer load or store
+    #[must_use]
+    pub fn memory_offset(&self) -> Option<i32> {
+        let raw = if self.is_load() {
+            (self.instruction as u32) >> 20
+        } else if self.is_store() {
+            (((self.instruction as u32) >> 25) << 5) | (((self.instruction as u32) >> 7) & 0x1f)
+        } else {
+            return None;
+        };
+        // sign-extend the 12-bit immediate
+        Some(((raw << 20) as i32) >> 20)
+    }
+
+    /// Set the offset of an integer load or store, truncated to 12 bits. Returns `false` for
+    /// other instructions.
+    pub fn set_memory_offset(&mut self, offset: i32) -> bool {
+        let fields = if self.is_load() {
+            vec![((20, 31), offset as u32)]
+        } else if self.is_store() {
+            vec![((25, 31), (offset as u32) >> 5), ((7, 11), offset as u32)]
+        } else {
+            return false;
+        };
+        for ((lsb, msb), value) in fields {
+            if let Some(idx) = self.operand_index(lsb, msb) {
+                self.set_operand_value(idx, value);
+            }
+        }
+        true
+    }
+
+    /// Set the base register of an integer load or store. Returns `false` for other
+    /// instructions.
+    pub fn set_base_register(&mut self, register: u32) -> bool {
+        match self.operand_index(15, 19) {
+            Some(idx) if self.is_load() || self.is_store() => {
+                self.set_operand_value(idx, register);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// The index of the operand encoded in bits `lsb` to `msb`
+    #[must_use]
+    pub fn operand_index(&self, lsb: u32, msb: u32) -> Option<usize> {
+        self.operands.iter().position(|operand| *operand == (lsb, msb))
+    }
+
+    /// The registers of the operands of kind `kind`. The 3-bit register fields of
+    /// compressed instructions are mapped to `x8`-`x15`.
+    #[must_use]
+    pub fn registers(&self, kind: OperandKind) -> Vec<u32> {
+        (0..self.operands.len())
+            .filter(|idx| self.operand_kind(*idx) == kind && kind.is_register())
+            .map(|idx| {
+                let (lsb, msb) = self.operands[idx];
+                let value = self.operand_value(idx);
+                if msb - lsb + 1 == 3 {
+                    value + 8
+                } else {
+                    value
+                }
+            })
+            .collect()
+    }
+}
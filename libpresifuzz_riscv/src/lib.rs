@@ -2,6 +2,7 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod asm;
 pub mod elf;
 pub mod disas;
 pub mod dasm;
@@ -9,6 +10,7 @@ pub mod states;
 pub mod defines;
 pub mod instruction;
 pub mod cpu_profile;
+pub mod profile;
 
 #[macro_use]
 extern crate lazy_static;
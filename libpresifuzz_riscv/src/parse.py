@@ -401,6 +401,36 @@ def instr_dict_2_extensions(instr_dict):
 #
 # SPDX-License-Identifier: Apache-2.0
 
+# extensions in decoding order, the width variants of an extension are sorted rv64, rv, rv32
+EXTENSION_ORDER = ['i', 'm', 'a', 'f', 'd', 'c', 'zicsr', 'zifencei', 'zba', 'zbb', 'zbs', 'v', 'system']
+
+def extension_key(ext):
+    [width, name] = ext.split('_', 1)
+    rank = EXTENSION_ORDER.index(name) if name in EXTENSION_ORDER else len(EXTENSION_ORDER)
+    return (rank, name, {'rv64': 0, 'rv': 1, 'rv32': 2}.get(width, 3))
+
+# what each operand field encodes, see OperandKind in instruction.rs
+OPERAND_KINDS = {
+    'DestReg': ['rd', 'rd_p', 'rd_n0', 'rd_n2', 'rd_rs1_n0', 'rd_rs1_p', 'vd'],
+    'SrcReg': ['rs1', 'rs2', 'rs3', 'rs1_p', 'rs2_p', 'rs1_n0', 'c_rs1_n0', 'c_rs2', 'c_rs2_n0',
+               'vs1', 'vs2', 'vs3'],
+    'SignedImm': ['imm12', 'imm12hi', 'imm12lo', 'bimm12hi', 'bimm12lo', 'jimm20', 'simm5',
+                  'c_imm6hi', 'c_imm6lo', 'c_imm12', 'c_bimm9hi', 'c_bimm9lo', 'c_nzimm6hi',
+                  'c_nzimm6lo', 'c_nzimm10hi', 'c_nzimm10lo', 'c_nzimm18hi', 'c_nzimm18lo'],
+    'UnsignedImm': ['imm20', 'zimm', 'zimm10', 'zimm11', 'c_nzuimm10', 'c_uimm7hi', 'c_uimm7lo',
+                    'c_uimm8hi', 'c_uimm8lo', 'c_uimm8sp_s', 'c_uimm8sphi', 'c_uimm8splo',
+                    'c_uimm9sp_s', 'c_uimm9sphi', 'c_uimm9splo'],
+    'Shamt': ['shamtd', 'shamtw', 'c_nzuimm5', 'c_nzuimm6hi', 'c_nzuimm6lo'],
+    'Csr': ['csr'],
+    'RoundingMode': ['rm'],
+}
+
+def operand_kind(operand):
+    for kind, names in OPERAND_KINDS.items():
+        if operand in names:
+            return kind
+    return 'Other'
+
 def make_rust(instr_dict):
 
     header = "// SPDX-FileCopyrightText: 2022 Intel Corporation\n"
@@ -412,12 +442,10 @@ def make_rust(instr_dict):
     header += "* Please, do not change this file directly but instead look at presifuzz/riscv-opcodes\n"
     header += "* This file contains helper functions to assemble all supported riscv instructions\n"
     header += "*/\n"
+    header += "#![allow(clippy::all)]\n"
 
     header += "use libafl::prelude::HasRand;\n"
-    header += "use crate::instruction::Instruction;\n"
-    header += "use libafl::prelude::HasMaxSize;\n"
-    header += "use libafl::prelude::MutationResult;\n"
-    header += "use libafl_bolts::Named;\n"
+    header += "use crate::instruction::{Instruction, OperandKind};\n"
     header += "\n"
     header += "use libafl_bolts::{\n"
     header += "    rands::Rand,\n"
@@ -444,11 +472,12 @@ def make_rust(instr_dict):
     header += "}\n"
 
 
+    # one table per extension, the tables are concatenated in decoding order
+    extensions = sorted(instr_dict_2_extensions(instr_dict), key=extension_key)
+    ext_metadata = {ext: "" for ext in extensions}
+
     insn_metadata = "use lazy_static::lazy_static;\n"
     insn_metadata += "lazy_static! {\n"
-    insn_metadata += "// Global Vec to hold instructions\n"
-    insn_metadata += "pub static ref INSTRUCTIONS: Vec<Instruction> = {\n"
-    insn_metadata += "    vec![\n"
 
 
     helper_functions = "/* Start of the helper function section */\n" 
@@ -483,6 +512,10 @@ def make_rust(instr_dict):
             (msb, lsb) = arg_lut[operand]
             variable_fields_array += f"({lsb},{msb}), "
 
+        operand_kinds_array = ""
+        for operand in instr_dict[i]["variable_fields"]:
+            operand_kinds_array += f"OperandKind::{operand_kind(operand)}, "
+
         helper_functions += f"pub fn {mnemonic.lower()}({args}) -> Instruction\n"
         helper_functions +=  "{\n"
         helper_functions +=  "    let mut inst : u32 = 0;\n"
@@ -497,22 +530,53 @@ def make_rust(instr_dict):
         helper_functions += f"        extension: String::from(\"{extension}\"),\n"
         helper_functions += f"        mnemonic: String::from(\"{mnemonic.lower()}\"),\n"
         helper_functions += f'        operands: vec![{variable_fields_array}],\n'
+        helper_functions += f'        operand_kinds: vec![{operand_kinds_array}],\n'
         helper_functions +=  "    };\n"
         helper_functions +=  "}\n"
 
-        insn_metadata += '        Instruction {\n'
-        insn_metadata += f'            instruction: 0 as u64,\n'
-        insn_metadata += f'            length: {length},\n'
-        insn_metadata += f'            mask: {mask},\n'
-        insn_metadata += f'            mmatch: {match},\n'
-        insn_metadata += f"            extension: String::from(\"{extension}\"),\n"
-        insn_metadata += f'            mnemonic: String::from(\"{mnemonic}\"),\n'
-        insn_metadata += f'            operands: vec![{variable_fields_array}],\n'
-        insn_metadata += '        },\n'
-
-    insn_metadata += '        ]\n'
+        ext_metadata[extension] += '        Instruction {\n'
+        ext_metadata[extension] += f'            instruction: 0 as u64,\n'
+        ext_metadata[extension] += f'            length: {length},\n'
+        ext_metadata[extension] += f'            mask: {mask},\n'
+        ext_metadata[extension] += f'            mmatch: {match},\n'
+        ext_metadata[extension] += f"            extension: String::from(\"{extension}\"),\n"
+        ext_metadata[extension] += f'            mnemonic: String::from(\"{mnemonic}\"),\n'
+        ext_metadata[extension] += f'            operands: vec![{variable_fields_array}],\n'
+        ext_metadata[extension] += f'            operand_kinds: vec![{operand_kinds_array}],\n'
+        ext_metadata[extension] += '        },\n'
+
+    for ext in extensions:
+        insn_metadata += f"// Instructions of the {ext} extension\n"
+        insn_metadata += f"pub static ref {ext.upper()}: Vec<Instruction> = {{\n"
+        insn_metadata += "    vec![\n"
+        insn_metadata += ext_metadata[ext]
+        insn_metadata += '        ]\n'
+        insn_metadata += '    };\n'
+
+    insn_metadata += "// Global Vec to hold instructions\n"
+    insn_metadata += "pub static ref INSTRUCTIONS: Vec<Instruction> = {\n"
+    insn_metadata += "    EXTENSIONS\n"
+    insn_metadata += "        .iter()\n"
+    insn_metadata += "        .flat_map(|ext| extension_instructions(ext).unwrap().iter().cloned())\n"
+    insn_metadata += "        .collect()\n"
     insn_metadata += '    };\n'
-    insn_metadata += '}\n'
+    insn_metadata += '}\n\n'
+
+    insn_metadata += "/// The extensions with an instruction table, in decoding order: the first matching\n"
+    insn_metadata += "/// instruction wins, so RV64 encodings come before the RV32 ones they overlap\n"
+    insn_metadata += "pub const EXTENSIONS: &[&str] = &[\n"
+    for ext in extensions:
+        insn_metadata += f"    \"{ext}\",\n"
+    insn_metadata += "];\n\n"
+
+    insn_metadata += "/// The instruction table of an extension, named after its riscv-opcodes file (e.g. `rv64_f`)\n"
+    insn_metadata += "pub fn extension_instructions(extension: &str) -> Option<&'static [Instruction]> {\n"
+    insn_metadata += "    match extension {\n"
+    for ext in extensions:
+        insn_metadata += f"        \"{ext}\" => Some(&{ext.upper()}),\n"
+    insn_metadata += "        _ => None,\n"
+    insn_metadata += "    }\n"
+    insn_metadata += "}\n"
     
     all_riscv_def = "pub const ALL_RISCV_INSTR: &'static [&'static str] = &[\n"
     k = 0
@@ -0,0 +1,351 @@
+// SPDX-FileCopyrightText: 2024 Intel Corporation
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! The instructions supported by the core under test.
+//!
+//! A [`CpuProfile`] selects the tables of [`crate::cpu_profile`] matching an ISA string
+//! (e.g. `rv64imac_zicsr`), so that the mutators only generate, and the decoder only
+//! recognizes, instructions the core implements. Each extension has a weight: generated
+//! instructions are drawn uniformly among the instructions of the profile, scaled by the
+//! weight of their extension.
+//!
+//! The privileged instructions (`mret`, `sret`, `wfi`, `sfence.vma`) form the
+//! [`PRIVILEGED_EXTENSION`]: every profile decodes them so that inserted privilege
+//! transitions survive later mutations, but they are only generated once weighted in.
+
+use std::collections::BTreeMap;
+
+use libafl::Error;
+use libafl_bolts::rands::Rand;
+
+use crate::cpu_profile::{extension_instructions, EXTENSIONS};
+use crate::instruction::Instruction;
+
+/// Weight of an extension unless configured otherwise
+pub const DEFAULT_EXTENSION_WEIGHT: f64 = 1.0;
+
+/// Extensions with an instruction table, named as in ISA strings, in decoding order
+pub const SUPPORTED_EXTENSIONS: &[&str] = &[
+    "i", "m", "a", "f", "d", "c", "zicsr", "zifencei", "zba", "zbb", "zbs", "v", PRIVILEGED_EXTENSION,
+];
+
+/// The privileged instructions, part of every profile with a weight of 0 by default
+pub const PRIVILEGED_EXTENSION: &str = "system";
+
+/// Extensions of the default profile, the instruction set of the mutators before profiles
+const DEFAULT_EXTENSIONS: &[&str] = &["i", "m", "c", PRIVILEGED_EXTENSION];
+
+/// An extension of a [`CpuProfile`] and its instructions
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProfileExtension {
+    /// Name of the extension, as in ISA strings (e.g. `m`, `zicsr`)
+    pub name: String,
+    /// Weight of the extension when generating instructions
+    pub weight: f64,
+    /// Instructions of the extension, in decoding order
+    pub instructions: Vec<&'static Instruction>,
+}
+
+/// The instructions a core supports, see the module documentation
+#[derive(Debug, Clone, PartialEq)]
+pub struct CpuProfile {
+    xlen: Option<u32>,
+    extensions: Vec<ProfileExtension>,
+    ignored: Vec<String>,
+}
+
+impl Default for CpuProfile {
+    /// The I, M and C tables, RV32 and RV64 variants included. Wider ISAs need an explicit
+    /// profile, see [`CpuProfile::from_isa_string`] and [`CpuProfile::from_config`].
+    fn default() -> Self {
+        let extensions = DEFAULT_EXTENSIONS
+            .iter()
+            .map(|name| ProfileExtension {
+                name: name.to_string(),
+                weight: Self::default_weight(name),
+                instructions: Self::tables(name, None),
+            })
+            .collect();
+
+        Self {
+            xlen: None,
+            extensions,
+            ignored: vec![],
+        }
+    }
+}
+
+impl CpuProfile {
+    /// Build a profile from an ISA string such as `rv64imac_zicsr` or `rv32gc`.
+    /// Single-letter extensions follow the base width, multi-letter ones are separated by
+    /// `_`. `g` stands for `imafd_zicsr_zifencei` and `b` for `zba_zbb_zbs`. Version
+    /// numbers are accepted and ignored, so are extensions without instruction table (see
+    /// [`CpuProfile::ignored_extensions`]).
+    pub fn from_isa_string(isa: &str) -> Result<Self, Error> {
+        let isa = isa.trim().to_lowercase();
+
+        let (xlen, rest) = if let Some(rest) = isa.strip_prefix("rv64") {
+            (64, rest)
+        } else if let Some(rest) = isa.strip_prefix("rv32") {
+            (32, rest)
+        } else {
+            return Err(Error::illegal_argument(format!(
+                "ISA string {isa} does not start with rv32 or rv64"
+            )));
+        };
+
+        let mut names: Vec<&str> = vec![];
+        let mut ignored = vec![];
+        let mut parts = rest.split('_').filter(|part| !part.is_empty());
+
+        // single-letter extensions, possibly versioned (e.g. i2p1)
+        let letters = parts.next().unwrap_or("");
+        let mut chars = letters.chars().peekable();
+        while let Some(letter) = chars.next() {
+            while chars.peek().is_some_and(|c| c.is_ascii_digit() || *c == 'p') {
+                chars.next();
+            }
+            match letter {
+                'i' | 'e' => names.push("i"),
+                'g' => names.extend(["i", "m", "a", "f", "d", "zicsr", "zifencei"]),
+                'b' => names.extend(["zba", "zbb", "zbs"]),
+                _ => match SUPPORTED_EXTENSIONS.iter().find(|ext| ext.len() == 1 && ext.starts_with(letter)) {
+                    Some(ext) => names.push(ext),
+                    None => ignored.push(letter.to_string()),
+                },
+            }
+        }
+
+        // multi-letter extensions
+        for part in parts {
+            let name = part.trim_end_matches(|c: char| c.is_ascii_digit() || c == 'p');
+            match SUPPORTED_EXTENSIONS.iter().find(|ext| **ext == name && name.len() > 1) {
+                Some(ext) => names.push(ext),
+                None => ignored.push(part.to_string()),
+            }
+        }
+
+        if !names.contains(&"i") {
+            return Err(Error::illegal_argument(format!("ISA string {isa} has no base integer ISA")));
+        }
+        names.push(PRIVILEGED_EXTENSION);
+
+        let extensions = SUPPORTED_EXTENSIONS
+            .iter()
+            .filter(|ext| names.contains(ext))
+            .map(|name| ProfileExtension {
+                name: name.to_string(),
+                weight: Self::default_weight(name),
+                instructions: Self::tables(name, Some(xlen)),
+            })
+            .collect();
+
+        Ok(Self {
+            xlen: Some(xlen),
+            extensions,
+            ignored,
+        })
+    }
+
+    /// Build a profile from the `fuzzer` section of a `config.yml`:
+    ///
+    /// ```yaml
+    /// fuzzer:
+    ///   isa: "rv64imac_zicsr"
+    ///   extension_weights:
+    ///     c: 0.5
+    /// ```
+    ///
+    /// Without `isa`, the default profile is used.
+    pub fn from_config(config: &serde_yaml::Value) -> Result<Self, Error> {
+        let fuzzer = &config["fuzzer"];
+
+        let mut profile = match fuzzer["isa"].as_str() {
+            Some(isa) => Self::from_isa_string(isa)?,
+            None => Self::default(),
+        };
+
+        if let Some(weights) = fuzzer["extension_weights"].as_mapping() {
+            for (name, weight) in weights {
+                let (Some(name), Some(weight)) = (name.as_str(), weight.as_f64()) else {
+                    return Err(Error::illegal_argument(format!(
+                        "Invalid extension weight {name:?}: {weight:?}"
+                    )));
+                };
+                profile = profile.with_weight(name, weight)?;
+            }
+        }
+
+        Ok(profile)
+    }
+
+    /// Build a profile from a `config.yml` file, see [`CpuProfile::from_config`]
+    pub fn from_config_file(config_filename: &str) -> Result<Self, Error> {
+        let yaml_fd = std::fs::File::open(config_filename)?;
+        let config: serde_yaml::Value = serde_yaml::from_reader(yaml_fd)
+            .map_err(|e| Error::serialize(format!("Unable to parse {config_filename}: {e}")))?;
+        Self::from_config(&config)
+    }
+
+    /// Set the weight of an extension of the profile. A weight of 0 keeps the extension
+    /// for decoding but never generates its instructions.
+    pub fn with_weight(mut self, extension: &str, weight: f64) -> Result<Self, Error> {
+        if !weight.is_finite() || weight < 0.0 {
+            return Err(Error::illegal_argument(format!("Invalid weight {weight} for {extension}")));
+        }
+
+        let ext = self
+            .extensions
+            .iter_mut()
+            .find(|ext| ext.name == extension)
+            .ok_or_else(|| Error::illegal_argument(format!("Extension {extension} is not in the profile")))?;
+        ext.weight = weight;
+
+        Ok(self)
+    }
+
+    /// The base width, `None` when the profile mixes RV32 and RV64 instructions
+    #[must_use]
+    pub fn xlen(&self) -> Option<u32> {
+        self.xlen
+    }
+
+    /// The extensions of the profile, in decoding order
+    #[must_use]
+    pub fn extensions(&self) -> &[ProfileExtension] {
+        &self.extensions
+    }
+
+    /// Whether the profile has the extension `name` (e.g. `c`)
+    #[must_use]
+    pub fn supports(&self, name: &str) -> bool {
+        self.extensions.iter().any(|ext| ext.name == name)
+    }
+
+    /// Extensions of the ISA string without instruction table
+    #[must_use]
+    pub fn ignored_extensions(&self) -> &[String] {
+        &self.ignored
+    }
+
+    /// The instructions of the profile, in decoding order
+    pub fn instructions(&self) -> impl Iterator<Item = &'static Instruction> + '_ {
+        self.extensions.iter().flat_map(|ext| ext.instructions.iter().copied())
+    }
+
+    /// The first instruction of the profile matching `insn`
+    #[must_use]
+    pub fn decode(&self, insn: u32) -> Option<&'static Instruction> {
+        self.instructions().find(|meta| insn & meta.mmatch == meta.mask)
+    }
+
+    /// Draw an instruction of the profile, see the module documentation.
+    /// Returns `None` when all the weights are 0.
+    pub fn random_instruction<R: Rand>(&self, rand: &mut R) -> Option<&'static Instruction> {
+        let total: f64 = self.extensions.iter().map(Self::scaled_weight).sum();
+        if total <= 0.0 {
+            return None;
+        }
+
+        // 53 random bits, uniform in [0, total)
+        let mut target = (rand.next() >> 11) as f64 / (1u64 << 53) as f64 * total;
+        for ext in &self.extensions {
+            let weight = Self::scaled_weight(ext);
+            if target < weight && !ext.instructions.is_empty() {
+                let idx = rand.below(ext.instructions.len() as u64) as usize;
+                return Some(ext.instructions[idx]);
+            }
+            target -= weight;
+        }
+
+        // rounding, fall back to the last extension with a weight
+        self.extensions
+            .iter()
+            .rev()
+            .find(|ext| Self::scaled_weight(ext) > 0.0)
+            .map(|ext| ext.instructions[rand.below(ext.instructions.len() as u64) as usize])
+    }
+
+    /// The weights of the extensions, by name
+    #[must_use]
+    pub fn weights(&self) -> BTreeMap<String, f64> {
+        self.extensions.iter().map(|ext| (ext.name.clone(), ext.weight)).collect()
+    }
+
+    fn default_weight(name: &str) -> f64 {
+        if name == PRIVILEGED_EXTENSION {
+            0.0
+        } else {
+            DEFAULT_EXTENSION_WEIGHT
+        }
+    }
+
+    fn scaled_weight(ext: &ProfileExtension) -> f64 {
+        ext.weight * ext.instructions.len() as f64
+    }
+
+    /// The instructions of the tables of extension `name` for `xlen`, all widths if `None`
+    fn tables(name: &str, xlen: Option<u32>) -> Vec<&'static Instruction> {
+        EXTENSIONS
+            .iter()
+            .filter(|table| {
+                let (width, ext) = table.split_once('_').unwrap();
+                ext == name
+                    && match xlen {
+                        Some(64) => width != "rv32",
+                        Some(_) => width != "rv64",
+                        None => true,
+                    }
+            })
+            .flat_map(|table| extension_instructions(table).unwrap().iter())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use libafl_bolts::rands::StdRand;
+
+    use super::CpuProfile;
+
+    #[test]
+    fn isa_string_profile() {
+        let profile = CpuProfile::from_isa_string("RV32IMC_Zicsr_Zihintpause").unwrap();
+        assert_eq!(profile.xlen(), Some(32));
+        assert!(profile.supports("c") && profile.supports("zicsr"));
+        assert!(!profile.supports("a"));
+        assert_eq!(profile.ignored_extensions(), &["zihintpause".to_string()]);
+
+        // rv64-only instructions are left out, the rv32 variants decode
+        assert!(profile.instructions().all(|i| !i.extension.starts_with("rv64")));
+        assert_eq!(profile.decode(0x00109093).unwrap().mnemonic, "SLLI_RV32");
+
+        let gc = CpuProfile::from_isa_string("rv64gc").unwrap();
+        assert_eq!(gc.decode(0x00109093).unwrap().mnemonic, "SLLI");
+        assert!(gc.supports("d") && gc.supports("zifencei") && !gc.supports("v"));
+        assert_eq!(gc.decode(0x30200073).unwrap().mnemonic, "MRET");
+        assert_eq!(gc.weights()["system"], 0.0);
+
+        // only the compressed instructions can be drawn
+        let mut rand = StdRand::with_seed(0);
+        let only_c = CpuProfile::from_isa_string("rv64imc")
+            .and_then(|p| p.with_weight("i", 0.0))
+            .and_then(|p| p.with_weight("m", 0.0))
+            .unwrap();
+        for _ in 0..100 {
+            assert_eq!(only_c.random_instruction(&mut rand).unwrap().length, 2);
+        }
+
+        assert!(CpuProfile::from_isa_string("x86_64").is_err());
+        assert!(CpuProfile::from_isa_string("rv64mac").is_err());
+        assert!(gc.with_weight("v", 1.0).is_err());
+
+        // the default profile sticks to the I, M and C instructions
+        let default = CpuProfile::default();
+        let names: Vec<&str> = default.extensions().iter().map(|ext| ext.name.as_str()).collect();
+        assert_eq!(names, ["i", "m", "c", "system"]);
+        assert!(default.decode(0x0000_0057).is_none());
+        assert_eq!(default.decode(0x00109093).unwrap().mnemonic, "SLLI");
+    }
+}
@@ -0,0 +1,405 @@
+// SPDX-FileCopyrightText: 2024 Intel Corporation
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! The [`CorpusExportStage`] writes every new corpus entry and objective as a self-contained
+//! reproducer: patched ELF, assembly listing, simulator command line and coverage delta.
+
+use core::marker::PhantomData;
+use std::{
+    collections::HashSet,
+    fs,
+    path::{Path, PathBuf},
+};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "introspection")]
+use crate::state::HasClientPerfMonitor;
+use libafl::{
+    corpus::{Corpus, CorpusId},
+    feedbacks::MapFeedbackMetadata,
+    inputs::{HasTargetBytes, UsesInput},
+    stages::Stage,
+    state::{HasCorpus, HasMetadata, HasSolutions, State, UsesState},
+    Error,
+};
+use libafl_bolts::{AsSlice, ErrorBacktrace};
+
+use libpresifuzz_riscv::{
+    dasm::{Dasm, RiscvInstruction, RiscvInstructions},
+    elf::ELF,
+};
+
+/// Name of the folder receiving the exported corpus entries
+pub const EXPORT_QUEUE_DIR: &str = "queue";
+/// Name of the folder receiving the exported objectives
+pub const EXPORT_CRASHES_DIR: &str = "crashes";
+
+/// Coverage brought by an exported testcase compared to the previously exported one
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct CoverageDelta {
+    /// Number of coverage points hit so far
+    pub total_bits: usize,
+    /// Number of coverage points newly hit
+    pub new_bits: usize,
+    /// Indexes of the coverage points newly hit
+    pub new_indexes: Vec<usize>,
+}
+
+impl CoverageDelta {
+    /// Compute the bits set in `current` and not in `previous`
+    #[must_use]
+    pub fn between(previous: &[u32], current: &[u32]) -> Self {
+        let mut new_indexes = vec![];
+
+        for (word_idx, &word) in current.iter().enumerate() {
+            let prev = previous.get(word_idx).copied().unwrap_or(0);
+            let mut new = word & !prev;
+            while new != 0 {
+                let bit = new.trailing_zeros() as usize;
+                new_indexes.push(word_idx * 32 + bit);
+                new &= new - 1;
+            }
+        }
+
+        Self {
+            total_bits: current.iter().map(|w| w.count_ones() as usize).sum(),
+            new_bits: new_indexes.len(),
+            new_indexes,
+        }
+    }
+}
+
+/// A stage exporting new corpus entries and objectives as human-readable RISC-V testcases
+#[derive(Debug)]
+pub struct CorpusExportStage<S> {
+    export_dir: PathBuf,
+    template_elf: ELF,
+    command_line: String,
+    disassembler: Option<Box<dyn Dasm>>,
+    exported_corpus: HashSet<CorpusId>,
+    exported_solutions: HashSet<CorpusId>,
+    last_coverage: Vec<u32>,
+    phantom: PhantomData<S>,
+}
+
+impl<S> UsesState for CorpusExportStage<S>
+where
+    S: State,
+{
+    type State = S;
+}
+
+impl<E, EM, S, Z> Stage<E, EM, Z> for CorpusExportStage<S>
+where
+    E: UsesState<State = S>,
+    EM: UsesState<State = S>,
+    Z: UsesState<State = S>,
+    S: State + HasCorpus + HasSolutions,
+    <S as UsesInput>::Input: HasTargetBytes,
+{
+    #[inline]
+    fn perform(
+        &mut self,
+        _fuzzer: &mut Z,
+        _executor: &mut E,
+        state: &mut Z::State,
+        _manager: &mut EM,
+        _corpus_idx: CorpusId,
+    ) -> Result<(), Error> {
+        let new_entries: Vec<CorpusId> = state
+            .corpus()
+            .ids()
+            .filter(|id| !self.exported_corpus.contains(id))
+            .collect();
+
+        for id in new_entries {
+            self.exported_corpus.insert(id);
+            let (bytes, coverage) = Self::load(state.corpus(), id)?;
+            let dir = self.export_dir.join(EXPORT_QUEUE_DIR).join(format!("id_{:06}", usize::from(id)));
+            if let Err(err) = self.export(&dir, &bytes, coverage.as_deref()) {
+                println!("Unable to export corpus entry {}: {}", id, err);
+            }
+        }
+
+        let new_solutions: Vec<CorpusId> = state
+            .solutions()
+            .ids()
+            .filter(|id| !self.exported_solutions.contains(id))
+            .collect();
+
+        for id in new_solutions {
+            self.exported_solutions.insert(id);
+            let (bytes, coverage) = Self::load(state.solutions(), id)?;
+            let dir = self.export_dir.join(EXPORT_CRASHES_DIR).join(format!("id_{:06}", usize::from(id)));
+            if let Err(err) = self.export(&dir, &bytes, coverage.as_deref()) {
+                println!("Unable to export objective {}: {}", id, err);
+            }
+        }
+
+        #[cfg(feature = "introspection")]
+        state.introspection_monitor_mut().finish_stage();
+
+        Ok(())
+    }
+}
+
+impl<S> CorpusExportStage<S>
+where
+    S: State,
+    <S as UsesInput>::Input: HasTargetBytes,
+{
+    /// Creates a new [`CorpusExportStage`]
+    ///
+    /// # Arguments
+    ///
+    /// * `export_dir` - The folder receiving the reproducers
+    /// * `template_elf` - The ELF template patched with each testcase, e.g. `iram.elf`, loaded once
+    /// * `command_line` - The simulator command line running `testcase.elf`
+    pub fn new(export_dir: PathBuf, template_elf: &str, command_line: &str) -> Result<Self, Error> {
+        Ok(Self {
+            export_dir,
+            template_elf: ELF::new(template_elf)?,
+            command_line: command_line.to_string(),
+            disassembler: None,
+            exported_corpus: HashSet::new(),
+            exported_solutions: HashSet::new(),
+            last_coverage: vec![],
+            phantom: PhantomData,
+        })
+    }
+
+    /// Use the given disassembler (e.g. `SpikeDasm` or `ObjdumpDasm`) for the `.S` listing.
+    /// Without disassembler, the listing only contains raw `.word`/`.half` directives.
+    #[must_use]
+    pub fn with_disassembler(mut self, disassembler: Box<dyn Dasm>) -> Self {
+        self.disassembler = Some(disassembler);
+        self
+    }
+
+    /// Returns the raw bytes of a testcase and, if recorded, its coverage map
+    fn load<C>(corpus: &C, id: CorpusId) -> Result<(Vec<u8>, Option<Vec<u32>>), Error>
+    where
+        C: Corpus<Input = <S as UsesInput>::Input>,
+    {
+        let input = corpus.cloned_input_for_id(id)?;
+        let bytes = input.target_bytes().as_slice().to_vec();
+
+        let coverage = corpus
+            .get(id)?
+            .borrow()
+            .metadata::<MapFeedbackMetadata<u32>>()
+            .ok()
+            .map(|m| m.history_map.clone());
+
+        Ok((bytes, coverage))
+    }
+
+    /// Write the reproducer of one testcase into `dir`
+    fn export(&mut self, dir: &Path, bytes: &[u8], coverage: Option<&[u32]>) -> Result<(), Error> {
+        let io_err = |e: std::io::Error| Error::Unknown(format!("{:?}: {}", dir, e), ErrorBacktrace::new());
+
+        fs::create_dir_all(dir).map_err(io_err)?;
+        fs::write(dir.join("testcase.bin"), bytes).map_err(io_err)?;
+
+        let instructions = RiscvInstructions::from_le(bytes.to_vec());
+
+        let mut elf = self.template_elf.clone();
+        if instructions.len() as u64 > elf.get_payload_size() {
+            return Err(Error::illegal_argument(format!(
+                "Testcase of {} bytes does not fit in the {} bytes payload",
+                instructions.len(),
+                elf.get_payload_size()
+            )));
+        }
+        elf.update(&instructions);
+        fs::write(dir.join("testcase.elf"), elf.get_elf_data()?).map_err(io_err)?;
+
+        let listing = self.listing(&instructions, elf.get_payload_address());
+        fs::write(dir.join("testcase.S"), listing).map_err(io_err)?;
+
+        fs::write(dir.join("run.sh"), format!("#!/bin/bash\n{}\n", self.command_line)).map_err(io_err)?;
+
+        if let Some(coverage) = coverage {
+            let delta = CoverageDelta::between(&self.last_coverage, coverage);
+            let json = serde_json::to_string_pretty(&delta)
+                .map_err(|e| Error::serialize(format!("Unable to serialize coverage delta: {e}")))?;
+            fs::write(dir.join("coverage.json"), json).map_err(io_err)?;
+            self.last_coverage = coverage.to_vec();
+        }
+
+        Ok(())
+    }
+
+    /// Produce the assembly listing of the payload. Instructions that can't be disassembled
+    /// are emitted as data directives so that the listing can always be reassembled.
+    fn listing(&mut self, instructions: &RiscvInstructions, address: u64) -> String {
+        let mut listing = String::from(".section .text\n.global payload\npayload:\n");
+
+        let disassembled = self
+            .disassembler
+            .as_mut()
+            .and_then(|dasm| dasm.process_slice(instructions, address).ok())
+            .filter(|d| d.len() == instructions.count());
+
+        let mut pc = address;
+        for (index, ins) in instructions.iter().enumerate() {
+            let value = ins.be_value();
+            let (directive, size) = match ins {
+                RiscvInstruction::U16(_) => (format!(".half 0x{:04x}", value), 2),
+                RiscvInstruction::U32(_) => (format!(".word 0x{:08x}", value), 4),
+            };
+
+            match disassembled.as_ref().map(|d| &d[index].1) {
+                Some(d) => listing.push_str(&format!(
+                    "    {:<40} # {:x}: {} {}\n",
+                    directive,
+                    pc,
+                    d.mnemonic,
+                    d.args.as_deref().unwrap_or("")
+                )),
+                None => listing.push_str(&format!("    {:<40} # {:x}\n", directive, pc)),
+            }
+
+            pc += size;
+        }
+
+        listing
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use libafl::{
+        corpus::{Corpus, CorpusId, InMemoryCorpus, Testcase},
+        events::NopEventManager,
+        feedbacks::MapFeedbackMetadata,
+        inputs::BytesInput,
+        stages::Stage,
+        state::{HasCorpus, HasMetadata, HasSolutions, StdState},
+    };
+    use libafl_bolts::rands::StdRand;
+
+    use super::{CorpusExportStage, CoverageDelta, EXPORT_CRASHES_DIR, EXPORT_QUEUE_DIR};
+
+    /// A RISC-V ELF with a 0x20 bytes `.text` section holding the `payload` symbol
+    fn template() -> Vec<u8> {
+        let text = vec![0xaa; 0x20];
+        let mut symtab = vec![0u8; 24];
+        symtab.extend(1u32.to_le_bytes());
+        symtab.extend([0x10, 0]);
+        symtab.extend(1u16.to_le_bytes());
+        symtab.extend(0x8000_0000u64.to_le_bytes());
+        symtab.extend(0u64.to_le_bytes());
+        let strtab = b"\0payload\0".to_vec();
+        let shstrtab = b"\0.text\0.symtab\0.strtab\0.shstrtab\0".to_vec();
+
+        // name, type, flags, address, link, entsize, contents
+        let sections: [(u32, u32, u64, u64, u32, u64, &[u8]); 4] = [
+            (1, 1, 6, 0x8000_0000, 0, 0, &text),
+            (7, 2, 0, 0, 3, 24, &symtab),
+            (15, 3, 0, 0, 0, 0, &strtab),
+            (23, 3, 0, 0, 0, 0, &shstrtab),
+        ];
+        let mut body = vec![];
+        let mut headers = vec![0u8; 64];
+        for (name, kind, flags, addr, link, entsize, contents) in sections {
+            headers.extend(name.to_le_bytes());
+            headers.extend(kind.to_le_bytes());
+            headers.extend(flags.to_le_bytes());
+            headers.extend(addr.to_le_bytes());
+            headers.extend((64 + body.len() as u64).to_le_bytes());
+            headers.extend((contents.len() as u64).to_le_bytes());
+            headers.extend(link.to_le_bytes());
+            headers.extend(u32::from(link != 0).to_le_bytes());
+            headers.extend(8u64.to_le_bytes());
+            headers.extend(entsize.to_le_bytes());
+            body.extend(contents);
+            body.resize(body.len().next_multiple_of(8), 0);
+        }
+
+        let mut elf = vec![0x7f, b'E', b'L', b'F', 2, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        elf.extend(2u16.to_le_bytes());
+        elf.extend(243u16.to_le_bytes());
+        elf.extend(1u32.to_le_bytes());
+        elf.extend(0x8000_0000u64.to_le_bytes());
+        elf.extend(0u64.to_le_bytes());
+        elf.extend((64 + body.len() as u64).to_le_bytes());
+        elf.extend(0u32.to_le_bytes());
+        elf.extend([64u16, 56, 0, 64, 5, 4].iter().flat_map(|v| v.to_le_bytes()));
+        elf.extend(body);
+        elf.extend(headers);
+        elf
+    }
+
+    #[test]
+    fn export_corpus_and_solutions() {
+        let dir = std::env::temp_dir().join(format!("presifuzz_export_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let template_elf = dir.join("iram.elf");
+        fs::write(&template_elf, template()).unwrap();
+
+        let mut state = StdState::new(
+            StdRand::with_seed(0),
+            InMemoryCorpus::<BytesInput>::new(),
+            InMemoryCorpus::new(),
+            &mut (),
+            &mut (),
+        )
+        .unwrap();
+        let mut testcase = Testcase::new(BytesInput::new(vec![0x13, 0x00, 0x00, 0x00]));
+        testcase.add_metadata(MapFeedbackMetadata::<u32>::with_history_map(vec![0b11]));
+        state.corpus_mut().add(testcase).unwrap();
+        state.solutions_mut().add(Testcase::new(BytesInput::new(vec![0x01, 0x00]))).unwrap();
+
+        let export_dir = dir.join("export");
+        let mut stage = CorpusExportStage::new(export_dir.clone(), template_elf.to_str().unwrap(), "./simv").unwrap();
+        // the stage only uses the state
+        let mut mgr = NopEventManager::new();
+        stage
+            .perform(&mut NopEventManager::new(), &mut NopEventManager::new(), &mut state, &mut mgr, CorpusId::from(0usize))
+            .unwrap();
+
+        let queue = export_dir.join(EXPORT_QUEUE_DIR).join("id_000000");
+        let crashes = export_dir.join(EXPORT_CRASHES_DIR).join("id_000000");
+        for file in ["testcase.bin", "testcase.elf", "testcase.S", "run.sh"] {
+            assert!(queue.join(file).is_file(), "{file}");
+            assert!(crashes.join(file).is_file(), "{file}");
+        }
+        assert_eq!(fs::read(queue.join("testcase.bin")).unwrap(), vec![0x13, 0x00, 0x00, 0x00]);
+        assert_eq!(fs::read_to_string(queue.join("run.sh")).unwrap(), "#!/bin/bash\n./simv\n");
+        assert!(fs::read_to_string(crashes.join("testcase.S")).unwrap().contains(".half 0x0001"));
+
+        // the payload is patched in place, the rest of the template is kept
+        let elf = fs::read(queue.join("testcase.elf")).unwrap();
+        assert_eq!(elf.len(), template().len());
+        assert_eq!(&elf[64..70], &[0x13, 0x00, 0x00, 0x00, 0x02, 0x90]);
+
+        // only the entries with a coverage map get a coverage delta
+        let delta: CoverageDelta = serde_json::from_slice(&fs::read(queue.join("coverage.json")).unwrap()).unwrap();
+        assert_eq!(delta.new_indexes, vec![0, 1]);
+        assert!(!crashes.join("coverage.json").exists());
+
+        // entries are exported once
+        fs::remove_dir_all(&export_dir).unwrap();
+        stage
+            .perform(&mut NopEventManager::new(), &mut NopEventManager::new(), &mut state, &mut mgr, CorpusId::from(0usize))
+            .unwrap();
+        assert!(!export_dir.exists());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn coverage_delta() {
+        let delta = CoverageDelta::between(&[0b0101], &[0b0111, 0x8000_0000]);
+        assert_eq!(delta.total_bits, 4);
+        assert_eq!(delta.new_bits, 2);
+        assert_eq!(delta.new_indexes, vec![1, 63]);
+
+        assert_eq!(CoverageDelta::between(&[1, 2], &[1, 2]).new_bits, 0);
+    }
+}
@@ -0,0 +1,364 @@
+// SPDX-FileCopyrightText: 2024 Intel Corporation
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! The [`ImportFromDirStage`] imports raw inputs produced by other tools: AFL++ queues,
+//! riscv-dv generated programs (`.S`, `.o`, ELF) or hand-written seeds.
+
+use core::marker::PhantomData;
+use std::{
+    collections::HashSet,
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "introspection")]
+use crate::state::HasClientPerfMonitor;
+use libafl::{
+    corpus::CorpusId,
+    executors::{Executor, HasObservers},
+    fuzzer::Evaluator,
+    stages::Stage,
+    state::{HasMetadata, State, UsesState},
+    Error,
+};
+use libafl_bolts::ErrorBacktrace;
+
+use libpresifuzz_riscv::elf::ELF;
+
+use crate::sync::content_hash;
+
+/// Symbol looked up by default to extract the fuzzable code of an ELF file
+pub const DEFAULT_PAYLOAD_SYMBOL: &str = "payload";
+
+/// Size of the smallest payload worth importing, a compressed instruction
+const MIN_PAYLOAD_SIZE: usize = 2;
+
+/// Metadata keeping track of the foreign inputs already imported
+#[cfg_attr(
+    any(not(feature = "serdeany_autoreg"), miri),
+    allow(clippy::unsafe_derive_deserialize)
+)] // for SerdeAny
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct ImportFromDirMetadata {
+    /// Paths of the files already imported (or rejected)
+    pub imported_files: HashSet<String>,
+    /// Hashes of the imported payloads, used to skip duplicates
+    pub imported_hashes: HashSet<u64>,
+}
+
+libafl_bolts::impl_serdeany!(ImportFromDirMetadata);
+
+/// The kind of a foreign input file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ForeignInputKind {
+    /// Raw instruction bytes, e.g. an AFL++ queue entry or a seed
+    Raw,
+    /// An ELF file (executable or relocatable object), the payload is extracted from a symbol
+    Elf,
+    /// An assembly source, it has to be assembled before the payload can be extracted
+    Assembly,
+}
+
+impl ForeignInputKind {
+    /// Guess the kind of a foreign input from its extension and its content
+    #[must_use]
+    pub fn detect(path: &Path, bytes: &[u8]) -> Self {
+        if bytes.starts_with(b"\x7fELF") {
+            return ForeignInputKind::Elf;
+        }
+
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("S") | Some("s") | Some("asm") => ForeignInputKind::Assembly,
+            _ => ForeignInputKind::Raw,
+        }
+    }
+}
+
+/// A stage that imports raw inputs from foreign directories and evaluates them
+#[derive(Debug)]
+pub struct ImportFromDirStage<S>
+where
+    S: State,
+{
+    import_dirs: Vec<PathBuf>,
+    payload_symbols: Vec<String>,
+    assembler: Option<(String, Vec<String>)>,
+    max_size: Option<usize>,
+    imported_testcases: u32,
+    phantom: PhantomData<S>,
+}
+
+impl<S> UsesState for ImportFromDirStage<S>
+where
+    S: State,
+{
+    type State = S;
+}
+
+impl<E, EM, S, Z> Stage<E, EM, Z> for ImportFromDirStage<S>
+where
+    EM: UsesState<State = S>,
+    S: State + HasMetadata,
+    S::Input: From<Vec<u8>>,
+    E: HasObservers<State = S> + Executor<EM, Z>,
+    Z: Evaluator<E, EM, State = S>,
+{
+    #[inline]
+    fn perform(
+        &mut self,
+        fuzzer: &mut Z,
+        executor: &mut E,
+        state: &mut Z::State,
+        manager: &mut EM,
+        _corpus_idx: CorpusId,
+    ) -> Result<(), Error> {
+        if !state.has_metadata::<ImportFromDirMetadata>() {
+            state.add_metadata(ImportFromDirMetadata::default());
+        }
+
+        let candidates: Vec<PathBuf> = {
+            let meta = state.metadata::<ImportFromDirMetadata>()?;
+            self.list_files()
+                .into_iter()
+                .filter(|p| !meta.imported_files.contains(&p.to_string_lossy().to_string()))
+                .collect()
+        };
+
+        for path in candidates {
+            // Files that cannot be read, or are too short to hold an instruction, may still be
+            // being written: they are retried on the next run. Invalid files are not.
+            let payload = match self.extract_payload(&path) {
+                Ok(payload) if payload.len() >= MIN_PAYLOAD_SIZE => payload,
+                Ok(_) => continue,
+                Err(err @ Error::IllegalArgument(..)) => {
+                    println!("Unable to import {:?}: {}", path, err);
+                    let meta = state.metadata_mut::<ImportFromDirMetadata>()?;
+                    meta.imported_files.insert(path.to_string_lossy().to_string());
+                    continue;
+                }
+                Err(err) => {
+                    println!("Unable to import {:?}, retrying later: {}", path, err);
+                    continue;
+                }
+            };
+
+            let meta = state.metadata_mut::<ImportFromDirMetadata>()?;
+            meta.imported_files.insert(path.to_string_lossy().to_string());
+            if !meta.imported_hashes.insert(content_hash(&payload)) {
+                continue;
+            }
+
+            self.imported_testcases += 1;
+            println!("+ imported: {:?}; total: {};", path, self.imported_testcases);
+
+            fuzzer.evaluate_input(state, executor, manager, S::Input::from(payload))?;
+        }
+
+        #[cfg(feature = "introspection")]
+        state.introspection_monitor_mut().finish_stage();
+
+        Ok(())
+    }
+}
+
+impl<S> ImportFromDirStage<S>
+where
+    S: State,
+{
+    /// Creates a new [`ImportFromDirStage`] importing from the given directories
+    #[must_use]
+    pub fn new(import_dirs: Vec<PathBuf>) -> Self {
+        Self {
+            import_dirs,
+            payload_symbols: vec![DEFAULT_PAYLOAD_SYMBOL.to_string()],
+            assembler: None,
+            max_size: None,
+            imported_testcases: 0,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Symbols tried, in order, to locate the payload in ELF inputs
+    #[must_use]
+    pub fn with_payload_symbols(mut self, symbols: &[&str]) -> Self {
+        self.payload_symbols = symbols.iter().map(|s| s.to_string()).collect();
+        self
+    }
+
+    /// Command used to assemble `.S` inputs, e.g. `riscv64-unknown-elf-gcc` with
+    /// `["-march=rv64gc", "-mabi=lp64"]`. Without assembler, assembly inputs are ignored.
+    #[must_use]
+    pub fn with_assembler(mut self, program: &str, args: &[&str]) -> Self {
+        self.assembler = Some((program.to_string(), args.iter().map(|a| a.to_string()).collect()));
+        self
+    }
+
+    /// Truncate imported payloads to `max_size` bytes
+    #[must_use]
+    pub fn with_max_size(mut self, max_size: usize) -> Self {
+        self.max_size = Some(max_size);
+        self
+    }
+
+    /// List the candidate files of every import dir. AFL++ output directories are
+    /// recognized, only their `queue/` folders are imported.
+    fn list_files(&self) -> Vec<PathBuf> {
+        let mut files = vec![];
+
+        for dir in &self.import_dirs {
+            for queue in Self::queue_dirs(dir) {
+                let Ok(it) = fs::read_dir(&queue) else {
+                    continue;
+                };
+
+                let mut entries: Vec<PathBuf> = it
+                    .flatten()
+                    .map(|e| e.path())
+                    .filter(|p| p.is_file())
+                    .filter(|p| {
+                        p.file_name()
+                            .and_then(|f| f.to_str())
+                            .is_some_and(|f| !f.starts_with('.'))
+                    })
+                    .collect();
+                entries.sort();
+                files.extend(entries);
+            }
+        }
+
+        files
+    }
+
+    /// Returns the directories holding the inputs of `dir`: `dir/queue` for an AFL++
+    /// instance, `dir/*/queue` for an AFL++ output dir, `dir` otherwise
+    fn queue_dirs(dir: &Path) -> Vec<PathBuf> {
+        if dir.join("queue").is_dir() {
+            return vec![dir.join("queue")];
+        }
+
+        let mut instances: Vec<PathBuf> = fs::read_dir(dir)
+            .map(|it| {
+                it.flatten()
+                    .map(|e| e.path().join("queue"))
+                    .filter(|q| q.is_dir())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        if instances.is_empty() {
+            vec![dir.to_path_buf()]
+        } else {
+            instances.sort();
+            instances
+        }
+    }
+
+    /// Read a foreign input and returns the instruction bytes to fuzz
+    pub fn extract_payload(&self, path: &Path) -> Result<Vec<u8>, Error> {
+        let bytes = fs::read(path)
+            .map_err(|e| Error::Unknown(format!("Could not read {:?}: {}", path, e), ErrorBacktrace::new()))?;
+
+        let mut payload = match ForeignInputKind::detect(path, &bytes) {
+            ForeignInputKind::Raw => bytes,
+            ForeignInputKind::Elf => self.extract_elf_payload(&bytes)?,
+            ForeignInputKind::Assembly => {
+                let object = self.assemble(path)?;
+                self.extract_elf_payload(&object)?
+            }
+        };
+
+        if let Some(max_size) = self.max_size {
+            payload.truncate(max_size);
+        }
+
+        Ok(payload)
+    }
+
+    fn extract_elf_payload(&self, elf: &Vec<u8>) -> Result<Vec<u8>, Error> {
+        for symbol in &self.payload_symbols {
+            if let Ok((_address, offset, size)) = ELF::find_symbol_bytes(elf, symbol) {
+                let start = offset as usize;
+                let end = start.saturating_add(size as usize);
+                return elf
+                    .get(start..end)
+                    .map(|s| s.to_vec())
+                    .ok_or(Error::illegal_argument(format!("Symbol <{}> is out of the file", symbol)));
+            }
+        }
+
+        Err(Error::illegal_argument(format!(
+            "None of the symbols {:?} was found",
+            self.payload_symbols
+        )))
+    }
+
+    fn assemble(&self, path: &Path) -> Result<Vec<u8>, Error> {
+        let Some((program, args)) = &self.assembler else {
+            return Err(Error::illegal_argument("No assembler configured for assembly inputs"));
+        };
+
+        let object = std::env::temp_dir().join(format!("presifuzz_import_{}.o", std::process::id()));
+
+        let status = Command::new(program)
+            .args(args)
+            .arg("-c")
+            .arg("-o")
+            .arg(&object)
+            .arg(path)
+            .status()
+            .map_err(|e| Error::Unknown(format!("Unable to run {}: {}", program, e), ErrorBacktrace::new()))?;
+
+        if !status.success() {
+            return Err(Error::Unknown(format!("{} failed on {:?}", program, path), ErrorBacktrace::new()));
+        }
+
+        let res = fs::read(&object)
+            .map_err(|e| Error::Unknown(format!("Could not read {:?}: {}", object, e), ErrorBacktrace::new()));
+        let _ = fs::remove_file(&object);
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf};
+
+    use libafl::{inputs::BytesInput, state::NopState};
+
+    use super::{ForeignInputKind, ImportFromDirStage};
+
+    #[test]
+    fn import_afl_queue() {
+        let out = std::env::temp_dir().join(format!("presifuzz_afl_out_{}", std::process::id()));
+        let queue = out.join("default").join("queue");
+        fs::create_dir_all(queue.join(".state")).unwrap();
+        fs::write(out.join("default").join("fuzzer_stats"), b"execs_done: 1").unwrap();
+        fs::write(queue.join("id:000000,orig:seed"), [0x13, 0x00, 0x00, 0x00, 0x01, 0x00]).unwrap();
+        fs::write(queue.join("nop.S"), b"nop").unwrap();
+
+        let stage = ImportFromDirStage::<NopState<BytesInput>>::new(vec![out.clone()]).with_max_size(4);
+        let files = stage.list_files();
+        assert_eq!(files, vec![queue.join("id:000000,orig:seed"), queue.join("nop.S")]);
+
+        assert_eq!(stage.extract_payload(&files[0]).unwrap(), vec![0x13, 0x00, 0x00, 0x00]);
+        // no assembler configured
+        assert!(stage.extract_payload(&files[1]).is_err());
+
+        fs::remove_dir_all(out).unwrap();
+    }
+
+    #[test]
+    fn detect_input_kind() {
+        let path = PathBuf::from("test.o");
+        assert_eq!(ForeignInputKind::detect(&path, b"\x7fELF\x02\x01"), ForeignInputKind::Elf);
+        assert_eq!(ForeignInputKind::detect(&PathBuf::from("test.S"), b"nop"), ForeignInputKind::Assembly);
+        assert_eq!(ForeignInputKind::detect(&PathBuf::from("id:000001"), b"\x13\x00"), ForeignInputKind::Raw);
+
+        // a corrupted ELF is reported, not a panic
+        let stage = ImportFromDirStage::<NopState<BytesInput>>::new(vec![]);
+        assert!(stage.extract_elf_payload(&b"\x7fELF\x02\x01".to_vec()).is_err());
+    }
+}
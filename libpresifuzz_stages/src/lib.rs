@@ -3,4 +3,6 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod sync;
-
+pub mod import;
+pub mod export;
+pub mod mutation_stats;
@@ -0,0 +1,204 @@
+// SPDX-FileCopyrightText: 2024 Intel Corporation
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! The [`MutationStatsStage`] reports how often each scheduled mutation found new coverage,
+//! as recorded in the [`ISAMutationStatsMetadata`] by the `StdISAScheduledMutator`.
+
+use core::{marker::PhantomData, time::Duration};
+
+use libafl::{
+    corpus::CorpusId,
+    events::{Event, EventFirer},
+    monitors::{AggregatorOps, UserStats, UserStatsValue},
+    stages::Stage,
+    state::{HasMetadata, State, UsesState},
+    Error,
+};
+use libafl_bolts::current_time;
+use libpresifuzz_mutators::scheduled::ISAMutationStatsMetadata;
+
+/// Default delay between two reports
+pub const DEFAULT_REPORT_INTERVAL: Duration = Duration::from_secs(15);
+
+/// A stage firing a `mutation_<name>` user stat, the finds over the uses of the mutation,
+/// for every scheduled mutation
+#[derive(Debug)]
+pub struct MutationStatsStage<S> {
+    interval: Duration,
+    last_report: Duration,
+    phantom: PhantomData<S>,
+}
+
+impl<S> UsesState for MutationStatsStage<S>
+where
+    S: State,
+{
+    type State = S;
+}
+
+impl<E, EM, S, Z> Stage<E, EM, Z> for MutationStatsStage<S>
+where
+    E: UsesState<State = S>,
+    EM: EventFirer<State = S>,
+    Z: UsesState<State = S>,
+    S: State + HasMetadata,
+{
+    #[inline]
+    fn perform(
+        &mut self,
+        _fuzzer: &mut Z,
+        _executor: &mut E,
+        state: &mut Z::State,
+        manager: &mut EM,
+        _corpus_idx: CorpusId,
+    ) -> Result<(), Error> {
+        let now = current_time();
+        if now.saturating_sub(self.last_report) < self.interval {
+            return Ok(());
+        }
+        self.last_report = now;
+
+        let Ok(metadata) = state.metadata::<ISAMutationStatsMetadata>() else {
+            return Ok(());
+        };
+        let stats: Vec<(String, u64, u64)> = metadata
+            .mutations
+            .iter()
+            .map(|(name, stats)| (name.clone(), stats.finds, stats.uses))
+            .collect();
+
+        for (name, finds, uses) in stats {
+            manager.fire(
+                state,
+                Event::UpdateUserStats {
+                    name: format!("mutation_{name}"),
+                    value: UserStats::new(UserStatsValue::Ratio(finds, uses), AggregatorOps::Sum),
+                    phantom: PhantomData,
+                },
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<S> Default for MutationStatsStage<S> {
+    fn default() -> Self {
+        Self::new(DEFAULT_REPORT_INTERVAL)
+    }
+}
+
+impl<S> MutationStatsStage<S> {
+    /// Creates a new [`MutationStatsStage`] reporting at most every `interval`
+    #[must_use]
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            last_report: Duration::ZERO,
+            phantom: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::{marker::PhantomData, time::Duration};
+
+    use libafl::{
+        corpus::{CorpusId, InMemoryCorpus},
+        events::{Event, EventFirer, NopEventManager},
+        inputs::{BytesInput, UsesInput},
+        monitors::UserStatsValue,
+        stages::Stage,
+        state::{HasMetadata, State, StdState, UsesState},
+        Error,
+    };
+    use libafl_bolts::rands::StdRand;
+    use libpresifuzz_mutators::scheduled::{ISAMutationStatsMetadata, MutationStats};
+
+    use super::MutationStatsStage;
+
+    type TestState = StdState<BytesInput, InMemoryCorpus<BytesInput>, StdRand, InMemoryCorpus<BytesInput>>;
+
+    /// Records the user stats fired by the stage
+    struct RecordingEventManager<S> {
+        ratios: Vec<(String, u64, u64)>,
+        phantom: PhantomData<S>,
+    }
+
+    impl<S> UsesState for RecordingEventManager<S>
+    where
+        S: State,
+    {
+        type State = S;
+    }
+
+    impl<S> EventFirer for RecordingEventManager<S>
+    where
+        S: State,
+    {
+        fn fire(&mut self, _state: &mut S, event: Event<<S as UsesInput>::Input>) -> Result<(), Error> {
+            if let Event::UpdateUserStats { name, value, .. } = event {
+                let UserStatsValue::Ratio(finds, uses) = value.value() else {
+                    panic!("{name} is not a ratio");
+                };
+                self.ratios.push((name, *finds, *uses));
+            }
+            Ok(())
+        }
+    }
+
+    fn perform(stage: &mut MutationStatsStage<TestState>, state: &mut TestState, mgr: &mut RecordingEventManager<TestState>) {
+        let mut fuzzer = NopEventManager::new();
+        let mut executor = NopEventManager::new();
+        stage.perform(&mut fuzzer, &mut executor, state, mgr, CorpusId::from(0_usize)).unwrap();
+    }
+
+    #[test]
+    fn report_mutation_ratios() {
+        let mut state: TestState = StdState::new(
+            StdRand::with_seed(0),
+            InMemoryCorpus::new(),
+            InMemoryCorpus::new(),
+            &mut (),
+            &mut (),
+        )
+        .unwrap();
+        let mut mgr = RecordingEventManager {
+            ratios: Vec::new(),
+            phantom: PhantomData,
+        };
+
+        // nothing to report before the mutator recorded its stats
+        let mut stage = MutationStatsStage::new(Duration::ZERO);
+        perform(&mut stage, &mut state, &mut mgr);
+        assert!(mgr.ratios.is_empty());
+
+        let mut metadata = ISAMutationStatsMetadata::default();
+        metadata
+            .mutations
+            .insert("OperandMutator".to_string(), MutationStats { uses: 10, finds: 3 });
+        metadata
+            .mutations
+            .insert("InstDeleteMutator".to_string(), MutationStats { uses: 4, finds: 0 });
+        state.add_metadata(metadata);
+
+        perform(&mut stage, &mut state, &mut mgr);
+        assert_eq!(
+            mgr.ratios,
+            [
+                ("mutation_InstDeleteMutator".to_string(), 0, 4),
+                ("mutation_OperandMutator".to_string(), 3, 10),
+            ]
+        );
+
+        // the next reports wait for the interval
+        mgr.ratios.clear();
+        let mut stage = MutationStatsStage::new(Duration::from_secs(3600));
+        perform(&mut stage, &mut state, &mut mgr);
+        assert_eq!(mgr.ratios.len(), 2);
+        perform(&mut stage, &mut state, &mut mgr);
+        assert_eq!(mgr.ratios.len(), 2);
+    }
+}
@@ -4,19 +4,24 @@
 
 //! The [`SyncFromDiskStage`] is a stage that imports inputs from disk for e.g. sync with AFL
 
-use core::marker::PhantomData;
+use core::{
+    hash::{BuildHasher, Hasher},
+    marker::PhantomData,
+};
 use std::{
+    collections::HashSet,
     fs,
-    path::{PathBuf},
+    path::{Path, PathBuf},
     time::SystemTime,
 };
+use ahash::RandomState;
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "introspection")]
 use crate::state::HasClientPerfMonitor;
 use libafl::{
     corpus::{CorpusId, HasTestcase},
-    events::{Event, EventFirer},
+    events::{Event, EventConfig, EventFirer},
     executors::{Executor, HasObservers},
     fuzzer::{EvaluatorObservers, ExecutionProcessor},
     inputs::{Input, UsesInput},
@@ -25,11 +30,15 @@ use libafl::{
     Error,
 };
 
-use std::fs::File;
-use std::io::{Read};
-
+use libpresifuzz_ec::{
+    compaction::{SyncDirReader, SyncEntry},
+    manager::deserialize_event,
+};
 use libpresifuzz_feedbacks::transferred::TransferringMetadata;
 
+/// Name of the directory, inside the sync dir, where corrupted event files are moved to
+pub const QUARANTINE_DIR_NAME: &str = "quarantine";
+
 /// Metadata used to store information about disk sync time
 #[cfg_attr(
     any(not(feature = "serdeany_autoreg"), miri),
@@ -39,6 +48,12 @@ use libpresifuzz_feedbacks::transferred::TransferringMetadata;
 pub struct SyncFromDiskMetadata {
     /// The last time the sync was done
     pub last_time: SystemTime,
+    /// Names of the files of the sync dir that were already imported (or rejected)
+    #[serde(default)]
+    pub imported_files: HashSet<String>,
+    /// Content hashes of the imported inputs, used to skip duplicated testcases
+    #[serde(default)]
+    pub imported_hashes: HashSet<u64>,
 }
 
 libafl_bolts::impl_serdeany!(SyncFromDiskMetadata);
@@ -47,10 +62,28 @@ impl SyncFromDiskMetadata {
     /// Create a new [`struct@SyncFromDiskMetadata`]
     #[must_use]
     pub fn new(last_time: SystemTime) -> Self {
-        Self { last_time: last_time}
+        Self {
+            last_time,
+            imported_files: HashSet::new(),
+            imported_hashes: HashSet::new(),
+        }
+    }
+
+    /// Returns `true` if the given file was already imported
+    #[must_use]
+    pub fn is_imported(&self, filename: &str) -> bool {
+        self.imported_files.contains(filename)
     }
 }
 
+/// Hash serialized content, e.g. an imported input, the hash is stable across runs
+#[must_use]
+pub fn content_hash(bytes: &[u8]) -> u64 {
+    let mut hasher = RandomState::with_seeds(0, 0, 0, 0).build_hasher();
+    hasher.write(bytes);
+    hasher.finish()
+}
+
 /// A stage that loads testcases from disk to sync with other fuzzers such as AFL++
 #[derive(Debug)]
 pub struct SyncFromDiskStage<S, DI>
@@ -59,7 +92,11 @@ where
     DI: Input,
 {
     sync_dir: PathBuf,
+    quarantine_dir: PathBuf,
+    reexecute: bool,
+    configuration: Option<EventConfig>,
     loaded_testcases: u32,
+    reader: SyncDirReader,
     phantom: PhantomData<(S, DI)>,
 }
 
@@ -84,190 +121,98 @@ where
     fn perform(
         &mut self,
         fuzzer: &mut Z,
-        _executor: &mut E,
+        executor: &mut E,
         state: &mut Z::State,
         manager: &mut EM,
         _corpus_idx: CorpusId,
     ) -> Result<(), Error> {
-        let last = state
-            .metadata_map()
-            .get::<SyncFromDiskMetadata>()
-            .map(|m| m.last_time);
-
-        let in_dir = self.sync_dir.clone();
-        if let Some(mut max_time) = {
-            let mut max_time = None;
-
-            let it = fs::read_dir(in_dir);
-            if it.is_err() {
-                println!("enable to read sync_dir {:?}. Maybe it does not exist?", self.sync_dir);
-                return Ok(());
-            }
-
-            for entry in it.unwrap() {
-                let entry = entry.expect("Unable to read files in sync_dir");
-                let path = entry.path();
-                let attributes = fs::metadata(&path);
-
-                if attributes.is_err() {
-                    continue;
-                }
-
-                let attr = attributes.expect("Unable to read files attributes in sync_dir");
-
-                if attr.is_file() && attr.len() > 0 {
-
-                    if path.file_name().is_some() {
-                        
-                        let filename = path.file_name().unwrap();
+        if !state.has_metadata::<SyncFromDiskMetadata>() {
+            state.add_metadata(SyncFromDiskMetadata::new(SystemTime::now()));
+        }
 
-                        if filename.to_str().is_some() {
-                            let filename = filename.to_str().unwrap();
+        let candidates = {
+            let meta = state.metadata::<SyncFromDiskMetadata>()?;
+            self.pending_events(meta)
+        };
 
-                            if filename.contains(".stats") {
-                                continue;
-                            }
-                        }
-                    }
+        for SyncEntry { name: filename, data: bytes, .. } in candidates {
+            let path = self.sync_dir.join(&filename);
 
-                    if let Ok(time) = attr.modified() {
-                            
-                        // Read the file back and deserialize the Event struct
-                        let path_str = path.to_str().expect("Failed to convert path to string");
-                        let mut file = File::open(path_str).expect("Failed to open file");
+            state
+                .metadata_mut::<SyncFromDiskMetadata>()?
+                .imported_files
+                .insert(filename);
 
-                        if let Some(l) = last {
-                            let elapsed = time.duration_since(l);
+            let event: Event<DI> = match deserialize_event(&bytes) {
+                Ok((event, _)) => event,
+                Err(err) => {
+                    println!("Quarantining corrupted testcase {:?}: {err}", path);
+                    self.quarantine(&path);
+                    continue;
+                }
+            };
+
+            if let Event::NewTestcase {
+                input,
+                exit_kind,
+                observers_buf,
+                client_config,
+                ..
+            } = event
+            {
+                // The same input can be sent by several clients, with another encoding or
+                // at another time, only the input itself is compared
+                let hash = content_hash(&postcard::to_allocvec(&input)?);
+                if !state.metadata_mut::<SyncFromDiskMetadata>()?.imported_hashes.insert(hash) {
+                    continue;
+                }
 
-                            if elapsed.is_err() {
-                                continue;
+                // Remote observers are only trusted if the sender runs the same setup as we do,
+                // otherwise the input is executed again locally
+                let observers: Option<E::Observers> = match observers_buf {
+                    Some(observers_buf) if self.trusts(&client_config) => {
+                        match postcard::from_bytes(&observers_buf) {
+                            Ok(observers) => Some(observers),
+                            Err(err) => {
+                                println!("Unable to deserialize observers of {:?}, re-executing: {err}", path);
+                                None
                             }
-                      
-                            let elapsed = elapsed.unwrap(); 
-                            if elapsed.as_nanos() == 0 {
-                                continue;
-                            } 
-
-                            println!("+ testcase: {}; total: {}; elapsed: {}s {}ms {}ns", path_str, self.loaded_testcases, elapsed.as_secs(), elapsed.as_millis(), elapsed.as_nanos());
-                        
-                            max_time = Some(max_time.map_or(time, |t: SystemTime| t.max(time)));
-                       } else {
-                            
-                            println!("+ reloading testcase: {}; total: {};", path_str, self.loaded_testcases);
-                       }
-                                    
-                        self.loaded_testcases += 1; 
-
-                        // Read the file back and deserialize the Event struct
-                        #[cfg(not(feature = "serialize_bytes"))]
-                        {
-                            let mut serialized_event = String::new();
-                            file.read_to_string(&mut serialized_event).expect("Failed to read file");
-
-                            // Deserialize the JSON string back into an Event struct
-                            let event = serde_json::from_str(&serialized_event);
-                            if !event.is_ok() {
-                                println!("Skipping testcase loading as it might be corrupted");
-                                continue; 
-                            }
-                            let event: Event<DI> = event.unwrap();
-                            
-                            match &event {
-                                Event::NewTestcase {
-                                    // input: DI,
-                                    input,
-                                    client_config: _,
-                                    exit_kind,
-                                    corpus_size: _,
-                                    observers_buf,
-                                    time: _,
-                                    executions: _,
-                                    forward_id: _,
-                                } => {
-
-                                    let observers: E::Observers =
-                                        postcard::from_bytes(observers_buf.as_ref().unwrap()).expect("Unable to deserialize new testcase from sync_dir");
-
-                                    if let Ok(meta) = state.metadata_mut::<TransferringMetadata>() {
-                                        meta.set_transferring(true);
-                                    }
-
-                                    fuzzer.process_execution(state, manager, input.clone(), &observers, &exit_kind, false)?;
-
-                                    if let Ok(meta) = state.metadata_mut::<TransferringMetadata>() {
-                                        meta.set_transferring(false);
-                                    }
-                                }
-                                _ => {},
-                            };
-                        }
-                        
-                        #[cfg(feature = "serialize_bytes")]
-                        {
-                            let mut serialized_event = Vec::new();
-                            file.read_to_end(&mut serialized_event).expect("Failed to read file");
-
-                            // Deserialize the binary data back into an Event struct
-                            let event: Event<DI> = bincode::deserialize(&serialized_event).expect("Failed to deserialize event");
-                            match &event {
-                                Event::NewTestcase {
-                                    // input: DI,
-                                    input,
-                                    client_config: _,
-                                    exit_kind,
-                                    corpus_size,
-                                    observers_buf,
-                                    time,
-                                    executions,
-                                    forward_id,
-                                } => {
-                                   let observers: E::Observers =
-                                        postcard::from_bytes(observers_buf.as_ref().unwrap()).expect("Unable to deserialize new testcase from sync_dir");
-                                    
-                                   println!("+ testcase: {}; total: {}", path_str, self.loaded_testcases);
-
-                                    self.loaded_testcases += 1; 
-
-                                    fuzzer.process_execution(state, manager, input.clone(), &observers, &exit_kind, false)?;
-                                }
-                                _ => return Err(Error::unknown(format!(
-                                    "Received illegal message that message should not have arrived: {:?}.",
-                                    event.name()
-                                ))),
-                            };
                         }
                     }
-                } 
-            }
+                    _ => None,
+                };
 
-            Some(max_time)
-        }
+                self.loaded_testcases += 1;
+                println!("+ testcase: {:?}; total: {};", path, self.loaded_testcases);
 
-        {
+                if let Ok(meta) = state.metadata_mut::<TransferringMetadata>() {
+                    meta.set_transferring(true);
+                }
 
-            if max_time.is_none() {
-                max_time = Some(SystemTime::now());
-            }
+                let res = match observers {
+                    Some(observers) => fuzzer
+                        .process_execution(state, manager, input, &observers, &exit_kind, false)
+                        .map(|_| ()),
+                    None => fuzzer
+                        .evaluate_input_with_observers::<E, EM>(state, executor, manager, input, false)
+                        .map(|_| ()),
+                };
+
+                if let Ok(meta) = state.metadata_mut::<TransferringMetadata>() {
+                    meta.set_transferring(false);
+                }
 
-            if last.is_none() {
-                state
-                    .metadata_map_mut()
-                    .insert(SyncFromDiskMetadata::new(max_time.unwrap()));
-            } else {
-                state
-                    .metadata_map_mut()
-                    .get_mut::<SyncFromDiskMetadata>()
-                    .unwrap()
-                    .last_time = max_time.unwrap();
+                res?;
             }
         }
 
+        state.metadata_mut::<SyncFromDiskMetadata>()?.last_time = SystemTime::now();
+
         #[cfg(feature = "introspection")]
         state.introspection_monitor_mut().finish_stage();
 
         Ok(())
     }
-
 }
 
 impl<S, DI> SyncFromDiskStage<S, DI>
@@ -278,10 +223,162 @@ where
     /// Creates a new [`SyncFromDiskStage`]
     #[must_use]
     pub fn new(sync_dir: PathBuf) -> Self {
+        let quarantine_dir = sync_dir.join(QUARANTINE_DIR_NAME);
+        Self::with_quarantine_dir(sync_dir, quarantine_dir)
+    }
+
+    /// Creates a new [`SyncFromDiskStage`] moving corrupted files to `quarantine_dir`
+    #[must_use]
+    pub fn with_quarantine_dir(sync_dir: PathBuf, quarantine_dir: PathBuf) -> Self {
         Self {
-            sync_dir: sync_dir,
+            reader: SyncDirReader::new(&sync_dir.to_string_lossy()),
+            sync_dir,
+            quarantine_dir,
+            reexecute: false,
+            configuration: None,
             loaded_testcases: 0,
             phantom: PhantomData,
         }
     }
+
+    /// Always run imported inputs through the local executor instead of using the
+    /// observers serialized by the remote client
+    #[must_use]
+    pub fn with_reexecution(mut self, reexecute: bool) -> Self {
+        self.reexecute = reexecute;
+        self
+    }
+
+    /// Set the local [`EventConfig`]. Testcases coming from clients with a different
+    /// configuration (e.g. other map sizes or metrics) are re-executed locally.
+    #[must_use]
+    pub fn with_configuration(mut self, configuration: EventConfig) -> Self {
+        self.configuration = Some(configuration);
+        self
+    }
+
+    /// Returns `true` if the observers sent by a client with `client_config` can be used as is
+    fn trusts(&self, client_config: &EventConfig) -> bool {
+        if self.reexecute {
+            return false;
+        }
+
+        match &self.configuration {
+            Some(configuration) => configuration.match_with(client_config),
+            None => true,
+        }
+    }
+
+    /// The events of the sync dir that were not imported yet, whether still in raw event
+    /// files or already compacted into segments, ordered by client and count. Unreadable and
+    /// empty files are retried on the next sync.
+    fn pending_events(&mut self, meta: &SyncFromDiskMetadata) -> Vec<SyncEntry> {
+        if !self.sync_dir.is_dir() {
+            println!("Unable to read sync_dir {:?}. Maybe it does not exist?", self.sync_dir);
+            return Vec::new();
+        }
+
+        self.reader
+            .pending_events(|filename, _| meta.is_imported(filename))
+            .into_iter()
+            .filter(|entry| !entry.data.is_empty())
+            .collect()
+    }
+
+    /// Move a corrupted file out of the sync dir so that nobody tries to load it again. Events
+    /// read from a segment are only marked as imported.
+    fn quarantine(&self, path: &Path) {
+        let Some(filename) = path.file_name() else {
+            return;
+        };
+        if !path.is_file() {
+            return;
+        }
+
+        let res = fs::create_dir_all(&self.quarantine_dir)
+            .and_then(|()| fs::rename(path, self.quarantine_dir.join(filename)));
+
+        if let Err(err) = res {
+            println!("Unable to quarantine {:?}: {err}", path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+    use std::{fs, time::SystemTime};
+
+    use libafl::{
+        events::{Event, EventConfig},
+        executors::ExitKind,
+        inputs::BytesInput,
+        state::NopState,
+    };
+
+    use libpresifuzz_ec::compaction::SyncDirCompactor;
+
+    use super::{content_hash, deserialize_event, SyncFromDiskMetadata, SyncFromDiskStage};
+
+    fn new_testcase() -> Event<BytesInput> {
+        Event::NewTestcase {
+            input: BytesInput::new(vec![0x13, 0x00, 0x00, 0x00]),
+            observers_buf: Some(vec![1, 2, 3]),
+            exit_kind: ExitKind::Ok,
+            corpus_size: 1,
+            client_config: EventConfig::from_name("default"),
+            time: Duration::from_secs(1),
+            executions: 42,
+            forward_id: None,
+        }
+    }
+
+    #[test]
+    fn stable_content_hash() {
+        let json = serde_json::to_vec(&new_testcase()).unwrap();
+        assert_eq!(content_hash(&json), content_hash(&json.clone()));
+        assert_ne!(content_hash(&json), content_hash(&json[1..]));
+
+        // the same input sent with another encoding has the same hash
+        let bin = bincode::serialize(&new_testcase()).unwrap();
+        let hashes: Vec<u64> = [json, bin]
+            .iter()
+            .map(|bytes| match deserialize_event::<BytesInput>(bytes).unwrap().0 {
+                Event::NewTestcase { input, .. } => content_hash(&postcard::to_allocvec(&input).unwrap()),
+                _ => unreachable!(),
+            })
+            .collect();
+        assert_eq!(hashes[0], hashes[1]);
+    }
+
+    #[test]
+    fn sync_compacted_events() {
+        let dir = std::env::temp_dir().join(format!("presifuzz_sync_compacted_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        let json = serde_json::to_vec(&new_testcase()).unwrap();
+        for count in 0..3 {
+            fs::write(dir.join(format!("client_1_{}.event", count)), &json).unwrap();
+        }
+        let report = SyncDirCompactor::new(dir.to_str().unwrap())
+            .with_min_age(Duration::ZERO)
+            .with_min_events(1)
+            .compact(|_| true)
+            .unwrap();
+        assert_eq!(report.compacted.len(), 3);
+        fs::write(dir.join("client_2_0.event"), &json).unwrap();
+        fs::write(dir.join("client_2.event.stats"), b"{}").unwrap();
+
+        let mut meta = SyncFromDiskMetadata::new(SystemTime::now());
+        meta.imported_files.insert("client_1_1.event".to_string());
+
+        let mut stage = SyncFromDiskStage::<NopState<BytesInput>, BytesInput>::new(dir.clone());
+        let events = stage.pending_events(&meta);
+        let names: Vec<&str> = events.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["client_1_0.event", "client_1_2.event", "client_2_0.event"]);
+        assert!(events.iter().all(|e| deserialize_event::<BytesInput>(&e.data).is_ok()));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
 }